@@ -247,6 +247,39 @@ pub struct ComponentReleaseConfig {
     /// Per-ZIP source-to-archive coverage declarations for transformed packages.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub package_coverage: Vec<PackageCoverageConfig>,
+    /// Options for the release `git.commit` step.
+    #[serde(
+        default,
+        skip_serializing_if = "ComponentReleaseCommitConfig::is_default",
+        alias = "gitCommit"
+    )]
+    pub git_commit: ComponentReleaseCommitConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct ComponentReleaseCommitConfig {
+    /// Append a one-line summary of the release notes (e.g. `2 added, 1 fixed`)
+    /// to the commit body. The subject is unchanged.
+    #[serde(
+        default,
+        skip_serializing_if = "is_false",
+        alias = "includeNotesSummary"
+    )]
+    pub include_notes_summary: bool,
+    /// Subject template for the release commit. Supports `{version}`, `{tag}`,
+    /// and `{date}`; defaults to `release: v{version}`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "messageTemplate"
+    )]
+    pub message_template: Option<String>,
+}
+
+impl ComponentReleaseCommitConfig {
+    pub fn is_default(value: &Self) -> bool {
+        value == &Self::default()
+    }
 }
 
 impl ComponentReleaseConfig {
@@ -17,9 +17,9 @@ pub mod model;
 
 pub use config::{
     ArtifactInput, CleanupArtifactDeclaration, CommandScopeConfig, ComponentDeployConfig,
-    ComponentGithubReleaseConfig, ComponentOverrideConfig, ComponentReleaseConfig,
-    ComponentScriptsConfig, DependencyStackEdge, GitDeployConfig, GithubConfig, GithubHostConfig,
-    GithubReleaseOwner, PackageCoverageArtifactMatch, PackageCoverageConfig, ScopeConfig,
-    ScopedExtensionConfig, VersionTarget,
+    ComponentGithubReleaseConfig, ComponentOverrideConfig, ComponentReleaseCommitConfig,
+    ComponentReleaseConfig, ComponentScriptsConfig, DependencyStackEdge, GitDeployConfig,
+    GithubConfig, GithubHostConfig, GithubReleaseOwner, PackageCoverageArtifactMatch,
+    PackageCoverageConfig, ScopeConfig, ScopedExtensionConfig, VersionTarget,
 };
 pub use model::{render_remote_path_template, Component, ComponentLifecycle};
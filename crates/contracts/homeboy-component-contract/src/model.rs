@@ -1,5 +1,6 @@
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::{BTreeMap, HashMap};
+use std::path::{self, Path, PathBuf};
 
 use crate::config::{
     is_default_github_config, ArtifactInput, CleanupArtifactDeclaration, ComponentDeployConfig,
@@ -53,6 +54,10 @@ impl ComponentLifecycle {
     }
 }
 
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
 fn is_active_lifecycle(lifecycle: &ComponentLifecycle) -> bool {
     lifecycle.is_active()
 }
@@ -73,6 +78,17 @@ pub struct Component {
     pub bundled_into: Option<String>,
     pub build_artifact: Option<String>,
     pub build_command: Option<String>,
+    /// Subdirectory of `local_path` the build runs in. Relative
+    /// `build_artifact` paths resolve from here when set.
+    pub build_dir: Option<String>,
+    /// Component IDs that must build and deploy before this one when they are
+    /// part of the same deploy.
+    pub depends_on: Vec<String>,
+    /// Deploy this component alone, after every non-exclusive component in
+    /// the same deploy (e.g. one that runs database migrations).
+    pub deploy_exclusive: bool,
+    /// Relative position within its deploy tier; lower values deploy first.
+    pub deploy_priority: Option<i32>,
     pub extensions: Option<HashMap<String, ScopedExtensionConfig>>,
     /// Explicit extension ownership by capability label.
     ///
@@ -88,12 +104,21 @@ pub struct Component {
     /// Events: `pre:version:bump`, `post:version:bump`, `post:release`, `post:deploy`
     pub hooks: HashMap<String, Vec<String>>,
     pub extract_command: Option<String>,
+    /// Local command run in `local_path` after the build and before upload.
+    /// Distinct from the build; a non-zero exit aborts the component deploy.
+    pub pre_deploy_command: Option<String>,
     pub remote_owner: Option<String>,
     pub deploy_strategy: Option<String>,
     pub git_deploy: Option<GitDeployConfig>,
     /// Git remote URL for the component's source repository (e.g., GitHub URL).
     /// Used by deploy to download release artifacts or initialize server-side git repos.
     pub remote_url: Option<String>,
+    /// Author name for commits Homeboy makes in this component (passed as
+    /// `git -c user.name=...`). Falls back to the ambient git config when unset.
+    pub git_author_name: Option<String>,
+    /// Author email for commits Homeboy makes in this component (passed as
+    /// `git -c user.email=...`). Falls back to the ambient git config when unset.
+    pub git_author_email: Option<String>,
     /// Host-scoped GitHub CLI/API environment used by release automation.
     #[serde(default, skip_serializing_if = "is_default_github_config")]
     pub github: GithubConfig,
@@ -186,6 +211,22 @@ struct RawComponent {
     build_artifact: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     build_command: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "deserialize_empty_as_none"
+    )]
+    build_dir: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", alias = "dependsOn")]
+    depends_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "is_false", alias = "deployExclusive")]
+    deploy_exclusive: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "deployPriority"
+    )]
+    deploy_priority: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     extensions: Option<HashMap<String, ScopedExtensionConfig>>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
@@ -202,6 +243,12 @@ struct RawComponent {
     hooks: HashMap<String, Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     extract_command: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "preDeployCommand"
+    )]
+    pre_deploy_command: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     remote_owner: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -210,6 +257,18 @@ struct RawComponent {
     git_deploy: Option<GitDeployConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     remote_url: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "deserialize_empty_as_none"
+    )]
+    git_author_name: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "deserialize_empty_as_none"
+    )]
+    git_author_email: Option<String>,
     #[serde(default, skip_serializing_if = "is_default_github_config")]
     github: GithubConfig,
     #[serde(default, skip_serializing_if = "ComponentReleaseConfig::is_default")]
@@ -263,6 +322,10 @@ impl From<RawComponent> for Component {
             bundled_into: raw.bundled_into,
             build_artifact: raw.build_artifact,
             build_command: raw.build_command,
+            build_dir: raw.build_dir,
+            depends_on: raw.depends_on,
+            deploy_exclusive: raw.deploy_exclusive,
+            deploy_priority: raw.deploy_priority,
             extensions: raw.extensions,
             capability_extensions: raw.capability_extensions,
             version_targets: raw.version_targets,
@@ -271,10 +334,13 @@ impl From<RawComponent> for Component {
             changelog_next_section_aliases: raw.changelog_next_section_aliases,
             hooks: raw.hooks,
             extract_command: raw.extract_command,
+            pre_deploy_command: raw.pre_deploy_command,
             remote_owner: raw.remote_owner,
             deploy_strategy: raw.deploy_strategy,
             git_deploy: raw.git_deploy,
             remote_url: raw.remote_url,
+            git_author_name: raw.git_author_name,
+            git_author_email: raw.git_author_email,
             github: raw.github,
             release: raw.release,
             triage_remote_url: raw.triage_remote_url,
@@ -310,6 +376,10 @@ impl From<Component> for RawComponent {
             bundled_into: c.bundled_into,
             build_artifact: c.build_artifact,
             build_command: c.build_command,
+            build_dir: c.build_dir,
+            depends_on: c.depends_on,
+            deploy_exclusive: c.deploy_exclusive,
+            deploy_priority: c.deploy_priority,
             extensions: c.extensions,
             capability_extensions: c.capability_extensions,
             version_targets: c.version_targets,
@@ -318,10 +388,13 @@ impl From<Component> for RawComponent {
             changelog_next_section_aliases: c.changelog_next_section_aliases,
             hooks: c.hooks,
             extract_command: c.extract_command,
+            pre_deploy_command: c.pre_deploy_command,
             remote_owner: c.remote_owner,
             deploy_strategy: c.deploy_strategy,
             git_deploy: c.git_deploy,
             remote_url: c.remote_url,
+            git_author_name: c.git_author_name,
+            git_author_email: c.git_author_email,
             github: c.github,
             release: c.release,
             triage_remote_url: c.triage_remote_url,
@@ -389,6 +462,10 @@ impl Component {
             bundled_into: None,
             build_artifact,
             build_command: None,
+            build_dir: None,
+            depends_on: Vec::new(),
+            deploy_exclusive: false,
+            deploy_priority: None,
             extensions: None,
             capability_extensions: HashMap::new(),
             version_targets: None,
@@ -397,10 +474,13 @@ impl Component {
             changelog_next_section_aliases: None,
             hooks: HashMap::new(),
             extract_command: None,
+            pre_deploy_command: None,
             remote_owner: None,
             deploy_strategy: None,
             git_deploy: None,
             remote_url: None,
+            git_author_name: None,
+            git_author_email: None,
             github: GithubConfig::default(),
             release: ComponentReleaseConfig::default(),
             triage_remote_url: None,
@@ -511,6 +591,41 @@ impl Component {
         !self.script_commands(capability).is_empty()
     }
 
+    /// Directory the build runs in and relative build artifacts resolve from:
+    /// `local_path` joined with `build_dir`, or `local_path` itself.
+    ///
+    /// `build_dir` must be relative and may not climb out of `local_path`.
+    pub fn build_root(&self, local_path: &Path) -> homeboy_error::Result<PathBuf> {
+        let Some(build_dir) = self
+            .build_dir
+            .as_deref()
+            .map(str::trim)
+            .filter(|dir| !dir.is_empty())
+        else {
+            return Ok(local_path.to_path_buf());
+        };
+
+        let escapes = Path::new(build_dir)
+            .components()
+            .any(|part| !matches!(part, path::Component::Normal(_) | path::Component::CurDir));
+        if escapes {
+            return Err(homeboy_error::Error::validation_invalid_argument(
+                "build_dir",
+                format!(
+                    "Component '{}' build_dir must be a relative path inside local_path",
+                    self.id
+                ),
+                Some(build_dir.to_string()),
+                Some(vec![
+                    "Use a subdirectory such as \"web\"; absolute paths and '..' are not allowed"
+                        .to_string(),
+                ]),
+            ));
+        }
+
+        Ok(local_path.join(build_dir))
+    }
+
     pub fn validate_supported_build_config(&self) -> homeboy_error::Result<()> {
         let Some(command) = self
             .build_command
@@ -599,4 +714,27 @@ mod tests {
             "real config drift must still change the attachment identity"
         );
     }
+
+    #[test]
+    fn build_root_joins_build_dir_and_rejects_escapes() {
+        let mut component = Component::new(
+            "site".to_string(),
+            "/source/site".to_string(),
+            String::new(),
+            None,
+        );
+        let local_path = Path::new("/source/site");
+        assert_eq!(component.build_root(local_path).unwrap(), local_path);
+
+        component.build_dir = Some("web".to_string());
+        assert_eq!(
+            component.build_root(local_path).unwrap(),
+            Path::new("/source/site/web")
+        );
+
+        for escape in ["../web", "web/../../x", "/tmp/web"] {
+            component.build_dir = Some(escape.to_string());
+            assert!(component.build_root(local_path).is_err(), "{escape}");
+        }
+    }
 }
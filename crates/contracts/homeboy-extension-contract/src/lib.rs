@@ -87,6 +87,7 @@ pub mod core_compat;
 pub mod exec_context;
 pub mod extension_contract_producer;
 pub mod fuzz_config;
+pub mod logs_config;
 pub mod manifest;
 pub mod manifest_action_config;
 pub mod manifest_capabilities;
@@ -97,6 +98,7 @@ pub mod manifest_toolchain_config;
 pub mod notification_transport_config;
 pub mod runner_contract;
 pub mod sidecar_config;
+pub use logs_config::{LogLevelPatterns, LogsConfig};
 pub use manifest::ExtensionManifest;
 pub mod source_metadata_repair;
 pub mod test_drift;
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Log viewing hints an extension contributes to `homeboy logs`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogsConfig {
+    #[serde(
+        default,
+        alias = "levelPatterns",
+        skip_serializing_if = "LogLevelPatterns::is_empty"
+    )]
+    pub level_patterns: LogLevelPatterns,
+}
+
+/// Regex patterns that mark a log line as a given severity. Extension patterns
+/// are checked before Homeboy's built-in defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogLevelPatterns {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warning: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notice: Vec<String>,
+}
+
+impl LogLevelPatterns {
+    pub fn is_empty(&self) -> bool {
+        self.error.is_empty() && self.warning.is_empty() && self.notice.is_empty()
+    }
+}
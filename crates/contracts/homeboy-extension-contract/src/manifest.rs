@@ -15,6 +15,7 @@ use crate::core_compat::*;
 use crate::exec_context::*;
 use crate::extension_contract_producer::*;
 use crate::fuzz_config::*;
+use crate::logs_config::*;
 use crate::manifest_action_config::*;
 use crate::manifest_capabilities::*;
 use crate::manifest_capability_config::*;
@@ -111,6 +112,10 @@ pub struct ExtensionManifest {
     pub fuzz: Option<FuzzConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trace: Option<TraceConfig>,
+    /// Level-matching patterns `homeboy logs` uses to annotate and colorize
+    /// log lines for projects that use this extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logs: Option<LogsConfig>,
     /// Post-write verify command used as a safety gate after `refactor --from ...`
     /// autofix writes to disk. If the command exits non-zero, the written files
     /// are reverted and the fixes are reclassified as declined. See #1167.
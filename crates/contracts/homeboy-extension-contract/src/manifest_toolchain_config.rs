@@ -43,6 +43,30 @@ pub struct DatabaseCliConfig {
     pub tables_command: String,
     pub describe_command: String,
     pub query_command: String,
+    /// Optional command returning per-table statistics as a JSON array of
+    /// `{ name, rows, size_bytes }` objects (information_schema column names
+    /// such as `TABLE_NAME`/`TABLE_ROWS` are also accepted).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_stats_command: Option<String>,
+    /// Optional search-replace command. Rendered with `{{search}}`,
+    /// `{{replace}}`, `{{tables}}` (space-separated, may be empty) and
+    /// `{{dryRun}}` (`--dry-run` when previewing, empty when applying).
+    /// Dry-run output is parsed for per-table replacement counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_replace_command: Option<String>,
+    /// Optional export command that writes a SQL dump to stdout (e.g.
+    /// `{{cliPath}} db export - --tables={{tables}}`). `{{tables}}` is a
+    /// comma-separated list, empty when exporting every table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_command: Option<String>,
+    /// Optional import command that reads a SQL dump from stdin (e.g.
+    /// `{{cliPath}} db import -`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_command: Option<String>,
+    /// Table-name suffixes holding sensitive data (e.g. `_users`,
+    /// `_usermeta`). `db tables --counts --skip-protected` leaves them uncounted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub protected_suffixes: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
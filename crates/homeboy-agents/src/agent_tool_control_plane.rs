@@ -382,7 +382,7 @@ mod tools {
                 staged_only: bool_input(input, "staged_only"),
                 files,
                 exclude: None,
-                amend: false,
+                ..Default::default()
             },
             workspace_path_for(input, true).as_deref(),
         ))
@@ -334,6 +334,21 @@ impl CliRuntime {
                 identifier: extension_cmd.project_id,
                 args: extension_cmd.args,
             };
+            if cli_args.is_interactive() {
+                return match cli::run_interactive(cli_args) {
+                    Ok(exit_code) => std::process::ExitCode::from(exit_code_to_u8(exit_code)),
+                    Err(err) => {
+                        let (json_result, exit_code) =
+                            output::map_cmd_result_to_json::<()>(Err(err));
+                        output_runtime::emit_json_result(
+                            json_result,
+                            output_file.as_deref(),
+                            exit_code,
+                        );
+                        std::process::ExitCode::from(exit_code_to_u8(exit_code))
+                    }
+                };
+            }
             let result = cli::run(cli_args, &global);
 
             let (json_result, exit_code) = output::map_cmd_result_to_json(result);
@@ -1500,6 +1515,43 @@ mod tests {
         });
     }
 
+    #[test]
+    fn extension_cli_passthrough_keeps_tool_flags_after_subcommand() {
+        crate::test_support::with_isolated_home(|home| {
+            write_cli_extension(home.path(), "node-runtime", "pm2");
+
+            let discovery = collect_extension_cli_info();
+            let matches = build_augmented_command(&discovery.info, &discovery.health)
+                .try_get_matches_from([
+                    "homeboy",
+                    "pm2",
+                    "my-node-app",
+                    "logs",
+                    "api",
+                    "--lines",
+                    "50",
+                    "--nostream",
+                ])
+                .expect("extension command parses");
+
+            let command = try_parse_extension_cli_command(&matches, &discovery.info)
+                .expect("pm2 routes to the extension CLI");
+            assert_eq!(command.tool, "pm2");
+            assert_eq!(command.project_id, "my-node-app");
+            assert_eq!(
+                command.args,
+                vec!["logs", "api", "--lines", "50", "--nostream"]
+            );
+
+            let matches = build_augmented_command(&discovery.info, &discovery.health)
+                .try_get_matches_from(["homeboy", "pm2", "my-node-app", "--list-targets"])
+                .expect("leading flag parses as passthrough");
+            let command = try_parse_extension_cli_command(&matches, &discovery.info)
+                .expect("pm2 routes to the extension CLI");
+            assert_eq!(command.args, vec!["--list-targets"]);
+        });
+    }
+
     #[test]
     fn augmented_manifest_includes_extension_command_contract_and_health() {
         crate::test_support::with_isolated_home(|home| {
@@ -5,9 +5,9 @@ use std::path::PathBuf;
 
 use crate::commands::{
     activity, agent_task, api, bench, cleanup, component, config, contract, daemon, db, deploy,
-    extension, file, fleet, fuzz, git, logs, observe, project, refactor, release, report, review,
-    rig, runner, runs, runtime, self_cmd, server, ssh, stack, status, trace, triage, tunnel,
-    upgrade, worktree,
+    exec, extension, file, fleet, fuzz, git, logs, observe, project, refactor, release, report,
+    review, rig, runner, runs, runtime, self_cmd, self_test, server, ssh, stack, status, trace,
+    triage, tunnel, upgrade, worktree,
 };
 
 const VERSION: &str = homeboy_product_identity::product_version();
@@ -136,6 +136,8 @@ pub enum Commands {
     Project(project::ProjectArgs),
     /// SSH into a project server or configured server
     Ssh(ssh::SshArgs),
+    /// Run a command from a project's base path, locally or over SSH
+    Exec(exec::ExecArgs),
     /// Manage SSH server configurations
     Server(server::ServerArgs),
     /// Run performance benchmarks for a component
@@ -202,6 +204,9 @@ pub enum Commands {
     /// Inspect the active Homeboy binary and install signals
     #[command(name = "self")]
     SelfCmd(self_cmd::SelfArgs),
+    /// Rehearse a deploy against a server: upload, extract, chmod, clean up
+    #[command(name = "self-test")]
+    SelfTest(self_test::SelfTestArgs),
     /// Manage stacks (combined-fixes branches built from base + cherry-picked PRs)
     Stack(stack::StackArgs),
     /// Make API requests to a project
@@ -239,6 +239,8 @@ fn command_safety_metadata(path: &[String]) -> CommandSafetyMetadata {
         }
         ["extension", "setup"]
         | ["extension", "refresh"]
+        | ["extension", "link"]
+        | ["extension", "unlink"]
         | ["extension", "relink"]
         | ["extension", "dev-run"]
         | ["extension", "install-for-component"]
@@ -342,10 +344,16 @@ fn command_safety_metadata(path: &[String]) -> CommandSafetyMetadata {
             );
             metadata.dangerous_flags = vec!["--force"];
         }
-        ["db", "delete-row"] | ["db", "drop-table"] => {
+        ["db", "delete-row"] | ["db", "drop-table"] | ["db", "search-replace"] => {
             metadata
                 .operator_mutating("default output is a non-mutating plan; pass --apply to mutate");
         }
+        ["db", "import"] => {
+            metadata.guarded_operator_mutating(
+                "overwrites database tables; requires typing the project id interactively or --force",
+                vec!["--force"],
+            );
+        }
         ["git", "issue", "create"]
         | ["git", "issue", "comment"]
         | ["git", "issue", "close"]
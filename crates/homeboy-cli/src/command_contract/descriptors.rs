@@ -10,6 +10,7 @@ macro_rules! ops_command_descriptors {
     ($consumer:ident) => {
         $consumer! {
             (ssh, Ssh, crate::commands::ssh::SshArgs, command_spec("ssh", CommandJsonFamily::Ops), crate::commands::ssh::run),
+            (exec, Exec, crate::commands::exec::ExecArgs, command_spec_with_output_notes_and_safety("exec", CommandJsonFamily::Ops, "runs an arbitrary command from the project base path, locally or over SSH", operator_safety(None, EXEC_DANGEROUS_FLAGS)), crate::commands::exec::run),
             (server, Server, crate::commands::server::ServerArgs, CommandSpec { subcommand_safety: SERVER_SUBCOMMAND_SAFETY, ..command_spec("server", CommandJsonFamily::Ops) }, crate::commands::server::run),
             (db, Db, crate::commands::db::DbArgs, command_spec("db", CommandJsonFamily::Ops), crate::commands::db::run),
             (file, File, crate::commands::file::FileArgs, CommandSpec { subcommand_safety: FILE_SUBCOMMAND_SAFETY, ..command_spec("file", CommandJsonFamily::Ops) }, crate::commands::file::run),
@@ -20,6 +21,7 @@ macro_rules! ops_command_descriptors {
             (status, Status, crate::commands::status::StatusArgs, command_spec("status", CommandJsonFamily::Ops), crate::commands::status::run),
             (git, Git, crate::commands::git::GitArgs, command_spec("git", CommandJsonFamily::Ops), crate::commands::git::run),
             (self_cmd, SelfCmd, crate::commands::self_cmd::SelfArgs, command_spec_with_output_notes("self", CommandJsonFamily::Ops, "inspects the active Homeboy runtime and renders built-in CLI documentation"), crate::commands::self_cmd::run),
+            (self_test, SelfTest, crate::commands::self_test::SelfTestArgs, command_spec_with_output_notes("self-test", CommandJsonFamily::Ops, "uploads, extracts, and removes a throwaway payload on the target server to rehearse a deploy"), crate::commands::self_test::run),
             (api, Api, crate::commands::api::ApiArgs, CommandSpec { subcommand_safety: API_SUBCOMMAND_SAFETY, ..command_spec("api", CommandJsonFamily::Ops) }, crate::commands::api::run),
             (upgrade, Upgrade, crate::commands::upgrade::UpgradeArgs, command_spec_with_output_notes_and_safety("upgrade", CommandJsonFamily::Ops, "upgrades the active Homeboy binary, extensions, runners, and services unless --check or skip flags are used", operator_safety(None, UPGRADE_DANGEROUS_FLAGS)), crate::commands::upgrade::run),
         }
@@ -36,6 +38,7 @@ macro_rules! ops_command_descriptors {
 #[macro_export]
 macro_rules! ops_command_spec {
     (ssh) => { command_spec("ssh", CommandJsonFamily::Ops) };
+    (exec) => { command_spec_with_output_notes_and_safety("exec", CommandJsonFamily::Ops, "runs an arbitrary command from the project base path, locally or over SSH", operator_safety(None, EXEC_DANGEROUS_FLAGS)) };
     (server) => { CommandSpec { subcommand_safety: SERVER_SUBCOMMAND_SAFETY, ..command_spec("server", CommandJsonFamily::Ops) } };
     (db) => { command_spec("db", CommandJsonFamily::Ops) };
     (file) => { CommandSpec { subcommand_safety: FILE_SUBCOMMAND_SAFETY, ..command_spec("file", CommandJsonFamily::Ops) } };
@@ -46,6 +49,7 @@ macro_rules! ops_command_spec {
     (status) => { command_spec("status", CommandJsonFamily::Ops) };
     (git) => { command_spec("git", CommandJsonFamily::Ops) };
     (self_cmd) => { command_spec_with_output_notes("self", CommandJsonFamily::Ops, "inspects the active Homeboy runtime and renders built-in CLI documentation") };
+    (self_test) => { command_spec_with_output_notes("self-test", CommandJsonFamily::Ops, "uploads, extracts, and removes a throwaway payload on the target server to rehearse a deploy") };
     (api) => { CommandSpec { subcommand_safety: API_SUBCOMMAND_SAFETY, ..command_spec("api", CommandJsonFamily::Ops) } };
     (upgrade) => { command_spec_with_output_notes_and_safety("upgrade", CommandJsonFamily::Ops, "upgrades the active Homeboy binary, extensions, runners, and services unless --check or skip flags are used", operator_safety(None, UPGRADE_DANGEROUS_FLAGS)) };
 }
@@ -107,6 +107,27 @@ pub const PUBLIC_OUTPUT_VARIANT_CONTRACTS: &[PublicOutputVariantContract] = &[
         discriminator_value: Some("query"),
         golden_fixture: None,
     },
+    PublicOutputVariantContract {
+        command: "db",
+        variant: "search_replace",
+        discriminator_field: Some("variant"),
+        discriminator_value: Some("search_replace"),
+        golden_fixture: None,
+    },
+    PublicOutputVariantContract {
+        command: "db",
+        variant: "export",
+        discriminator_field: Some("variant"),
+        discriminator_value: Some("export"),
+        golden_fixture: None,
+    },
+    PublicOutputVariantContract {
+        command: "db",
+        variant: "import",
+        discriminator_field: Some("variant"),
+        discriminator_value: Some("import"),
+        golden_fixture: None,
+    },
     PublicOutputVariantContract {
         command: "db",
         variant: "tunnel",
@@ -128,6 +149,13 @@ pub const PUBLIC_OUTPUT_VARIANT_CONTRACTS: &[PublicOutputVariantContract] = &[
         discriminator_value: Some("multi_project"),
         golden_fixture: Some("deploy_contract.json"),
     },
+    PublicOutputVariantContract {
+        command: "deploy",
+        variant: "diff",
+        discriminator_field: Some("variant"),
+        discriminator_value: Some("diff"),
+        golden_fixture: None,
+    },
     PublicOutputVariantContract {
         command: "git",
         variant: "single",
@@ -142,6 +170,20 @@ pub const PUBLIC_OUTPUT_VARIANT_CONTRACTS: &[PublicOutputVariantContract] = &[
         discriminator_value: Some("bulk"),
         golden_fixture: None,
     },
+    PublicOutputVariantContract {
+        command: "git",
+        variant: "log",
+        discriminator_field: Some("variant"),
+        discriminator_value: Some("log"),
+        golden_fixture: None,
+    },
+    PublicOutputVariantContract {
+        command: "git",
+        variant: "branch",
+        discriminator_field: Some("variant"),
+        discriminator_value: Some("branch"),
+        golden_fixture: None,
+    },
     PublicOutputVariantContract {
         command: "git",
         variant: "issue",
@@ -395,6 +395,7 @@ const UPGRADE_DANGEROUS_FLAGS: &[&str] = &["--force", "--upgrade-runner"];
 const FUZZ_DANGEROUS_FLAGS: &[&str] = &["--allow-destructive"];
 const CLEANUP_DANGEROUS_FLAGS: &[&str] = &["--apply"];
 const TRIAGE_DANGEROUS_FLAGS: &[&str] = &["--auto-merge"];
+const EXEC_DANGEROUS_FLAGS: &[&str] = &["passthrough args"];
 const REFACTOR_DANGEROUS_FLAGS: &[&str] = &["--write", "--commit"];
 const FILE_APPLY_DANGEROUS_FLAGS: &[&str] = &["--apply"];
 const FLEET_EXEC_DANGEROUS_FLAGS: &[&str] = &["--apply"];
@@ -471,7 +472,15 @@ const fn paths_safety_without_notes(
 }
 
 const DEPS_MUTATING_PATHS: &[&str] = &["install", "update", "stack apply"];
-const FILE_APPLY_PATHS: &[&str] = &["write", "delete", "mkdir", "rename"];
+const FILE_APPLY_PATHS: &[&str] = &[
+    "write",
+    "delete",
+    "mkdir",
+    "touch",
+    "rename",
+    "duplicate",
+    "upload",
+];
 const FILE_TRANSFER_PATHS: &[&str] = &["copy", "sync"];
 const FLEET_CONFIG_PATHS: &[&str] = &["create", "set", "delete", "add", "remove"];
 const API_MUTATION_PATHS: &[&str] = &["post", "put", "patch", "delete"];
@@ -490,6 +499,8 @@ const SERVER_OPERATOR_PATHS: &[&str] = &[
     "delete",
     "connect",
     "disconnect",
+    "keygen",
+    "copy-id",
     "key generate",
     "key import",
     "key use",
@@ -503,6 +514,7 @@ const PROJECT_MUTATING_PATHS: &[&str] = &[
     "rename",
     "delete",
     "init",
+    "import",
     "components set",
     "components attach-path",
     "components remove",
@@ -615,6 +627,7 @@ pub const COMMAND_SPECS: &[CommandSpec] = &[
         ..command_spec("project", CommandJsonFamily::Workspace)
     },
     crate::ops_command_spec!(ssh),
+    crate::ops_command_spec!(exec),
     crate::ops_command_spec!(server),
     command_spec_with_representative_argv(
         &["homeboy", "bench"],
@@ -797,6 +810,7 @@ pub const COMMAND_SPECS: &[CommandSpec] = &[
         "inspects persisted evidence, artifacts, artifact postprocessing, and finding reconciliation workflows",
     ),
     crate::ops_command_spec!(self_cmd),
+    crate::ops_command_spec!(self_test),
     command_spec("stack", CommandJsonFamily::Workspace),
     crate::ops_command_spec!(api),
     crate::ops_command_spec!(upgrade),
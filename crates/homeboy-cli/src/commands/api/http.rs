@@ -54,8 +54,8 @@ pub(crate) struct RequestArgs {
     form: Vec<(String, String)>,
 }
 
-pub fn run(args: HttpArgs, _global: &GlobalArgs) -> CmdResult<HttpRequestOutput> {
-    let input = match args.command {
+pub fn run(args: HttpArgs, debug: bool, _global: &GlobalArgs) -> CmdResult<HttpRequestOutput> {
+    let mut input = match args.command {
         HttpCommand::Get(args) => build_input("GET", args),
         HttpCommand::Request {
             method,
@@ -66,6 +66,7 @@ pub fn run(args: HttpArgs, _global: &GlobalArgs) -> CmdResult<HttpRequestOutput>
             build_input(&method, args)
         }
     };
+    input.debug = debug;
 
     let output = http_request::run(input)?;
     Ok((output, 0))
@@ -104,6 +105,7 @@ fn build_input(method: &str, args: RequestArgs) -> HttpRequestInput {
         headers: args.headers,
         json_body: args.json,
         form_body: args.form,
+        debug: false,
     }
 }
 
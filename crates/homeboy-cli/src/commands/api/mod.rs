@@ -8,6 +8,11 @@ pub mod http;
 
 #[derive(Args)]
 pub struct ApiArgs {
+    /// Log the request and response (auth redacted) to stderr. Extra header
+    /// names to redact can be listed in HOMEBOY_REDACTION_SENSITIVE_HEADERS.
+    #[arg(long, global = true)]
+    debug: bool,
+
     #[command(subcommand)]
     command: ApiCommand,
 }
@@ -96,8 +101,14 @@ pub enum ApiCommandOutput {
 pub fn run(args: ApiArgs, global: &crate::commands::GlobalArgs) -> CmdResult<ApiCommandOutput> {
     match args.command {
         ApiCommand::Auth(args) => map_nested(auth::run(args, global), ApiCommandOutput::Auth),
-        ApiCommand::Http(args) => map_nested(http::run(args, global), ApiCommandOutput::Http),
-        command => run_project(ApiArgs { command })
+        ApiCommand::Http(http_args) => map_nested(
+            http::run(http_args, args.debug, global),
+            ApiCommandOutput::Http,
+        ),
+        command => run_project(ApiArgs {
+            debug: args.debug,
+            command,
+        })
             .map(|(output, code)| (ApiCommandOutput::Project(output), code)),
     }
 }
@@ -228,6 +239,7 @@ fn build_api_json(args: &ApiArgs) -> String {
         "endpoint": endpoint,
         "body": body,
         "bodyFormat": body_format,
+        "debug": args.debug,
     })
     .to_string()
 }
@@ -162,6 +162,15 @@ enum ComponentCommand {
         #[arg(long)]
         apply: bool,
     },
+    /// Check one component's deployability: local path, version targets,
+    /// build configuration, and artifact shape
+    Doctor {
+        /// Component ID (optional when --path is provided or run from a component checkout)
+        id: Option<String>,
+        /// Discover component from a directory's homeboy.json instead of the registry
+        #[arg(long)]
+        path: Option<String>,
+    },
     /// Report or remove declared reconstructable artifacts for a component
     Artifacts {
         /// Component ID (optional when --path is provided or run from a component checkout)
@@ -347,12 +356,37 @@ pub fn run(
             skip_dependencies,
         ),
         ComponentCommand::Reconcile { id, apply } => reconcile(&id, apply),
+        ComponentCommand::Doctor { id, path } => doctor(id.as_deref(), path.as_deref()),
         ComponentCommand::Artifacts { id, path, apply } => {
             artifacts(id.as_deref(), path.as_deref(), apply)
         }
     }
 }
 
+fn doctor(id: Option<&str>, path: Option<&str>) -> CmdResult<ComponentOutput> {
+    let component =
+        component::resolve_effective(id, path, None).map_err(|e| e.with_contextual_hint())?;
+    let report = homeboy_release::deploy::component_doctor(&component);
+    let exit_code = if report.has_failures() { 1 } else { 0 };
+
+    Ok((
+        ComponentOutput {
+            command: "component.doctor".to_string(),
+            id: Some(report.component_id.clone()),
+            entity: Some(serde_json::to_value(&report).map_err(|error| {
+                homeboy::core::Error::validation_invalid_argument(
+                    "component.doctor",
+                    "Failed to serialize component doctor report",
+                    Some(error.to_string()),
+                    None,
+                )
+            })?),
+            ..Default::default()
+        },
+        exit_code,
+    ))
+}
+
 fn artifacts(id: Option<&str>, path: Option<&str>, apply: bool) -> CmdResult<ComponentOutput> {
     let component =
         component::resolve_effective(id, path, None).map_err(|e| e.with_contextual_hint())?;
@@ -7,7 +7,7 @@
 
 use crate::cli_surface::Commands;
 use crate::command_contract::CommandSpec;
-use crate::commands::{adapter, file, logs, report, review, runner, runtime, trace};
+use crate::commands::{adapter, file, logs, project, report, review, runner, runtime, trace};
 
 use crate::command_contract::{
     CommandDescriptor, CommandJsonFamily, CommandOutputContractKind, CommandOutputDescriptor,
@@ -45,12 +45,10 @@ impl Commands {
         }
 
         match self {
-            Commands::Ssh(args) if args.subcommand.is_none() && args.command.is_empty() => {
-                raw_ops_descriptor(
-                    CommandRawOutputMode::InteractivePassthrough,
-                    output_file_mode,
-                )
-            }
+            Commands::Ssh(args) if args.is_interactive() => raw_ops_descriptor(
+                CommandRawOutputMode::InteractivePassthrough,
+                output_file_mode,
+            ),
             Commands::Logs(args) if logs::is_interactive(args) => raw_ops_descriptor(
                 CommandRawOutputMode::InteractivePassthrough,
                 output_file_mode,
@@ -70,6 +68,16 @@ impl Commands {
                 output_file_mode,
                 CommandOutputContractKind::JsonEnvelope,
             ),
+            Commands::Release(args) if args.is_version_env() => workspace_descriptor(
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText),
+                output_file_mode,
+                CommandOutputContractKind::JsonEnvelope,
+            ),
+            Commands::Project(args) if project::is_env_show(args) => workspace_descriptor(
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText),
+                output_file_mode,
+                CommandOutputContractKind::JsonEnvelope,
+            ),
             Commands::Review(args) => CommandOutputDescriptor {
                 response_mode: markdown_or_json_response(review::is_markdown_mode(args)),
                 output_file_mode,
@@ -1,11 +1,16 @@
 use clap::{Args, Subcommand};
 use serde::{Serialize, Serializer};
 
-use homeboy::core::db::{self, DbResult, DbTunnelResult};
+use std::path::PathBuf;
+
+use homeboy::core::db::{
+    self, DbExportResult, DbImportResult, DbResult, DbTunnelResult, SearchReplaceResult,
+};
 use homeboy::core::engine::text;
 use homeboy::core::observation::store::{self, ObservationDbStatus};
 use homeboy::core::project;
 
+use super::utils::tty;
 use super::CmdResult;
 
 #[derive(Args)]
@@ -22,6 +27,12 @@ enum DbCommand {
     Tables {
         /// Project ID
         project_id: String,
+        /// Also report exact per-table row counts (one grouped COUNT(*) query)
+        #[arg(long)]
+        counts: bool,
+        /// With --counts, skip tables matching the extension's protected_suffixes
+        #[arg(long, requires = "counts")]
+        skip_protected: bool,
         /// Optional subtarget
         #[arg(trailing_var_arg = true)]
         args: Vec<String>,
@@ -86,6 +97,55 @@ enum DbCommand {
         #[arg(trailing_var_arg = true)]
         args: Vec<String>,
     },
+    /// Search-replace across tables (dry-run with per-table counts by default)
+    SearchReplace {
+        /// Project ID
+        project_id: String,
+        /// String to search for
+        search: String,
+        /// Replacement string
+        replace: String,
+        /// Tables to target (comma-separated or repeated). Generic estimates
+        /// need `table.column` targets.
+        #[arg(long, value_delimiter = ',')]
+        tables: Vec<String>,
+        /// Apply the replacements. Without this flag, reports counts only.
+        #[arg(long)]
+        apply: bool,
+        /// Report counts without writing (the default; explicit for scripts)
+        #[arg(long, conflicts_with = "apply")]
+        dry_run: bool,
+        /// Optional subtarget
+        #[arg(long)]
+        subtarget: Option<String>,
+    },
+    /// Export the database to a local SQL file (streamed, not buffered)
+    Export {
+        /// Project ID
+        project_id: String,
+        /// Local file to write the dump to
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Only export these tables (comma-separated or repeated)
+        #[arg(long, value_delimiter = ',')]
+        tables: Vec<String>,
+        /// Optional subtarget
+        #[arg(long)]
+        subtarget: Option<String>,
+    },
+    /// Import a local SQL file into the database (overwrites existing tables)
+    Import {
+        /// Project ID
+        project_id: String,
+        /// Local SQL file to import
+        file: PathBuf,
+        /// Skip the interactive "type the project id" confirmation
+        #[arg(long)]
+        force: bool,
+        /// Optional subtarget
+        #[arg(long)]
+        subtarget: Option<String>,
+    },
     /// Open SSH tunnel to database
     Tunnel {
         /// Project ID
@@ -114,6 +174,9 @@ fn is_false(value: &bool) -> bool {
 pub enum DbResultVariant {
     Status(ObservationDbStatus),
     Query(DbResult),
+    SearchReplace(SearchReplaceResult),
+    Export(DbExportResult),
+    Import(DbImportResult),
     Tunnel(DbTunnelResult),
 }
 
@@ -140,6 +203,21 @@ impl Serialize for DbResultVariant {
                 result,
             }
             .serialize(serializer),
+            DbResultVariant::SearchReplace(result) => TaggedDbResult {
+                variant: "search_replace",
+                result,
+            }
+            .serialize(serializer),
+            DbResultVariant::Export(result) => TaggedDbResult {
+                variant: "export",
+                result,
+            }
+            .serialize(serializer),
+            DbResultVariant::Import(result) => TaggedDbResult {
+                variant: "import",
+                result,
+            }
+            .serialize(serializer),
             DbResultVariant::Tunnel(result) => TaggedDbResult {
                 variant: "tunnel",
                 result,
@@ -152,7 +230,19 @@ impl Serialize for DbResultVariant {
 pub fn run(args: DbArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<DbOutput> {
     match args.command {
         DbCommand::Status => status(),
-        DbCommand::Tables { project_id, args } => tables(&project_id, &args),
+        DbCommand::Tables {
+            project_id,
+            counts,
+            skip_protected,
+            args,
+        } => tables(
+            &project_id,
+            &args,
+            &db::ListTablesOptions {
+                counts,
+                skip_protected,
+            },
+        ),
         DbCommand::Describe { project_id, args } => describe(&project_id, &args),
         DbCommand::Query { project_id, args } => query(&project_id, &args),
         DbCommand::Search {
@@ -182,6 +272,34 @@ pub fn run(args: DbArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<DbO
             apply,
             args,
         } => drop_table(&project_id, &args, apply),
+        DbCommand::SearchReplace {
+            project_id,
+            search,
+            replace,
+            tables,
+            apply,
+            dry_run: _,
+            subtarget,
+        } => search_replace(
+            &project_id,
+            &search,
+            &replace,
+            &tables,
+            apply,
+            subtarget.as_deref(),
+        ),
+        DbCommand::Export {
+            project_id,
+            output,
+            tables,
+            subtarget,
+        } => export(&project_id, &output, &tables, subtarget.as_deref()),
+        DbCommand::Import {
+            project_id,
+            file,
+            force,
+            subtarget,
+        } => import(&project_id, &file, force, subtarget.as_deref()),
         DbCommand::Tunnel {
             project_id,
             local_port,
@@ -223,9 +341,13 @@ fn parse_subtarget(
     Ok((None, args.to_vec()))
 }
 
-fn tables(project_id: &str, args: &[String]) -> CmdResult<DbOutput> {
+fn tables(
+    project_id: &str,
+    args: &[String],
+    options: &db::ListTablesOptions,
+) -> CmdResult<DbOutput> {
     let (subtarget, _) = parse_subtarget(project_id, args)?;
-    let result = db::list_tables(project_id, subtarget.as_deref())?;
+    let result = db::list_tables_with_options(project_id, subtarget.as_deref(), options)?;
     let exit_code = result.exit_code;
 
     Ok((
@@ -334,6 +456,9 @@ fn delete_row(project_id: &str, args: &[String], apply: bool) -> CmdResult<DbOut
                     exit_code: 0,
                     success: true,
                     tables: None,
+                    table_prefix: None,
+                    table_stats: None,
+                    table_counts: None,
                     table: Some(table.to_string()),
                     sql: Some(sql),
                 }),
@@ -383,6 +508,9 @@ fn drop_table(project_id: &str, args: &[String], apply: bool) -> CmdResult<DbOut
                     exit_code: 0,
                     success: true,
                     tables: None,
+                    table_prefix: None,
+                    table_stats: None,
+                    table_counts: None,
                     table: Some(table.to_string()),
                     sql: Some(sql),
                 }),
@@ -404,6 +532,100 @@ fn drop_table(project_id: &str, args: &[String], apply: bool) -> CmdResult<DbOut
     ))
 }
 
+fn search_replace(
+    project_id: &str,
+    search: &str,
+    replace: &str,
+    tables: &[String],
+    apply: bool,
+    subtarget: Option<&str>,
+) -> CmdResult<DbOutput> {
+    let result = db::search_replace(project_id, search, replace, tables, apply, subtarget)?;
+    let exit_code = result.exit_code;
+
+    Ok((
+        DbOutput {
+            command: "db.searchReplace".to_string(),
+            dry_run: result.dry_run,
+            action_required: result.dry_run.then(|| {
+                "Review counts, then re-run with --apply to write the replacements.".to_string()
+            }),
+            result: DbResultVariant::SearchReplace(result),
+        },
+        exit_code,
+    ))
+}
+
+fn export(
+    project_id: &str,
+    output: &std::path::Path,
+    tables: &[String],
+    subtarget: Option<&str>,
+) -> CmdResult<DbOutput> {
+    let result = db::export(project_id, tables, output, subtarget)?;
+    let exit_code = result.exit_code;
+
+    Ok((
+        DbOutput {
+            command: "db.export".to_string(),
+            dry_run: false,
+            action_required: None,
+            result: DbResultVariant::Export(result),
+        },
+        exit_code,
+    ))
+}
+
+fn import(
+    project_id: &str,
+    file: &std::path::Path,
+    force: bool,
+    subtarget: Option<&str>,
+) -> CmdResult<DbOutput> {
+    if !force {
+        confirm_import(project_id)?;
+    }
+    let result = db::import(project_id, file, subtarget)?;
+    let exit_code = result.exit_code;
+
+    Ok((
+        DbOutput {
+            command: "db.import".to_string(),
+            dry_run: false,
+            action_required: None,
+            result: DbResultVariant::Import(result),
+        },
+        exit_code,
+    ))
+}
+
+/// Ask the operator to type the project id before an import overwrites the
+/// database. Without a terminal there is nobody to ask, so `--force` is required.
+fn confirm_import(project_id: &str) -> homeboy::core::Result<()> {
+    if !tty::is_stdin_tty() {
+        return Err(homeboy::core::Error::validation_invalid_argument(
+            "force",
+            "db import overwrites database tables; pass --force to import without an interactive confirmation",
+            Some(project_id.to_string()),
+            None,
+        ));
+    }
+
+    let typed = tty::prompt(&format!(
+        "This import overwrites tables in the '{}' database. Type the project id to confirm: ",
+        project_id
+    ))?;
+    if typed != project_id {
+        return Err(homeboy::core::Error::validation_invalid_argument(
+            "confirmation",
+            "Confirmation did not match the project id; import cancelled",
+            Some(project_id.to_string()),
+            None,
+        ));
+    }
+    Ok(())
+}
+
 fn tunnel(project_id: &str, local_port: Option<u16>) -> CmdResult<DbOutput> {
     let result = db::create_tunnel(project_id, local_port)?;
     let exit_code = result.exit_code;
@@ -1,10 +1,10 @@
-use clap::Args;
+use clap::{Args, ValueEnum};
 use serde::Serialize;
 use std::collections::BTreeMap;
 
 use homeboy_release::deploy::{
-    self, ComponentDeployResult, DeployConfig, DeploySummary, MultiDeploySummary,
-    ProjectDeployResult,
+    self, ComponentDeployResult, DeployConfig, DeployHealthWait, DeploySummary, DeployTransport,
+    MultiDeployResult, MultiDeploySummary, ProjectDeployResult,
 };
 
 use super::utils::resolve::{infer_project_for_components, resolve_project_components};
@@ -23,12 +23,12 @@ const DEPLOY_RECIPES: &[&str] = &[
 pub struct DeployArgs {
     /// Target ID: project ID or component ID (order is auto-detected)
     pub target_id: Option<String>,
-    /// Additional component IDs (enables project/component order detection)
+    /// Additional component IDs or glob patterns like 'theme-*' (enables project/component order detection)
     pub component_ids: Vec<String>,
     /// Explicit project ID (takes precedence over positional detection)
     #[arg(long, short = 'p')]
     pub project: Option<String>,
-    /// Explicit component IDs (takes precedence over positional)
+    /// Explicit component IDs or glob patterns (takes precedence over positional)
     #[arg(long, short = 'c')]
     pub component: Option<Vec<String>>,
     /// JSON input spec for bulk operations (array or {"component_ids": [...]})
@@ -52,6 +52,12 @@ pub struct DeployArgs {
     /// Check component status without building or deploying
     #[arg(long, visible_alias = "status")]
     pub check: bool,
+    /// Compare the current local artifact against the last deploy manifest without deploying
+    #[arg(
+        long,
+        conflicts_with_all = ["dry_run", "check", "projects", "fleet", "shared", "release_set", "resume"]
+    )]
+    pub diff: bool,
     /// Deploy even with uncommitted changes
     #[arg(long)]
     pub force: bool,
@@ -102,6 +108,36 @@ pub struct DeployArgs {
     /// Resume a prior multi-project deploy run after exact identity validation
     #[arg(long, value_name = "RUN_ID")]
     pub resume: Option<String>,
+    /// After each component deploys, poll the project health URL until it returns 2xx
+    #[arg(long)]
+    pub wait_healthy: bool,
+    /// Seconds between --wait-healthy polls
+    #[arg(long, value_name = "SECS", default_value_t = 5, requires = "wait_healthy")]
+    pub health_interval: u64,
+    /// Seconds to keep polling before marking the deploy degraded
+    #[arg(long, value_name = "SECS", default_value_t = 20, requires = "wait_healthy")]
+    pub health_timeout: u64,
+    /// Compress artifact uploads in transit (scp -C / rsync -z); helps on slow links
+    #[arg(long)]
+    pub compress: bool,
+    /// Directory transfer backend; rsync falls back to scp when unavailable
+    #[arg(long, value_enum, default_value_t = DeployTransportArg::Rsync)]
+    pub transport: DeployTransportArg,
+    /// Skip the SHA-256 comparison of uploaded archives before extraction
+    #[arg(long)]
+    pub no_verify_checksum: bool,
+    /// Deploy up to N independent components of a project concurrently
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub jobs: u32,
+    /// Remote backups kept per component before each artifact deploy (default 0: backups are opt-in)
+    #[arg(long, value_name = "N", default_value_t = deploy::DEFAULT_KEEP_BACKUPS)]
+    pub keep_backups: u32,
+    /// Restore the most recent remote backup of the given components instead of deploying
+    #[arg(
+        long,
+        conflicts_with_all = ["dry_run", "check", "diff", "all", "outdated", "behind_upstream", "release_set", "requested_ref", "head", "tagged", "fleet", "shared", "projects"]
+    )]
+    pub rollback: bool,
     // Populated only by a validated release-set manifest.
     #[arg(skip)]
     exact_refs: BTreeMap<String, String>,
@@ -113,6 +149,21 @@ pub struct DeployArgs {
     preflighted_component_identities: BTreeMap<String, String>,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum DeployTransportArg {
+    Rsync,
+    Scp,
+}
+
+impl From<DeployTransportArg> for DeployTransport {
+    fn from(arg: DeployTransportArg) -> Self {
+        match arg {
+            DeployTransportArg::Rsync => DeployTransport::Rsync,
+            DeployTransportArg::Scp => DeployTransport::Scp,
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct DeployOutput {
     pub command: String,
@@ -124,8 +175,11 @@ pub struct DeployOutput {
     pub dry_run: bool,
     pub check: bool,
     pub force: bool,
+    pub compress: bool,
     pub results: Vec<ComponentDeployResult>,
     pub summary: DeploySummary,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deploy_order: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub release_set_identity: Option<String>,
     #[serde(
@@ -145,6 +199,7 @@ pub struct MultiProjectDeployOutput {
     pub dry_run: bool,
     pub check: bool,
     pub force: bool,
+    pub compress: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub release_set_identity: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -156,11 +211,21 @@ pub struct MultiProjectDeployOutput {
     pub actionable: Option<CommandActionableMetadata>,
 }
 
+#[derive(Serialize)]
+pub struct DeployDiffOutput {
+    pub command: String,
+    pub variant: &'static str,
+    pub project_id: String,
+    pub components: Vec<deploy::DeployManifestDiff>,
+    pub changed_components: usize,
+}
+
 #[derive(Serialize)]
 #[serde(untagged)]
 pub enum DeployCommandOutput {
     Single(DeployOutput),
     Multi(MultiProjectDeployOutput),
+    Diff(DeployDiffOutput),
 }
 
 pub fn run(
@@ -196,7 +261,8 @@ pub fn run(
     if let Some(ref fleet_id) = args.fleet {
         let fl = homeboy::core::fleet::load(fleet_id)?;
         let (component_ids, config) = resolve_multi_args(&args)?;
-        return run_multi_output(&fl.project_ids, &component_ids, &config, &args, release_set.as_ref());
+        let result = deploy::run_fleet(fleet_id, &fl.project_ids, &component_ids, &config)?;
+        return multi_output(result, &args, release_set.as_ref());
     }
 
     // Shared component deploy (find all projects using the component)
@@ -225,6 +291,14 @@ pub fn run(
         args.component_ids = deploy::parse_bulk_component_ids(spec)?;
     }
 
+    if args.diff {
+        return run_diff(&project_id, &args.component_ids);
+    }
+
+    if args.rollback {
+        return run_rollback(&project_id, &args);
+    }
+
     let config = build_config(&args, false);
 
     let result = deploy::run(&project_id, &config).map_err(|e| {
@@ -256,8 +330,10 @@ pub fn run(
             dry_run: args.dry_run,
             check: args.check,
             force: args.force,
+            compress: args.compress,
             results: result.results,
             summary: result.summary,
+            deploy_order: result.deploy_order,
             release_set_identity: release_set.map(|value| value.identity.clone()),
             actionable: Some(deploy_actionable(&project_id)),
         }),
@@ -265,6 +341,69 @@ pub fn run(
     ))
 }
 
+/// Preview file-level changes against the last recorded deploy manifest.
+fn run_diff(project_id: &str, component_ids: &[String]) -> CmdResult<DeployCommandOutput> {
+    if component_ids.is_empty() {
+        return Err(homeboy::core::Error::validation_invalid_argument(
+            "component",
+            "--diff requires at least one component ID",
+            None,
+            Some(vec![format!(
+                "homeboy deploy {} <component-id> --diff",
+                project_id
+            )]),
+        ));
+    }
+    let project = homeboy::core::project::load(project_id)?;
+    let components = component_ids
+        .iter()
+        .map(|component_id| {
+            let component =
+                homeboy::core::project::resolve_project_component(&project, component_id)?;
+            deploy::diff_against_last(project_id, &component)
+        })
+        .collect::<homeboy::core::Result<Vec<_>>>()?;
+    let changed_components = components.iter().filter(|diff| diff.has_changes()).count();
+
+    Ok((
+        DeployCommandOutput::Diff(DeployDiffOutput {
+            command: "deploy.diff".to_string(),
+            variant: "diff",
+            project_id: project_id.to_string(),
+            components,
+            changed_components,
+        }),
+        0,
+    ))
+}
+
+/// Restore each component's most recent remote backup.
+fn run_rollback(project_id: &str, args: &DeployArgs) -> CmdResult<DeployCommandOutput> {
+    let result = deploy::rollback(project_id, &args.component_ids)?;
+    let exit_code = if result.summary.failed > 0 { 1 } else { 0 };
+
+    Ok((
+        DeployCommandOutput::Single(DeployOutput {
+            command: "deploy.rollback".to_string(),
+            variant: "single",
+            project_id: project_id.to_string(),
+            all: false,
+            outdated: false,
+            behind_upstream: false,
+            dry_run: false,
+            check: false,
+            force: args.force,
+            compress: false,
+            results: result.results,
+            summary: result.summary,
+            deploy_order: result.deploy_order,
+            release_set_identity: None,
+            actionable: Some(deploy_actionable(project_id)),
+        }),
+        exit_code,
+    ))
+}
+
 // === Argument resolution helpers ===
 
 fn validate_apply_boundary(args: &DeployArgs) -> homeboy::core::Result<()> {
@@ -537,6 +676,15 @@ fn build_config(args: &DeployArgs, skip_build: bool) -> DeployConfig {
         tagged: args.tagged,
         prepared_artifact: None,
         resume_run_id: args.resume.clone(),
+        wait_healthy: args.wait_healthy.then_some(DeployHealthWait {
+            interval_secs: args.health_interval,
+            timeout_secs: args.health_timeout,
+        }),
+        compress: args.compress,
+        transport: args.transport.into(),
+        verify_checksum: !args.no_verify_checksum,
+        jobs: args.jobs,
+        keep_backups: args.keep_backups,
     }
 }
 
@@ -548,6 +696,14 @@ fn run_multi_output(
     release_set: Option<&homeboy_core::release_set::NormalizedReleaseSet>,
 ) -> CmdResult<DeployCommandOutput> {
     let result = deploy::run_multi(project_ids, component_ids, config)?;
+    multi_output(result, args, release_set)
+}
+
+fn multi_output(
+    result: MultiDeployResult,
+    args: &DeployArgs,
+    release_set: Option<&homeboy_core::release_set::NormalizedReleaseSet>,
+) -> CmdResult<DeployCommandOutput> {
     let exit_code = if result.summary.failed > 0 { 1 } else { 0 };
 
     let actionable = multi_deploy_actionable(&result.projects);
@@ -561,6 +717,7 @@ fn run_multi_output(
             dry_run: args.dry_run,
             check: args.check,
             force: args.force,
+            compress: args.compress,
             release_set_identity: release_set.map(|value| value.identity.clone()),
             deploy_run_id: result.deploy_run_id,
             actionable: Some(actionable),
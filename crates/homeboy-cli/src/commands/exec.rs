@@ -0,0 +1,154 @@
+use clap::Args;
+use homeboy::core::context::{is_local_project, require_project_base_path};
+use homeboy::core::engine::{executor, shell};
+use homeboy::core::project::{self, Project};
+use serde::Serialize;
+
+use super::CmdResult;
+
+#[derive(Args)]
+pub struct ExecArgs {
+    /// Project ID
+    pub project_id: String,
+
+    /// Command to run from the project base path.
+    ///
+    /// Examples:
+    ///   homeboy exec my-project -- ls -la
+    ///   homeboy exec my-project -- git status --short
+    ///
+    /// If you need shell operators (&&, |, redirects), pass a single quoted string:
+    ///   homeboy exec my-project "ls | head"
+    #[arg(
+        num_args = 1..,
+        required = true,
+        trailing_var_arg = true,
+        allow_hyphen_values = true
+    )]
+    pub command: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExecOutput {
+    pub project_id: String,
+    /// `local` or `ssh`, from whether the project has a `server_id`.
+    pub route: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_id: Option<String>,
+    pub base_path: String,
+    pub command: String,
+    pub stdout: String,
+    pub stderr: String,
+    pub success: bool,
+    pub exit_code: i32,
+}
+
+pub fn run(args: ExecArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<ExecOutput> {
+    let project = project::load(&args.project_id)?;
+    let output = exec_in_project(&args.project_id, &project, &args.command)?;
+    let exit_code = output.exit_code;
+    Ok((output, exit_code))
+}
+
+fn exec_in_project(
+    project_id: &str,
+    project: &Project,
+    command: &[String],
+) -> homeboy::core::Result<ExecOutput> {
+    let base_path = require_project_base_path(project_id, project)?;
+
+    // A single argument is a raw shell command (operators allowed); multiple
+    // arguments (typically after `--`) are quoted so they pass through verbatim.
+    let command_string = if command.len() == 1 {
+        command[0].clone()
+    } else {
+        shell::quote_args(command)
+    };
+    let effective_command = format!("cd {} && {}", shell::quote_path(&base_path), command_string);
+
+    let output = executor::execute_for_project(project, &effective_command)?;
+    let local = is_local_project(project);
+
+    Ok(ExecOutput {
+        project_id: project_id.to_string(),
+        route: if local { "local" } else { "ssh" }.to_string(),
+        server_id: if local {
+            None
+        } else {
+            project.server_id.clone()
+        },
+        base_path,
+        command: command_string,
+        stdout: output.stdout,
+        stderr: output.stderr,
+        success: output.success,
+        exit_code: output.exit_code,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local_project(base_path: &std::path::Path) -> Project {
+        Project {
+            id: "sandbox".to_string(),
+            base_path: Some(base_path.display().to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn local_project_runs_from_base_path_with_verbatim_args() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let project = local_project(dir.path());
+
+        let output = exec_in_project(
+            "sandbox",
+            &project,
+            &[
+                "sh".into(),
+                "-c".into(),
+                "pwd; printf '%s' \"$0\"".into(),
+                "a b".into(),
+            ],
+        )
+        .expect("exec");
+
+        assert_eq!(output.route, "local");
+        assert!(output.server_id.is_none());
+        assert!(output.success);
+        let canonical = dir.path().canonicalize().expect("canonical");
+        let mut lines = output.stdout.lines();
+        assert_eq!(
+            std::path::Path::new(lines.next().expect("pwd"))
+                .canonicalize()
+                .expect("pwd path"),
+            canonical
+        );
+        assert_eq!(lines.next(), Some("a b"));
+    }
+
+    #[test]
+    fn failing_command_reports_exit_code_and_stderr() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let project = local_project(dir.path());
+
+        let output =
+            exec_in_project("sandbox", &project, &["echo oops >&2; exit 3".into()]).expect("exec");
+
+        assert!(!output.success);
+        assert_eq!(output.exit_code, 3);
+        assert_eq!(output.stderr.trim(), "oops");
+    }
+
+    #[test]
+    fn missing_base_path_is_a_config_error() {
+        let project = Project {
+            id: "sandbox".to_string(),
+            ..Default::default()
+        };
+
+        assert!(exec_in_project("sandbox", &project, &["ls".into()]).is_err());
+    }
+}
@@ -109,6 +109,22 @@ enum ExtensionCommand {
         #[arg(long = "ref")]
         revision: Option<String>,
     },
+    /// Link a local extension directory for development (shadows any install with the same id)
+    Link {
+        /// Local path to extension directory
+        source: String,
+        /// Override extension id
+        #[arg(long)]
+        id: Option<String>,
+        /// Discard a shadowed copied install instead of keeping it for unlink to restore
+        #[arg(long)]
+        force: bool,
+    },
+    /// Remove a linked extension without touching its source directory
+    Unlink {
+        /// Extension ID
+        extension_id: String,
+    },
     /// Relink an installed symlinked extension to a new local source path
     Relink {
         /// Extension ID
@@ -167,6 +183,9 @@ enum ExtensionCommand {
         /// JSON array of selected data rows
         #[arg(long)]
         data: Option<String>,
+        /// JSON payload passed to the action (inline, @file, or - for stdin)
+        #[arg(long)]
+        payload: Option<String>,
     },
     /// Run a tool from a extension's vendor directory
     Exec {
@@ -236,6 +255,10 @@ pub fn run(
             id,
             revision,
         } => refresh_extension(&source, id.as_deref(), revision.as_deref()),
+        ExtensionCommand::Link { source, id, force } => {
+            link_extension(&source, id.as_deref(), force)
+        }
+        ExtensionCommand::Unlink { extension_id } => unlink_extension(&extension_id),
         ExtensionCommand::Relink {
             extension_id,
             source,
@@ -260,7 +283,8 @@ pub fn run(
             action_id,
             project,
             data,
-        } => run_action(&extension_id, &action_id, project, data),
+            payload,
+        } => run_action(&extension_id, &action_id, project, data, payload),
         ExtensionCommand::Exec {
             extension_id,
             component,
@@ -374,6 +398,27 @@ pub enum ExtensionOutput {
         #[serde(skip_serializing_if = "Option::is_none")]
         source_revision: Option<String>,
     },
+    #[serde(rename = "extension.link")]
+    Link {
+        extension_id: String,
+        source: String,
+        path: String,
+        manifest_path: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        previous_path: Option<String>,
+        shadowed_install: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        backup_path: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source_revision: Option<String>,
+    },
+    #[serde(rename = "extension.unlink")]
+    Unlink {
+        extension_id: String,
+        source: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        restored_install: Option<String>,
+    },
     #[serde(rename = "extension.install_for_component")]
     InstallForComponent {
         component_id: String,
@@ -658,6 +703,7 @@ fn runner_diff_installed(
                 exit_code: output.status.code().unwrap_or(1),
                 timed_out: false,
                 child_resource: None,
+                combined: None,
             }
         }
         RunnerKind::Ssh => {
@@ -1052,6 +1098,43 @@ fn refresh_extension(
     ))
 }
 
+fn link_extension(source: &str, id: Option<&str>, force: bool) -> CmdResult<ExtensionOutput> {
+    let result = homeboy_extension::link(source, id, force)?;
+
+    Ok((
+        ExtensionOutput::Link {
+            extension_id: result.extension_id,
+            source: result.source,
+            path: result.path.to_string_lossy().to_string(),
+            manifest_path: result.manifest_path.to_string_lossy().to_string(),
+            previous_path: result
+                .previous_path
+                .map(|path| path.to_string_lossy().to_string()),
+            shadowed_install: result.shadowed_install,
+            backup_path: result
+                .backup_path
+                .map(|path| path.to_string_lossy().to_string()),
+            source_revision: result.source_revision,
+        },
+        0,
+    ))
+}
+
+fn unlink_extension(extension_id: &str) -> CmdResult<ExtensionOutput> {
+    let result = homeboy_extension::unlink(extension_id)?;
+
+    Ok((
+        ExtensionOutput::Unlink {
+            extension_id: extension_id.to_string(),
+            source: result.source.to_string_lossy().to_string(),
+            restored_install: result
+                .restored_install
+                .map(|path| path.to_string_lossy().to_string()),
+        },
+        0,
+    ))
+}
+
 fn relink_extension(extension_id: &str, source: &str) -> CmdResult<ExtensionOutput> {
     let result = homeboy_extension::relink(extension_id, source)?;
 
@@ -1316,12 +1399,27 @@ fn run_action(
     action_id: &str,
     project_id: Option<String>,
     data: Option<String>,
+    payload: Option<String>,
 ) -> CmdResult<ExtensionOutput> {
+    let payload = payload
+        .map(|spec| {
+            let raw = homeboy::core::config::read_json_spec_to_string(&spec)?;
+            serde_json::from_str::<serde_json::Value>(&raw).map_err(|e| {
+                homeboy::core::Error::validation_invalid_argument(
+                    "payload",
+                    e.to_string(),
+                    None,
+                    None,
+                )
+            })
+        })
+        .transpose()?;
     let response = homeboy_extension::run_action(
         extension_id,
         action_id,
         project_id.as_deref(),
         data.as_deref(),
+        payload.as_ref(),
     )?;
 
     Ok((
@@ -25,6 +25,13 @@ pub(crate) enum FileCommand {
         /// Remote directory path
         path: String,
     },
+    /// Show one file's size, permissions, owner, group, and modification time
+    Stat {
+        /// Project ID
+        project_id: String,
+        /// Remote file path
+        path: String,
+    },
     /// Read file content
     Read {
         /// Project ID
@@ -34,6 +41,15 @@ pub(crate) enum FileCommand {
         /// Output raw content only (no JSON wrapper)
         #[arg(long)]
         raw: bool,
+        /// Read only the first N lines
+        #[arg(long, value_name = "LINES", conflicts_with = "tail")]
+        head: Option<u32>,
+        /// Read only the last N lines
+        #[arg(long, value_name = "LINES")]
+        tail: Option<u32>,
+        /// Cap the content at N bytes (applied after --head/--tail)
+        #[arg(long, value_name = "BYTES")]
+        max_bytes: Option<u64>,
     },
     /// Write content to file (from stdin)
     Write {
@@ -41,6 +57,15 @@ pub(crate) enum FileCommand {
         project_id: String,
         /// Remote file path
         path: String,
+        /// Create missing parent directories before writing (like `mkdir -p`)
+        #[arg(short, long)]
+        parents: bool,
+        /// Append to the file instead of overwriting it
+        #[arg(long)]
+        append: bool,
+        /// Write directly to the target instead of a temp file renamed into place
+        #[arg(long)]
+        no_atomic: bool,
         /// Apply the destructive write. Without this flag, prints a plan only.
         #[arg(long)]
         apply: bool,
@@ -51,10 +76,23 @@ pub(crate) enum FileCommand {
         project_id: String,
         /// Remote directory path
         path: String,
+        /// Create missing parent directories (like `mkdir -p`)
+        #[arg(short, long)]
+        parents: bool,
         /// Apply the directory creation. Without this flag, prints a plan only.
         #[arg(long)]
         apply: bool,
     },
+    /// Create an empty file or update its timestamps
+    Touch {
+        /// Project ID
+        project_id: String,
+        /// Remote file path
+        path: String,
+        /// Apply the file creation. Without this flag, prints a plan only.
+        #[arg(long)]
+        apply: bool,
+    },
     /// Delete a file or directory
     Delete {
         /// Project ID
@@ -80,6 +118,22 @@ pub(crate) enum FileCommand {
         #[arg(long)]
         apply: bool,
     },
+    /// Copy a file or directory within the project's server
+    #[command(visible_alias = "cp")]
+    Duplicate {
+        /// Project ID
+        project_id: String,
+        /// Source path
+        source_path: String,
+        /// Destination path (an existing file is overwritten)
+        destination_path: String,
+        /// Copy directories recursively
+        #[arg(short, long)]
+        recursive: bool,
+        /// Apply the copy. Without this flag, prints a plan only.
+        #[arg(long)]
+        apply: bool,
+    },
     /// Find files by name pattern
     Find {
         /// Project ID
@@ -113,6 +167,34 @@ pub(crate) enum FileCommand {
         /// Case insensitive search
         #[arg(short = 'i', long)]
         ignore_case: bool,
+        /// Match the pattern as a literal string instead of a regex
+        #[arg(short = 'F', long)]
+        fixed: bool,
+        /// Lines of context before each match
+        #[arg(short = 'B', long, value_name = "N")]
+        before: Option<u32>,
+        /// Lines of context after each match
+        #[arg(short = 'A', long, value_name = "N")]
+        after: Option<u32>,
+        /// Lines of context before and after each match (-A/-B take precedence)
+        #[arg(short = 'C', long, value_name = "N")]
+        context: Option<u32>,
+    },
+    /// Find files by name whose contents match a pattern (one round trip)
+    Search {
+        /// Project ID
+        project_id: String,
+        /// Directory path to search
+        path: String,
+        /// Filename pattern (glob, e.g., "*.php")
+        #[arg(long)]
+        name: Option<String>,
+        /// Content pattern the file must contain
+        #[arg(long)]
+        pattern: String,
+        /// Case insensitive content match
+        #[arg(short = 'i', long)]
+        ignore_case: bool,
     },
     /// Download a file or directory from remote server
     Download {
@@ -127,6 +209,21 @@ pub(crate) enum FileCommand {
         #[arg(short, long)]
         recursive: bool,
     },
+    /// Upload a local file or directory to the project's server
+    Upload {
+        /// Project ID
+        project_id: String,
+        /// Local source path
+        local_path: String,
+        /// Remote destination path
+        path: String,
+        /// Upload directories recursively
+        #[arg(short, long)]
+        recursive: bool,
+        /// Apply the upload. Without this flag, prints a plan only.
+        #[arg(long)]
+        apply: bool,
+    },
     /// Copy a file or path between local and remote targets
     Copy(TransferArgs),
     /// Sync a directory between local and remote targets without deleting extras
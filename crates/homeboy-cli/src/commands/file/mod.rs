@@ -11,13 +11,12 @@ mod output;
 pub use args::FileArgs;
 pub use output::{
     FileCommandOutput, FileDownloadOutput, FileEditOutput, FileFindOutput, FileGrepOutput,
-    FileOutput,
+    FileOutput, FileSearchOutput, FileStatOutput, FileUploadOutput,
 };
 
 use args::{EditArgs, FileCommand};
 
 use homeboy::core::context::require_project_base_path;
-use homeboy::core::engine::{command, executor, shell};
 use homeboy::core::project::files;
 use homeboy::core::server::transfer::{self, TransferConfig, TransferOutput};
 use homeboy::core::{join_remote_path, project};
@@ -34,33 +33,69 @@ pub fn run(args: FileArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<F
             let (out, code) = list(&project_id, &path)?;
             Ok((FileCommandOutput::Standard(out), code))
         }
+        FileCommand::Stat { project_id, path } => {
+            let stat = files::stat(&project_id, &path)?;
+            Ok((
+                FileCommandOutput::Stat(FileStatOutput {
+                    command: "file.stat".to_string(),
+                    project_id,
+                    stat,
+                }),
+                0,
+            ))
+        }
         FileCommand::Read {
             project_id,
             path,
             raw,
+            head,
+            tail,
+            max_bytes,
         } => {
+            let options = files::ReadOptions {
+                head,
+                tail,
+                max_bytes,
+            };
             if raw {
-                let result = files::read(&project_id, &path)?;
+                let result = files::read_with_options(&project_id, &path, options)?;
                 Ok((FileCommandOutput::Raw(result.content), 0))
             } else {
-                let (out, code) = read(&project_id, &path)?;
+                let (out, code) = read(&project_id, &path, options)?;
                 Ok((FileCommandOutput::Standard(out), code))
             }
         }
         FileCommand::Write {
             project_id,
             path,
+            parents,
+            append,
+            no_atomic,
             apply,
         } => {
-            let (out, code) = write(&project_id, &path, apply)?;
+            let options = files::WriteOptions {
+                create_parents: parents,
+                append,
+                atomic: !no_atomic,
+            };
+            let (out, code) = write(&project_id, &path, options, apply)?;
             Ok((FileCommandOutput::Standard(out), code))
         }
         FileCommand::Mkdir {
+            project_id,
+            path,
+            parents,
+            apply,
+        } => {
+            let (out, code) = mkdir(&project_id, &path, parents, apply)?;
+            Ok((FileCommandOutput::Standard(out), code))
+        }
+        FileCommand::Touch {
             project_id,
             path,
             apply,
         } => {
-            let (out, code) = mkdir(&project_id, &path, apply)?;
+            let (out, code) = touch(&project_id, &path, apply)?;
             Ok((FileCommandOutput::Standard(out), code))
         }
         FileCommand::Delete {
@@ -81,6 +116,22 @@ pub fn run(args: FileArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<F
             let (out, code) = rename(&project_id, &old_path, &new_path, apply)?;
             Ok((FileCommandOutput::Standard(out), code))
         }
+        FileCommand::Duplicate {
+            project_id,
+            source_path,
+            destination_path,
+            recursive,
+            apply,
+        } => {
+            let (out, code) = duplicate(
+                &project_id,
+                &source_path,
+                &destination_path,
+                recursive,
+                apply,
+            )?;
+            Ok((FileCommandOutput::Standard(out), code))
+        }
         FileCommand::Find {
             project_id,
             path,
@@ -104,6 +155,10 @@ pub fn run(args: FileArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<F
             name,
             max_depth,
             ignore_case,
+            fixed,
+            before,
+            after,
+            context,
         } => {
             let (out, code) = grep(
                 &project_id,
@@ -112,9 +167,22 @@ pub fn run(args: FileArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<F
                 name.as_deref(),
                 max_depth,
                 ignore_case,
+                fixed,
+                before.or(context),
+                after.or(context),
             )?;
             Ok((FileCommandOutput::Grep(out), code))
         }
+        FileCommand::Search {
+            project_id,
+            path,
+            name,
+            pattern,
+            ignore_case,
+        } => {
+            let (out, code) = search(&project_id, &path, name.as_deref(), &pattern, ignore_case)?;
+            Ok((FileCommandOutput::Search(out), code))
+        }
         FileCommand::Download {
             project_id,
             path,
@@ -129,12 +197,23 @@ pub fn run(args: FileArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<F
                 remote_path: result.remote_path,
                 local_path: result.local_path,
                 recursive: result.recursive,
+                bytes: result.bytes,
                 success: result.success,
                 exit_code: result.exit_code,
                 error: result.error,
             };
             Ok((FileCommandOutput::Download(out), code))
         }
+        FileCommand::Upload {
+            project_id,
+            local_path,
+            path,
+            recursive,
+            apply,
+        } => {
+            let (out, code) = upload(&project_id, &local_path, &path, recursive, apply)?;
+            Ok((FileCommandOutput::Upload(out), code))
+        }
         FileCommand::Copy(args) => transfer_command(args.into_config()),
         FileCommand::Sync(args) => transfer_command(args.into_config()),
         FileCommand::Edit(args) => {
@@ -169,6 +248,10 @@ fn list(project_id: &str, path: &str) -> CmdResult<FileOutput> {
             content: None,
             size: None,
             bytes_written: None,
+            parents_created: None,
+            appended: None,
+            atomic: None,
+            truncated: None,
             dry_run: false,
             action_required: None,
             stdout: None,
@@ -180,8 +263,8 @@ fn list(project_id: &str, path: &str) -> CmdResult<FileOutput> {
     ))
 }
 
-fn read(project_id: &str, path: &str) -> CmdResult<FileOutput> {
-    let result = files::read(project_id, path)?;
+fn read(project_id: &str, path: &str, options: files::ReadOptions) -> CmdResult<FileOutput> {
+    let result = files::read_with_options(project_id, path, options)?;
 
     Ok((
         FileOutput {
@@ -196,6 +279,10 @@ fn read(project_id: &str, path: &str) -> CmdResult<FileOutput> {
             content: Some(result.content),
             size: result.size,
             bytes_written: None,
+            parents_created: None,
+            appended: None,
+            atomic: None,
+            truncated: Some(result.truncated),
             dry_run: false,
             action_required: None,
             stdout: None,
@@ -207,7 +294,12 @@ fn read(project_id: &str, path: &str) -> CmdResult<FileOutput> {
     ))
 }
 
-fn write(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
+fn write(
+    project_id: &str,
+    path: &str,
+    options: files::WriteOptions,
+    apply: bool,
+) -> CmdResult<FileOutput> {
     let content = files::read_stdin()?;
     if !apply {
         let project = project::load(project_id)?;
@@ -227,6 +319,10 @@ fn write(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
                 content: None,
                 size: None,
                 bytes_written: Some(content.len()),
+                parents_created: None,
+                appended: Some(options.append),
+                atomic: Some(options.atomic),
+                truncated: None,
                 dry_run: true,
                 action_required: Some(
                     "Re-run with --apply to write stdin to the remote file.".to_string(),
@@ -239,7 +335,7 @@ fn write(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
             0,
         ));
     }
-    let result = files::write(project_id, path, &content)?;
+    let result = files::write_with_options(project_id, path, &content, options)?;
 
     Ok((
         FileOutput {
@@ -254,6 +350,10 @@ fn write(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
             content: None,
             size: None,
             bytes_written: Some(result.bytes_written),
+            parents_created: Some(result.parents_created),
+            appended: Some(result.appended),
+            atomic: Some(result.atomic),
+            truncated: None,
             dry_run: false,
             action_required: None,
             stdout: None,
@@ -284,6 +384,10 @@ fn delete(project_id: &str, path: &str, recursive: bool, apply: bool) -> CmdResu
                 content: None,
                 size: None,
                 bytes_written: None,
+                parents_created: None,
+                appended: None,
+                atomic: None,
+                truncated: None,
                 dry_run: true,
                 action_required: Some("Re-run with --apply to delete the remote path.".to_string()),
                 stdout: None,
@@ -309,6 +413,10 @@ fn delete(project_id: &str, path: &str, recursive: bool, apply: bool) -> CmdResu
             content: None,
             size: None,
             bytes_written: None,
+            parents_created: None,
+            appended: None,
+            atomic: None,
+            truncated: None,
             dry_run: false,
             action_required: None,
             stdout: None,
@@ -320,12 +428,12 @@ fn delete(project_id: &str, path: &str, recursive: bool, apply: bool) -> CmdResu
     ))
 }
 
-fn mkdir(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
-    let project = project::load(project_id)?;
-    let project_base_path = require_project_base_path(project_id, &project)?;
-    let full_path = join_remote_path(Some(&project_base_path), path)?;
-
+fn mkdir(project_id: &str, path: &str, parents: bool, apply: bool) -> CmdResult<FileOutput> {
     if !apply {
+        let project = project::load(project_id)?;
+        let project_base_path = require_project_base_path(project_id, &project)?;
+        let full_path = join_remote_path(Some(&project_base_path), path)?;
+
         return Ok((
             FileOutput {
                 command: "file.mkdir".to_string(),
@@ -339,6 +447,10 @@ fn mkdir(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
                 content: None,
                 size: None,
                 bytes_written: None,
+                parents_created: None,
+                appended: None,
+                atomic: None,
+                truncated: None,
                 dry_run: true,
                 action_required: Some(
                     "Re-run with --apply to create the remote directory.".to_string(),
@@ -352,18 +464,78 @@ fn mkdir(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
         ));
     }
 
-    let output = executor::execute_for_project(
-        &project,
-        &format!("mkdir {}", shell::quote_path(&full_path)),
-    )?;
-    command::require_success(output.success, &output.stderr, "MKDIR")?;
+    let result = files::mkdir(project_id, path, parents)?;
 
     Ok((
         FileOutput {
             command: "file.mkdir".to_string(),
             project_id: project_id.to_string(),
-            base_path: Some(project_base_path),
-            path: Some(full_path),
+            base_path: result.base_path,
+            path: Some(result.path),
+            old_path: None,
+            new_path: None,
+            recursive: None,
+            entries: None,
+            content: None,
+            size: None,
+            bytes_written: None,
+            parents_created: None,
+            appended: None,
+            atomic: None,
+            truncated: None,
+            dry_run: false,
+            action_required: None,
+            stdout: None,
+            stderr: None,
+            exit_code: 0,
+            success: true,
+        },
+        0,
+    ))
+}
+
+fn touch(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
+    if !apply {
+        let project = project::load(project_id)?;
+        let project_base_path = require_project_base_path(project_id, &project)?;
+        let full_path = join_remote_path(Some(&project_base_path), path)?;
+
+        return Ok((
+            FileOutput {
+                command: "file.touch".to_string(),
+                project_id: project_id.to_string(),
+                base_path: Some(project_base_path),
+                path: Some(full_path),
+                old_path: None,
+                new_path: None,
+                recursive: None,
+                entries: None,
+                content: None,
+                size: None,
+                bytes_written: None,
+                parents_created: None,
+                appended: None,
+                atomic: None,
+                truncated: None,
+                dry_run: true,
+                action_required: Some("Re-run with --apply to touch the remote file.".to_string()),
+                stdout: None,
+                stderr: None,
+                exit_code: 0,
+                success: true,
+            },
+            0,
+        ));
+    }
+
+    let result = files::touch(project_id, path)?;
+
+    Ok((
+        FileOutput {
+            command: "file.touch".to_string(),
+            project_id: project_id.to_string(),
+            base_path: result.base_path,
+            path: Some(result.path),
             old_path: None,
             new_path: None,
             recursive: None,
@@ -371,6 +543,10 @@ fn mkdir(project_id: &str, path: &str, apply: bool) -> CmdResult<FileOutput> {
             content: None,
             size: None,
             bytes_written: None,
+            parents_created: None,
+            appended: None,
+            atomic: None,
+            truncated: None,
             dry_run: false,
             action_required: None,
             stdout: None,
@@ -402,6 +578,10 @@ fn rename(project_id: &str, old_path: &str, new_path: &str, apply: bool) -> CmdR
                 content: None,
                 size: None,
                 bytes_written: None,
+                parents_created: None,
+                appended: None,
+                atomic: None,
+                truncated: None,
                 dry_run: true,
                 action_required: Some(
                     "Re-run with --apply to rename or move the remote path.".to_string(),
@@ -430,6 +610,132 @@ fn rename(project_id: &str, old_path: &str, new_path: &str, apply: bool) -> CmdR
             content: None,
             size: None,
             bytes_written: None,
+            parents_created: None,
+            appended: None,
+            atomic: None,
+            truncated: None,
+            dry_run: false,
+            action_required: None,
+            stdout: None,
+            stderr: None,
+            exit_code: 0,
+            success: true,
+        },
+        0,
+    ))
+}
+
+fn upload(
+    project_id: &str,
+    local_path: &str,
+    path: &str,
+    recursive: bool,
+    apply: bool,
+) -> CmdResult<FileUploadOutput> {
+    if !apply {
+        let project = project::load(project_id)?;
+        let project_base_path = require_project_base_path(project_id, &project)?;
+        let full_path = join_remote_path(Some(&project_base_path), path)?;
+
+        return Ok((
+            FileUploadOutput {
+                command: "file.upload".to_string(),
+                project_id: project_id.to_string(),
+                local_path: local_path.to_string(),
+                remote_path: full_path,
+                recursive,
+                bytes: None,
+                dry_run: true,
+                action_required: Some("Re-run with --apply to upload the file.".to_string()),
+                success: true,
+                exit_code: 0,
+                error: None,
+            },
+            0,
+        ));
+    }
+
+    let result = files::upload(project_id, local_path, path, recursive)?;
+    let code = result.exit_code;
+
+    Ok((
+        FileUploadOutput {
+            command: "file.upload".to_string(),
+            project_id: project_id.to_string(),
+            local_path: result.local_path,
+            remote_path: result.remote_path,
+            recursive: result.recursive,
+            bytes: result.bytes,
+            dry_run: false,
+            action_required: None,
+            success: result.success,
+            exit_code: result.exit_code,
+            error: result.error,
+        },
+        code,
+    ))
+}
+
+fn duplicate(
+    project_id: &str,
+    source_path: &str,
+    destination_path: &str,
+    recursive: bool,
+    apply: bool,
+) -> CmdResult<FileOutput> {
+    if !apply {
+        let project = project::load(project_id)?;
+        let project_base_path = require_project_base_path(project_id, &project)?;
+        let full_source = join_remote_path(Some(&project_base_path), source_path)?;
+        let full_destination = join_remote_path(Some(&project_base_path), destination_path)?;
+
+        return Ok((
+            FileOutput {
+                command: "file.duplicate".to_string(),
+                project_id: project_id.to_string(),
+                base_path: Some(project_base_path),
+                path: None,
+                old_path: Some(full_source),
+                new_path: Some(full_destination),
+                recursive: Some(recursive),
+                entries: None,
+                content: None,
+                size: None,
+                bytes_written: None,
+                parents_created: None,
+                appended: None,
+                atomic: None,
+                truncated: None,
+                dry_run: true,
+                action_required: Some("Re-run with --apply to copy the remote path.".to_string()),
+                stdout: None,
+                stderr: None,
+                exit_code: 0,
+                success: true,
+            },
+            0,
+        ));
+    }
+
+    let result = files::copy(project_id, source_path, destination_path, recursive)?;
+
+    Ok((
+        FileOutput {
+            command: "file.duplicate".to_string(),
+            project_id: project_id.to_string(),
+            base_path: result.base_path,
+            path: None,
+            old_path: Some(result.source_path),
+            new_path: Some(result.destination_path),
+            recursive: Some(result.recursive),
+            entries: None,
+            content: None,
+            size: None,
+            bytes_written: None,
+            parents_created: None,
+            appended: None,
+            atomic: None,
+            truncated: None,
             dry_run: false,
             action_required: None,
             stdout: None,
@@ -465,6 +771,7 @@ fn find(
     ))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn grep(
     project_id: &str,
     path: &str,
@@ -472,6 +779,9 @@ fn grep(
     name_filter: Option<&str>,
     max_depth: Option<u32>,
     case_insensitive: bool,
+    fixed_strings: bool,
+    before: Option<u32>,
+    after: Option<u32>,
 ) -> CmdResult<FileGrepOutput> {
     let result = files::grep(
         project_id,
@@ -480,8 +790,11 @@ fn grep(
         name_filter,
         max_depth,
         case_insensitive,
+        fixed_strings,
+        before,
+        after,
     )?;
-    let match_count = result.matches.len();
+    let match_count = result.matches.iter().filter(|m| !m.is_context).count();
 
     Ok((
         FileGrepOutput {
@@ -497,6 +810,31 @@ fn grep(
     ))
 }
 
+fn search(
+    project_id: &str,
+    path: &str,
+    name_pattern: Option<&str>,
+    pattern: &str,
+    case_insensitive: bool,
+) -> CmdResult<FileSearchOutput> {
+    let result = files::search(project_id, path, name_pattern, pattern, case_insensitive)?;
+    let file_count = result.files.len();
+
+    Ok((
+        FileSearchOutput {
+            command: "file.search".to_string(),
+            project_id: project_id.to_string(),
+            base_path: result.base_path,
+            path: result.path,
+            name_pattern: result.name_pattern,
+            pattern: result.pattern,
+            files: result.files,
+            file_count,
+        },
+        0,
+    ))
+}
+
 fn edit(args: EditArgs) -> CmdResult<FileEditOutput> {
     let EditArgs {
         project_id,
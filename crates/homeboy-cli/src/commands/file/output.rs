@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use homeboy::core::project::files::{FileEntry, GrepMatch, LineChange};
+use homeboy::core::project::files::{FileEntry, GrepMatch, LineChange, StatResult};
 use homeboy::core::server::transfer::TransferOutput;
 
 #[derive(Serialize)]
@@ -16,6 +16,14 @@ pub struct FileOutput {
     pub(crate) content: Option<String>,
     pub(crate) size: Option<i64>,
     pub(crate) bytes_written: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parents_created: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) appended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) atomic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) truncated: Option<bool>,
     #[serde(skip_serializing_if = "is_false")]
     pub(crate) dry_run: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,6 +38,14 @@ fn is_false(value: &bool) -> bool {
     !*value
 }
 
+#[derive(Serialize)]
+pub struct FileStatOutput {
+    pub(crate) command: String,
+    pub(crate) project_id: String,
+    #[serde(flatten)]
+    pub(crate) stat: StatResult,
+}
+
 #[derive(Serialize)]
 pub struct FileFindOutput {
     pub(crate) command: String,
@@ -52,6 +68,18 @@ pub struct FileGrepOutput {
     pub(crate) match_count: usize,
 }
 
+#[derive(Serialize)]
+pub struct FileSearchOutput {
+    pub(crate) command: String,
+    pub(crate) project_id: String,
+    pub(crate) base_path: Option<String>,
+    pub(crate) path: String,
+    pub(crate) name_pattern: Option<String>,
+    pub(crate) pattern: String,
+    pub(crate) files: Vec<String>,
+    pub(crate) file_count: usize,
+}
+
 #[derive(Serialize)]
 pub struct FileEditOutput {
     pub(crate) command: String,
@@ -73,6 +101,26 @@ pub struct FileDownloadOutput {
     pub(crate) remote_path: String,
     pub(crate) local_path: String,
     pub(crate) recursive: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bytes: Option<u64>,
+    pub(crate) success: bool,
+    pub(crate) exit_code: i32,
+    pub(crate) error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct FileUploadOutput {
+    pub(crate) command: String,
+    pub(crate) project_id: String,
+    pub(crate) local_path: String,
+    pub(crate) remote_path: String,
+    pub(crate) recursive: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bytes: Option<u64>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub(crate) dry_run: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) action_required: Option<String>,
     pub(crate) success: bool,
     pub(crate) exit_code: i32,
     pub(crate) error: Option<String>,
@@ -82,10 +130,13 @@ pub struct FileDownloadOutput {
 #[serde(untagged)]
 pub enum FileCommandOutput {
     Standard(FileOutput),
+    Stat(FileStatOutput),
     Find(FileFindOutput),
     Grep(FileGrepOutput),
+    Search(FileSearchOutput),
     Edit(FileEditOutput),
     Download(FileDownloadOutput),
+    Upload(FileUploadOutput),
     Transfer(TransferOutput),
     Raw(String),
 }
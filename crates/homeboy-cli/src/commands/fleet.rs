@@ -9,6 +9,7 @@ use homeboy::core::plan::HomeboyPlan;
 use homeboy::core::project::Project;
 use homeboy::core::EntityCrudOutput;
 
+use super::utils::args::ListQueryArgs;
 use super::{adapter, CmdResult, DynamicSetArgs};
 use crate::command_contract::{CommandJsonFamily, CommandOutputFileMode, LabCommandContract};
 
@@ -57,6 +58,19 @@ enum FleetCommand {
     Set {
         #[command(flatten)]
         args: DynamicSetArgs,
+
+        /// Set the fleet description (merged with any --json fields)
+        #[arg(long, short = 'd')]
+        description: Option<String>,
+    },
+    /// Rename a fleet (changes ID)
+    ///
+    /// No other entity stores fleet IDs, so nothing else needs updating.
+    Rename {
+        /// Current fleet ID
+        id: String,
+        /// New fleet ID
+        new_id: String,
     },
     /// Delete a fleet
     Delete {
@@ -64,7 +78,12 @@ enum FleetCommand {
         id: String,
     },
     /// List all fleets
-    List,
+    ///
+    /// Sort/filter keys: name, description, project (member project ID)
+    List {
+        #[command(flatten)]
+        query: ListQueryArgs,
+    },
     /// Add a project to a fleet
     Add {
         /// Fleet ID
@@ -114,6 +133,10 @@ enum FleetCommand {
         /// Only show components that need updates
         #[arg(long)]
         outdated: bool,
+
+        /// Only report this component (projects that do not use it are omitted)
+        #[arg(long, value_name = "COMPONENT_ID")]
+        component: Option<String>,
     },
     /// Run a command across all projects in a fleet via SSH
     Exec {
@@ -173,9 +196,10 @@ pub fn run(args: FleetArgs, _global: &super::GlobalArgs) -> CmdResult<FleetOutpu
             description,
         } => create(&id, projects.unwrap_or_default(), description),
         FleetCommand::Show { id } => show(&id),
-        FleetCommand::Set { args } => set(args),
+        FleetCommand::Set { args, description } => set(args, description),
+        FleetCommand::Rename { id, new_id } => rename(&id, &new_id),
         FleetCommand::Delete { id } => delete(&id),
-        FleetCommand::List => list(),
+        FleetCommand::List { query } => list(&query),
         FleetCommand::Add { id, project } => add(&id, &project),
         FleetCommand::Remove { id, project } => remove(&id, &project),
         FleetCommand::Projects { id } => projects(&id),
@@ -185,7 +209,11 @@ pub fn run(args: FleetArgs, _global: &super::GlobalArgs) -> CmdResult<FleetOutpu
             cached,
             health_only,
         } => status(&id, cached, health_only),
-        FleetCommand::Check { id, outdated } => check(&id, outdated),
+        FleetCommand::Check {
+            id,
+            outdated,
+            component,
+        } => check(&id, outdated, component.as_deref()),
         FleetCommand::Exec {
             id,
             command,
@@ -259,18 +287,37 @@ fn show(id: &str) -> CmdResult<FleetOutput> {
     ))
 }
 
-fn set(args: DynamicSetArgs) -> CmdResult<FleetOutput> {
-    let merged = super::merge_dynamic_args(&args)?.ok_or_else(|| {
-        homeboy::core::Error::validation_invalid_argument(
-            "spec",
-            "Provide --json '<object>' or --base64 <encoded-json>",
-            None,
-            Some(vec![
-                "Arbitrary fleet updates must use explicit JSON input.".to_string(),
-                "Example: homeboy fleet set <id> --json '{\"projects\":[\"site\"]}'".to_string(),
-            ]),
-        )
-    })?;
+fn set(args: DynamicSetArgs, description: Option<String>) -> CmdResult<FleetOutput> {
+    let mut merged = match super::merge_dynamic_args(&args)? {
+        Some(merged) => merged,
+        None if description.is_some() => serde_json::json!({}),
+        None => {
+            return Err(homeboy::core::Error::validation_invalid_argument(
+                "spec",
+                "Provide --description, --json '<object>', or --base64 <encoded-json>",
+                None,
+                Some(vec![
+                    "Arbitrary fleet updates must use explicit JSON input.".to_string(),
+                    "Example: homeboy fleet set <id> --json '{\"project_ids\":[\"site\"]}'"
+                        .to_string(),
+                ]),
+            ))
+        }
+    };
+    if let Some(description) = description {
+        let object = merged.as_object_mut().ok_or_else(|| {
+            homeboy::core::Error::validation_invalid_argument(
+                "json",
+                "Fleet set JSON must be an object",
+                None,
+                None,
+            )
+        })?;
+        object.insert(
+            "description".to_string(),
+            serde_json::Value::String(description),
+        );
+    }
     let (json_string, replace_fields) = super::finalize_set_spec(&merged, &args.replace)?;
 
     match fleet::merge(args.id.as_deref(), &json_string, &replace_fields)? {
@@ -293,6 +340,21 @@ fn set(args: DynamicSetArgs) -> CmdResult<FleetOutput> {
     }
 }
 
+fn rename(id: &str, new_id: &str) -> CmdResult<FleetOutput> {
+    let fl = fleet::rename(id, new_id)?;
+
+    Ok((
+        FleetOutput {
+            command: "fleet.rename".to_string(),
+            id: Some(fl.id.clone()),
+            entity: Some(fl),
+            updated_fields: vec!["id".to_string()],
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
 fn delete(id: &str) -> CmdResult<FleetOutput> {
     fleet::delete(id)?;
 
@@ -307,8 +369,13 @@ fn delete(id: &str) -> CmdResult<FleetOutput> {
     ))
 }
 
-fn list() -> CmdResult<FleetOutput> {
-    let fleets = fleet::list()?;
+fn list(query: &ListQueryArgs) -> CmdResult<FleetOutput> {
+    let mut fleets = fleet::list()?;
+    query.apply(
+        &mut fleets,
+        fleet::LIST_QUERY_KEYS,
+        fleet::list_query_values,
+    )?;
 
     Ok((
         FleetOutput {
@@ -508,8 +575,8 @@ fn log_fleet_dashboard(result: &FleetStatusResult) {
     }
 }
 
-fn check(id: &str, only_outdated: bool) -> CmdResult<FleetOutput> {
-    let (project_checks, summary, exit_code) = fleet::collect_check(id, only_outdated)?;
+fn check(id: &str, only_outdated: bool, component: Option<&str>) -> CmdResult<FleetOutput> {
+    let (project_checks, summary, exit_code) = fleet::collect_check(id, only_outdated, component)?;
 
     Ok((
         FleetOutput {
@@ -1,6 +1,6 @@
 use clap::{Args, Subcommand};
 
-use homeboy::core::git::{self, CherryPickOptions, PushOptions, RebaseOptions};
+use homeboy::core::git::{self, CherryPickOptions, DiffOptions, PushOptions, RebaseOptions};
 
 use crate::commands::release::version;
 
@@ -233,6 +233,88 @@ enum GitCommand {
         #[arg(short, long)]
         message: Option<String>,
 
+        /// List tags instead of creating one, optionally filtered by a glob
+        /// pattern (e.g. `--list 'v1.*'`)
+        #[arg(
+            long,
+            value_name = "PATTERN",
+            num_args = 0..=1,
+            conflicts_with_all = ["tag_name", "message", "delete"]
+        )]
+        list: Option<Option<String>>,
+
+        /// Delete this tag instead of creating one
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["tag_name", "message"])]
+        delete: Option<String>,
+
+        /// With --delete, also delete the tag on the default remote
+        /// (`git push <remote> :refs/tags/<name>`)
+        #[arg(long, requires = "delete")]
+        remote: bool,
+
+        /// Workspace path to operate on directly. Useful for unregistered
+        /// checkouts (CI runners, ad-hoc clones, worktrees).
+        #[arg(long, value_name = "PATH")]
+        path: Option<String>,
+    },
+    /// Show uncommitted changes as a raw diff
+    Diff {
+        /// Component ID. When omitted, the component is auto-detected from
+        /// CWD via the registry or a portable `homeboy.json`.
+        component_id: Option<String>,
+
+        /// Show changes staged for the next commit instead of the working tree
+        #[arg(long)]
+        staged: bool,
+
+        /// Show a per-file summary (`git diff --stat`) instead of the full patch
+        #[arg(long)]
+        stat: bool,
+
+        /// Workspace path to operate on directly. Useful for unregistered
+        /// checkouts (CI runners, ad-hoc clones, worktrees).
+        #[arg(long, value_name = "PATH")]
+        path: Option<String>,
+    },
+    /// List local branches, marking the checked-out one
+    Branch {
+        /// Component ID. When omitted, the component is auto-detected from
+        /// CWD via the registry or a portable `homeboy.json`.
+        component_id: Option<String>,
+
+        /// Workspace path to operate on directly. Useful for unregistered
+        /// checkouts (CI runners, ad-hoc clones, worktrees).
+        #[arg(long, value_name = "PATH")]
+        path: Option<String>,
+    },
+    /// Switch to another branch. Refuses when tracked files have
+    /// uncommitted changes.
+    ///
+    /// `homeboy git checkout <component> <branch>`, or
+    /// `homeboy git checkout <branch>` to auto-detect the component from CWD.
+    Checkout {
+        /// Component ID, or the branch when only one argument is given.
+        #[arg(value_name = "COMPONENT_ID")]
+        component_id: Option<String>,
+
+        /// Branch to check out.
+        branch: Option<String>,
+
+        /// Workspace path to operate on directly. Useful for unregistered
+        /// checkouts (CI runners, ad-hoc clones, worktrees).
+        #[arg(long, value_name = "PATH")]
+        path: Option<String>,
+    },
+    /// Show recent commits (hash, subject, author, date)
+    Log {
+        /// Component ID. When omitted, the component is auto-detected from
+        /// CWD via the registry or a portable `homeboy.json`.
+        component_id: Option<String>,
+
+        /// Number of commits to show
+        #[arg(short = 'n', long, default_value_t = git::DEFAULT_LOG_COUNT)]
+        count: usize,
+
         /// Workspace path to operate on directly. Useful for unregistered
         /// checkouts (CI runners, ad-hoc clones, worktrees).
         #[arg(long, value_name = "PATH")]
@@ -332,7 +414,7 @@ pub fn run(args: GitArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Gi
                 staged_only,
                 files: resolved_files,
                 exclude,
-                amend: false,
+                ..Default::default()
             };
             let output = git::commit_at(
                 component_id.as_deref(),
@@ -394,8 +476,25 @@ pub fn run(args: GitArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Gi
             component_id,
             tag_name,
             message,
+            list,
+            delete,
+            remote,
             path,
         } => {
+            if let Some(pattern) = list {
+                let output =
+                    git::tag_list_at(component_id.as_deref(), pattern.as_deref(), path.as_deref())?;
+                let exit_code = output.exit_code;
+                return Ok((GitCommandOutput::Single(output), exit_code));
+            }
+
+            if let Some(name) = delete {
+                let output =
+                    git::tag_delete_at(component_id.as_deref(), &name, remote, path.as_deref())?;
+                let exit_code = output.exit_code;
+                return Ok((GitCommandOutput::Single(output), exit_code));
+            }
+
             // Derive tag from version if not provided
             let final_tag = match tag_name {
                 Some(name) => name,
@@ -428,6 +527,55 @@ pub fn run(args: GitArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Gi
             let exit_code = output.exit_code;
             Ok((GitCommandOutput::Single(output), exit_code))
         }
+        GitCommand::Diff {
+            component_id,
+            staged,
+            stat,
+            path,
+        } => {
+            let output = git::diff_at(
+                component_id.as_deref(),
+                DiffOptions { staged, stat },
+                path.as_deref(),
+            )?;
+            let exit_code = output.exit_code;
+            Ok((GitCommandOutput::Single(output), exit_code))
+        }
+        GitCommand::Branch { component_id, path } => {
+            let output = git::branches_at(component_id.as_deref(), path.as_deref())?;
+            let exit_code = output.exit_code;
+            Ok((GitCommandOutput::Branch(output), exit_code))
+        }
+        GitCommand::Checkout {
+            component_id,
+            branch,
+            path,
+        } => {
+            let (component_id, branch) = match (component_id, branch) {
+                (component_id, Some(branch)) => (component_id, branch),
+                (Some(branch), None) => (None, branch),
+                (None, None) => {
+                    return Err(homeboy::core::Error::validation_invalid_argument(
+                        "branch",
+                        "Missing branch to check out",
+                        None,
+                        None,
+                    ))
+                }
+            };
+            let output = git::checkout_at(component_id.as_deref(), &branch, path.as_deref())?;
+            let exit_code = output.exit_code;
+            Ok((GitCommandOutput::Branch(output), exit_code))
+        }
+        GitCommand::Log {
+            component_id,
+            count,
+            path,
+        } => {
+            let output = git::log_at(component_id.as_deref(), count, path.as_deref())?;
+            let exit_code = output.exit_code;
+            Ok((GitCommandOutput::Log(output), exit_code))
+        }
         GitCommand::Rebase {
             component_id,
             onto,
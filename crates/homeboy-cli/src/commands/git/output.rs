@@ -1,15 +1,17 @@
 use serde::{Serialize, Serializer};
 
 use homeboy::core::git::{
-    GitOutput, GithubFindOutput, GithubIssueOutput, GithubPrFleetOutput, GithubPrOutput,
-    GithubPrReadinessOutput, PrLandOutput, PrMergeabilityReconcileOutput, PrPolicyDecision,
-    PrRefreshOutput,
+    GitBranchOutput, GitLogOutput, GitOutput, GithubFindOutput, GithubIssueOutput,
+    GithubPrFleetOutput, GithubPrOutput, GithubPrReadinessOutput, PrLandOutput,
+    PrMergeabilityReconcileOutput, PrPolicyDecision, PrRefreshOutput,
 };
 use homeboy::core::BulkResult;
 
 pub enum GitCommandOutput {
     Single(GitOutput),
     Bulk(BulkResult<GitOutput>),
+    Log(GitLogOutput),
+    Branch(GitBranchOutput),
     Issue(GithubIssueOutput),
     Pr(GithubPrOutput),
     PrRefresh(PrRefreshOutput),
@@ -29,6 +31,8 @@ impl Serialize for GitCommandOutput {
         let (variant, payload) = match self {
             GitCommandOutput::Single(output) => ("single", serde_json::to_value(output)),
             GitCommandOutput::Bulk(output) => ("bulk", serde_json::to_value(output)),
+            GitCommandOutput::Log(output) => ("log", serde_json::to_value(output)),
+            GitCommandOutput::Branch(output) => ("branch", serde_json::to_value(output)),
             GitCommandOutput::Issue(output) => ("issue", serde_json::to_value(output)),
             GitCommandOutput::Pr(output) => ("pr", serde_json::to_value(output)),
             GitCommandOutput::PrRefresh(output) => ("pr_refresh", serde_json::to_value(output)),
@@ -3,12 +3,22 @@ use serde::Serialize;
 
 use crate::commands::CmdResult;
 
+/// Leading flag that runs the tool with inherited stdio (e.g. `wp shell`)
+/// instead of capturing its output.
+pub const INTERACTIVE_FLAG: &str = "--interactive";
+
 pub struct CliArgs {
     pub tool: String,
     pub identifier: String,
     pub args: Vec<String>,
 }
 
+impl CliArgs {
+    pub fn is_interactive(&self) -> bool {
+        self.args.first().map(String::as_str) == Some(INTERACTIVE_FLAG)
+    }
+}
+
 #[derive(Serialize)]
 pub struct CliOutput {
     pub command: String,
@@ -28,3 +38,36 @@ pub fn run(args: CliArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Cl
         exit_code,
     ))
 }
+
+/// Run an `--interactive` invocation with inherited stdio. Returns the tool's
+/// exit code; there is no JSON envelope on success.
+pub fn run_interactive(args: CliArgs) -> homeboy::core::Result<i32> {
+    let command_args = if args.is_interactive() {
+        &args.args[1..]
+    } else {
+        &args.args[..]
+    };
+    cli_tool::run_interactive(&args.tool, &args.identifier, command_args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> CliArgs {
+        CliArgs {
+            tool: "wp".to_string(),
+            identifier: "site".to_string(),
+            args: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn interactive_flag_is_only_recognized_before_the_command() {
+        assert!(args(&["--interactive", "shell"]).is_interactive());
+        assert!(args(&["--interactive"]).is_interactive());
+        assert!(!args(&["shell", "--interactive"]).is_interactive());
+        assert!(!args(&["post", "list"]).is_interactive());
+        assert!(!args(&[]).is_interactive());
+    }
+}
@@ -24,14 +24,17 @@ pub enum LogsCommand {
     Show {
         /// Project ID
         project_id: String,
-        /// Log file path (optional - shows all pinned logs if omitted)
+        /// Log file path or pinned log label (optional - shows all pinned logs if omitted)
         path: Option<String>,
-        /// Number of lines to show
+        /// Number of lines to show (initial backlog when following)
         #[arg(short = 'n', long, default_value = "100")]
         lines: u32,
-        /// Follow log output (like tail -f)
+        /// Follow log output (like tail -f) until interrupted; on a terminal, lines are colorized by level
         #[arg(short, long)]
         follow: bool,
+        /// With --follow and no path, follow all pinned logs together, each line prefixed with its log's name
+        #[arg(long, requires = "follow", conflicts_with = "path")]
+        merge: bool,
         /// Execute locally instead of via SSH (for when running on the target server)
         #[arg(long)]
         local: bool,
@@ -82,14 +85,16 @@ pub fn run(args: LogsArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<L
             lines,
             follow,
             local,
+            ..
         } => show(&project_id, &path, lines, follow, local),
         LogsCommand::Show {
             project_id,
             path: None,
             lines,
             follow,
+            merge,
             local,
-        } => show_pinned(&project_id, lines, follow, local),
+        } => show_pinned(&project_id, lines, follow, merge, local),
         LogsCommand::Clear {
             project_id,
             path,
@@ -157,7 +162,8 @@ fn show(
     local: bool,
 ) -> CmdResult<LogsOutput> {
     if follow {
-        let code = logs::follow(project_id, path, local)?;
+        let colorize = std::io::IsTerminal::is_terminal(&std::io::stdout());
+        let code = logs::follow(project_id, path, lines, local, colorize)?;
 
         Ok((
             LogsOutput {
@@ -189,14 +195,38 @@ fn show(
     }
 }
 
-fn show_pinned(project_id: &str, lines: u32, follow: bool, local: bool) -> CmdResult<LogsOutput> {
+fn show_pinned(
+    project_id: &str,
+    lines: u32,
+    follow: bool,
+    merge: bool,
+    local: bool,
+) -> CmdResult<LogsOutput> {
+    if follow && merge {
+        let colorize = std::io::IsTerminal::is_terminal(&std::io::stdout());
+        let code = logs::follow_pinned(project_id, lines, local, colorize)?;
+
+        return Ok((
+            LogsOutput {
+                command: "logs.follow".to_string(),
+                project_id: project_id.to_string(),
+                entries: None,
+                log: None,
+                pinned_logs: None,
+                cleared_path: None,
+                search_result: None,
+            },
+            code,
+        ));
+    }
     if follow {
         return Err(homeboy::core::Error::validation_invalid_argument(
             "follow",
-            "Cannot follow multiple pinned logs. Specify a log path to follow.",
+            "Cannot follow multiple pinned logs. Specify a log path to follow, or add --merge to follow them all together.",
             None,
             Some(vec![
                 format!("homeboy logs show {} <path> --follow", project_id),
+                format!("homeboy logs show {} --follow --merge", project_id),
                 format!("homeboy logs list {}", project_id),
             ]),
         ));
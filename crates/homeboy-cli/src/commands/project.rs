@@ -1,6 +1,7 @@
 use clap::{Args, Subcommand, ValueEnum};
 use std::path::Path;
 
+use super::utils::args::{EnvFormatArgs, ListQueryArgs};
 use super::CmdResult;
 use homeboy::core::project::{self};
 
@@ -13,11 +14,19 @@ pub struct ProjectArgs {
 #[derive(Subcommand)]
 enum ProjectCommand {
     /// List all configured projects
-    List,
+    ///
+    /// Sort/filter keys: name, domain, type (linked extension ID)
+    List {
+        #[command(flatten)]
+        query: ListQueryArgs,
+    },
     /// Show project configuration
     Show {
         /// Project ID
         project_id: String,
+
+        #[command(flatten)]
+        format: EnvFormatArgs,
     },
     /// Resolve a filesystem path to its configured Homeboy project
     ResolvePath {
@@ -80,6 +89,22 @@ enum ProjectCommand {
         #[command(subcommand)]
         command: ProjectPinCommand,
     },
+    /// Export a project, its server, and its components as a portable JSON bundle
+    Export {
+        /// Project ID
+        project_id: String,
+        /// Also write the bundle to this file
+        #[arg(long, value_name = "PATH")]
+        file: Option<String>,
+    },
+    /// Recreate a project, its server, and its components from an exported bundle
+    Import {
+        /// Bundle file path (or - for stdin)
+        file: String,
+        /// Skip items that already exist instead of reporting errors
+        #[arg(long)]
+        skip_existing: bool,
+    },
     /// Delete a project configuration
     Delete {
         /// Project ID
@@ -211,10 +236,35 @@ enum ProjectPinType {
 
 pub type ProjectOutput = homeboy::core::project::ProjectReportOutput;
 
+pub(crate) fn is_env_show(args: &ProjectArgs) -> bool {
+    matches!(&args.command, ProjectCommand::Show { format, .. } if format.is_env())
+}
+
+/// Render the scalar basics of `project show` as `KEY=value` lines.
+pub fn run_env(args: ProjectArgs) -> homeboy::core::Result<(String, i32)> {
+    let ProjectCommand::Show { project_id, format } = args.command else {
+        return Err(homeboy::core::Error::validation_invalid_argument(
+            "format",
+            "--format env is only supported by project show",
+            None,
+            None,
+        ));
+    };
+    let report = project::show_report(&project_id)?;
+    format.render_env(&serde_json::json!({
+        "project_id": report.project.id,
+        "domain": report.project.domain,
+        "server_id": report.project.server_id,
+        "base_path": report.project.base_path,
+        "table_prefix": report.project.table_prefix,
+        "deploy_ready": report.deploy_ready,
+    }))
+}
+
 pub fn run(args: ProjectArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<ProjectOutput> {
     match args.command {
-        ProjectCommand::List => list(),
-        ProjectCommand::Show { project_id } => show(&project_id),
+        ProjectCommand::List { query } => list(&query),
+        ProjectCommand::Show { project_id, .. } => show(&project_id),
         ProjectCommand::ResolvePath { path } => resolve_path(&path),
         ProjectCommand::Create {
             json,
@@ -273,6 +323,11 @@ pub fn run(args: ProjectArgs, _global: &crate::commands::GlobalArgs) -> CmdResul
         ProjectCommand::Rename { project_id, new_id } => rename(&project_id, &new_id),
         ProjectCommand::Components { command } => components(command),
         ProjectCommand::Pin { command } => pin(command),
+        ProjectCommand::Export { project_id, file } => export(&project_id, file.as_deref()),
+        ProjectCommand::Import {
+            file,
+            skip_existing,
+        } => import(&file, skip_existing),
         ProjectCommand::Delete { project_id } => delete(&project_id),
         ProjectCommand::Init { project_id } => init(&project_id),
         ProjectCommand::Status {
@@ -282,8 +337,11 @@ pub fn run(args: ProjectArgs, _global: &crate::commands::GlobalArgs) -> CmdResul
     }
 }
 
-fn list() -> CmdResult<ProjectOutput> {
-    Ok((project::build_list_output(project::list_report()?), 0))
+fn list(query: &ListQueryArgs) -> CmdResult<ProjectOutput> {
+    Ok((
+        project::build_list_output(project::list_report(query.sort.as_deref(), &query.filters)?),
+        0,
+    ))
 }
 
 fn show(project_id: &str) -> CmdResult<ProjectOutput> {
@@ -336,6 +394,31 @@ fn rename(project_id: &str, new_id: &str) -> CmdResult<ProjectOutput> {
     ))
 }
 
+fn export(project_id: &str, file: Option<&str>) -> CmdResult<ProjectOutput> {
+    let bundle = project::portability::export(project_id)?;
+    if let Some(file) = file {
+        let content = homeboy::core::config::to_string_pretty(&bundle)?;
+        std::fs::write(file, content).map_err(|e| {
+            homeboy::core::Error::internal_io(e.to_string(), Some(format!("write {}", file)))
+        })?;
+    }
+
+    Ok((project::build_export_output(project_id, bundle), 0))
+}
+
+fn import(file: &str, skip_existing: bool) -> CmdResult<ProjectOutput> {
+    let spec = if file == "-" {
+        file.to_string()
+    } else {
+        format!("@{}", file)
+    };
+
+    Ok(project::build_import_output(project::portability::import(
+        &spec,
+        skip_existing,
+    )?))
+}
+
 fn delete(project_id: &str) -> CmdResult<ProjectOutput> {
     project::delete(project_id)?;
 
@@ -3,7 +3,9 @@ use crate::command_contract::{CommandRawOutputMode, CommandStdoutMode};
 
 use super::output_runtime::CommandRun;
 use super::utils::{response as output, tty};
-use super::{file, release, report, review, runner, runs, runtime, self_cmd, trace, GlobalArgs};
+use super::{
+    file, project, release, report, review, runner, runs, runtime, self_cmd, trace, GlobalArgs,
+};
 
 pub enum RawExecution {
     Handled(i32),
@@ -79,6 +81,11 @@ fn run_plain_text(command: Commands, global: &GlobalArgs) -> CommandRun {
             runner_compact_exec(args, global)
         }
         Commands::Runtime(args) => raw_stdout_only(runtime::run_plain_text(args)),
+        Commands::Release(args) => match args.version_env_command() {
+            Some(command) => raw_stdout_only(release::version::run_env(command)),
+            None => raw_stdout_only(unsupported_output("plain text")),
+        },
+        Commands::Project(args) => raw_stdout_only(project::run_env(args)),
         _ => raw_stdout_only(unsupported_output("plain text")),
     }
 }
@@ -2,7 +2,7 @@ use clap::{Args, Subcommand};
 use serde::Serialize;
 
 use crate::commands::CmdResult;
-use homeboy_release::release::changelog::{self, ShowOutput};
+use homeboy_release::release::changelog::{self, AddOutput, NotesOutput, ShowOutput};
 
 #[derive(Args)]
 pub struct ChangelogArgs {
@@ -17,6 +17,28 @@ pub enum ChangelogCommand {
         /// Component ID to show changelog for
         component_id: Option<String>,
     },
+    /// Merge a component's changelog sections across a version range
+    Notes {
+        /// Component ID to read changelog notes for
+        component_id: String,
+        /// Oldest version to include (inclusive); defaults to the oldest release
+        #[arg(long)]
+        since: Option<String>,
+        /// Newest version to include (inclusive); defaults to the latest release
+        #[arg(long)]
+        until: Option<String>,
+    },
+    /// Add entries to a component's unreleased changelog section
+    Add {
+        /// Component ID whose changelog to edit
+        component_id: String,
+        /// Keep a Changelog subsection: added, changed, deprecated, removed, fixed, or security
+        #[arg(long = "type", value_name = "TYPE")]
+        entry_type: String,
+        /// Entry text; repeat for several entries
+        #[arg(short = 'm', long = "message", required = true)]
+        messages: Vec<String>,
+    },
 }
 
 #[derive(Serialize)]
@@ -32,6 +54,10 @@ pub enum ChangelogOutput {
     Show(ChangelogShowOutput),
 
     ShowComponent(ShowOutput),
+
+    Notes(NotesOutput),
+
+    Add(AddOutput),
 }
 
 pub fn run_markdown(args: ChangelogArgs) -> CmdResult<String> {
@@ -44,6 +70,8 @@ pub fn run_markdown(args: ChangelogArgs) -> CmdResult<String> {
             let output = changelog::show(id)?;
             Ok((output.content, 0))
         }
+        Some(ChangelogCommand::Notes { .. }) => unreachable!("notes is not a markdown command"),
+        Some(ChangelogCommand::Add { .. }) => unreachable!("add is not a markdown command"),
     }
 }
 
@@ -70,6 +98,22 @@ pub fn run(
             let output = changelog::show(id)?;
             Ok((ChangelogOutput::ShowComponent(output), 0))
         }
+        Some(ChangelogCommand::Notes {
+            component_id,
+            since,
+            until,
+        }) => {
+            let output = changelog::notes(component_id, since.as_deref(), until.as_deref())?;
+            Ok((ChangelogOutput::Notes(output), 0))
+        }
+        Some(ChangelogCommand::Add {
+            component_id,
+            entry_type,
+            messages,
+        }) => {
+            let output = changelog::add(component_id, entry_type, messages)?;
+            Ok((ChangelogOutput::Add(output), 0))
+        }
     }
 }
 
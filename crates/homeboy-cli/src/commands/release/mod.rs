@@ -35,6 +35,22 @@ impl ReleaseArgs {
         )
     }
 
+    pub(crate) fn is_version_env(&self) -> bool {
+        matches!(
+            &self.command,
+            Some(ReleaseSubcommand::Version(args)) if version::is_env_format(&args.command)
+        )
+    }
+
+    pub(crate) fn version_env_command(self) -> Option<version::VersionCommand> {
+        match self.command {
+            Some(ReleaseSubcommand::Version(args)) if version::is_env_format(&args.command) => {
+                Some(args.command)
+            }
+            _ => None,
+        }
+    }
+
     pub(crate) fn markdown_changelog_args(self) -> Option<changelog::ChangelogArgs> {
         match self.command {
             Some(ReleaseSubcommand::Changelog(args)) if changelog::is_show_markdown(&args) => {
@@ -142,7 +158,25 @@ pub struct ReleaseExecuteArgs {
     #[arg(long)]
     skip_build_validation: bool,
 
-    /// Force a specific version bump: major, minor, patch, or an explicit version (e.g. 2.0.0).
+    /// Keep running sibling module steps (dependencies, lint, test, package,
+    /// prepare) after one fails and report every failure together. Core steps
+    /// (version, commit, tag, push, GitHub Release) stay fail-fast and never
+    /// run after a failure.
+    #[arg(long)]
+    keep_going: bool,
+
+    /// When a failed release is rolled back, also delete the local tag it
+    /// created. Tags that already reached the remote are left alone.
+    #[arg(long)]
+    rollback_on_failure: bool,
+
+    /// Run publish steps for all publish targets concurrently. Each target
+    /// still reports its own result, and one failure does not stop the others.
+    #[arg(long)]
+    parallel_publish: bool,
+
+    /// Force a specific version bump: major, minor, patch, a pre-release bump (alpha, beta, rc,
+    /// prerelease, finalize), or an explicit version (e.g. 2.0.0).
     /// Overrides auto-detection from commit history.
     #[arg(long)]
     bump: Option<String>,
@@ -151,6 +185,17 @@ pub struct ReleaseExecuteArgs {
     #[arg(long)]
     force_lower_bump: bool,
 
+    /// Keep the current pre-release suffix on a major/minor/patch bump
+    /// (`1.2.3-beta` patch → `1.2.4-beta`) instead of dropping it.
+    #[arg(long)]
+    preserve_prerelease: bool,
+
+    /// Bump from this version instead of the one read from the version
+    /// targets. Use when the source files drifted from the last release (e.g.
+    /// after a bad manual edit). Must be valid semver.
+    #[arg(long, value_name = "VERSION")]
+    from_version: Option<String>,
+
     /// Skip registry/package publishing only (version bump + tag + push).
     /// This does NOT skip GitHub Release creation — a GitHub Release is still
     /// created unless you ALSO pass --no-github-release. Use when CI handles
@@ -251,6 +296,9 @@ impl ReleaseExecuteArgs {
             skip_publish: self.skip_publish,
             head: self.head,
             from_artifacts: self.from_artifacts.clone(),
+            keep_going: self.keep_going,
+            rollback_on_failure: self.rollback_on_failure,
+            parallel_publish: self.parallel_publish,
         }
     }
 
@@ -365,13 +413,18 @@ impl ReleaseExecuteArgs {
             tag: None,
             skip_checks: if skip_checks { Some(Vec::new()) } else { None },
             skip_build_validation: false,
+            keep_going: false,
+            rollback_on_failure: false,
+            parallel_publish: false,
             bump,
             force_lower_bump: false,
+            preserve_prerelease: false,
             skip_publish,
             no_github_release: false,
             i_know_ci_creates_the_github_release: false,
             i_know_this_is_a_manual_tag_only_release: false,
             git_identity: None,
+            from_version: None,
             cascade: false,
         }
     }
@@ -431,9 +484,11 @@ fn run_execute(args: ReleaseExecuteArgs) -> CmdResult<ReleaseCommandOutput> {
             skip_deps_hydration: crate::commands::skip_deps_hydration(),
             bump_override: bump_override.clone(),
             force_lower_bump: args.force_lower_bump,
+            preserve_prerelease: args.preserve_prerelease,
             pipeline: args.pipeline_options(),
             skip_github_release: args.no_github_release,
             git_identity: args.git_identity.clone(),
+            from_version: args.from_version.clone(),
             execution: Some(execution.clone()),
         };
         let (result, exit_code) = release::run_command(input.clone())?;
@@ -485,6 +540,14 @@ fn run_execute(args: ReleaseExecuteArgs) -> CmdResult<ReleaseCommandOutput> {
             None,
         ));
     }
+    if args.from_version.is_some() {
+        return Err(homeboy::core::Error::validation_invalid_argument(
+            "from-version",
+            "--from-version sets one component's baseline and is not supported for batch releases",
+            args.from_version.clone(),
+            None,
+        ));
+    }
     if args.from_artifacts.is_some() {
         return Err(homeboy::core::Error::validation_invalid_argument(
             "from-artifacts",
@@ -506,14 +569,19 @@ fn run_execute(args: ReleaseExecuteArgs) -> CmdResult<ReleaseCommandOutput> {
         skip_deps_hydration: crate::commands::skip_deps_hydration(),
         bump_override,
         force_lower_bump: args.force_lower_bump,
+        preserve_prerelease: args.preserve_prerelease,
         pipeline: ReleasePipelineOptions {
             deploy: args.deploy,
             skip_publish: args.skip_publish,
             head: false,
             from_artifacts: None,
+            keep_going: args.keep_going,
+            rollback_on_failure: args.rollback_on_failure,
+            parallel_publish: args.parallel_publish,
         },
         skip_github_release: args.no_github_release,
         git_identity: args.git_identity.clone(),
+        from_version: None,
         execution: Some(execution),
     };
 
@@ -1019,13 +1087,18 @@ mod tests {
             skip_checks: skip_checks
                 .map(|values| values.iter().map(|value| value.to_string()).collect()),
             skip_build_validation: false,
+            keep_going: false,
+            rollback_on_failure: false,
+            parallel_publish: false,
             bump: None,
             force_lower_bump: false,
+            preserve_prerelease: false,
             skip_publish: false,
             no_github_release: false,
             i_know_ci_creates_the_github_release: false,
             i_know_this_is_a_manual_tag_only_release: false,
             git_identity: None,
+            from_version: None,
             cascade: false,
         }
     }
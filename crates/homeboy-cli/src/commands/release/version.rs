@@ -4,6 +4,7 @@ use serde::Serialize;
 use homeboy::core::component;
 use homeboy_release::release::version::{read_component_version, read_version, VersionTargetInfo};
 
+use crate::commands::utils::args::EnvFormatArgs;
 use crate::commands::CmdResult;
 
 #[derive(Serialize)]
@@ -28,6 +29,9 @@ pub enum VersionCommand {
         /// Override local_path for version file lookup
         #[arg(long)]
         path: Option<String>,
+
+        #[command(flatten)]
+        format: EnvFormatArgs,
     },
 }
 
@@ -52,7 +56,9 @@ pub fn run(
     args: VersionArgs,
     _global: &crate::commands::GlobalArgs,
 ) -> crate::commands::CmdResult<VersionOutput> {
-    let VersionCommand::Show { component_id, path } = args.command;
+    let VersionCommand::Show {
+        component_id, path, ..
+    } = args.command;
     show(VersionShowArgs { component_id, path })
 }
 
@@ -60,10 +66,31 @@ pub fn run_command(
     command: VersionCommand,
     _global: &crate::commands::GlobalArgs,
 ) -> CmdResult<VersionOutput> {
-    let VersionCommand::Show { component_id, path } = command;
+    let VersionCommand::Show {
+        component_id, path, ..
+    } = command;
     show(VersionShowArgs { component_id, path })
 }
 
+pub(crate) fn is_env_format(command: &VersionCommand) -> bool {
+    matches!(command, VersionCommand::Show { format, .. } if format.is_env())
+}
+
+/// Render `version show` as `KEY=value` lines. Version targets are a list, so
+/// only the scalar identity fields are exported.
+pub fn run_env(command: VersionCommand) -> homeboy::core::Result<(String, i32)> {
+    let VersionCommand::Show {
+        component_id,
+        path,
+        format,
+    } = command;
+    let (VersionOutput::Show(output), _) = show(VersionShowArgs { component_id, path })?;
+    format.render_env(&serde_json::json!({
+        "component_id": output.component_id,
+        "version": output.version,
+    }))
+}
+
 fn show(args: VersionShowArgs) -> CmdResult<VersionOutput> {
     let component_id = args.component_id;
     let path = args.path;
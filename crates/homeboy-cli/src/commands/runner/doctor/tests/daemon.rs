@@ -201,6 +201,7 @@ fn disconnected_lab_doctor_reuses_daemon_recovery_envelope() {
         auth: None,
         env: Default::default(),
         runner: None,
+        remote_temp_dir: None,
     };
 
     let report = remote::disconnected_report("lab", &runner, &server, Some(recovery));
@@ -0,0 +1,16 @@
+use clap::Args;
+use homeboy_release::deploy::{self, SelfTestReport};
+
+use crate::commands::{CmdResult, GlobalArgs};
+
+#[derive(Args)]
+pub struct SelfTestArgs {
+    /// Project ID or server ID to rehearse a deploy against
+    pub target: String,
+}
+
+pub fn run(args: SelfTestArgs, _global: &GlobalArgs) -> CmdResult<SelfTestReport> {
+    let report = deploy::self_test(&args.target)?;
+    let exit_code = if report.has_failures() { 1 } else { 0 };
+    Ok((report, exit_code))
+}
@@ -2,9 +2,12 @@ use clap::{Args, Subcommand};
 use serde::Serialize;
 
 use homeboy::core::redaction::RedactionPolicy;
-use homeboy::core::server::{self, Server, ServerSessionConfig, SshClient};
+use homeboy::core::server::{
+    self, ConnectionTestResult, Server, ServerReachability, ServerSessionConfig, SshClient,
+};
 use homeboy::core::{EntityCrudOutput, MergeOutput};
 
+use super::utils::args::ListQueryArgs;
 use super::{CmdResult, DynamicSetArgs};
 
 /// Entity-specific fields for server commands.
@@ -14,6 +17,10 @@ pub struct ServerExtra {
     pub key: Option<ServerKeyOutput>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session: Option<ServerSessionOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<ConnectionTestResult>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reachability: Vec<ServerReachability>,
 }
 
 pub type ServerOutput = EntityCrudOutput<Server, ServerExtra>;
@@ -28,6 +35,8 @@ pub struct ServerKeyOutput {
     public_key: Option<String>,
     identity_file: Option<String>,
     imported: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    already_present: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -87,7 +96,15 @@ enum ServerCommand {
         server_id: String,
     },
     /// List all configured servers
-    List,
+    ///
+    /// Sort/filter keys: name, host, user, type (server kind)
+    List {
+        #[command(flatten)]
+        query: ListQueryArgs,
+        /// Probe each listed server's SSH reachability and latency
+        #[arg(long)]
+        check: bool,
+    },
     /// Open a managed SSH control-master session for this server
     Connect {
         /// Server ID
@@ -103,6 +120,27 @@ enum ServerCommand {
         /// Server ID
         server_id: String,
     },
+    /// Verify SSH connectivity: latency, remote uname and shell, or a classified failure
+    Test {
+        /// Server ID
+        server_id: String,
+    },
+    /// Generate a dedicated ed25519 key pair for this server and set it as identity_file
+    Keygen {
+        /// Server ID
+        server_id: String,
+        /// Replace an existing key pair
+        #[arg(long)]
+        force: bool,
+    },
+    /// Install a public key in the server's ~/.ssh/authorized_keys (prompts for the password once)
+    CopyId {
+        /// Server ID
+        server_id: String,
+        /// Key to install (private or .pub path; defaults to the server's identity_file)
+        #[arg(long)]
+        key: Option<String>,
+    },
     /// Manage SSH keys
     Key(KeyArgs),
 }
@@ -119,6 +157,9 @@ enum KeyCommand {
     Generate {
         /// Server ID
         server_id: String,
+        /// Replace an existing key pair
+        #[arg(long)]
+        force: bool,
     },
     /// Display the public SSH key
     Show {
@@ -197,6 +238,7 @@ pub fn run(args: ServerArgs, _global: &crate::commands::GlobalArgs) -> CmdResult
                     auth: None,
                     env: std::collections::HashMap::new(),
                     runner: None,
+                    remote_temp_dir: None,
                 };
 
                 homeboy::core::config::to_json_string(&new_server)?
@@ -229,10 +271,15 @@ pub fn run(args: ServerArgs, _global: &crate::commands::GlobalArgs) -> CmdResult
         ServerCommand::Show { server_id } => show(&server_id),
         ServerCommand::Set { args } => set(args),
         ServerCommand::Delete { server_id } => delete(&server_id),
-        ServerCommand::List => list(),
+        ServerCommand::List { query, check } => list(&query, check),
         ServerCommand::Connect { server_id } => session_connect(&server_id),
         ServerCommand::Status { server_id } => session_status(&server_id),
         ServerCommand::Disconnect { server_id } => session_disconnect(&server_id),
+        ServerCommand::Test { server_id } => test(&server_id),
+        ServerCommand::Keygen { server_id, force } => {
+            key_generate(&server_id, force, "server.keygen")
+        }
+        ServerCommand::CopyId { server_id, key } => copy_id(&server_id, key.as_deref()),
         ServerCommand::Key(key_args) => run_key(key_args),
     })
 }
@@ -321,7 +368,9 @@ fn run_session_action(server_id: &str, action: &str) -> CmdResult<ServerOutput>
 
 fn run_key(args: KeyArgs) -> CmdResult<ServerOutput> {
     match args.command {
-        KeyCommand::Generate { server_id } => key_generate(&server_id),
+        KeyCommand::Generate { server_id, force } => {
+            key_generate(&server_id, force, "server.key.generate")
+        }
         KeyCommand::Show { server_id } => key_show(&server_id),
         KeyCommand::Import {
             server_id,
@@ -406,25 +455,81 @@ fn delete(server_id: &str) -> CmdResult<ServerOutput> {
     ))
 }
 
-fn list() -> CmdResult<ServerOutput> {
-    let servers = server::list()?;
+fn list(query: &ListQueryArgs, check: bool) -> CmdResult<ServerOutput> {
+    let mut servers = server::list()?;
+    query.apply(
+        &mut servers,
+        server::LIST_QUERY_KEYS,
+        server::list_query_values,
+    )?;
+    let reachability = if check {
+        server::check_reachability(&servers)
+    } else {
+        Vec::new()
+    };
 
     Ok((
         ServerOutput {
             command: "server.list".to_string(),
             entities: servers,
+            extra: ServerExtra {
+                reachability,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
+fn test(server_id: &str) -> CmdResult<ServerOutput> {
+    let result = server::test_connection(server_id)?;
+    let exit_code = if result.success { 0 } else { 1 };
+
+    Ok((
+        ServerOutput {
+            command: "server.test".to_string(),
+            id: Some(server_id.to_string()),
+            extra: ServerExtra {
+                test: Some(result),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        exit_code,
+    ))
+}
+
+fn copy_id(server_id: &str, key: Option<&str>) -> CmdResult<ServerOutput> {
+    let result = server::copy_id(server_id, key)?;
+
+    Ok((
+        ServerOutput {
+            command: "server.copy_id".to_string(),
+            id: Some(server_id.to_string()),
+            extra: ServerExtra {
+                key: Some(ServerKeyOutput {
+                    action: "copy-id".to_string(),
+                    server_id: server_id.to_string(),
+                    public_key: Some(result.public_key),
+                    identity_file: None,
+                    imported: None,
+                    already_present: Some(result.already_present),
+                }),
+                ..Default::default()
+            },
             ..Default::default()
         },
         0,
     ))
 }
 
-fn key_generate(server_id: &str) -> CmdResult<ServerOutput> {
-    let result = server::generate_key(server_id)?;
+fn key_generate(server_id: &str, force: bool, command: &str) -> CmdResult<ServerOutput> {
+    let result = server::generate_key(server_id, force)?;
 
     Ok((
         ServerOutput {
-            command: "server.key.generate".to_string(),
+            command: command.to_string(),
             id: Some(server_id.to_string()),
             entity: Some(result.server),
             updated_fields: vec!["identity_file".to_string()],
@@ -435,6 +540,7 @@ fn key_generate(server_id: &str) -> CmdResult<ServerOutput> {
                     public_key: Some(result.public_key),
                     identity_file: Some(result.identity_file),
                     imported: None,
+                    already_present: None,
                 }),
                 ..Default::default()
             },
@@ -458,6 +564,7 @@ fn key_show(server_id: &str) -> CmdResult<ServerOutput> {
                     public_key: Some(public_key),
                     identity_file: None,
                     imported: None,
+                    already_present: None,
                 }),
                 ..Default::default()
             },
@@ -484,6 +591,7 @@ fn key_use(server_id: &str, private_key_path: &str) -> CmdResult<ServerOutput> {
                     public_key: None,
                     identity_file,
                     imported: None,
+                    already_present: None,
                 }),
                 ..Default::default()
             },
@@ -509,6 +617,7 @@ fn key_unset(server_id: &str) -> CmdResult<ServerOutput> {
                     public_key: None,
                     identity_file: None,
                     imported: None,
+                    already_present: None,
                 }),
                 ..Default::default()
             },
@@ -534,6 +643,7 @@ fn key_import(server_id: &str, private_key_path: &str) -> CmdResult<ServerOutput
                     public_key: Some(result.public_key),
                     identity_file: Some(result.identity_file),
                     imported: Some(result.imported_from),
+                    already_present: None,
                 }),
                 ..Default::default()
             },
@@ -581,6 +691,7 @@ mod tests {
                     },
                     ..Default::default()
                 }),
+                remote_temp_dir: None,
             }),
             ..Default::default()
         };
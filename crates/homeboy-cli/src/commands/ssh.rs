@@ -26,6 +26,14 @@ pub struct SshArgs {
     #[arg(long)]
     pub as_server: bool,
 
+    /// Connect to this project ID (skips bare-ID resolution)
+    #[arg(long, value_name = "PROJECT_ID", conflicts_with_all = ["server", "as_server"])]
+    pub project: Option<String>,
+
+    /// Connect to this server ID (skips bare-ID resolution)
+    #[arg(long, value_name = "SERVER_ID", conflicts_with = "as_server")]
+    pub server: Option<String>,
+
     /// Override the SSH user (instead of the server's configured user)
     #[arg(long)]
     pub user: Option<String>,
@@ -70,6 +78,49 @@ pub struct SshListOutput {
     pub servers: Vec<Server>,
 }
 
+impl SshArgs {
+    /// An explicit `--project`/`--server` target means the first positional is
+    /// the start of the remote command, not a target ID.
+    fn resolve_target(&self) -> (SshResolveArgs, Vec<String>) {
+        if self.project.is_some() || self.server.is_some() {
+            let command = self
+                .target
+                .iter()
+                .chain(self.command.iter())
+                .cloned()
+                .collect();
+            return (
+                SshResolveArgs {
+                    id: None,
+                    project: self.project.clone(),
+                    server: self.server.clone(),
+                },
+                command,
+            );
+        }
+
+        let resolve_args = if self.as_server {
+            SshResolveArgs {
+                id: None,
+                project: None,
+                server: self.target.clone(),
+            }
+        } else {
+            SshResolveArgs {
+                id: self.target.clone(),
+                project: None,
+                server: None,
+            }
+        };
+        (resolve_args, self.command.clone())
+    }
+
+    /// Connect without a remote command opens an interactive shell.
+    pub fn is_interactive(&self) -> bool {
+        self.subcommand.is_none() && self.resolve_target().1.is_empty()
+    }
+}
+
 pub fn run(args: SshArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<SshOutput> {
     match args.subcommand {
         Some(SshSubcommand::List) => {
@@ -77,31 +128,18 @@ pub fn run(args: SshArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Ss
             Ok((SshOutput::List(SshListOutput { servers }), 0))
         }
         None => {
-            // Build resolve args based on simplified CLI args
-            let resolve_args = if args.as_server {
-                SshResolveArgs {
-                    id: None,
-                    project: None,
-                    server: args.target.clone(),
-                }
-            } else {
-                SshResolveArgs {
-                    id: args.target.clone(),
-                    project: None,
-                    server: None,
-                }
-            };
+            let (resolve_args, command) = args.resolve_target();
             let result = resolve_context(&resolve_args)?;
 
-            let command_string: Option<String> = if args.command.is_empty() {
+            let command_string: Option<String> = if command.is_empty() {
                 None
-            } else if args.command.len() == 1 {
+            } else if command.len() == 1 {
                 // Preserve legacy behavior: a single string is treated as a raw shell command.
-                Some(args.command[0].clone())
+                Some(command[0].clone())
             } else {
                 // Multi-arg form (typically from `-- <cmd...>`): quote args safely.
                 // Note: this intentionally does NOT support shell operators; pass a single string for that.
-                Some(shell::quote_args(&args.command))
+                Some(shell::quote_args(&command))
             };
 
             // When project is resolved with base_path, auto-cd to project root
@@ -121,7 +159,7 @@ pub fn run(args: SshArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Ss
                 client.user = user_override.clone();
             }
 
-            if !args.command.is_empty() {
+            if !command.is_empty() {
                 // Non-interactive: capture output for JSON response
                 let cmd = effective_command.as_deref().ok_or_else(|| {
                     homeboy::core::Error::internal_unexpected(
@@ -207,6 +245,45 @@ fn ssh_failure_reason(success: bool, exit_code: i32) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        ssh: SshArgs,
+    }
+
+    fn parse(argv: &[&str]) -> SshArgs {
+        TestCli::try_parse_from(std::iter::once("ssh").chain(argv.iter().copied()))
+            .expect("ssh args should parse")
+            .ssh
+    }
+
+    #[test]
+    fn bare_target_resolves_by_id_and_keeps_command() {
+        let (resolve, command) = parse(&["site", "--", "ls", "-la"]).resolve_target();
+        assert_eq!(resolve.id.as_deref(), Some("site"));
+        assert!(resolve.project.is_none() && resolve.server.is_none());
+        assert_eq!(command, ["ls", "-la"]);
+    }
+
+    #[test]
+    fn explicit_server_flag_forces_resolution_and_treats_positional_as_command() {
+        let args = parse(&["--server", "prod", "uptime"]);
+        assert!(!args.is_interactive());
+        let (resolve, command) = args.resolve_target();
+        assert_eq!(resolve.server.as_deref(), Some("prod"));
+        assert!(resolve.id.is_none());
+        assert_eq!(command, ["uptime"]);
+    }
+
+    #[test]
+    fn explicit_project_flag_without_command_is_interactive() {
+        let args = parse(&["--project", "site"]);
+        assert!(args.is_interactive());
+        assert_eq!(args.resolve_target().0.project.as_deref(), Some("site"));
+        assert!(TestCli::try_parse_from(["ssh", "--project", "a", "--server", "b"]).is_err());
+    }
 
     #[test]
     fn ssh_success_classification_does_not_depend_on_output() {
@@ -29,10 +29,12 @@ use git_cache::{fetch_project_remote_versions, log_unreleased_merges, StatusGitC
 
 pub use types::{
     ProjectComponentDashboardStatus, ProjectDashboardOutput, ProjectDashboardSummary,
-    ProjectStatusRow, StatusArgs, StatusOutput, StatusResult, StatusTiming,
+    ProjectStatusRow, StatusArgs, StatusOutput, StatusResult, StatusSection, StatusTiming,
     UnregisteredContextStatusOutput, UnreleasedMerge, UpstreamDrift,
 };
-use types::{StatusTimer, READY_TO_DEPLOY_NOTE, UNRELEASED_MERGES_NOTE};
+use types::{
+    StatusTimer, ALWAYS_INCLUDED_REPORT_KEYS, READY_TO_DEPLOY_NOTE, UNRELEASED_MERGES_NOTE,
+};
 
 pub fn run(args: StatusArgs, _global: &super::GlobalArgs) -> CmdResult<StatusResult> {
     if args.path.is_some() {
@@ -53,7 +55,7 @@ pub fn run(args: StatusArgs, _global: &super::GlobalArgs) -> CmdResult<StatusRes
     if args.full {
         let mut report = context::build_report(args.all, "status")?;
         report.command = "status".to_string();
-        return Ok((StatusResult::Full(report), 0));
+        return Ok((full_report_result(report, &args.sections)?, 0));
     }
 
     let mut timer = StatusTimer::new(args.timings);
@@ -233,12 +235,37 @@ fn run_path_status(args: &StatusArgs) -> CmdResult<StatusResult> {
     if args.full {
         let mut report = context::build_report_for_component(args.all, "status", component, path)?;
         report.command = "status".to_string();
-        return Ok((StatusResult::Full(report), 0));
+        return Ok((full_report_result(report, &args.sections)?, 0));
     }
 
     summarize_components(vec![component], args, timer)
 }
 
+/// Return the full report, keeping only the requested `--section`s when any
+/// were given. Each kept section retains its normal structure.
+fn full_report_result(
+    report: context::report::ContextReport,
+    sections: &[StatusSection],
+) -> homeboy::core::Result<StatusResult> {
+    if sections.is_empty() {
+        return Ok(StatusResult::Full(report));
+    }
+
+    let value = serde_json::to_value(&report).map_err(|e| {
+        homeboy::core::Error::internal_json(e.to_string(), Some("serialize status report".into()))
+    })?;
+    let serde_json::Value::Object(mut fields) = value else {
+        return Ok(StatusResult::Full(report));
+    };
+    fields.retain(|key, _| {
+        ALWAYS_INCLUDED_REPORT_KEYS.contains(&key.as_str())
+            || sections
+                .iter()
+                .any(|section| section.report_keys().contains(&key.as_str()))
+    });
+    Ok(StatusResult::FullSections(fields))
+}
+
 /// Project dashboard: show version drift across all components in a project.
 ///
 /// Combines local version, remote (deployed) version, release state, upstream
@@ -529,6 +556,7 @@ mod tests {
             project,
             path: Some(path),
             full,
+            sections: Vec::new(),
             uncommitted: false,
             needs_release: false,
             ready: false,
@@ -545,6 +573,7 @@ mod tests {
             project: None,
             path: None,
             full: false,
+            sections: Vec::new(),
             uncommitted: false,
             needs_release: false,
             ready: false,
@@ -720,6 +749,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parser_accepts_repeatable_full_sections() {
+        let cli = Cli::try_parse_from([
+            "homeboy",
+            "status",
+            "--full",
+            "--section",
+            "modules",
+            "--section",
+            "projects",
+        ])
+        .expect("status --full --section parses");
+
+        match cli.command {
+            Commands::Status(args) => assert_eq!(
+                args.sections,
+                vec![StatusSection::Extensions, StatusSection::Projects]
+            ),
+            _ => panic!("expected status command"),
+        }
+
+        assert!(Cli::try_parse_from(["homeboy", "status", "--section", "servers"]).is_err());
+    }
+
     #[test]
     fn parser_accepts_status_id_with_path() {
         let cli = Cli::try_parse_from([
@@ -921,6 +974,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn status_full_sections_keep_only_requested_report_keys() {
+        let (_dir, repo) = make_git_repo("sectioned-checkout");
+        let mut args = status_args(
+            Some("sectioned".to_string()),
+            repo.to_string_lossy().to_string(),
+            true,
+        );
+        args.sections = vec![StatusSection::Components];
+
+        let (result, code) = run(args, &GlobalArgs {}).expect("status --full --section succeeds");
+
+        assert_eq!(code, 0);
+        match result {
+            StatusResult::FullSections(fields) => {
+                assert_eq!(fields["command"], "status");
+                assert_eq!(fields["components"][0]["id"], "sectioned");
+                assert!(fields.contains_key("summary"));
+                assert!(!fields.contains_key("context"));
+                assert!(!fields.contains_key("extensions"));
+            }
+            _ => panic!("expected sectioned full output"),
+        }
+    }
+
     #[test]
     fn upstream_drift_cache_is_component_scoped_in_shared_repos() {
         let (_dir, repo) = make_git_repo("monorepo");
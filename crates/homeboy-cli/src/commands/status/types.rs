@@ -19,6 +19,10 @@ pub struct StatusArgs {
     #[arg(long)]
     pub full: bool,
 
+    /// Limit the full report to these sections (repeatable)
+    #[arg(long = "section", value_enum, requires = "full")]
+    pub sections: Vec<StatusSection>,
+
     /// Show only components with uncommitted changes
     #[arg(long)]
     pub uncommitted: bool,
@@ -53,6 +57,43 @@ pub struct StatusArgs {
     pub unreleased: bool,
 }
 
+/// Section of the `--full` report selectable with `--section`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum StatusSection {
+    /// Current directory context plus the matched component's version, git,
+    /// release, and changelog snapshots.
+    Context,
+    Servers,
+    Projects,
+    /// Component summaries with the release-state rollups.
+    Components,
+    #[value(alias = "modules")]
+    Extensions,
+}
+
+impl StatusSection {
+    /// Top-level report keys that make up this section.
+    pub(crate) fn report_keys(self) -> &'static [&'static str] {
+        match self {
+            StatusSection::Context => &[
+                "context",
+                "version",
+                "git",
+                "last_release",
+                "changelog",
+                "agent_context_files",
+            ],
+            StatusSection::Servers => &["servers"],
+            StatusSection::Projects => &["projects"],
+            StatusSection::Components => &["components", "status", "summary"],
+            StatusSection::Extensions => &["extensions"],
+        }
+    }
+}
+
+/// Report keys kept regardless of `--section`.
+pub(crate) const ALWAYS_INCLUDED_REPORT_KEYS: &[&str] = &["command", "next_steps", "warnings"];
+
 /// Per-component upstream drift info.
 #[derive(Debug, Clone, Serialize)]
 pub struct UpstreamDrift {
@@ -300,6 +341,8 @@ pub enum StatusResult {
     Summary(StatusOutput),
     UnregisteredContext(UnregisteredContextStatusOutput),
     Full(homeboy::core::context::report::ContextReport),
+    /// Full report narrowed to the `--section` selection.
+    FullSections(serde_json::Map<String, serde_json::Value>),
     Dashboard(ProjectDashboardOutput),
 }
 
@@ -312,6 +355,7 @@ impl serde::Serialize for StatusResult {
             StatusResult::Summary(output) => output.serialize(serializer),
             StatusResult::UnregisteredContext(output) => output.serialize(serializer),
             StatusResult::Full(output) => output.serialize(serializer),
+            StatusResult::FullSections(output) => output.serialize(serializer),
             StatusResult::Dashboard(output) => output.serialize(serializer),
         }
     }
@@ -6,7 +6,7 @@
 //!
 //! See: https://github.com/Extra-Chill/homeboy/issues/436
 
-use clap::{Arg, ArgAction, Args, Command, CommandFactory};
+use clap::{Arg, ArgAction, Args, Command, CommandFactory, ValueEnum};
 
 use crate::cli_surface::Cli;
 use homeboy::core::component::{self, Component};
@@ -735,6 +735,40 @@ pub struct WriteModeArgs {
     pub write: bool,
 }
 
+// ============================================================================
+// ListQueryArgs: --sort <key> + --filter <expr> (entity list commands)
+// ============================================================================
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct ListQueryArgs {
+    /// Sort the list by this key (see the command docs for allowed keys)
+    #[arg(long, value_name = "KEY")]
+    pub sort: Option<String>,
+
+    /// Keep only items matching `key=value` (exact) or `key~=value`
+    /// (substring); repeat to require every filter
+    #[arg(long = "filter", value_name = "EXPR")]
+    pub filters: Vec<String>,
+}
+
+impl ListQueryArgs {
+    /// Filter and sort `items` in place using the entity's query keys.
+    pub fn apply<T>(
+        &self,
+        items: &mut Vec<T>,
+        keys: &[&str],
+        values: impl Fn(&T, &str) -> Vec<String>,
+    ) -> homeboy::core::Result<()> {
+        homeboy::core::list_query::apply_list_query(
+            items,
+            self.sort.as_deref(),
+            &self.filters,
+            keys,
+            values,
+        )
+    }
+}
+
 // ============================================================================
 // DryRunArgs: --dry-run (execute by default)
 // ============================================================================
@@ -745,6 +779,51 @@ pub struct DryRunArgs {
     pub dry_run: bool,
 }
 
+// ============================================================================
+// EnvFormatArgs: --format json|env + --env-prefix (flat results only)
+// ============================================================================
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlatOutputFormat {
+    #[default]
+    Json,
+    Env,
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct EnvFormatArgs {
+    /// Output format: `json` envelope, or `env` for `KEY=value` lines suitable for `eval`
+    #[arg(long, value_enum, default_value_t = FlatOutputFormat::Json)]
+    pub format: FlatOutputFormat,
+
+    /// Key prefix for `--format env`
+    #[arg(long, value_name = "PREFIX", default_value = homeboy::core::output::DEFAULT_ENV_PREFIX)]
+    pub env_prefix: String,
+}
+
+impl EnvFormatArgs {
+    pub fn is_env(&self) -> bool {
+        self.format == FlatOutputFormat::Env
+    }
+
+    /// Render a flat projection of a command result as `KEY=value` lines.
+    pub fn render_env<T: serde::Serialize>(
+        &self,
+        flat: &T,
+    ) -> homeboy::core::Result<(String, i32)> {
+        let value = serde_json::to_value(flat).map_err(|error| {
+            homeboy::core::Error::internal_unexpected(format!(
+                "Failed to serialize env output: {error}"
+            ))
+        })?;
+        let rendered =
+            homeboy::core::output::render_env(&value, &self.env_prefix).map_err(|problem| {
+                homeboy::core::Error::validation_invalid_argument("format", problem, None, None)
+            })?;
+        Ok((rendered, 0))
+    }
+}
+
 // ============================================================================
 // SettingArgs: --settings-json-file + --setting key=value + --setting-json key=<json>
 // ============================================================================
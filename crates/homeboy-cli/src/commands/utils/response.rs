@@ -2,7 +2,6 @@
 //!
 //! Provides JSON envelope, printing, and exit code mapping.
 
-use homeboy::core::error::Hint;
 use homeboy::core::{Error, ErrorCode, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -228,8 +227,9 @@ pub struct CommandDiagnostics {
     pub code: String,
     pub message: String,
     pub details: serde_json::Value,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub hints: Option<Vec<Hint>>,
+    /// Remediation steps as plain strings. Always serialized (possibly empty)
+    /// so wrapping tools can read `diagnostics.hints` without probing.
+    pub hints: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retryable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -304,11 +304,7 @@ impl CommandResultEnvelope<()> {
                 code: err.code.as_str().to_string(),
                 message: err.message.clone(),
                 details: err.details.clone(),
-                hints: if err.hints.is_empty() {
-                    None
-                } else {
-                    Some(err.hints.clone())
-                },
+                hints: err.hints.iter().map(|hint| hint.message.clone()).collect(),
                 retryable: err.retryable,
                 failure_digest: failure_digest_for_error(err),
             }),
@@ -391,6 +387,7 @@ fn exit_code_for_error(code: ErrorCode) -> i32 {
         | ErrorCode::FleetNotFound
         | ErrorCode::ExtensionNotFound
         | ErrorCode::ExtensionUnsupported
+        | ErrorCode::CliToolMissing
         | ErrorCode::DocsTopicNotFound
         | ErrorCode::RigNotFound
         | ErrorCode::RunnerNotFound
@@ -569,7 +566,7 @@ fn failure_diagnostics_for_data(
         code: "command.failed".to_string(),
         message: failure_digest.summary.clone(),
         details: serde_json::json!({ "exit_code": exit_code }),
-        hints: None,
+        hints: Vec::new(),
         retryable: failure_digest.retryable,
         failure_digest: Some(failure_digest),
     })
@@ -1078,6 +1075,28 @@ mod tests {
         assert!(value.get("evidence").is_none());
     }
 
+    #[test]
+    fn error_hints_serialize_as_string_array() {
+        let err = Error::validation_missing_argument(vec!["component".to_string()])
+            .with_hint("Run 'homeboy component list' to see available components")
+            .with_hint("Pass --all to deploy everything");
+        let response = cli_response_for_json_result_for_command(&Err(err), 2, "deploy", None);
+        let value = serde_json::to_value(response).expect("response json");
+
+        assert_eq!(
+            value["diagnostics"]["hints"],
+            json!([
+                "Run 'homeboy component list' to see available components",
+                "Pass --all to deploy everything"
+            ])
+        );
+
+        let bare = Error::validation_missing_argument(vec!["component".to_string()]);
+        let response = cli_response_for_json_result_for_command(&Err(bare), 2, "deploy", None);
+        let value = serde_json::to_value(response).expect("response json");
+        assert_eq!(value["diagnostics"]["hints"], json!([]));
+    }
+
     #[test]
     fn remote_command_failures_include_typed_failure_digest() {
         let err = Error::remote_command_failed(homeboy::core::error::RemoteCommandFailedDetails {
@@ -4,7 +4,7 @@
 
 use std::io::{self, BufRead, IsTerminal, Write};
 
-fn is_stdin_tty() -> bool {
+pub fn is_stdin_tty() -> bool {
     io::stdin().is_terminal()
 }
 
@@ -52,7 +52,38 @@ pub fn resolve(topic: &[String]) -> homeboy::core::Result<ResolvedDoc> {
         }
     }
 
-    Err(homeboy::core::Error::docs_topic_not_found(&key))
+    let available = available_topics();
+    let suggestions = suggest_topics(&key, &available);
+    Err(homeboy::core::Error::docs_topic_not_found_with_topics(
+        &key,
+        suggestions,
+        available,
+    ))
+}
+
+/// Close matches for an unknown topic: topics whose full key or last segment
+/// contains the input, or is within a small edit distance of it.
+fn suggest_topics(key: &str, available: &[String]) -> Vec<String> {
+    const MAX_SUGGESTIONS: usize = 3;
+    let key = key.to_lowercase();
+    let mut ranked: Vec<(usize, &String)> = available
+        .iter()
+        .filter_map(|topic| {
+            let lower = topic.to_lowercase();
+            let leaf = lower.rsplit('/').next().unwrap_or(&lower);
+            if lower.contains(&key) || leaf.contains(&key) {
+                return Some((0, topic));
+            }
+            let distance = text::levenshtein(&key, leaf).min(text::levenshtein(&key, &lower));
+            (distance <= 3).then_some((distance, topic))
+        })
+        .collect();
+    ranked.sort();
+    ranked
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, topic)| topic.clone())
+        .collect()
 }
 
 fn load_extension_doc(topic: &str) -> Option<(String, String)> {
@@ -194,4 +225,17 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn unknown_topic_error_lists_topics_and_suggestions() {
+        let err = resolve(&["commands/deplyo".to_string()]).expect_err("unknown topic");
+
+        assert_eq!(err.code.as_str(), "docs.topic_not_found");
+        assert!(err.details["available_topics"]
+            .as_array()
+            .is_some_and(|topics| !topics.is_empty()));
+        assert!(err.details["suggestions"]
+            .as_array()
+            .is_some_and(|topics| topics.iter().any(|topic| topic == "commands/deploy")));
+    }
 }
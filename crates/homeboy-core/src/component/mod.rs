@@ -36,10 +36,10 @@ pub use audit::{
 };
 pub use config::{
     ArtifactInput, CleanupArtifactDeclaration, CommandScopeConfig, ComponentDeployConfig,
-    ComponentGithubReleaseConfig, ComponentOverrideConfig, ComponentReleaseConfig,
-    ComponentScriptsConfig, DependencyStackEdge, GitDeployConfig, GithubConfig, GithubHostConfig,
-    GithubReleaseOwner, PackageCoverageArtifactMatch, PackageCoverageConfig, ScopeConfig,
-    ScopedExtensionConfig, VersionTarget,
+    ComponentGithubReleaseConfig, ComponentOverrideConfig, ComponentReleaseCommitConfig,
+    ComponentReleaseConfig, ComponentScriptsConfig, DependencyStackEdge, GitDeployConfig,
+    GithubConfig, GithubHostConfig, GithubReleaseOwner, PackageCoverageArtifactMatch,
+    PackageCoverageConfig, ScopeConfig, ScopedExtensionConfig, VersionTarget,
 };
 pub use inventory::{
     exists, extension_provides_artifact_pattern, inventory, list, list_ids, load,
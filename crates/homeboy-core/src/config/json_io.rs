@@ -14,7 +14,7 @@ where
     T: DeserializeOwned,
 {
     let raw = std::fs::read_to_string(path).map_err(map_read_error)?;
-    serde_json::from_str(&raw).map_err(|error| map_parse_error(error, raw))
+    serde_json::from_str(&strip_json_comments(&raw)).map_err(|error| map_parse_error(error, raw))
 }
 
 pub fn read_optional_json_file_with<T, E>(
@@ -36,11 +36,99 @@ pub fn try_read_json_file<T: DeserializeOwned>(path: &Path) -> Option<T> {
 }
 
 /// Parse JSON string into typed value.
+///
+/// `//` and `/* */` comments and trailing commas are accepted; see
+/// [`strip_json_comments`].
 pub fn from_str<T: DeserializeOwned>(s: &str) -> Result<T> {
-    serde_json::from_str(s)
+    serde_json::from_str(&strip_json_comments(s))
         .map_err(|e| Error::validation_invalid_json(e, Some("parse json".to_string()), None))
 }
 
+/// Blank out `//` and `/* */` comments and trailing commas so hand-annotated
+/// config files parse as strict JSON.
+///
+/// Removed bytes are replaced with spaces (newlines are kept), so line and
+/// column numbers in parse errors still point at the original text. String
+/// contents are never touched. Writes always serialize strict JSON, so
+/// comments do not survive a save.
+pub fn strip_json_comments(raw: &str) -> String {
+    let mut bytes = raw.as_bytes().to_vec();
+    blank_comments(&mut bytes);
+    blank_trailing_commas(&mut bytes);
+    // Only ASCII bytes outside strings were replaced with ASCII spaces, and
+    // whole multi-byte characters inside comments were blanked byte-for-byte.
+    String::from_utf8(bytes).unwrap_or_else(|_| raw.to_string())
+}
+
+fn blank_comments(bytes: &mut [u8]) {
+    let mut i = 0;
+    let mut in_string = false;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if in_string {
+            match byte {
+                b'\\' => i += 1,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            i += 1;
+            continue;
+        }
+
+        match (byte, bytes.get(i + 1)) {
+            (b'"', _) => in_string = true,
+            (b'/', Some(b'/')) => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    bytes[i] = b' ';
+                    i += 1;
+                }
+                continue;
+            }
+            (b'/', Some(b'*')) => {
+                let mut end = i + 2;
+                while end < bytes.len()
+                    && !(bytes[end] == b'*' && bytes.get(end + 1) == Some(&b'/'))
+                {
+                    end += 1;
+                }
+                let end = (end + 2).min(bytes.len());
+                for b in &mut bytes[i..end] {
+                    if *b != b'\n' {
+                        *b = b' ';
+                    }
+                }
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+fn blank_trailing_commas(bytes: &mut [u8]) {
+    let mut i = 0;
+    let mut in_string = false;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if in_string {
+            match byte {
+                b'\\' => i += 1,
+                b'"' => in_string = false,
+                _ => {}
+            }
+        } else if byte == b'"' {
+            in_string = true;
+        } else if byte == b',' {
+            let next = bytes[i + 1..].iter().find(|b| !b.is_ascii_whitespace());
+            if matches!(next, Some(b'}') | Some(b']')) {
+                bytes[i] = b' ';
+            }
+        }
+        i += 1;
+    }
+}
+
 /// Serialize value to pretty-printed JSON string.
 pub fn to_string_pretty<T: Serialize>(data: &T) -> Result<String> {
     serde_json::to_string_pretty(data)
@@ -224,6 +312,37 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn commented_json_with_trailing_commas_parses() {
+        let raw = r#"{
+            // Production site
+            "domain": "example.com", /* primary */
+            "note": "keeps // and /* inside strings */",
+            "tags": ["a", "b",],
+        }"#;
+
+        let value: serde_json::Value = from_str(raw).unwrap();
+        assert_eq!(value["domain"], "example.com");
+        assert_eq!(value["note"], "keeps // and /* inside strings */");
+        assert_eq!(value["tags"], serde_json::json!(["a", "b"]));
+
+        let path = temp_json_path("commented.json");
+        fs::write(&path, raw).unwrap();
+        let value: serde_json::Value =
+            read_json_file_with(&path, |e| e.to_string(), |e, _| e.to_string()).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(value["tags"], serde_json::json!(["a", "b"]));
+    }
+
+    #[test]
+    fn stripped_json_keeps_error_positions() {
+        let raw = "// header\n{\n  \"a\": 1,\n  \"b\": oops\n}";
+        assert_eq!(strip_json_comments(raw).len(), raw.len());
+        let error =
+            serde_json::from_str::<serde_json::Value>(&strip_json_comments(raw)).unwrap_err();
+        assert_eq!(error.line(), 4);
+    }
+
     #[test]
     fn json_value_spec_accepts_legacy_bare_path() {
         let path = temp_json_path("bare-spec.json");
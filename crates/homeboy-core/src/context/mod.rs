@@ -403,14 +403,48 @@ pub(crate) fn resolve_project_server(project_id: &str) -> Result<ProjectServerCo
     })
 }
 
-pub fn require_project_base_path(project_id: &str, project: &Project) -> Result<String> {
+/// True when commands for `project` run on this machine (no `server_id`).
+pub fn is_local_project(project: &Project) -> bool {
     project
+        .server_id
+        .as_ref()
+        .is_none_or(|s| s.trim().is_empty())
+}
+
+/// Resolve `project.base_path` for command execution.
+///
+/// Every command that needs the project root goes through here so a missing
+/// or blank value always surfaces as the same `project.base_path` config
+/// error. Local paths (`use_local`) become a process working directory, so
+/// `~` is expanded here; remote paths are handed to the server's shell as-is.
+pub fn resolve_base_path(project: &Project, use_local: bool) -> Result<String> {
+    base_path_for(&project.id, project, use_local)
+}
+
+/// The configured `project.base_path`, trimmed and validated but otherwise
+/// unchanged, reporting `project_id` in the missing-key error.
+pub fn require_project_base_path(project_id: &str, project: &Project) -> Result<String> {
+    base_path_for(project_id, project, false)
+}
+
+fn base_path_for(project_id: &str, project: &Project, use_local: bool) -> Result<String> {
+    let base_path = project
         .base_path
-        .clone()
+        .as_deref()
+        .map(str::trim)
         .filter(|p| !p.is_empty())
-        .ok_or_else(|| Error::config_missing_key("project.base_path", Some(project_id.to_string())))
+        .ok_or_else(|| {
+            Error::config_missing_key("project.base_path", Some(project_id.to_string()))
+        })?;
+
+    if use_local {
+        Ok(shellexpand::tilde(base_path).into_owned())
+    } else {
+        Ok(base_path.to_string())
+    }
 }
 
+#[derive(Clone)]
 pub struct RemoteProjectContext {
     pub project: Project,
     pub server_id: String,
@@ -458,6 +492,54 @@ mod tests {
         manifest
     }
 
+    fn project_with_base_path(server_id: Option<&str>, base_path: Option<&str>) -> Project {
+        Project {
+            id: "site".to_string(),
+            server_id: server_id.map(str::to_string),
+            base_path: base_path.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resolve_base_path_expands_tilde_only_for_local_execution() {
+        with_isolated_home(|home| {
+            let local = project_with_base_path(None, Some("~/sites/demo"));
+            assert!(is_local_project(&local));
+            assert_eq!(
+                resolve_base_path(&local, true).expect("local base path"),
+                home.path().join("sites/demo").display().to_string()
+            );
+            assert_eq!(
+                require_project_base_path("site", &local).expect("local base path"),
+                "~/sites/demo"
+            );
+
+            let remote = project_with_base_path(Some("prod"), Some("~/public_html "));
+            assert!(!is_local_project(&remote));
+            assert_eq!(
+                resolve_base_path(&remote, false).expect("remote base path"),
+                "~/public_html"
+            );
+            assert_eq!(
+                require_project_base_path("site", &remote).expect("remote base path"),
+                "~/public_html"
+            );
+        });
+    }
+
+    #[test]
+    fn resolve_base_path_reports_the_same_error_when_missing_or_blank() {
+        for base_path in [None, Some(""), Some("   ")] {
+            for use_local in [true, false] {
+                let project = project_with_base_path(None, base_path);
+                let error = resolve_base_path(&project, use_local).expect_err("missing");
+                assert_eq!(error.code, crate::error::ErrorCode::ConfigMissingKey);
+                assert!(error.details.to_string().contains("project.base_path"));
+            }
+        }
+    }
+
     #[test]
     fn extension_suggestions_are_manifest_driven() {
         let dir = tempfile::tempdir().expect("tempdir");
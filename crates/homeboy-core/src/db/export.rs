@@ -0,0 +1,149 @@
+//! Full database export to a local SQL file.
+//!
+//! Runs the extension's `export_command` remotely and streams its stdout
+//! straight into `<output>.tmp`, so multi-gigabyte dumps never sit in memory.
+//! The temp file is renamed over the output only once the export succeeded.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::operations::build_context;
+use crate::engine::executor::execute_for_project_to_file;
+use crate::engine::shell;
+use crate::engine::template::{render_map, TemplateVars};
+use crate::{Error, Result};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DbExportResult {
+    pub project_id: String,
+    pub domain: Option<String>,
+    pub output: String,
+    pub tables: Vec<String>,
+    pub bytes_written: u64,
+    pub command: String,
+    pub stderr: Option<String>,
+    pub exit_code: i32,
+    pub success: bool,
+}
+
+/// Export the project database (or only `tables`) into `output`. A failed
+/// export removes its partial temp file and leaves any previous dump at
+/// `output` untouched.
+pub fn export(
+    project_id: &str,
+    tables: &[String],
+    output: &Path,
+    subtarget: Option<&str>,
+) -> Result<DbExportResult> {
+    let ctx = build_context(project_id, subtarget)?;
+    let template = ctx.db_cli.export_command.clone().ok_or_else(|| {
+        Error::validation_invalid_argument(
+            "export_command",
+            "The database extension does not define database.cli.export_command",
+            Some(project_id.to_string()),
+            None,
+        )
+    })?;
+
+    let mut vars = ctx.base_template_vars();
+    vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
+    vars.insert(TemplateVars::TABLES.to_string(), export_tables_arg(tables));
+    let command = render_map(&template, &vars);
+
+    let temp = export_temp_path(output);
+    let file = std::fs::File::create(&temp).map_err(|e| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("create export file {}", temp.display())),
+        )
+    })?;
+    let result = match execute_for_project_to_file(&ctx.project, &command, file) {
+        Ok(result) => result,
+        Err(error) => {
+            let _ = std::fs::remove_file(&temp);
+            return Err(error);
+        }
+    };
+    let bytes_written = finish_export(&temp, output, result.success)?;
+
+    Ok(DbExportResult {
+        project_id: ctx.project.id.clone(),
+        domain: Some(ctx.domain),
+        output: output.display().to_string(),
+        tables: tables.to_vec(),
+        bytes_written,
+        command,
+        stderr: Some(result.stderr),
+        exit_code: result.exit_code,
+        success: result.success,
+    })
+}
+
+/// Temp file the dump streams into: `<output>.tmp`, beside the output so the
+/// final rename stays on one filesystem.
+fn export_temp_path(output: &Path) -> PathBuf {
+    let mut temp = output.as_os_str().to_owned();
+    temp.push(".tmp");
+    PathBuf::from(temp)
+}
+
+/// Rename a successful dump over `output` and return its size, or discard a
+/// failed one. Either way `output` is never truncated or half-written.
+fn finish_export(temp: &Path, output: &Path, success: bool) -> Result<u64> {
+    if !success {
+        let _ = std::fs::remove_file(temp);
+        return Ok(0);
+    }
+    let bytes_written = std::fs::metadata(temp).map(|m| m.len()).unwrap_or(0);
+    std::fs::rename(temp, output).map_err(|e| {
+        let _ = std::fs::remove_file(temp);
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("move export file into {}", output.display())),
+        )
+    })?;
+    Ok(bytes_written)
+}
+
+/// Render `{{tables}}` as one shell-quoted, comma-separated argument, or an
+/// empty string so the template exports every table.
+fn export_tables_arg(tables: &[String]) -> String {
+    if tables.is_empty() {
+        return String::new();
+    }
+    shell::quote_arg(&tables.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_tables_arg_joins_and_quotes_tables() {
+        assert_eq!(export_tables_arg(&[]), "");
+        assert_eq!(
+            export_tables_arg(&["wp_posts".to_string(), "wp_postmeta".to_string()]),
+            "wp_posts,wp_postmeta"
+        );
+        assert_eq!(export_tables_arg(&["odd name".to_string()]), "'odd name'");
+    }
+
+    #[test]
+    fn finish_export_replaces_output_only_on_success() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let output = dir.path().join("site.sql");
+        let temp = export_temp_path(&output);
+        assert_eq!(temp, dir.path().join("site.sql.tmp"));
+        std::fs::write(&output, "previous dump").expect("write previous dump");
+
+        std::fs::write(&temp, "partial").expect("write partial dump");
+        assert_eq!(finish_export(&temp, &output, false).expect("discard"), 0);
+        assert!(!temp.exists());
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "previous dump");
+
+        std::fs::write(&temp, "new dump").expect("write new dump");
+        assert_eq!(finish_export(&temp, &output, true).expect("commit"), 8);
+        assert!(!temp.exists());
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "new dump");
+    }
+}
@@ -0,0 +1,155 @@
+//! Database import from a local SQL file.
+//!
+//! Streams the dump as stdin into the extension's `import_command`, so the file
+//! is never staged on the server or held in memory. Confirmation that the
+//! import may overwrite the database is the caller's job.
+
+use serde::Serialize;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use super::operations::build_context;
+use crate::engine::executor::execute_for_project_with_stdin_file;
+use crate::engine::template::{render_map, TemplateVars};
+use crate::{Error, Result};
+
+#[derive(Debug, Serialize, Clone)]
+pub struct DbImportResult {
+    pub project_id: String,
+    pub domain: Option<String>,
+    pub input: String,
+    /// Tables the dump creates, read from its `CREATE TABLE` statements.
+    pub tables: Vec<String>,
+    pub command: String,
+    pub stdout: Option<String>,
+    pub stderr: Option<String>,
+    pub exit_code: i32,
+    pub success: bool,
+}
+
+/// Import the SQL dump at `input` into the project database.
+pub fn import(project_id: &str, input: &Path, subtarget: Option<&str>) -> Result<DbImportResult> {
+    if !input.is_file() {
+        return Err(Error::validation_invalid_argument(
+            "input",
+            format!("SQL file not found: {}", input.display()),
+            Some(project_id.to_string()),
+            None,
+        ));
+    }
+
+    let ctx = build_context(project_id, subtarget)?;
+    let template = ctx.db_cli.import_command.clone().ok_or_else(|| {
+        Error::validation_invalid_argument(
+            "import_command",
+            "The database extension does not define database.cli.import_command",
+            Some(project_id.to_string()),
+            None,
+        )
+    })?;
+
+    let tables = dump_tables(input)?;
+
+    let mut vars = ctx.base_template_vars();
+    vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
+    let command = render_map(&template, &vars);
+
+    let input_path = input.to_string_lossy();
+    let output = execute_for_project_with_stdin_file(&ctx.project, &command, &input_path)?;
+
+    Ok(DbImportResult {
+        project_id: ctx.project.id.clone(),
+        domain: Some(ctx.domain),
+        input: input.display().to_string(),
+        tables,
+        command,
+        stdout: Some(output.stdout),
+        stderr: Some(output.stderr),
+        exit_code: output.exit_code,
+        success: output.success,
+    })
+}
+
+/// Scan the dump line by line for the tables it creates, in dump order.
+fn dump_tables(input: &Path) -> Result<Vec<String>> {
+    let file = std::fs::File::open(input).map_err(|e| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("open SQL file {}", input.display())),
+        )
+    })?;
+
+    let mut tables: Vec<String> = Vec::new();
+    for line in BufReader::new(file).split(b'\n') {
+        let line = line.map_err(|e| {
+            Error::internal_io(
+                e.to_string(),
+                Some(format!("read SQL file {}", input.display())),
+            )
+        })?;
+        if let Some(table) = create_table_name(&String::from_utf8_lossy(&line)) {
+            if !tables.contains(&table) {
+                tables.push(table);
+            }
+        }
+    }
+    Ok(tables)
+}
+
+/// Table name from a `CREATE TABLE [IF NOT EXISTS] name` line.
+fn create_table_name(line: &str) -> Option<String> {
+    let rest = strip_prefix_ignore_case(line.trim_start(), "CREATE TABLE ")?.trim_start();
+    let rest = strip_prefix_ignore_case(rest, "IF NOT EXISTS ")
+        .unwrap_or(rest)
+        .trim_start();
+    let name: String = rest
+        .trim_start_matches(['`', '"'])
+        .chars()
+        .take_while(|c| !matches!(c, '`' | '"' | ' ' | '(' | ';'))
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = value.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &value[prefix.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_tables_lists_created_tables_in_order() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let dump = dir.path().join("dump.sql");
+        std::fs::write(
+            &dump,
+            "DROP TABLE IF EXISTS `wp_posts`;\n\
+             CREATE TABLE `wp_posts` (\n  `ID` bigint\n);\n\
+             INSERT INTO `wp_posts` VALUES (1);\n\
+             create table if not exists wp_options (id int);\n\
+             CREATE TABLE `wp_posts` (\n",
+        )
+        .expect("write dump");
+
+        assert_eq!(
+            dump_tables(&dump).expect("scan dump"),
+            vec!["wp_posts", "wp_options"]
+        );
+    }
+
+    #[test]
+    fn create_table_name_ignores_other_statements() {
+        assert_eq!(
+            create_table_name("INSERT INTO `wp_posts` VALUES (1);"),
+            None
+        );
+        assert_eq!(create_table_name("-- CREATE TABLE comment"), None);
+        assert_eq!(
+            create_table_name("CREATE TABLE \"users\" (id int);").as_deref(),
+            Some("users")
+        );
+    }
+}
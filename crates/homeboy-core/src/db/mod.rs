@@ -3,14 +3,26 @@
 //! Two subsystems:
 //! - **Operations**: Query, search, list/describe tables, delete rows, drop tables
 //!   via extension-defined CLI commands.
+//! - **Search-replace**: guarded search-replace with per-table dry-run counts.
+//! - **Export**: stream a full or per-table SQL dump into a local file.
+//! - **Import**: stream a local SQL dump into the database.
 //! - **SSH forward**: ad-hoc SSH port-forward for connecting local ports to
 //!   remote databases (distinct from the `core/tunnel` service-tunnel entity).
 
+mod export;
+mod import;
 mod operations;
+mod search_replace;
 mod ssh_forward;
 
 // Re-export everything at module level to preserve existing import paths.
+pub use export::{export, DbExportResult};
+pub use import::{import, DbImportResult};
 pub use operations::{
-    delete_row, describe_table, drop_table, list_tables, query, search, DbResult,
+    delete_row, describe_table, drop_table, list_tables, list_tables_with_options, query, search,
+    DbResult, ListTablesOptions, TableRowCount, TableStats,
+};
+pub use search_replace::{
+    search_replace, ReplacementCountSource, SearchReplaceResult, TableReplacementCount,
 };
 pub use ssh_forward::{create_tunnel, DbTunnelInfo, DbTunnelResult};
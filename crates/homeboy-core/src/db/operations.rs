@@ -1,9 +1,9 @@
 //! Database query and table operations.
 //!
-//! Provides list_tables, describe_table, query, search, delete_row, and drop_table
+//! Provides list_tables (with optional per-table statistics and exact row counts), describe_table, query, search, delete_row, and drop_table
 //! operations that execute through extension-defined CLI commands.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::context::require_project_base_path;
@@ -26,21 +26,54 @@ pub struct DbResult {
     pub exit_code: i32,
     pub success: bool,
     pub tables: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_stats: Option<Vec<TableStats>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_counts: Option<Vec<TableRowCount>>,
     pub table: Option<String>,
     pub sql: Option<String>,
 }
 
-struct DbContext {
-    project: Project,
-    base_path: String,
-    domain: String,
-    cli_path: String,
-    db_cli: DatabaseCliConfig,
+/// Per-table statistics reported by `db tables`.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct TableStats {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+}
+
+/// Exact row count for one table, from `db tables --counts`.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct TableRowCount {
+    pub name: String,
+    pub rows: u64,
+}
+
+/// Options for [`list_tables_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct ListTablesOptions {
+    /// Run one grouped `COUNT(*)` query for exact per-table row counts.
+    pub counts: bool,
+    /// Leave tables matching the extension's `protected_suffixes` uncounted.
+    pub skip_protected: bool,
+}
+
+pub(super) struct DbContext {
+    pub(super) project: Project,
+    pub(super) base_path: String,
+    pub(super) domain: String,
+    pub(super) cli_path: String,
+    pub(super) db_cli: DatabaseCliConfig,
+    pub(super) table_prefix: Option<String>,
 }
 
 impl DbContext {
     /// Build base template variables for database commands.
-    fn base_template_vars(&self) -> HashMap<String, String> {
+    pub(super) fn base_template_vars(&self) -> HashMap<String, String> {
         let mut vars = HashMap::with_capacity(8);
         vars.insert(TemplateVars::SITE_PATH.to_string(), self.base_path.clone());
         vars.insert(TemplateVars::CLI_PATH.to_string(), self.cli_path.clone());
@@ -65,11 +98,12 @@ impl DbContext {
     }
 }
 
-fn build_context(project_id: &str, subtarget: Option<&str>) -> Result<DbContext> {
+pub(super) fn build_context(project_id: &str, subtarget: Option<&str>) -> Result<DbContext> {
     let project = project::load(project_id)?;
     let base_path = require_project_base_path(project_id, &project)?;
 
     let domain = resolve_domain(&project, subtarget, project_id)?;
+    let table_prefix = resolve_table_prefix(&project, subtarget);
 
     let extensions = load_all_extensions().unwrap_or_default();
 
@@ -91,6 +125,23 @@ fn build_context(project_id: &str, subtarget: Option<&str>) -> Result<DbContext>
         domain,
         cli_path,
         db_cli,
+        table_prefix,
+    })
+}
+
+/// Resolve the effective table prefix for the project, honoring multisite
+/// subtargets (`wp_` for the main site, `wp_2_` for site 2, ...).
+fn resolve_table_prefix(project: &Project, subtarget: Option<&str>) -> Option<String> {
+    let base = project.table_prefix.as_deref()?;
+    let target = subtarget.and_then(|sub_id| {
+        project.sub_targets.iter().find(|t| {
+            project::slugify_id(&t.name).ok().as_deref() == Some(sub_id)
+                || text::identifier_eq(&t.name, sub_id)
+        })
+    });
+    Some(match target {
+        Some(target) => target.table_prefix(base),
+        None => base.to_string(),
     })
 }
 
@@ -156,7 +207,75 @@ fn parse_json_tables(json: &str) -> Vec<String> {
     serde_json::from_str::<Vec<String>>(json).unwrap_or_default()
 }
 
+/// Keep only tables belonging to `prefix`. On a multisite main-site prefix,
+/// tables grouped under a numbered site prefix (`wp_2_posts`) are excluded.
+fn filter_tables_by_prefix(tables: Vec<String>, prefix: &str, multisite: bool) -> Vec<String> {
+    tables
+        .into_iter()
+        .filter(|name| {
+            let Some(rest) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            !multisite || !is_numbered_site_table(rest)
+        })
+        .collect()
+}
+
+fn is_numbered_site_table(rest: &str) -> bool {
+    let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    digits > 0 && rest[digits..].starts_with('_')
+}
+
+#[derive(Deserialize)]
+struct RawTableStats {
+    #[serde(alias = "Name", alias = "TABLE_NAME", alias = "table")]
+    name: String,
+    #[serde(default, alias = "Rows", alias = "TABLE_ROWS")]
+    rows: Option<serde_json::Value>,
+    #[serde(default, alias = "Size", alias = "size", alias = "SIZE_BYTES")]
+    size_bytes: Option<serde_json::Value>,
+}
+
+/// Parse a stats value that may be a JSON number or a string such as `"16384 B"`.
+fn stat_value(value: Option<&serde_json::Value>) -> Option<u64> {
+    match value? {
+        serde_json::Value::Number(n) => n.as_u64(),
+        serde_json::Value::String(s) => {
+            let digits: String = s
+                .trim()
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            digits.parse().ok()
+        }
+        _ => None,
+    }
+}
+
+fn parse_json_table_stats(json: &str) -> HashMap<String, TableStats> {
+    serde_json::from_str::<Vec<RawTableStats>>(json)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|raw| {
+            let stats = TableStats {
+                rows: stat_value(raw.rows.as_ref()),
+                size_bytes: stat_value(raw.size_bytes.as_ref()),
+                name: raw.name,
+            };
+            (stats.name.clone(), stats)
+        })
+        .collect()
+}
+
 pub fn list_tables(project_id: &str, subtarget: Option<&str>) -> Result<DbResult> {
+    list_tables_with_options(project_id, subtarget, &ListTablesOptions::default())
+}
+
+pub fn list_tables_with_options(
+    project_id: &str,
+    subtarget: Option<&str>,
+    options: &ListTablesOptions,
+) -> Result<DbResult> {
     let ctx = build_context(project_id, subtarget)?;
 
     let vars = ctx.base_template_vars();
@@ -164,11 +283,60 @@ pub fn list_tables(project_id: &str, subtarget: Option<&str>) -> Result<DbResult
 
     let output = execute_for_project(&ctx.project, &command)?;
     let tables = if output.success {
-        Some(parse_json_tables(&output.stdout))
+        let mut tables = parse_json_tables(&output.stdout);
+        if let Some(prefix) = ctx.table_prefix.as_deref() {
+            let multisite = !ctx.project.sub_targets.is_empty();
+            tables = filter_tables_by_prefix(tables, prefix, multisite);
+        }
+        tables.sort();
+        tables.dedup();
+        Some(tables)
     } else {
         None
     };
 
+    // Statistics are best-effort: a failing stats command still reports names.
+    let table_stats = match (&tables, ctx.db_cli.table_stats_command.as_deref()) {
+        (Some(tables), Some(stats_template)) => {
+            let stats_output =
+                execute_for_project(&ctx.project, &render_map(stats_template, &vars))?;
+            let mut by_name = if stats_output.success {
+                parse_json_table_stats(&stats_output.stdout)
+            } else {
+                HashMap::new()
+            };
+            Some(
+                tables
+                    .iter()
+                    .map(|name| {
+                        by_name.remove(name).unwrap_or_else(|| TableStats {
+                            name: name.clone(),
+                            rows: None,
+                            size_bytes: None,
+                        })
+                    })
+                    .collect(),
+            )
+        }
+        _ => None,
+    };
+
+    let table_counts = match &tables {
+        Some(tables) if options.counts => {
+            let counted: Vec<&str> = tables
+                .iter()
+                .map(String::as_str)
+                .filter(|name| {
+                    !options.skip_protected
+                        || !is_protected_table(name, &ctx.db_cli.protected_suffixes)
+                })
+                .filter(|name| is_countable_table_name(name))
+                .collect();
+            Some(count_table_rows(&ctx, &counted)?)
+        }
+        _ => None,
+    };
+
     Ok(DbResult {
         project_id: ctx.project.id.clone(),
         base_path: Some(ctx.base_path),
@@ -179,11 +347,100 @@ pub fn list_tables(project_id: &str, subtarget: Option<&str>) -> Result<DbResult
         exit_code: output.exit_code,
         success: output.success,
         tables,
+        table_prefix: ctx.table_prefix,
+        table_stats,
+        table_counts,
         table: None,
         sql: None,
     })
 }
 
+fn is_protected_table(name: &str, protected_suffixes: &[String]) -> bool {
+    protected_suffixes
+        .iter()
+        .any(|suffix| !suffix.is_empty() && name.ends_with(suffix.as_str()))
+}
+
+/// Count rows for every table in one `UNION ALL` query through `query_command`.
+fn count_table_rows(ctx: &DbContext, tables: &[&str]) -> Result<Vec<TableRowCount>> {
+    if tables.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut vars = ctx.base_template_vars();
+    vars.insert(TemplateVars::QUERY.to_string(), row_count_sql(tables));
+    vars.insert(TemplateVars::FORMAT.to_string(), "json".to_string());
+    vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
+    let command = render_map(&ctx.db_cli.query_command, &vars);
+
+    let output = execute_for_project(&ctx.project, &command)?;
+    if !output.success {
+        return Err(Error::internal_unexpected(format!(
+            "Row count query failed: {}",
+            output.stderr.trim()
+        )));
+    }
+
+    let mut by_name = parse_row_counts(&output.stdout);
+    tables
+        .iter()
+        .map(|name| {
+            by_name
+                .remove(*name)
+                .map(|rows| TableRowCount {
+                    name: name.to_string(),
+                    rows,
+                })
+                .ok_or_else(|| {
+                    Error::internal_unexpected(format!(
+                        "Row count query returned no count for {name}: {}",
+                        output.stdout.trim()
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Table names are interpolated unquoted into `query_command`, which is
+/// usually a double-quoted shell argument (`wp db query "{{query}}"`), so only
+/// names that need neither SQL nor shell quoting are counted. `$` is excluded
+/// because the shell would expand it.
+fn is_countable_table_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn row_count_sql(tables: &[&str]) -> String {
+    tables
+        .iter()
+        .map(|name| {
+            format!("SELECT '{name}' AS homeboy_table, COUNT(*) AS homeboy_count FROM {name}")
+        })
+        .collect::<Vec<_>>()
+        .join(" UNION ALL ")
+}
+
+/// Parse grouped counts from JSON (`[{"homeboy_table":"t","homeboy_count":"3"}]`)
+/// or tab-separated `table\tcount` rows.
+fn parse_row_counts(stdout: &str) -> HashMap<String, u64> {
+    if let Ok(rows) = serde_json::from_str::<Vec<serde_json::Value>>(stdout.trim()) {
+        return rows
+            .iter()
+            .filter_map(|row| {
+                let name = row.get("homeboy_table")?.as_str()?.to_string();
+                Some((name, stat_value(row.get("homeboy_count"))?))
+            })
+            .collect();
+    }
+
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, count) = line.trim().split_once('\t')?;
+            Some((name.to_string(), count.trim().parse().ok()?))
+        })
+        .collect()
+}
+
 pub fn describe_table(
     project_id: &str,
     table: Option<&str>,
@@ -208,6 +465,9 @@ pub fn describe_table(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_prefix: None,
+        table_stats: None,
+        table_counts: None,
         table: Some(table.to_string()),
         sql: None,
     })
@@ -257,6 +517,9 @@ pub fn query(project_id: &str, sql: &str, subtarget: Option<&str>) -> Result<DbR
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_prefix: None,
+        table_stats: None,
+        table_counts: None,
         table: None,
         sql: Some(sql.to_string()),
     })
@@ -318,6 +581,9 @@ pub fn search(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_prefix: None,
+        table_stats: None,
+        table_counts: None,
         table: Some(table.to_string()),
         sql: Some(search_sql),
     })
@@ -356,6 +622,9 @@ pub fn delete_row(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_prefix: None,
+        table_stats: None,
+        table_counts: None,
         table: Some(table.to_string()),
         sql: Some(delete_sql),
     })
@@ -389,6 +658,9 @@ pub fn drop_table(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_prefix: None,
+        table_stats: None,
+        table_counts: None,
         table: Some(table.to_string()),
         sql: Some(drop_sql),
     })
@@ -405,10 +677,92 @@ mod tests {
         assert_eq!(tables, vec!["wp_posts", "wp_options", "wp_users"]);
     }
 
+    #[test]
+    fn row_count_query_renders_safely_in_double_quoted_template() {
+        let counted: Vec<&str> = [
+            "wp_posts",
+            "o'dd`name",
+            "wp_$(touch x)",
+            "wp_$HOME",
+            "wp_2_options",
+        ]
+        .into_iter()
+        .filter(|name| is_countable_table_name(name))
+        .collect();
+        assert_eq!(counted, vec!["wp_posts", "wp_2_options"]);
+
+        let mut vars = HashMap::new();
+        vars.insert(TemplateVars::QUERY.to_string(), row_count_sql(&counted));
+        let command = render_map(r#"wp db query "{{query}}" --format=json"#, &vars);
+
+        assert_eq!(
+            command,
+            "wp db query \"SELECT 'wp_posts' AS homeboy_table, COUNT(*) AS homeboy_count FROM wp_posts \
+             UNION ALL SELECT 'wp_2_options' AS homeboy_table, COUNT(*) AS homeboy_count FROM wp_2_options\" --format=json"
+        );
+        assert!(!command.contains('`'));
+        assert!(!command.contains("$("));
+    }
+
+    #[test]
+    fn parse_row_counts_reads_json_and_tab_separated_rows() {
+        let json = parse_row_counts(
+            r#"[{"homeboy_table":"wp_posts","homeboy_count":"12"},{"homeboy_table":"wp_users","homeboy_count":3}]"#,
+        );
+        assert_eq!(json.get("wp_posts"), Some(&12));
+        assert_eq!(json.get("wp_users"), Some(&3));
+
+        let plain = parse_row_counts("homeboy_table\thomeboy_count\nwp_posts\t12\n");
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain.get("wp_posts"), Some(&12));
+    }
+
+    #[test]
+    fn is_protected_table_matches_suffixes() {
+        let suffixes = vec!["_users".to_string(), "_usermeta".to_string(), String::new()];
+        assert!(is_protected_table("wp_users", &suffixes));
+        assert!(is_protected_table("wp_2_usermeta", &suffixes));
+        assert!(!is_protected_table("wp_posts", &suffixes));
+    }
+
     #[test]
     fn parse_json_tables_returns_empty_on_invalid() {
         let invalid = "not json";
         let tables = parse_json_tables(invalid);
         assert!(tables.is_empty());
     }
+
+    #[test]
+    fn filter_tables_by_prefix_separates_multisite_groups() {
+        let tables = vec![
+            "wp_posts".to_string(),
+            "wp_2_posts".to_string(),
+            "wp_20_options".to_string(),
+            "wp_2fa_tokens".to_string(),
+            "other_table".to_string(),
+        ];
+
+        assert_eq!(
+            filter_tables_by_prefix(tables.clone(), "wp_", true),
+            vec!["wp_posts", "wp_2fa_tokens"]
+        );
+        assert_eq!(
+            filter_tables_by_prefix(tables.clone(), "wp_2_", true),
+            vec!["wp_2_posts"]
+        );
+        assert_eq!(filter_tables_by_prefix(tables, "wp_", false).len(), 4);
+    }
+
+    #[test]
+    fn parse_json_table_stats_accepts_cli_and_information_schema_shapes() {
+        let wp = parse_json_table_stats(r#"[{"Name":"wp_posts","Size":"16384 B"}]"#);
+        assert_eq!(wp["wp_posts"].size_bytes, Some(16384));
+        assert_eq!(wp["wp_posts"].rows, None);
+
+        let schema = parse_json_table_stats(
+            r#"[{"TABLE_NAME":"wp_options","TABLE_ROWS":"412","size_bytes":98304}]"#,
+        );
+        assert_eq!(schema["wp_options"].rows, Some(412));
+        assert_eq!(schema["wp_options"].size_bytes, Some(98304));
+    }
 }
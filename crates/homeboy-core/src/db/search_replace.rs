@@ -0,0 +1,395 @@
+//! Search-replace across database tables with reviewable dry-run counts.
+//!
+//! With an extension `search_replace_command` (e.g. `wp search-replace`), the
+//! dry-run output is parsed into per-table replacement counts. Without one, a
+//! dry-run estimates counts through the generic `query_command` with one
+//! `SELECT COUNT(*) ... LIKE` per targeted `table.column`. Tools that only
+//! print a summary (`N replacements`) still report `total_replacements`.
+
+use serde::Serialize;
+
+use super::operations::{build_context, DbContext};
+use crate::engine::executor::execute_for_project;
+use crate::engine::shell;
+use crate::engine::template::{render_map, TemplateVars};
+use crate::{Error, Result};
+
+/// Replacements reported (or estimated) for one table.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct TableReplacementCount {
+    pub table: String,
+    pub replacements: u64,
+}
+
+/// Where `counts` came from.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplacementCountSource {
+    /// Parsed from the extension tool's own dry-run/apply report.
+    Tool,
+    /// Matching-row estimate from `SELECT COUNT(*) ... LIKE` queries.
+    LikeEstimate,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SearchReplaceResult {
+    pub project_id: String,
+    pub domain: Option<String>,
+    pub search: String,
+    pub replace: String,
+    pub tables: Vec<String>,
+    pub dry_run: bool,
+    pub count_source: ReplacementCountSource,
+    pub counts: Vec<TableReplacementCount>,
+    pub total_replacements: u64,
+    pub commands: Vec<String>,
+    pub stdout: Option<String>,
+    pub stderr: Option<String>,
+    pub exit_code: i32,
+    pub success: bool,
+}
+
+pub fn search_replace(
+    project_id: &str,
+    search: &str,
+    replace: &str,
+    tables: &[String],
+    apply: bool,
+    subtarget: Option<&str>,
+) -> Result<SearchReplaceResult> {
+    if search.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "search",
+            "Search string must not be empty",
+            Some(project_id.to_string()),
+            None,
+        ));
+    }
+
+    let ctx = build_context(project_id, subtarget)?;
+
+    match ctx.db_cli.search_replace_command.clone() {
+        Some(template) => run_tool(&ctx, &template, search, replace, tables, apply),
+        None if apply => Err(Error::validation_invalid_argument(
+            "search_replace_command",
+            "The database extension does not define database.cli.search_replace_command; search-replace can only be estimated, not applied",
+            Some(project_id.to_string()),
+            None,
+        )),
+        None => estimate_with_like(&ctx, search, replace, tables),
+    }
+}
+
+fn run_tool(
+    ctx: &DbContext,
+    template: &str,
+    search: &str,
+    replace: &str,
+    tables: &[String],
+    apply: bool,
+) -> Result<SearchReplaceResult> {
+    let mut vars = ctx.base_template_vars();
+    vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
+    vars.insert(TemplateVars::SEARCH.to_string(), shell::quote_arg(search));
+    vars.insert(TemplateVars::REPLACE.to_string(), shell::quote_arg(replace));
+    vars.insert(TemplateVars::TABLES.to_string(), shell::quote_args(tables));
+    vars.insert(
+        TemplateVars::DRY_RUN.to_string(),
+        if apply { "" } else { "--dry-run" }.to_string(),
+    );
+    let command = render_map(template, &vars);
+
+    let output = execute_for_project(&ctx.project, &command)?;
+    let counts = if output.success {
+        parse_tool_report(&output.stdout)
+    } else {
+        Vec::new()
+    };
+    // Tools without a per-table report may still print a summary total.
+    let total_replacements = if counts.is_empty() && output.success {
+        parse_tool_total(&output.stdout).unwrap_or(0)
+    } else {
+        counts.iter().map(|c| c.replacements).sum()
+    };
+
+    Ok(SearchReplaceResult {
+        project_id: ctx.project.id.clone(),
+        domain: Some(ctx.domain.clone()),
+        search: search.to_string(),
+        replace: replace.to_string(),
+        tables: tables.to_vec(),
+        dry_run: !apply,
+        count_source: ReplacementCountSource::Tool,
+        total_replacements,
+        counts,
+        commands: vec![command],
+        stdout: Some(output.stdout),
+        stderr: Some(output.stderr),
+        exit_code: output.exit_code,
+        success: output.success,
+    })
+}
+
+fn estimate_with_like(
+    ctx: &DbContext,
+    search: &str,
+    replace: &str,
+    tables: &[String],
+) -> Result<SearchReplaceResult> {
+    let targets = parse_estimate_targets(&ctx.project.id, tables)?;
+    let pattern = escape_like(search).replace('\'', "''");
+
+    let mut counts: Vec<TableReplacementCount> = Vec::new();
+    let mut commands = Vec::new();
+    let mut stderr = String::new();
+    let mut exit_code = 0;
+
+    for (table, column) in targets {
+        let sql = format!(
+            "SELECT COUNT(*) AS homeboy_count FROM {table} WHERE {column} LIKE '%{pattern}%'"
+        );
+        let mut vars = ctx.base_template_vars();
+        vars.insert(TemplateVars::QUERY.to_string(), sql);
+        vars.insert(TemplateVars::FORMAT.to_string(), "json".to_string());
+        vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
+        let command = render_map(&ctx.db_cli.query_command, &vars);
+        let output = execute_for_project(&ctx.project, &command)?;
+        commands.push(command);
+
+        if !output.success {
+            stderr.push_str(&output.stderr);
+            exit_code = output.exit_code;
+            break;
+        }
+        let rows = parse_count_output(&output.stdout).ok_or_else(|| {
+            Error::internal_unexpected(format!(
+                "Could not parse COUNT(*) result for {table}.{column}: {}",
+                output.stdout.trim()
+            ))
+        })?;
+
+        match counts.iter_mut().find(|c| c.table == table) {
+            Some(existing) => existing.replacements += rows,
+            None => counts.push(TableReplacementCount {
+                table,
+                replacements: rows,
+            }),
+        }
+    }
+
+    counts.sort_by(|a, b| a.table.cmp(&b.table));
+
+    Ok(SearchReplaceResult {
+        project_id: ctx.project.id.clone(),
+        domain: Some(ctx.domain.clone()),
+        search: search.to_string(),
+        replace: replace.to_string(),
+        tables: tables.to_vec(),
+        dry_run: true,
+        count_source: ReplacementCountSource::LikeEstimate,
+        total_replacements: counts.iter().map(|c| c.replacements).sum(),
+        counts,
+        commands,
+        stdout: None,
+        stderr: (!stderr.is_empty()).then_some(stderr),
+        exit_code,
+        success: exit_code == 0,
+    })
+}
+
+/// Targets for the generic estimate must name `table.column`; there is no
+/// portable way to LIKE-match a whole row.
+fn parse_estimate_targets(project_id: &str, tables: &[String]) -> Result<Vec<(String, String)>> {
+    if tables.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "tables",
+            "This project's database extension has no search_replace_command, so a dry-run can only be estimated. Pass --tables as table.column targets (e.g. wp_posts.post_content)",
+            Some(project_id.to_string()),
+            None,
+        ));
+    }
+
+    tables
+        .iter()
+        .map(|target| {
+            let parsed = target
+                .split_once('.')
+                .filter(|(table, column)| is_sql_identifier(table) && is_sql_identifier(column));
+            parsed
+                .map(|(table, column)| (table.to_string(), column.to_string()))
+                .ok_or_else(|| {
+                    Error::validation_invalid_argument(
+                        "tables",
+                        format!(
+                            "Estimate target '{}' must be table.column using letters, digits, '_' or '$'",
+                            target
+                        ),
+                        Some(project_id.to_string()),
+                        None,
+                    )
+                })
+        })
+        .collect()
+}
+
+fn is_sql_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Parse a COUNT(*) result from JSON (`[{"homeboy_count":"3"}]`) or plain
+/// tabular output (`homeboy_count\n3`).
+fn parse_count_output(stdout: &str) -> Option<u64> {
+    if let Ok(rows) = serde_json::from_str::<Vec<serde_json::Value>>(stdout.trim()) {
+        let value = rows.first()?.get("homeboy_count")?;
+        return match value {
+            serde_json::Value::Number(n) => n.as_u64(),
+            serde_json::Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        };
+    }
+
+    stdout
+        .lines()
+        .rev()
+        .find_map(|line| line.split_whitespace().last()?.parse().ok())
+}
+
+/// Parse a wp-cli style report (`Table | Column | Replacements | Type`), in
+/// ASCII-table or tab-separated form, into per-table totals.
+fn parse_tool_report(stdout: &str) -> Vec<TableReplacementCount> {
+    let mut counts: Vec<TableReplacementCount> = Vec::new();
+
+    for line in stdout.lines() {
+        let cells: Vec<&str> = line
+            .split(['|', '\t'])
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        if cells.len() < 3 {
+            continue;
+        }
+        let Ok(replacements) = cells[2].parse::<u64>() else {
+            continue;
+        };
+        let table = cells[0];
+        match counts.iter_mut().find(|c| c.table == table) {
+            Some(existing) => existing.replacements += replacements,
+            None => counts.push(TableReplacementCount {
+                table: table.to_string(),
+                replacements,
+            }),
+        }
+    }
+
+    counts.sort_by(|a, b| a.table.cmp(&b.table));
+    counts
+}
+
+/// Parse a summary total such as `Success: 17 replacements to be made.` or
+/// `Made 1 replacement.`; the last matching line wins.
+fn parse_tool_total(stdout: &str) -> Option<u64> {
+    stdout.lines().rev().find_map(|line| {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        words.windows(2).find_map(|pair| {
+            pair[1]
+                .to_ascii_lowercase()
+                .starts_with("replacement")
+                .then(|| pair[0].parse().ok())
+                .flatten()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tool_report_sums_columns_per_table() {
+        let stdout = "\
++-------------+--------------+--------------+------+
+| Table       | Column       | Replacements | Type |
++-------------+--------------+--------------+------+
+| wp_posts    | post_content | 12           | SQL  |
+| wp_posts    | guid         | 3            | SQL  |
+| wp_options  | option_value | 2            | PHP  |
+| wp_users    | user_url     | 0            | SQL  |
++-------------+--------------+--------------+------+
+Success: 17 replacements to be made.";
+
+        assert_eq!(
+            parse_tool_report(stdout),
+            vec![
+                TableReplacementCount {
+                    table: "wp_options".to_string(),
+                    replacements: 2
+                },
+                TableReplacementCount {
+                    table: "wp_posts".to_string(),
+                    replacements: 15
+                },
+                TableReplacementCount {
+                    table: "wp_users".to_string(),
+                    replacements: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_tool_report_accepts_tab_separated_rows() {
+        let stdout = "Table\tColumn\tReplacements\tType\nwp_posts\tguid\t4\tSQL\n";
+        let counts = parse_tool_report(stdout);
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[0].replacements, 4);
+    }
+
+    #[test]
+    fn parse_tool_total_reads_summary_lines() {
+        assert_eq!(
+            parse_tool_total("Success: 17 replacements to be made."),
+            Some(17)
+        );
+        assert_eq!(parse_tool_total("done\nMade 1 replacement.\n"), Some(1));
+        assert_eq!(parse_tool_total("Replacements: none"), None);
+    }
+
+    #[test]
+    fn parse_count_output_reads_json_and_plain_rows() {
+        assert_eq!(parse_count_output(r#"[{"homeboy_count":"7"}]"#), Some(7));
+        assert_eq!(parse_count_output(r#"[{"homeboy_count":9}]"#), Some(9));
+        assert_eq!(parse_count_output("homeboy_count\n11\n"), Some(11));
+        assert_eq!(parse_count_output("no rows"), None);
+    }
+
+    #[test]
+    fn estimate_targets_require_table_dot_column() {
+        let error = parse_estimate_targets("site", &[]).expect_err("no targets");
+        assert!(error.details.to_string().contains("search_replace_command"));
+
+        let error =
+            parse_estimate_targets("site", &["wp_posts".to_string()]).expect_err("bare table");
+        assert!(error.details.to_string().contains("table.column"));
+
+        assert_eq!(
+            parse_estimate_targets("site", &["wp_posts.post_content".to_string()]).unwrap(),
+            vec![("wp_posts".to_string(), "post_content".to_string())]
+        );
+        assert!(parse_estimate_targets("site", &["wp_posts.x;DROP".to_string()]).is_err());
+    }
+
+    #[test]
+    fn escape_like_escapes_wildcards() {
+        assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+    }
+}
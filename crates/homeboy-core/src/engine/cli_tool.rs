@@ -2,7 +2,7 @@ use serde::Serialize;
 use std::collections::HashMap;
 
 use crate::component::{self, Component};
-use crate::context::resolve_project_ssh;
+use crate::context::{is_local_project, resolve_base_path, resolve_project_ssh};
 use crate::engine::executor;
 use crate::engine::shell;
 use crate::engine::template::{render_map, TemplateVars};
@@ -13,7 +13,11 @@ use crate::project::{self, Project};
 use crate::server;
 use crate::server::{execute_local_command, CommandOutput};
 use crate::{Error, Result};
-use homeboy_extension_contract::{CliAutoFlag, CliConfig};
+use homeboy_extension_contract::{CliAutoFlag, CliConfig, ExtensionManifest};
+
+/// Set (to anything but `0`) to skip the pre-flight check that the resolved
+/// CLI binary is installed, once it has been confirmed for a target.
+pub const SKIP_TOOL_CHECK_ENV: &str = "HOMEBOY_SKIP_CLI_TOOL_CHECK";
 
 #[derive(Serialize, Clone)]
 
@@ -24,10 +28,30 @@ pub struct CliToolResult {
     pub target_domain: Option<String>,
     pub executed_command: String,
     pub stdout: String,
+    /// Stdout parsed as JSON when the tool emitted a JSON document
+    /// (e.g. `pm2 jlist`, `wp post list --format=json`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdout_json: Option<serde_json::Value>,
     pub stderr: String,
     pub exit_code: i32,
+    /// Subtargets reported by `--list-targets` instead of running the tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<CliTarget>>,
+}
+
+/// A project subtarget addressable as `homeboy <tool> <project>:<id> ...`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct CliTarget {
+    pub id: String,
+    pub name: String,
+    pub domain: String,
+    pub is_default: bool,
 }
 
+/// Passed as the only argument, lists the project's subtargets without
+/// executing the tool.
+pub const LIST_TARGETS_FLAG: &str = "--list-targets";
+
 pub fn run(tool: &str, identifier: &str, args: &[String]) -> Result<CliToolResult> {
     // Normalize args: split quoted strings containing spaces.
     // This ensures both syntaxes work identically:
@@ -35,23 +59,87 @@ pub fn run(tool: &str, identifier: &str, args: &[String]) -> Result<CliToolResul
     //   homeboy wp extra-chill:events "sampleplugin pipelines list"
     let args = shell::normalize_args(args);
 
-    // Parse project:subtarget syntax
+    // Try component first (uses original identifier for component lookup)
+    if let Some(result) = try_run_for_component(tool, identifier, &args) {
+        return result;
+    }
+
+    let (project_id, full_args) = project_args(identifier, &args);
+    run_for_project(tool, project_id, &full_args)
+}
+
+/// Run a project CLI command with inherited stdio (e.g. `wp shell`) instead of
+/// capturing its output. Subtarget and domain resolution match [`run`].
+/// Returns the tool's exit code.
+pub fn run_interactive(tool: &str, identifier: &str, args: &[String]) -> Result<i32> {
+    let args = shell::normalize_args(args);
+    let (project_id, full_args) = project_args(identifier, &args);
+    if full_args.is_empty() {
+        return Err(Error::validation_missing_argument(vec![
+            "command".to_string()
+        ]));
+    }
+
+    let (extension, cli_config) = cli_extension(tool)?;
+    let project = project::load(project_id)?;
+    let cli_path = resolve_cli_path(&project, &cli_config);
+
+    if is_local_project(&project) {
+        let base_path = resolve_base_path(&project, true).ok();
+        ensure_tool_installed(
+            &cli_config,
+            &cli_path,
+            base_path.as_deref(),
+            "local",
+            execute_local_command,
+        )?;
+    } else {
+        let ctx = resolve_project_ssh(project_id)?;
+        ensure_tool_installed(
+            &cli_config,
+            &cli_path,
+            ctx.base_path.as_deref(),
+            &format!("server '{}'", ctx.server_id),
+            |probe| ctx.client.execute(probe),
+        )?;
+    }
+
+    let (_, command) = build_project_command(&project, &cli_config, &extension.id, &full_args)?;
+    executor::execute_for_project_interactive(&project, &command)
+}
+
+/// Split `project:subtarget` and prepend the embedded subtarget to the args.
+fn project_args<'a>(identifier: &'a str, args: &[String]) -> (&'a str, Vec<String>) {
     let (project_id, embedded_subtarget) = crate::engine::text::split_identifier(identifier);
 
-    // Build args with embedded subtarget prepended if present
-    let full_args: Vec<String> = match embedded_subtarget {
+    let full_args = match embedded_subtarget {
         Some(sub) => std::iter::once(sub.to_string())
             .chain(args.iter().cloned())
             .collect(),
         None => args.to_vec(),
     };
 
-    // Try component first (uses original identifier for component lookup)
-    if let Some(result) = try_run_for_component(tool, identifier, &args) {
-        return result;
-    }
+    (project_id, full_args)
+}
 
-    run_for_project(tool, project_id, &full_args)
+fn cli_extension(tool: &str) -> Result<(ExtensionManifest, CliConfig)> {
+    let extension = find_extension_by_tool(tool).ok_or_else(|| {
+        Error::validation_invalid_argument(
+            "tool",
+            format!("No extension provides tool '{}'", tool),
+            Some(tool.to_string()),
+            None,
+        )
+    })?;
+
+    let cli_config = extension.cli.clone().ok_or_else(|| {
+        Error::config(format!(
+            "Extension '{}' does not have CLI configuration",
+            extension.id
+        ))
+    })?;
+
+    Ok((extension, cli_config))
 }
 
 fn try_run_for_component(
@@ -64,6 +152,16 @@ fn try_run_for_component(
             let extension = find_extension_by_tool(tool)?;
             let cli_config = extension.cli.as_ref()?;
 
+            if let Err(e) = ensure_tool_installed(
+                cli_config,
+                &default_cli_path(cli_config),
+                Some(&component.local_path),
+                "local",
+                execute_local_command,
+            ) {
+                return Some(Err(e));
+            }
+
             let command = build_component_command(&component, cli_config, &extension, args);
             let output = execute_local_command(&command);
 
@@ -73,9 +171,11 @@ fn try_run_for_component(
                 identifier: identifier.to_string(),
                 target_domain: None,
                 executed_command: command,
+                stdout_json: parse_json_stdout(&output.stdout),
                 stdout: output.stdout,
                 stderr: output.stderr,
                 exit_code: output.exit_code,
+                targets: None,
             }))
         }
         Err(e) if e.code == ErrorCode::ComponentNotFound => None,
@@ -96,10 +196,7 @@ fn build_component_command(
     );
     variables.insert(
         TemplateVars::CLI_PATH.to_string(),
-        cli_config
-            .default_cli_path
-            .clone()
-            .unwrap_or_else(|| cli_config.tool.clone()),
+        default_cli_path(cli_config),
     );
     variables.insert(TemplateVars::ARGS.to_string(), shell::quote_args(args));
 
@@ -127,25 +224,28 @@ fn run_for_project_with_executor(
         ]));
     }
 
-    let extension = find_extension_by_tool(tool).ok_or_else(|| {
-        Error::validation_invalid_argument(
-            "tool",
-            format!("No extension provides tool '{}'", tool),
-            Some(tool.to_string()),
-            None,
-        )
-    })?;
-
-    let cli_config = extension.cli.as_ref().ok_or_else(|| {
-        Error::config(format!(
-            "Extension '{}' does not have CLI configuration",
-            extension.id
-        ))
-    })?;
+    let (extension, cli_config) = cli_extension(tool)?;
+    let cli_config = &cli_config;
 
     let project = project_loader(project_id)?;
 
-    let (target_domain, command_args) = resolve_subtarget(&project, args)?;
+    if args.len() == 1 && args[0] == LIST_TARGETS_FLAG {
+        let targets = list_cli_targets(&project);
+        return Ok(CliToolResult {
+            tool: tool.to_string(),
+            extension_id: extension.id,
+            identifier: project_id.to_string(),
+            target_domain: project.domain.clone(),
+            executed_command: String::new(),
+            stdout: format_target_listing(&project, &targets),
+            stdout_json: None,
+            stderr: String::new(),
+            exit_code: 0,
+            targets: Some(targets),
+        });
+    }
+
+    let (target_domain, command_args) = resolve_cli_target(&project, cli_config, args)?;
 
     if command_args.is_empty() {
         return Err(Error::validation_missing_argument(vec![
@@ -153,8 +253,19 @@ fn run_for_project_with_executor(
         ]));
     }
 
+    let cli_path = resolve_cli_path(&project, cli_config);
+
     // Try direct execution first (bypasses shell escaping issues)
-    let (output, executed_command) = if project.server_id.as_ref().is_none_or(|s| s.is_empty()) {
+    let (output, executed_command) = if is_local_project(&project) {
+        let base_path = resolve_base_path(&project, true).ok();
+        ensure_tool_installed(
+            cli_config,
+            &cli_path,
+            base_path.as_deref(),
+            "local",
+            local_executor,
+        )?;
+
         let result = executor::execute_for_project_direct(
             &project,
             cli_config,
@@ -176,6 +287,13 @@ fn run_for_project_with_executor(
         }
     } else {
         let ctx = resolve_project_ssh(project_id)?;
+        ensure_tool_installed(
+            cli_config,
+            &cli_path,
+            ctx.base_path.as_deref(),
+            &format!("server '{}'", ctx.server_id),
+            |probe| ctx.client.execute(probe),
+        )?;
         let (_, rendered_cmd) = build_project_command(&project, cli_config, &extension.id, args)?;
         let cmd_output = ctx.client.execute(&rendered_cmd);
         (cmd_output, rendered_cmd)
@@ -187,25 +305,68 @@ fn run_for_project_with_executor(
         identifier: project_id.to_string(),
         target_domain: Some(target_domain),
         executed_command,
+        stdout_json: parse_json_stdout(&output.stdout),
         stdout: output.stdout,
         stderr: output.stderr,
         exit_code: output.exit_code,
+        targets: None,
     })
 }
 
+fn list_cli_targets(project: &Project) -> Vec<CliTarget> {
+    project
+        .sub_targets
+        .iter()
+        .map(|t| CliTarget {
+            id: project::slugify_id(&t.name).unwrap_or_else(|_| t.name.clone()),
+            name: t.name.clone(),
+            domain: t.domain.clone(),
+            is_default: t.is_default,
+        })
+        .collect()
+}
+
+fn format_target_listing(project: &Project, targets: &[CliTarget]) -> String {
+    if targets.is_empty() {
+        return match &project.domain {
+            Some(domain) => format!(
+                "Project '{}' has no subtargets; commands run against {}\n",
+                project.id, domain
+            ),
+            None => format!("Project '{}' has no subtargets\n", project.id),
+        };
+    }
+
+    targets
+        .iter()
+        .map(|t| {
+            let default = if t.is_default { " (default)" } else { "" };
+            format!("{}\t{}\t{}{}\n", t.id, t.name, t.domain, default)
+        })
+        .collect()
+}
+
+/// Parse tool stdout as JSON when it is a JSON object or array.
+///
+/// Extension tools stay opaque to core; this only lets callers consume
+/// machine-readable output without re-parsing the raw string.
+fn parse_json_stdout(stdout: &str) -> Option<serde_json::Value> {
+    let trimmed = stdout.trim();
+    if !(trimmed.starts_with('[') || trimmed.starts_with('{')) {
+        return None;
+    }
+    serde_json::from_str(trimmed).ok()
+}
+
 fn build_project_command(
     project: &Project,
     cli_config: &CliConfig,
     extension_id: &str,
     args: &[String],
 ) -> Result<(String, String)> {
-    let base_path = project
-        .base_path
-        .clone()
-        .filter(|p| !p.is_empty())
-        .ok_or_else(|| Error::config("Base path not configured".to_string()))?;
+    let base_path = resolve_base_path(project, is_local_project(project))?;
 
-    let (target_domain, command_args) = resolve_subtarget(project, args)?;
+    let (target_domain, command_args) = resolve_cli_target(project, cli_config, args)?;
 
     if command_args.is_empty() {
         return Err(Error::validation_missing_argument(vec![
@@ -213,12 +374,7 @@ fn build_project_command(
         ]));
     }
 
-    let cli_path = project::project_cli_path(project).unwrap_or_else(|| {
-        cli_config
-            .default_cli_path
-            .clone()
-            .unwrap_or_else(|| cli_config.tool.clone())
-    });
+    let cli_path = resolve_cli_path(project, cli_config);
 
     let mut variables = HashMap::new();
     variables.insert(TemplateVars::PROJECT_ID.to_string(), project.id.clone());
@@ -269,6 +425,77 @@ fn build_project_command(
     Ok((target_domain, rendered))
 }
 
+fn default_cli_path(cli_config: &CliConfig) -> String {
+    cli_config
+        .default_cli_path
+        .clone()
+        .unwrap_or_else(|| cli_config.tool.clone())
+}
+
+fn resolve_cli_path(project: &Project, cli_config: &CliConfig) -> String {
+    project::project_cli_path(project).unwrap_or_else(|| default_cli_path(cli_config))
+}
+
+fn tool_check_skipped() -> bool {
+    std::env::var(SKIP_TOOL_CHECK_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Build a shell probe that succeeds only when the program `cli_path` starts
+/// with resolves from `working_dir`. Returns `None` when the command template
+/// never invokes `{{cliPath}}`, since there is then no binary to check.
+fn tool_probe_command(
+    cli_config: &CliConfig,
+    cli_path: &str,
+    working_dir: Option<&str>,
+) -> Option<String> {
+    let placeholder = format!("{{{{{}}}}}", TemplateVars::CLI_PATH);
+    if !cli_config.command_template.contains(&placeholder) {
+        return None;
+    }
+
+    let program = cli_path.split_whitespace().next()?;
+    let probe = format!("command -v {} >/dev/null 2>&1", shell::quote_arg(program));
+    Some(match working_dir {
+        Some(dir) => format!("cd {} 2>/dev/null; {}", shell::quote_path(dir), probe),
+        None => probe,
+    })
+}
+
+/// Pre-flight: fail with `cli.tool_missing` instead of a raw "command not
+/// found" when the resolved CLI binary is not installed where it will run.
+fn ensure_tool_installed(
+    cli_config: &CliConfig,
+    cli_path: &str,
+    working_dir: Option<&str>,
+    location: &str,
+    run_probe: impl FnOnce(&str) -> CommandOutput,
+) -> Result<()> {
+    if tool_check_skipped() {
+        return Ok(());
+    }
+    let Some(probe) = tool_probe_command(cli_config, cli_path, working_dir) else {
+        return Ok(());
+    };
+    if run_probe(&probe).success {
+        return Ok(());
+    }
+
+    Err(Error::cli_tool_missing(
+        &cli_config.tool,
+        cli_path,
+        location,
+        working_dir.map(str::to_string),
+    )
+    .with_hint(format!(
+        "Install {} on {}, or set the project's cli_path to its location",
+        cli_config.display_name, location
+    ))
+    .with_hint(format!(
+        "Set {}=1 to skip this check once the tool is confirmed installed",
+        SKIP_TOOL_CHECK_ENV
+    )))
+}
+
 fn project_server_user(project: &Project) -> Option<String> {
     let server_id = project.server_id.as_ref().filter(|s| !s.is_empty())?;
     server::load(server_id).ok().map(|svr| svr.user)
@@ -291,6 +518,73 @@ fn auto_flag_matches(auto_flag: &CliAutoFlag, server_user: Option<&str>) -> bool
     true
 }
 
+const NETWORK_FLAG: &str = "--network";
+
+/// Resolve the target domain and command args, applying multisite handling.
+///
+/// For `multisite` projects, a leading `--network` targets the network's main
+/// site and is forwarded to the command; otherwise the subtarget must be a
+/// configured site. `--url=<domain>` is injected unless the command template
+/// already renders the domain or the caller passed `--url` explicitly.
+fn resolve_cli_target(
+    project: &Project,
+    cli_config: &CliConfig,
+    args: &[String],
+) -> Result<(String, Vec<String>)> {
+    let network = args.first().map(String::as_str) == Some(NETWORK_FLAG);
+    if network && !project.multisite {
+        return Err(Error::validation_invalid_argument(
+            "network",
+            format!(
+                "Project '{}' is not a multisite network. Set \"multisite\": true to use --network",
+                project.id
+            ),
+            Some(project.id.clone()),
+            None,
+        ));
+    }
+
+    let (domain, mut command_args) = if network {
+        let domain = network_main_domain(project).ok_or_else(|| {
+            Error::validation_invalid_argument(
+                "domain",
+                "Multisite --network requires a project domain or a default subtarget",
+                Some(project.id.clone()),
+                None,
+            )
+        })?;
+        let mut command_args = args[1..].to_vec();
+        command_args.push(NETWORK_FLAG.to_string());
+        (domain, command_args)
+    } else {
+        resolve_subtarget(project, args)?
+    };
+
+    let explicit_url = command_args
+        .iter()
+        .any(|arg| arg == "--url" || arg.starts_with("--url="));
+    if project.multisite
+        && !explicit_url
+        && !command_args.is_empty()
+        && !cli_config.command_template.contains("{{domain}}")
+    {
+        command_args.push(format!("--url={}", domain));
+    }
+
+    Ok((domain, command_args))
+}
+
+fn network_main_domain(project: &Project) -> Option<String> {
+    project.domain.clone().or_else(|| {
+        project
+            .sub_targets
+            .iter()
+            .find(|t| t.is_default)
+            .or_else(|| project.sub_targets.first())
+            .map(|t| t.domain.clone())
+    })
+}
+
 fn resolve_subtarget(project: &Project, args: &[String]) -> Result<(String, Vec<String>)> {
     let require_domain = || {
         Error::validation_invalid_argument(
@@ -359,13 +653,15 @@ fn format_subtarget_syntax(project_id: &str) -> String {
              OR  homeboy <tool> {project_id} <subtarget> <command>...\n\n\
          Commands can be quoted or unquoted:\n  \
            homeboy wp {project_id}:events post list\n  \
-           homeboy wp {project_id}:events \"post list\""
+           homeboy wp {project_id}:events \"post list\"\n\n\
+         List subtargets: homeboy <tool> {project_id} --list-targets"
     )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::project::SubTarget;
     use homeboy_extension_contract::{CliAutoFlagCondition, CliHelpConfig};
 
     fn cli_config(auto_flags: Vec<CliAutoFlag>) -> CliConfig {
@@ -381,6 +677,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn json_stdout_is_parsed_only_for_json_documents() {
+        let parsed = parse_json_stdout(
+            "[{\"name\":\"api\",\"pid\":42,\"pm2_env\":{\"status\":\"online\"}}]\n",
+        )
+        .expect("jlist output parses");
+        assert_eq!(parsed[0]["name"], "api");
+        assert_eq!(parsed[0]["pm2_env"]["status"], "online");
+
+        assert!(parse_json_stdout("┌────┬──────┐\n│ id │ name │").is_none());
+        assert!(parse_json_stdout("42").is_none());
+        assert!(parse_json_stdout("[not json").is_none());
+        assert!(parse_json_stdout("").is_none());
+    }
+
     #[test]
     fn auto_flags_match_server_user_conditions() {
         let config = cli_config(vec![
@@ -454,6 +765,269 @@ mod tests {
         assert_eq!(config.auto_flags[0].flag, "--allow-root");
     }
 
+    fn multisite_project() -> Project {
+        Project {
+            id: "network".to_string(),
+            domain: Some("example.com".to_string()),
+            base_path: Some("/var/www/html".to_string()),
+            multisite: true,
+            sub_targets: vec![
+                SubTarget {
+                    name: "Main".to_string(),
+                    domain: "example.com".to_string(),
+                    number: Some(1),
+                    is_default: true,
+                },
+                SubTarget {
+                    name: "Events".to_string(),
+                    domain: "events.example.com".to_string(),
+                    number: Some(2),
+                    is_default: false,
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn multisite_subtarget_injects_url_for_resolved_site() {
+        let project = multisite_project();
+        let config = cli_config(Vec::new());
+
+        let (domain, args) = resolve_cli_target(
+            &project,
+            &config,
+            &["events".into(), "post".into(), "list".into()],
+        )
+        .expect("resolve site");
+        assert_eq!(domain, "events.example.com");
+        assert_eq!(args, vec!["post", "list", "--url=events.example.com"]);
+
+        let error = resolve_cli_target(&project, &config, &["shop".into(), "post".into()])
+            .expect_err("unknown site");
+        assert!(error.message.contains("Subtarget 'shop' not found"));
+    }
+
+    #[test]
+    fn mistyped_subtarget_never_falls_back_to_default_site() {
+        let project = multisite_project();
+        let config = cli_config(Vec::new());
+
+        let error = resolve_cli_target(
+            &project,
+            &config,
+            &["evnts".into(), "post".into(), "list".into()],
+        )
+        .expect_err("typo must not run against the default subtarget");
+        assert!(error.message.contains("Subtarget 'evnts' not found"));
+        assert!(error.message.contains("- Main (use: main)"));
+        assert!(error.message.contains("- Events (use: events)"));
+
+        let single_site = Project {
+            multisite: false,
+            ..project
+        };
+        assert!(
+            resolve_cli_target(&single_site, &config, &["plugin".into(), "list".into()]).is_err()
+        );
+    }
+
+    #[test]
+    fn list_targets_reports_subtarget_ids_domains_and_default() {
+        let project = multisite_project();
+        let targets = list_cli_targets(&project);
+
+        assert_eq!(
+            targets,
+            vec![
+                CliTarget {
+                    id: "main".to_string(),
+                    name: "Main".to_string(),
+                    domain: "example.com".to_string(),
+                    is_default: true,
+                },
+                CliTarget {
+                    id: "events".to_string(),
+                    name: "Events".to_string(),
+                    domain: "events.example.com".to_string(),
+                    is_default: false,
+                },
+            ]
+        );
+        assert_eq!(
+            format_target_listing(&project, &targets),
+            "main\tMain\texample.com (default)\nevents\tEvents\tevents.example.com\n"
+        );
+
+        let single = Project {
+            sub_targets: Vec::new(),
+            ..project
+        };
+        assert!(format_target_listing(&single, &list_cli_targets(&single))
+            .contains("no subtargets; commands run against example.com"));
+    }
+
+    #[test]
+    fn multisite_network_flag_targets_main_site_and_forwards_flag() {
+        let project = multisite_project();
+        let config = cli_config(Vec::new());
+
+        let (domain, args) = resolve_cli_target(
+            &project,
+            &config,
+            &["--network".into(), "plugin".into(), "list".into()],
+        )
+        .expect("resolve network");
+        assert_eq!(domain, "example.com");
+        assert_eq!(
+            args,
+            vec!["plugin", "list", "--network", "--url=example.com"]
+        );
+
+        let single_site = Project {
+            multisite: false,
+            ..project
+        };
+        assert!(resolve_cli_target(&single_site, &config, &["--network".into()]).is_err());
+    }
+
+    #[test]
+    fn multisite_url_not_duplicated_when_template_or_args_supply_it() {
+        let project = multisite_project();
+        let mut config = cli_config(Vec::new());
+
+        let (_, args) = resolve_cli_target(
+            &project,
+            &config,
+            &[
+                "events".into(),
+                "option".into(),
+                "--url=other.example.com".into(),
+            ],
+        )
+        .expect("explicit url");
+        assert_eq!(args, vec!["option", "--url=other.example.com"]);
+
+        config.command_template = "{{cliPath}} --url={{domain}} {{args}}".to_string();
+        let (_, args) = resolve_cli_target(&project, &config, &["events".into(), "option".into()])
+            .expect("template url");
+        assert_eq!(args, vec!["option"]);
+    }
+
+    fn probe_output(success: bool) -> CommandOutput {
+        CommandOutput {
+            stdout: String::new(),
+            stderr: String::new(),
+            success,
+            exit_code: if success { 0 } else { 1 },
+            timed_out: false,
+            child_resource: None,
+            combined: None,
+        }
+    }
+
+    #[test]
+    fn tool_probe_checks_cli_program_from_working_dir() {
+        let mut config = cli_config(Vec::new());
+
+        assert_eq!(
+            tool_probe_command(&config, "vendor/bin/wp --quiet", Some("/var/www/html")).as_deref(),
+            Some("cd '/var/www/html' 2>/dev/null; command -v vendor/bin/wp >/dev/null 2>&1")
+        );
+        assert_eq!(
+            tool_probe_command(&config, "wp", None).as_deref(),
+            Some("command -v wp >/dev/null 2>&1")
+        );
+
+        config.command_template = "lando wp {{args}}".to_string();
+        assert!(tool_probe_command(&config, "wp", None).is_none());
+    }
+
+    #[test]
+    fn missing_cli_tool_reports_tool_and_location() {
+        let config = cli_config(Vec::new());
+
+        assert!(
+            ensure_tool_installed(&config, "wp", None, "local", |_| probe_output(true)).is_ok()
+        );
+
+        let error = ensure_tool_installed(
+            &config,
+            "/opt/bin/wp",
+            Some("/var/www/html"),
+            "server 'prod'",
+            |_| probe_output(false),
+        )
+        .expect_err("missing tool");
+        assert_eq!(error.code, ErrorCode::CliToolMissing);
+        assert_eq!(error.details["tool"], "wp");
+        assert_eq!(error.details["cli_path"], "/opt/bin/wp");
+        assert_eq!(error.details["location"], "server 'prod'");
+        assert!(error
+            .message
+            .contains("'/opt/bin/wp' was not found on server 'prod'"));
+    }
+
+    #[test]
+    fn settings_flags_inject_only_configured_settings() {
+        let mut config = cli_config(Vec::new());
+        config
+            .settings_flags
+            .insert("wp_path".to_string(), "--path={{value}}".to_string());
+        config
+            .settings_flags
+            .insert("wp_user".to_string(), "--user={{value}}".to_string());
+
+        let mut settings = HashMap::new();
+        settings.insert(
+            "wp_path".to_string(),
+            serde_json::Value::String("/srv/wp".to_string()),
+        );
+        settings.insert(
+            "wp_user".to_string(),
+            serde_json::Value::String(String::new()),
+        );
+        let project = Project {
+            id: "sandbox".to_string(),
+            domain: Some("example.com".to_string()),
+            base_path: Some("/srv/wp".to_string()),
+            extensions: Some(HashMap::from([(
+                "wordpress".to_string(),
+                crate::component::ScopedExtensionConfig {
+                    version: None,
+                    settings,
+                },
+            )])),
+            ..Default::default()
+        };
+
+        let (_, command) = build_project_command(
+            &project,
+            &config,
+            "wordpress",
+            &["core".into(), "version".into()],
+        )
+        .expect("build command");
+
+        assert!(command.ends_with("core version --path=/srv/wp"));
+        assert!(!command.contains("--user"));
+
+        let unconfigured = Project {
+            extensions: None,
+            ..project
+        };
+        let (_, command) = build_project_command(
+            &unconfigured,
+            &config,
+            "wordpress",
+            &["core".into(), "version".into()],
+        )
+        .expect("build command");
+
+        assert!(!command.contains("--path"));
+        assert!(!command.contains("--user"));
+    }
+
     #[test]
     fn project_cli_path_overrides_manifest_default() {
         let mut project = Project {
@@ -33,13 +33,22 @@
 // ### Routing Logic
 // execute_for_project() -> routes to local or SSH based on server_id
 // execute_for_project_interactive() -> routes local/SSH with inherited stdio
+// execute_for_project_lines() -> routes local/SSH, streaming stdout line by line
+// execute_for_project_to_file() -> routes local/SSH, streaming stdout into a local file
+// execute_for_project_with_stdin_file() -> routes local/SSH, streaming a local file as stdin
 // execute_for_project_direct() -> tries direct first, falls back to shell
+//
+// Every SSH route multiplexes through a short-lived ControlMaster socket
+// (see project_ssh_client), so multi-step operations pay one handshake.
 
-use crate::context::resolve_project_ssh;
+use crate::context::{is_local_project, resolve_base_path, resolve_project_ssh};
 use crate::engine::shell;
 use crate::error::{Error, Result};
 use crate::project::{self, Project};
-use crate::server::{execute_local_command, execute_local_command_interactive, CommandOutput};
+use crate::server::{
+    execute_local_command, execute_local_command_interactive, execute_local_command_lines,
+    execute_local_command_to_file, CommandOutput, OutputCapture, SshClient,
+};
 use homeboy_extension_contract::CliConfig;
 use std::process::Command;
 
@@ -50,29 +59,88 @@ use std::process::Command;
 ///
 /// This is the same pattern used by cli_tool.rs for extension CLI commands.
 pub fn execute_for_project(project: &Project, command: &str) -> Result<CommandOutput> {
-    if project.server_id.as_ref().is_none_or(|s| s.is_empty()) {
+    execute_for_project_with_capture(project, command, OutputCapture::Separate)
+}
+
+/// [`execute_for_project`] with an explicit output capture mode. Pass
+/// `OutputCapture::Combined` to keep stdout/stderr interleaving (e.g. build or
+/// migration logs) in `CommandOutput::combined`.
+pub fn execute_for_project_with_capture(
+    project: &Project,
+    command: &str,
+    capture: OutputCapture,
+) -> Result<CommandOutput> {
+    if is_local_project(project) {
         // Local execution
-        Ok(execute_local_command(command))
+        Ok(capture.finish(execute_local_command(&capture.wrap(command))))
     } else {
         // SSH execution
-        let ctx = resolve_project_ssh(&project.id)?;
-        Ok(ctx.client.execute(command))
+        Ok(project_ssh_client(project)?.execute_with_capture(command, capture))
     }
 }
 
+/// SSH client for a remote project, multiplexed so consecutive commands
+/// reuse one connection.
+fn project_ssh_client(project: &Project) -> Result<SshClient> {
+    Ok(resolve_project_ssh(&project.id)?.client.with_multiplexing())
+}
+
 /// Execute an interactive command for a project (e.g., `tail -f`).
 /// Returns exit code.
 ///
 /// When `server_id` is not configured: executes locally with inherited stdio
 /// When `server_id` is configured: executes via SSH interactive session
 pub fn execute_for_project_interactive(project: &Project, command: &str) -> Result<i32> {
-    if project.server_id.as_ref().is_none_or(|s| s.is_empty()) {
+    if is_local_project(project) {
         // Local interactive execution
         Ok(execute_local_command_interactive(command, None, None))
     } else {
         // SSH interactive execution
-        let ctx = resolve_project_ssh(&project.id)?;
-        Ok(ctx.client.execute_interactive(Some(command)))
+        Ok(project_ssh_client(project)?.execute_interactive(Some(command)))
+    }
+}
+
+/// Execute a long-running command for a project, handing each stdout line to
+/// `on_line` as it arrives (e.g. `tail -f` with per-line rewriting).
+/// Returns exit code.
+pub fn execute_for_project_lines(
+    project: &Project,
+    command: &str,
+    on_line: &mut dyn FnMut(&str),
+) -> Result<i32> {
+    if is_local_project(project) {
+        Ok(execute_local_command_lines(command, on_line))
+    } else {
+        Ok(project_ssh_client(project)?.execute_lines(command, on_line))
+    }
+}
+
+/// Execute a command for a project, streaming stdout into `stdout_file` instead
+/// of capturing it (e.g. a database dump). `CommandOutput::stdout` is empty.
+pub fn execute_for_project_to_file(
+    project: &Project,
+    command: &str,
+    stdout_file: std::fs::File,
+) -> Result<CommandOutput> {
+    if is_local_project(project) {
+        Ok(execute_local_command_to_file(command, stdout_file))
+    } else {
+        Ok(project_ssh_client(project)?.execute_to_file(command, stdout_file))
+    }
+}
+
+/// Execute a command for a project with the local file at `stdin_path`
+/// streamed as its stdin (e.g. a SQL dump fed to an import tool).
+pub fn execute_for_project_with_stdin_file(
+    project: &Project,
+    command: &str,
+    stdin_path: &str,
+) -> Result<CommandOutput> {
+    if is_local_project(project) {
+        let piped = format!("cat {} | {}", shell::quote_path(stdin_path), command);
+        Ok(execute_local_command(&piped))
+    } else {
+        Ok(project_ssh_client(project)?.execute_with_stdin_file(command, stdin_path))
     }
 }
 
@@ -89,11 +157,7 @@ pub fn execute_for_project_direct(
     args: &[String],
     target_domain: &str,
 ) -> Result<CommandOutput> {
-    let base_path = project
-        .base_path
-        .clone()
-        .filter(|p| !p.is_empty())
-        .ok_or_else(|| Error::config("Base path not configured".to_string()))?;
+    let base_path = resolve_base_path(project, true)?;
 
     // Args are normalized at the cli_tool::run() entry point
 
@@ -164,6 +228,7 @@ fn try_execute_direct(
         exit_code: out.status.code().unwrap_or(-1),
         timed_out: false,
         child_resource: None,
+        combined: None,
     })
 }
 
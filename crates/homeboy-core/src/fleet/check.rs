@@ -18,6 +18,8 @@ pub struct FleetComponentCheck {
     pub local_version: Option<String>,
     pub remote_version: Option<String>,
     pub status: String,
+    /// True when the deployed version lags the local one and needs a deploy.
+    pub behind: bool,
 }
 
 #[derive(Debug, Default, Clone, Serialize)]
@@ -30,9 +32,14 @@ pub struct FleetCheckSummary {
     pub components_unknown: u32,
 }
 
+/// Compare local and deployed component versions for every fleet project.
+///
+/// `component` narrows the report to a single component; projects that do not
+/// use it are left out.
 pub fn collect_check(
     fleet_id: &str,
     only_outdated: bool,
+    component: Option<&str>,
 ) -> crate::Result<(Vec<FleetProjectCheck>, FleetCheckSummary, i32)> {
     let fl = super::load(fleet_id)?;
     let mut project_checks = Vec::new();
@@ -50,6 +57,10 @@ pub fn collect_check(
                 let mut component_checks = Vec::new();
 
                 for comp_result in &results {
+                    if component.is_some_and(|id| id != comp_result.id) {
+                        continue;
+                    }
+
                     let status_str = match &comp_result.component_status {
                         Some(ComponentStatus::UpToDate) => "up_to_date",
                         Some(ComponentStatus::NeedsUpdate) => "needs_update",
@@ -82,10 +93,14 @@ pub fn collect_check(
                         local_version: comp_result.local_version.clone(),
                         remote_version: comp_result.remote_version.clone(),
                         status: status_str.to_string(),
+                        behind: comp_result
+                            .component_status
+                            .as_ref()
+                            .is_some_and(ComponentStatus::requires_deploy),
                     });
                 }
 
-                if only_outdated && component_checks.is_empty() {
+                if (only_outdated || component.is_some()) && component_checks.is_empty() {
                     continue;
                 }
 
@@ -105,8 +120,12 @@ pub fn collect_check(
 
                 if !only_outdated {
                     if let Ok(proj) = project::load(project_id) {
-                        let components = cached_project_component_checks(&proj, &mut summary);
+                        let components =
+                            cached_project_component_checks(&proj, component, &mut summary);
                         summary.projects_checked += 1;
+                        if component.is_some() && components.is_empty() {
+                            continue;
+                        }
                         project_checks.push(FleetProjectCheck {
                             project_id: project_id.clone(),
                             server_id: proj.server_id,
@@ -138,10 +157,12 @@ pub fn collect_check(
 
 fn cached_project_component_checks(
     proj: &project::Project,
+    component: Option<&str>,
     summary: &mut FleetCheckSummary,
 ) -> Vec<FleetComponentCheck> {
     project::project_component_ids(proj)
         .into_iter()
+        .filter(|component_id| component.is_none_or(|id| id == component_id))
         .map(|component_id| {
             let local_version = project::resolve_project_component(proj, &component_id)
                 .ok()
@@ -154,6 +175,7 @@ fn cached_project_component_checks(
                 local_version,
                 remote_version: None,
                 status: "unknown".to_string(),
+                behind: false,
             }
         })
         .collect()
@@ -167,6 +189,39 @@ mod tests {
     use crate::project::{self, Project, ProjectComponentAttachment};
     use crate::test_support::with_isolated_home;
 
+    #[test]
+    fn cached_component_checks_can_be_scoped_to_one_component() {
+        let project = Project {
+            id: "site".to_string(),
+            components: ["plugin", "theme"]
+                .iter()
+                .map(|id| ProjectComponentAttachment {
+                    id: id.to_string(),
+                    local_path: "/tmp/homeboy-missing-component-path".to_string(),
+                    remote_path: None,
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let mut summary = FleetCheckSummary::default();
+        let checks = cached_project_component_checks(&project, Some("theme"), &mut summary);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].component_id, "theme");
+        assert!(!checks[0].behind);
+        assert_eq!(summary.components_unknown, 1);
+
+        let mut summary = FleetCheckSummary::default();
+        assert!(cached_project_component_checks(&project, Some("other"), &mut summary).is_empty());
+        assert_eq!(summary.components_unknown, 0);
+
+        let mut summary = FleetCheckSummary::default();
+        assert_eq!(
+            cached_project_component_checks(&project, None, &mut summary).len(),
+            2
+        );
+    }
+
     #[test]
     fn fleet_check_falls_back_to_cached_components_when_live_check_fails() {
         with_isolated_home(|home| {
@@ -202,7 +257,7 @@ mod tests {
             .expect("fleet config");
 
             let (checks, summary, exit_code) =
-                collect_check("local-fleet", false).expect("fleet check");
+                collect_check("local-fleet", false, None).expect("fleet check");
 
             assert_eq!(exit_code, 0);
             assert_eq!(summary.projects_checked, 1);
@@ -215,7 +270,7 @@ mod tests {
             assert_eq!(checks[0].components[0].status, "unknown");
 
             let (outdated_checks, outdated_summary, outdated_exit_code) =
-                collect_check("local-fleet", true).expect("outdated fleet check");
+                collect_check("local-fleet", true, None).expect("outdated fleet check");
 
             assert_eq!(outdated_exit_code, 0);
             assert_eq!(outdated_summary.projects_checked, 1);
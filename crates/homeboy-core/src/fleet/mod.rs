@@ -104,6 +104,9 @@ impl ConfigEntity for Fleet {
     fn not_found_error(id: String, suggestions: Vec<String>) -> Error {
         Error::fleet_not_found(id, suggestions)
     }
+    // No other entity stores a fleet ID (fleets reference projects, never the
+    // reverse), so the default no-op `on_rename` is correct: renaming a fleet
+    // needs no cascade.
 }
 
 // ============================================================================
@@ -112,6 +115,19 @@ impl ConfigEntity for Fleet {
 
 entity_crud!(Fleet; list_ids, merge);
 
+/// Keys accepted by `fleet list --sort/--filter`.
+pub const LIST_QUERY_KEYS: &[&str] = &["name", "description", "project"];
+
+/// Values of a list query key for one fleet (`project` is each member ID).
+pub fn list_query_values(fleet: &Fleet, key: &str) -> Vec<String> {
+    match key {
+        "name" => vec![fleet.id.clone()],
+        "description" => fleet.description.iter().cloned().collect(),
+        "project" => fleet.project_ids.clone(),
+        _ => Vec::new(),
+    }
+}
+
 // ============================================================================
 // Operations
 // ============================================================================
@@ -198,6 +214,25 @@ pub fn component_usage_with_resolution(
 mod tests {
     use super::*;
 
+    #[test]
+    fn rename_moves_fleet_config_and_rejects_taken_ids() {
+        let _home = crate::test_support::HomeGuard::new();
+        let mut fleet = Fleet::new("staging".to_string(), vec!["site-a".to_string()]);
+        fleet.description = Some("Staging sites".to_string());
+        save(&fleet).unwrap();
+        save(&Fleet::new("production".to_string(), vec![])).unwrap();
+
+        let error = rename("staging", "production").unwrap_err();
+        assert!(error.details.to_string().contains("already exists"));
+        assert!(exists("staging"));
+
+        let renamed = rename("staging", "Preview").unwrap();
+        assert_eq!(renamed.id, "preview");
+        assert_eq!(renamed.project_ids, vec!["site-a"]);
+        assert_eq!(renamed.description.as_deref(), Some("Staging sites"));
+        assert!(!exists("staging"));
+    }
+
     #[test]
     fn fleet_new_has_empty_component_overrides() {
         let fleet = Fleet::new("test-fleet".to_string(), vec!["project-a".to_string()]);
@@ -7,8 +7,10 @@ mod github_pr_comments;
 mod github_types;
 mod operation_output;
 mod operations;
+mod operations_branch;
 mod operations_changes;
 mod operations_commit;
+mod operations_log;
 mod operations_push;
 mod operations_tags;
 mod pr_land;
@@ -53,9 +55,12 @@ pub use github_types::{
 };
 pub use operation_output::GitOutput;
 pub use operations::{
-    cherry_pick, cherry_pick_at, execute_git_for_release, fetch_and_fast_forward,
+    cherry_pick, cherry_pick_at, diff, diff_at, execute_git_for_release, fetch_and_fast_forward,
     fetch_and_get_behind_count, get_repo_snapshot, pull, pull_at, pull_bulk, rebase, rebase_at,
-    status, status_at, status_bulk, CherryPickOptions, RebaseOptions, RepoSnapshot,
+    status, status_at, status_bulk, CherryPickOptions, DiffOptions, RebaseOptions, RepoSnapshot,
+};
+pub use operations_branch::{
+    branches, branches_at, checkout, checkout_at, BranchEntry, GitBranchOutput,
 };
 pub use operations_changes::{
     build_repo_baseline_snapshot, changes, changes_at, changes_bulk, changes_project,
@@ -66,11 +71,12 @@ pub use operations_changes::{
     ChangesOutput, RepoBaselineSnapshot,
 };
 pub use operations_commit::{commit, commit_at, commit_from_json, CommitJsonOutput, CommitOptions};
+pub use operations_log::{log, log_at, GitLogOutput, LogEntry, DEFAULT_LOG_COUNT};
 pub use operations_push::{push, push_at, push_bulk, PushOptions};
 pub use operations_tags::{
     delete_local_tag, delete_remote_tag, fetch_origin, fetch_tags, get_head_commit, get_tag_commit,
     is_ancestor, remote_branch_commit, remote_tag_commit, short_head_revision_at, tag, tag_at,
-    tag_exists_locally, tag_exists_on_remote,
+    tag_delete_at, tag_exists_locally, tag_exists_on_remote, tag_list_at,
 };
 pub use pr_land::{land_prs, PrLandOptions, PrLandOutput, PrLandRefreshHelper};
 pub use pr_policy::{
@@ -103,6 +103,224 @@ fn test_remote_tag_commit() {
     assert_eq!(remote_tag_commit(&path, "v9.9.9").unwrap(), None);
 }
 
+#[test]
+fn log_returns_recent_commits_newest_first() {
+    let (_dir, path) = init_repo_with_initial_commit();
+    for subject in ["second", "third"] {
+        Command::new("git")
+            .args(["commit", "-q", "--allow-empty", "-m", subject])
+            .current_dir(&path)
+            .output()
+            .unwrap();
+    }
+
+    let out = log_at(None, 2, Some(&path)).expect("log_at");
+
+    assert!(out.success, "git log should succeed: {:?}", out.stderr);
+    assert_eq!(out.action, "log");
+    let subjects: Vec<&str> = out.entries.iter().map(|e| e.subject.as_str()).collect();
+    assert_eq!(subjects, vec!["third", "second"]);
+    assert_eq!(out.entries[0].author, "Test");
+    assert!(!out.entries[0].hash.is_empty());
+    assert!(!out.entries[0].date.is_empty());
+}
+
+#[test]
+fn diff_separates_working_tree_and_staged_changes() {
+    let (dir, path) = init_repo_with_initial_commit();
+    std::fs::write(dir.path().join("README.md"), "initial\nunstaged\n").unwrap();
+    std::fs::write(dir.path().join("NEW.md"), "staged\n").unwrap();
+    Command::new("git")
+        .args(["add", "NEW.md"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+
+    let working = diff_at(None, DiffOptions::default(), Some(&path)).expect("diff_at");
+    assert!(
+        working.success,
+        "git diff should succeed: {:?}",
+        working.stderr
+    );
+    assert_eq!(working.action, "diff");
+    assert!(working.stdout.contains("+unstaged"));
+    assert!(!working.stdout.contains("NEW.md"));
+
+    let staged = diff_at(
+        None,
+        DiffOptions {
+            staged: true,
+            stat: true,
+        },
+        Some(&path),
+    )
+    .expect("diff_at staged");
+    assert!(staged.stdout.contains("NEW.md"));
+    assert!(staged.stdout.contains("1 file changed"));
+    assert!(!staged.stdout.contains("README.md"));
+}
+
+#[test]
+fn branches_mark_current_and_checkout_switches() {
+    let (_dir, path) = init_repo_with_initial_commit();
+    Command::new("git")
+        .args(["branch", "feature/x"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+
+    let listed = branches_at(None, Some(&path)).expect("branches_at");
+    assert!(
+        listed.success,
+        "git branch should succeed: {:?}",
+        listed.stderr
+    );
+    assert_eq!(listed.current.as_deref(), Some("main"));
+    assert_eq!(
+        listed.branches,
+        vec![
+            BranchEntry {
+                name: "feature/x".to_string(),
+                current: false,
+            },
+            BranchEntry {
+                name: "main".to_string(),
+                current: true,
+            },
+        ]
+    );
+
+    let switched = checkout_at(None, "feature/x", Some(&path)).expect("checkout_at");
+    assert!(
+        switched.success,
+        "checkout should succeed: {:?}",
+        switched.stderr
+    );
+    assert_eq!(switched.action, "checkout");
+    assert_eq!(switched.current.as_deref(), Some("feature/x"));
+}
+
+#[test]
+fn checkout_refuses_dirty_working_tree() {
+    let (dir, path) = init_repo_with_initial_commit();
+    Command::new("git")
+        .args(["branch", "feature/x"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    std::fs::write(dir.path().join("README.md"), "edited\n").unwrap();
+
+    let err = checkout_at(None, "feature/x", Some(&path)).expect_err("dirty tree must refuse");
+    assert!(
+        err.message.contains("uncommitted changes"),
+        "{}",
+        err.message
+    );
+
+    let current = super::primitives_query::current_branch(std::path::Path::new(&path));
+    assert_eq!(current.as_deref(), Some("main"));
+}
+
+#[test]
+fn checkout_never_treats_branch_as_pathspec() {
+    let (_dir, path) = init_repo_with_initial_commit();
+
+    let result = checkout_at(None, "README.md", Some(&path)).expect("checkout_at");
+    assert!(!result.success, "a path is not a branch: {:?}", result);
+    assert_eq!(result.current.as_deref(), Some("main"));
+}
+
+fn committed_files(path: &str) -> Vec<String> {
+    let output = Command::new("git")
+        .args(["show", "--name-only", "--format=", "HEAD"])
+        .current_dir(path)
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn commit_with_files_stages_only_the_listed_paths() {
+    let (dir, path) = init_repo_with_initial_commit();
+    std::fs::write(dir.path().join("src.txt"), "source\n").unwrap();
+    std::fs::write(dir.path().join("build.out"), "artifact\n").unwrap();
+
+    let options = CommitOptions {
+        files: Some(vec!["src.txt".to_string()]),
+        ..Default::default()
+    };
+    let out = commit_at(None, Some("add source"), options, Some(&path)).expect("commit_at");
+
+    assert!(out.success, "commit should succeed: {:?}", out.stderr);
+    assert_eq!(committed_files(&path), vec!["src.txt"]);
+}
+
+#[test]
+fn commit_with_exclude_skips_excluded_and_missing_paths() {
+    let (dir, path) = init_repo_with_initial_commit();
+    std::fs::write(dir.path().join("src.txt"), "source\n").unwrap();
+    std::fs::write(dir.path().join("build.out"), "artifact\n").unwrap();
+
+    let options = CommitOptions {
+        exclude: Some(vec!["build.out".to_string(), "not-there.log".to_string()]),
+        ..Default::default()
+    };
+    let out = commit_at(None, Some("add source"), options, Some(&path)).expect("commit_at");
+
+    assert!(out.success, "commit should succeed: {:?}", out.stderr);
+    assert_eq!(committed_files(&path), vec!["src.txt"]);
+    let status = super::primitives_query::status_porcelain(std::path::Path::new(&path)).unwrap();
+    assert!(status.contains("?? build.out"), "{}", status);
+}
+
+#[test]
+fn commit_staged_only_leaves_unstaged_changes_alone() {
+    let (dir, path) = init_repo_with_initial_commit();
+    std::fs::write(dir.path().join("staged.txt"), "staged\n").unwrap();
+    std::fs::write(dir.path().join("README.md"), "unstaged edit\n").unwrap();
+    Command::new("git")
+        .args(["add", "staged.txt"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+
+    let options = CommitOptions {
+        staged_only: true,
+        ..Default::default()
+    };
+    let out = commit_at(None, Some("staged only"), options, Some(&path)).expect("commit_at");
+
+    assert!(out.success, "commit should succeed: {:?}", out.stderr);
+    assert_eq!(committed_files(&path), vec!["staged.txt"]);
+}
+
+#[test]
+fn commit_author_override_beats_ambient_identity() {
+    let (dir, path) = init_repo_with_initial_commit();
+    std::fs::write(dir.path().join("client.txt"), "work\n").unwrap();
+
+    let options = CommitOptions {
+        author_name: Some("Client Bot".to_string()),
+        author_email: Some("bot@client.example".to_string()),
+        ..Default::default()
+    };
+    let out = commit_at(None, Some("client work"), options, Some(&path)).expect("commit_at");
+
+    assert!(out.success, "commit should succeed: {:?}", out.stderr);
+    let identity = Command::new("git")
+        .args(["log", "-1", "--format=%an <%ae>|%cn <%ce>"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&identity.stdout).trim(),
+        "Client Bot <bot@client.example>|Client Bot <bot@client.example>"
+    );
+}
+
 #[test]
 fn rebase_against_self_is_a_noop_success() {
     let (_dir, path) = init_repo_with_initial_commit();
@@ -293,6 +511,67 @@ fn push_options_remote_url_refspec_and_strip_extraheader_push_to_bare_remote() {
     assert!(verify.status.success(), "expected autofix branch on remote");
 }
 
+#[test]
+fn tag_list_filters_by_pattern() {
+    let (_dir, path) = init_repo_with_initial_commit();
+    for name in ["v1.0.0", "v1.1.0", "v2.0.0"] {
+        tag_at(None, Some(name), None, Some(&path)).expect("tag_at");
+    }
+
+    let out = tag_list_at(None, Some("v1.*"), Some(&path)).expect("tag_list_at");
+
+    assert!(out.success, "tag list should succeed: {:?}", out.stderr);
+    assert_eq!(out.action, "tag.list");
+    assert_eq!(
+        out.stdout.lines().collect::<Vec<_>>(),
+        vec!["v1.0.0", "v1.1.0"]
+    );
+}
+
+#[test]
+fn tag_delete_with_remote_clears_local_and_remote_tags() {
+    let (_dir, path) = init_repo_with_initial_commit();
+    let remote = tempfile::TempDir::new().expect("bare remote tempdir");
+    Command::new("git")
+        .args(["init", "-q", "--bare"])
+        .current_dir(remote.path())
+        .output()
+        .expect("git init --bare");
+    let remote_url = remote.path().to_string_lossy().to_string();
+    Command::new("git")
+        .args(["remote", "add", "origin", &remote_url])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    tag_at(None, Some("v1.0.0"), None, Some(&path)).expect("tag_at");
+    Command::new("git")
+        .args(["push", "-q", "origin", "v1.0.0"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+
+    let local_only = tag_delete_at(None, "v9.9.9", false, Some(&path)).expect("tag_delete_at");
+    assert!(
+        !local_only.success,
+        "deleting a missing local tag should fail"
+    );
+
+    let out = tag_delete_at(None, "v1.0.0", true, Some(&path)).expect("tag_delete_at");
+
+    assert!(out.success, "tag delete should succeed: {:?}", out.stderr);
+    assert_eq!(out.action, "tag.delete_remote");
+    assert!(!tag_exists_locally(&path, "v1.0.0").unwrap());
+    let remote_tag = Command::new("git")
+        .args(["show-ref", "--verify", "refs/tags/v1.0.0"])
+        .current_dir(remote.path())
+        .output()
+        .unwrap();
+    assert!(
+        !remote_tag.status.success(),
+        "expected remote tag to be gone"
+    );
+}
+
 #[test]
 fn push_token_requires_github_remote_url() {
     let (_dir, path) = init_repo_with_initial_commit();
@@ -121,6 +121,37 @@ pub fn pull_at(component_id: Option<&str>, path_override: Option<&str>) -> Resul
     super::run_resolved_git(component_id, path_override, "pull", &["pull"])
 }
 
+/// Options for [`diff`].
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+    /// Show changes staged for the next commit (`git diff --cached`)
+    /// instead of unstaged working-tree changes.
+    pub staged: bool,
+    /// Emit `git diff --stat` summary instead of the full patch.
+    pub stat: bool,
+}
+
+/// Uncommitted changes for a component as a raw diff in `stdout`.
+pub fn diff(component_id: Option<&str>, options: DiffOptions) -> Result<GitOutput> {
+    diff_at(component_id, options, None)
+}
+
+/// Like [`diff`] but with an explicit path override for git operations.
+pub fn diff_at(
+    component_id: Option<&str>,
+    options: DiffOptions,
+    path_override: Option<&str>,
+) -> Result<GitOutput> {
+    let mut args = vec!["diff", "--no-color"];
+    if options.staged {
+        args.push("--cached");
+    }
+    if options.stat {
+        args.push("--stat");
+    }
+    super::run_resolved_git(component_id, path_override, "diff", &args)
+}
+
 /// Options for [`rebase`].
 #[derive(Debug, Clone, Default)]
 pub struct RebaseOptions {
@@ -0,0 +1,130 @@
+//! Branch listing and switching for a component checkout
+//! (`homeboy git branch` / `homeboy git checkout`).
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+use super::primitives_query::{current_branch, status_porcelain};
+use super::{execute_git, resolve_target};
+
+const FIELD_SEP: char = '\x1f';
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BranchEntry {
+    pub name: String,
+    pub current: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitBranchOutput {
+    pub component_id: String,
+    pub path: String,
+    pub action: String,
+    pub success: bool,
+    pub exit_code: i32,
+    /// Branch checked out after the operation; `None` on a detached HEAD.
+    pub current: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<BranchEntry>,
+    pub stderr: String,
+}
+
+/// List local branches for a component, marking the checked-out one.
+pub fn branches(component_id: Option<&str>) -> Result<GitBranchOutput> {
+    branches_at(component_id, None)
+}
+
+/// Like [`branches`] but with an explicit path override for git operations.
+pub fn branches_at(
+    component_id: Option<&str>,
+    path_override: Option<&str>,
+) -> Result<GitBranchOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+    let format_arg = format!("--format=%(HEAD){FIELD_SEP}%(refname:short)");
+    let output = execute_git(&path, &["branch", "--list", &format_arg])
+        .map_err(|e| Error::git_command_failed(e.to_string()))?;
+
+    Ok(GitBranchOutput {
+        current: current_branch(Path::new(&path)),
+        component_id: id,
+        path,
+        action: "branch".to_string(),
+        success: output.status.success(),
+        exit_code: output.status.code().unwrap_or(1),
+        branches: parse_branch_list(&String::from_utf8_lossy(&output.stdout)),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+/// Switch a component checkout to `branch`.
+///
+/// Refuses when tracked files have uncommitted changes so a switch never
+/// carries or clobbers in-progress work. Untracked files are left to git,
+/// which already refuses a checkout that would overwrite them.
+pub fn checkout(component_id: Option<&str>, branch: &str) -> Result<GitBranchOutput> {
+    checkout_at(component_id, branch, None)
+}
+
+/// Like [`checkout`] but with an explicit path override for git operations.
+pub fn checkout_at(
+    component_id: Option<&str>,
+    branch: &str,
+    path_override: Option<&str>,
+) -> Result<GitBranchOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+
+    let status = status_porcelain(Path::new(&path)).ok_or_else(|| {
+        Error::git_command_failed(format!("Failed to read git status in {}", path))
+    })?;
+    let dirty: Vec<&str> = status
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with("??"))
+        .collect();
+    if !dirty.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "branch",
+            format!(
+                "Working tree has uncommitted changes ({} file(s)); commit or stash them before switching to '{}'",
+                dirty.len(),
+                branch
+            ),
+            Some(id),
+            None,
+        )
+        .with_hint("Run `homeboy git status` to see the changes"));
+    }
+
+    // `--` keeps a branch name that matches a path from being read as a pathspec.
+    let output = execute_git(&path, &["checkout", branch, "--"])
+        .map_err(|e| Error::git_command_failed(e.to_string()))?;
+
+    Ok(GitBranchOutput {
+        current: current_branch(Path::new(&path)),
+        component_id: id,
+        path,
+        action: "checkout".to_string(),
+        success: output.status.success(),
+        exit_code: output.status.code().unwrap_or(1),
+        branches: Vec::new(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+fn parse_branch_list(raw: &str) -> Vec<BranchEntry> {
+    raw.lines()
+        .filter_map(|line| {
+            let (head, name) = line.split_once(FIELD_SEP)?;
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+            Some(BranchEntry {
+                name: name.to_string(),
+                current: head.trim() == "*",
+            })
+        })
+        .collect()
+}
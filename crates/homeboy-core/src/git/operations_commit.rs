@@ -36,6 +36,10 @@ pub struct CommitOptions {
     pub exclude: Option<Vec<String>>,
     /// Amend the previous commit instead of creating a new one
     pub amend: bool,
+    /// Commit author name (`git -c user.name=...`); ambient config when `None`
+    pub author_name: Option<String>,
+    /// Commit author email (`git -c user.email=...`); ambient config when `None`
+    pub author_email: Option<String>,
 }
 
 /// Commit changes for a component.
@@ -122,22 +126,21 @@ pub fn commit_at(
                     return Ok(GitOutput::from_output(id, path, "commit", add_output));
                 }
             }
-            // Exclude specific files: stage all, then unstage excluded
+            // Exclude specific files: stage everything else in one pass via
+            // `:(exclude)` pathspecs, so excluded paths that are missing or
+            // already unchanged don't fail the way `git reset -- <path>` would.
             (None, Some(excluded)) => {
-                let add_output = execute_git(&path, &["add", "."])
+                let exclude_specs: Vec<String> = excluded
+                    .iter()
+                    .map(|file| format!(":(exclude){}", file))
+                    .collect();
+                let mut args = vec!["add", "-A", "--", "."];
+                args.extend(exclude_specs.iter().map(|s| s.as_str()));
+                let add_output = execute_git(&path, &args)
                     .map_err(|e| Error::git_command_failed(e.to_string()))?;
                 if !add_output.status.success() {
                     return Ok(GitOutput::from_output(id, path, "commit", add_output));
                 }
-                // Unstage excluded files (git reset -- file1 file2)
-                // Note: git reset without --hard only unstages, does not discard changes
-                let mut reset_args = vec!["reset", "--"];
-                reset_args.extend(excluded.iter().map(|s| s.as_str()));
-                let reset_output = execute_git(&path, &reset_args)
-                    .map_err(|e| Error::git_command_failed(e.to_string()))?;
-                if !reset_output.status.success() {
-                    return Ok(GitOutput::from_output(id, path, "commit", reset_output));
-                }
             }
             // Default: stage all
             (None, None) => {
@@ -150,11 +153,22 @@ pub fn commit_at(
         }
     }
 
-    let args: Vec<&str> = if options.amend {
-        vec!["commit", "--amend", "-m", msg]
-    } else {
-        vec!["commit", "-m", msg]
-    };
+    let identity_config: Vec<String> = [
+        ("user.name", &options.author_name),
+        ("user.email", &options.author_email),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| value.as_ref().map(|value| format!("{}={}", key, value)))
+    .collect();
+    let mut args: Vec<&str> = Vec::new();
+    for config in &identity_config {
+        args.extend(["-c", config.as_str()]);
+    }
+    args.push("commit");
+    if options.amend {
+        args.push("--amend");
+    }
+    args.extend(["-m", msg]);
     let output = execute_git(&path, &args).map_err(|e| Error::git_command_failed(e.to_string()))?;
     Ok(GitOutput::from_output(id, path, "commit", output))
 }
@@ -183,7 +197,7 @@ fn commit_bulk(json_spec: &str) -> Result<BulkResult<GitOutput>> {
             staged_only: spec.staged_only,
             files: spec.files.clone(),
             exclude: spec.exclude_files.clone(),
-            amend: false,
+            ..Default::default()
         };
         match commit(Some(&id), Some(&spec.message), options) {
             Ok(output) => {
@@ -245,7 +259,7 @@ pub fn commit_from_json(id: Option<&str>, json_spec: &str) -> Result<CommitJsonO
         staged_only: spec.staged_only,
         files: spec.files,
         exclude: spec.exclude_files,
-        amend: false,
+        ..Default::default()
     };
 
     let output = commit(target_id.as_deref(), Some(&spec.message), options)?;
@@ -0,0 +1,108 @@
+//! Recent-history query for a component checkout (`homeboy git log`).
+
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+use super::{execute_git, resolve_target};
+
+/// Commits shown by [`log`] when no count is given.
+pub const DEFAULT_LOG_COUNT: usize = 20;
+
+const FIELD_SEP: char = '\x1f';
+const RECORD_SEP: char = '\x1e';
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LogEntry {
+    /// Abbreviated commit hash.
+    pub hash: String,
+    pub subject: String,
+    pub author: String,
+    /// Author date (ISO 8601 with offset).
+    pub date: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitLogOutput {
+    pub component_id: String,
+    pub path: String,
+    pub action: String,
+    pub success: bool,
+    pub exit_code: i32,
+    /// Newest first.
+    pub entries: Vec<LogEntry>,
+    pub stderr: String,
+}
+
+/// Recent commits for a component, newest first.
+pub fn log(component_id: Option<&str>, count: usize) -> Result<GitLogOutput> {
+    log_at(component_id, count, None)
+}
+
+/// Like [`log`] but with an explicit path override for git operations.
+pub fn log_at(
+    component_id: Option<&str>,
+    count: usize,
+    path_override: Option<&str>,
+) -> Result<GitLogOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+    let count_arg = format!("-n{}", count);
+    let format_arg = format!("--format=%h{FIELD_SEP}%s{FIELD_SEP}%an{FIELD_SEP}%aI{RECORD_SEP}");
+    let output = execute_git(&path, &["log", &count_arg, &format_arg])
+        .map_err(|e| Error::git_command_failed(e.to_string()))?;
+
+    Ok(GitLogOutput {
+        component_id: id,
+        path,
+        action: "log".to_string(),
+        success: output.status.success(),
+        exit_code: output.status.code().unwrap_or(1),
+        entries: parse_log_entries(&String::from_utf8_lossy(&output.stdout)),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+fn parse_log_entries(raw: &str) -> Vec<LogEntry> {
+    raw.split(RECORD_SEP)
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').split(FIELD_SEP);
+            let hash = fields.next()?.trim();
+            if hash.is_empty() {
+                return None;
+            }
+            Some(LogEntry {
+                hash: hash.to_string(),
+                subject: fields.next()?.to_string(),
+                author: fields.next()?.to_string(),
+                date: fields.next()?.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_log_entries_reads_each_record() {
+        let raw =
+            "abc1234\u{1f}fix: handle | pipes\u{1f}Ada\u{1f}2026-01-02T03:04:05+00:00\u{1e}\n\
+                   def5678\u{1f}Initial\u{1f}Grace Hopper\u{1f}2026-01-01T00:00:00-05:00\u{1e}\n";
+
+        let entries = parse_log_entries(raw);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0],
+            LogEntry {
+                hash: "abc1234".to_string(),
+                subject: "fix: handle | pipes".to_string(),
+                author: "Ada".to_string(),
+                date: "2026-01-02T03:04:05+00:00".to_string(),
+            }
+        );
+        assert_eq!(entries[1].author, "Grace Hopper");
+        assert!(parse_log_entries("").is_empty());
+    }
+}
@@ -211,26 +211,79 @@ pub fn is_ancestor(path: &str, ancestor: &str, descendant: &str) -> Result<bool>
 /// Delete a local tag. No-op-safe: returns the git output for inspection.
 pub fn delete_local_tag(path: &str, tag_name: &str) -> Result<GitOutput> {
     let (id, resolved) = resolve_target(None, Some(path))?;
-    let output = execute_git(&resolved, &["tag", "-d", tag_name])
-        .map_err(|e| Error::git_command_failed(e.to_string()))?;
-    Ok(GitOutput::from_output(id, resolved, "tag.delete", output))
+    delete_local_tag_in(id, resolved, tag_name)
 }
 
 /// Delete a tag on the resolved default remote.
 pub fn delete_remote_tag(path: &str, tag_name: &str) -> Result<GitOutput> {
     let (id, resolved) = resolve_target(None, Some(path))?;
-    let remote = super::resolve_default_remote(Path::new(&resolved));
+    delete_remote_tag_in(id, resolved, tag_name)
+}
+
+fn delete_local_tag_in(id: String, path: String, tag_name: &str) -> Result<GitOutput> {
+    let output = execute_git(&path, &["tag", "-d", tag_name])
+        .map_err(|e| Error::git_command_failed(e.to_string()))?;
+    Ok(GitOutput::from_output(id, path, "tag.delete", output))
+}
+
+fn delete_remote_tag_in(id: String, path: String, tag_name: &str) -> Result<GitOutput> {
+    let remote = super::resolve_default_remote(Path::new(&path));
     let refspec = format!(":refs/tags/{}", tag_name);
-    let output = execute_git(&resolved, &["push", &remote, &refspec])
+    let output = execute_git(&path, &["push", &remote, &refspec])
         .map_err(|e| Error::git_command_failed(e.to_string()))?;
     Ok(GitOutput::from_output(
         id,
-        resolved,
+        path,
         "tag.delete_remote",
         output,
     ))
 }
 
+/// List a component's local tags, optionally filtered by a `git tag --list`
+/// glob pattern.
+pub fn tag_list_at(
+    component_id: Option<&str>,
+    pattern: Option<&str>,
+    path_override: Option<&str>,
+) -> Result<GitOutput> {
+    let mut args = vec!["tag", "--list"];
+    if let Some(pattern) = pattern {
+        args.push(pattern);
+    }
+    super::run_resolved_git(component_id, path_override, "tag.list", &args)
+}
+
+/// Delete a component tag locally and, with `remote`, on the default remote.
+///
+/// With `remote`, a tag that only exists on the remote is still deleted
+/// there, which is what clears a stale release tag pointing at the wrong
+/// commit.
+pub fn tag_delete_at(
+    component_id: Option<&str>,
+    tag_name: &str,
+    remote: bool,
+    path_override: Option<&str>,
+) -> Result<GitOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+
+    if !remote {
+        return delete_local_tag_in(id, path, tag_name);
+    }
+
+    let mut local_stdout = String::new();
+    if tag_exists_locally(&path, tag_name)? {
+        let local = delete_local_tag_in(id.clone(), path.clone(), tag_name)?;
+        if !local.success {
+            return Ok(local);
+        }
+        local_stdout = local.stdout;
+    }
+
+    let mut output = delete_remote_tag_in(id, path, tag_name)?;
+    output.stdout = format!("{}{}", local_stdout, output.stdout);
+    Ok(output)
+}
+
 /// Get the current HEAD short commit SHA, returning `None` outside git checkouts.
 pub fn short_head_revision_at(path: &Path) -> Option<String> {
     short_head_revision(path)
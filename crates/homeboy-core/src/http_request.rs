@@ -16,6 +16,8 @@ pub struct HttpRequestInput {
     pub headers: Vec<String>,
     pub json_body: Option<String>,
     pub form_body: Vec<(String, String)>,
+    /// Log the redacted request and response to stderr.
+    pub debug: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -53,7 +55,7 @@ pub fn run(input: HttpRequestInput) -> Result<HttpRequestOutput> {
         request = request.form(&input.form_body);
     }
 
-    let response = request.send().map_err(http::http_error)?;
+    let response = http::send_request(&client, request, input.debug)?;
     response_output(method.as_str(), &input.url, response)
 }
 
@@ -156,6 +158,7 @@ mod tests {
             headers: Vec::new(),
             json_body: None,
             form_body: Vec::new(),
+            debug: false,
         })
         .unwrap();
 
@@ -175,6 +178,7 @@ mod tests {
             headers: vec!["no-colon".to_string()],
             json_body: None,
             form_body: Vec::new(),
+            debug: false,
         })
         .unwrap_err();
 
@@ -0,0 +1,183 @@
+//! Client-side `--sort` / `--filter` shaping for entity list commands.
+//!
+//! Each list command declares the keys it supports and how to read a key's
+//! values from one item; this module parses the expressions and applies them
+//! to the already-loaded list. Filters are `key=value` (exact) or
+//! `key~=value` (substring), both case-insensitive. A key may yield several
+//! values (e.g. a project's extension types); a filter matches when any value
+//! does, and sorting uses the first value.
+
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListFilter {
+    pub key: String,
+    pub value: String,
+    /// `~=` substring match instead of exact `=`.
+    pub contains: bool,
+}
+
+impl ListFilter {
+    fn matches(&self, values: &[String]) -> bool {
+        let wanted = self.value.to_lowercase();
+        values.iter().any(|value| {
+            let value = value.to_lowercase();
+            if self.contains {
+                value.contains(&wanted)
+            } else {
+                value == wanted
+            }
+        })
+    }
+}
+
+/// Parse one `--filter` expression against the command's allowed keys.
+pub fn parse_filter(expr: &str, keys: &[&str]) -> Result<ListFilter> {
+    let (key, value, contains) = match expr.split_once("~=") {
+        Some((key, value)) => (key, value, true),
+        None => match expr.split_once('=') {
+            Some((key, value)) => (key, value, false),
+            None => {
+                return Err(Error::validation_invalid_argument(
+                    "filter",
+                    format!(
+                        "Invalid filter expression '{expr}': expected key=value or key~=value ({})",
+                        allowed_keys(keys)
+                    ),
+                    Some(expr.to_string()),
+                    None,
+                ))
+            }
+        },
+    };
+
+    let key = key.trim();
+    require_known_key("filter", key, keys)?;
+
+    Ok(ListFilter {
+        key: key.to_string(),
+        value: value.trim().to_string(),
+        contains,
+    })
+}
+
+/// Filter then sort `items` in place. With no sort key and no filters the
+/// list is left exactly as loaded.
+pub fn apply_list_query<T>(
+    items: &mut Vec<T>,
+    sort: Option<&str>,
+    filters: &[String],
+    keys: &[&str],
+    values: impl Fn(&T, &str) -> Vec<String>,
+) -> Result<()> {
+    let filters = filters
+        .iter()
+        .map(|expr| parse_filter(expr, keys))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(sort) = sort {
+        require_known_key("sort", sort, keys)?;
+    }
+
+    items.retain(|item| {
+        filters
+            .iter()
+            .all(|filter| filter.matches(&values(item, &filter.key)))
+    });
+
+    if let Some(sort) = sort {
+        // Items without a value for the key sort after those with one.
+        items.sort_by_cached_key(|item| {
+            let first = values(item, sort)
+                .into_iter()
+                .next()
+                .map(|value| value.to_lowercase());
+            (first.is_none(), first)
+        });
+    }
+
+    Ok(())
+}
+
+fn require_known_key(field: &str, key: &str, keys: &[&str]) -> Result<()> {
+    if keys.contains(&key) {
+        return Ok(());
+    }
+    Err(Error::validation_invalid_argument(
+        field,
+        format!("Unknown {field} key '{key}' ({})", allowed_keys(keys)),
+        Some(key.to_string()),
+        None,
+    ))
+}
+
+fn allowed_keys(keys: &[&str]) -> String {
+    format!("allowed keys: {}", keys.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYS: &[&str] = &["name", "domain", "type"];
+
+    fn values(item: &(&str, Option<&str>, Vec<&str>), key: &str) -> Vec<String> {
+        match key {
+            "name" => vec![item.0.to_string()],
+            "domain" => item.1.map(str::to_string).into_iter().collect(),
+            "type" => item.2.iter().map(|t| t.to_string()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn fixture() -> Vec<(&'static str, Option<&'static str>, Vec<&'static str>)> {
+        vec![
+            ("shop", Some("shop.example.com"), vec!["wordpress"]),
+            ("api", None, vec!["rust"]),
+            (
+                "blog",
+                Some("Blog.example.org"),
+                vec!["nodejs", "wordpress"],
+            ),
+        ]
+    }
+
+    fn names(items: &[(&str, Option<&str>, Vec<&str>)]) -> Vec<String> {
+        items.iter().map(|item| item.0.to_string()).collect()
+    }
+
+    #[test]
+    fn no_options_keeps_loaded_order() {
+        let mut items = fixture();
+        apply_list_query(&mut items, None, &[], KEYS, values).unwrap();
+        assert_eq!(names(&items), vec!["shop", "api", "blog"]);
+    }
+
+    #[test]
+    fn sorts_and_filters_with_exact_and_substring_matches() {
+        let mut items = fixture();
+        apply_list_query(&mut items, Some("name"), &[], KEYS, values).unwrap();
+        assert_eq!(names(&items), vec!["api", "blog", "shop"]);
+
+        let mut items = fixture();
+        apply_list_query(&mut items, Some("domain"), &[], KEYS, values).unwrap();
+        assert_eq!(names(&items), vec!["blog", "shop", "api"]);
+
+        let mut items = fixture();
+        let filters = vec!["type=WordPress".to_string(), "domain~=example".to_string()];
+        apply_list_query(&mut items, Some("name"), &filters, KEYS, values).unwrap();
+        assert_eq!(names(&items), vec!["blog", "shop"]);
+    }
+
+    #[test]
+    fn invalid_keys_and_expressions_list_allowed_keys() {
+        let mut items = fixture();
+        let error = apply_list_query(&mut items, Some("size"), &[], KEYS, values).unwrap_err();
+        let details = error.details.to_string();
+        assert!(details.contains("Unknown sort key 'size'"));
+        assert!(details.contains("allowed keys: name, domain, type"));
+
+        assert!(parse_filter("owner=me", KEYS).is_err());
+        let error = parse_filter("wordpress", KEYS).unwrap_err();
+        assert!(error.details.to_string().contains("key=value"));
+    }
+}
@@ -14,7 +14,7 @@ use crate::engine::{command, shell};
 use crate::error::{Error, Result};
 use crate::paths::resolve_path_string;
 use crate::project;
-use crate::server::CommandOutput;
+use crate::server::{CommandOutput, SshClient};
 
 use std::path::Path;
 use std::process::Command;
@@ -23,6 +23,9 @@ mod edit;
 
 const STDIN_CONTENT_LIMIT_BYTES: u64 = 1024 * 1024;
 
+/// Directories pruned from `search` so dependency trees don't drown results.
+const SEARCH_EXCLUDE_DIRS: &[&str] = &[".git", "node_modules", "vendor"];
+
 pub use edit::{
     edit_append, edit_append_with_options, edit_delete_line, edit_delete_line_with_options,
     edit_delete_lines, edit_delete_lines_with_options, edit_delete_pattern,
@@ -57,6 +60,73 @@ pub struct ReadResult {
     pub path: String,
     pub size: Option<i64>,
     pub content: String,
+    /// Whether `head`, `tail`, or `max_bytes` clipped the content.
+    pub truncated: bool,
+}
+
+/// Limits for reading part of a large file instead of the whole thing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReadOptions {
+    /// Read only the first N lines.
+    pub head: Option<u32>,
+    /// Read only the last N lines.
+    pub tail: Option<u32>,
+    /// Cap the content at N bytes, applied after `head`/`tail`.
+    pub max_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+
+pub struct StatResult {
+    pub base_path: Option<String>,
+    pub path: String,
+    /// `stat`'s description, e.g. `regular file`, `directory`, `symbolic link`.
+    pub file_type: String,
+    pub size: i64,
+    /// Symbolic permissions, e.g. `-rw-r--r--`.
+    pub permissions: String,
+    /// Octal permissions, e.g. `644`.
+    pub mode: String,
+    pub owner: String,
+    pub group: String,
+    /// Last modification time (RFC 3339, UTC).
+    pub modified: String,
+}
+
+/// Exit code `stat_command` uses when the path does not exist.
+const STAT_MISSING_EXIT_CODE: i32 = 44;
+
+/// Stat one path with a fixed `|`-separated format. A missing path exits
+/// with [`STAT_MISSING_EXIT_CODE`] before `stat` runs, so the caller never
+/// has to interpret `stat`'s stderr. GNU `stat -c` is tried first, then the
+/// BSD/macOS `stat -f` equivalent.
+fn stat_command(full_path: &str) -> String {
+    let quoted = shell::quote_path(full_path);
+    format!(
+        "if [ ! -e {quoted} ] && [ ! -L {quoted} ]; then exit {STAT_MISSING_EXIT_CODE}; fi; stat -c '%F|%s|%A|%a|%U|%G|%Y' {quoted} 2>/dev/null || stat -f '%HT|%z|%Sp|%Lp|%Su|%Sg|%m' {quoted}"
+    )
+}
+
+/// Parse the `stat_command` output line; `None` when it is malformed.
+fn parse_stat_output(output: &str, base_path: &str, full_path: &str) -> Option<StatResult> {
+    let fields: Vec<&str> = output.trim().split('|').collect();
+    let [file_type, size, permissions, mode, owner, group, modified] = fields.as_slice() else {
+        return None;
+    };
+    let modified = chrono::DateTime::from_timestamp(modified.parse().ok()?, 0)?;
+
+    Some(StatResult {
+        base_path: Some(base_path.to_string()),
+        path: full_path.to_string(),
+        // BSD `stat` capitalizes the type (`Regular File`).
+        file_type: file_type.to_lowercase(),
+        size: size.parse().ok()?,
+        permissions: permissions.to_string(),
+        mode: mode.to_string(),
+        owner: owner.to_string(),
+        group: group.to_string(),
+        modified: modified.to_rfc3339(),
+    })
 }
 
 fn parse_file_size(output: &str) -> Option<i64> {
@@ -111,6 +181,50 @@ pub struct WriteResult {
     pub base_path: Option<String>,
     pub path: String,
     pub bytes_written: usize,
+    /// True when the parent directory did not exist and was created first.
+    pub parents_created: bool,
+    /// True when content was appended instead of replacing the file.
+    pub appended: bool,
+    /// True when the write went through a temp file renamed into place.
+    pub atomic: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WriteOptions {
+    /// Create missing parent directories first (like `mkdir -p`).
+    pub create_parents: bool,
+    /// Append to the file (`>>`) instead of truncating it.
+    pub append: bool,
+    /// Write to `<path>.homeboy-tmp` and rename it into place, so an
+    /// interrupted write never leaves a half-written target. Symlinks are
+    /// written through, and targets whose directory is not writable are
+    /// written in place. On by default.
+    pub atomic: bool,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            create_parents: false,
+            append: false,
+            atomic: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+
+pub struct MkdirResult {
+    pub base_path: Option<String>,
+    pub path: String,
+    pub parents: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+
+pub struct TouchResult {
+    pub base_path: Option<String>,
+    pub path: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -129,6 +243,15 @@ pub struct RenameResult {
     pub new_path: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+
+pub struct CopyResult {
+    pub base_path: Option<String>,
+    pub source_path: String,
+    pub destination_path: String,
+    pub recursive: bool,
+}
+
 /// Parse `ls -la` output into structured file entries.
 fn parse_ls_output(output: &str, base_path: &str) -> Vec<FileEntry> {
     let mut entries: Vec<FileEntry> =
@@ -233,19 +356,101 @@ pub fn list(project_id: &str, path: &str) -> Result<ListResult> {
 
 /// Read file content.
 pub fn read(project_id: &str, path: &str) -> Result<ReadResult> {
+    read_with_options(project_id, path, ReadOptions::default())
+}
+
+/// Read file content, optionally limited to its first/last lines or a byte cap.
+pub fn read_with_options(project_id: &str, path: &str, options: ReadOptions) -> Result<ReadResult> {
+    if options.head.is_some() && options.tail.is_some() {
+        return Err(Error::validation_invalid_argument(
+            "tail",
+            "Use either head or tail, not both",
+            None,
+            None,
+        ));
+    }
+
     let project = project::load(project_id)?;
     let project_base_path = require_project_base_path(project_id, &project)?;
     let full_path = resolve_remote_path(&project, &project_base_path, path)?;
-    let command = format!("cat {}", shell::quote_path(&full_path));
+    let command = read_command(&full_path, options);
     let output = execute_for_project(&project, &command)?;
     require_file_command_success(&output, "READ", &full_path)?;
     let size = file_size(&project, &full_path);
+    let truncated = is_read_truncated(size, &output.stdout, options);
 
     Ok(ReadResult {
         base_path: Some(project_base_path),
         path: full_path,
         size,
         content: output.stdout,
+        truncated,
+    })
+}
+
+/// Build the read command. When the byte cap is piped after `head`/`tail`,
+/// a zero-byte `head -c 0` probe runs first so a missing or unreadable file
+/// still fails instead of yielding empty output from the pipe.
+fn read_command(full_path: &str, options: ReadOptions) -> String {
+    let quoted = shell::quote_path(full_path);
+    let source = match (options.head, options.tail) {
+        (Some(lines), _) => format!("head -n {} {}", lines, quoted),
+        (None, Some(lines)) => format!("tail -n {} {}", lines, quoted),
+        (None, None) => match options.max_bytes {
+            Some(bytes) => return format!("head -c {} {}", bytes, quoted),
+            None => return format!("cat {}", quoted),
+        },
+    };
+
+    match options.max_bytes {
+        Some(bytes) => format!("head -c 0 {} && {} | head -c {}", quoted, source, bytes),
+        None => source,
+    }
+}
+
+fn is_read_truncated(size: Option<i64>, content: &str, options: ReadOptions) -> bool {
+    let Some(size) = size else {
+        return false;
+    };
+    let over_byte_cap = options
+        .max_bytes
+        .is_some_and(|bytes| size > bytes.min(i64::MAX as u64) as i64);
+
+    over_byte_cap || (content.len() as i64) < size
+}
+
+/// Get one path's metadata without listing its directory.
+pub fn stat(project_id: &str, path: &str) -> Result<StatResult> {
+    let project = project::load(project_id)?;
+    let project_base_path = require_project_base_path(project_id, &project)?;
+    let full_path = resolve_remote_path(&project, &project_base_path, path)?;
+    let output = execute_for_project(&project, &stat_command(&full_path))?;
+    if output.exit_code == STAT_MISSING_EXIT_CODE {
+        return Err(Error::validation_invalid_argument(
+            "path",
+            format!("No such file or directory: {}", full_path),
+            Some(path.to_string()),
+            Some(vec![format!(
+                "List the parent directory: homeboy file list {} {}",
+                project_id,
+                Path::new(&full_path)
+                    .parent()
+                    .map(|parent| parent.display().to_string())
+                    .unwrap_or_else(|| "/".to_string())
+            )]),
+        ));
+    }
+    require_file_command_success(&output, "STAT", &full_path)?;
+
+    parse_stat_output(&output.stdout, &project_base_path, &full_path).ok_or_else(|| {
+        Error::internal_io(
+            format!(
+                "STAT_FAILED: path={}; unexpected stat output: {}",
+                full_path,
+                output.stdout.trim()
+            ),
+            Some("STAT".to_string()),
+        )
     })
 }
 
@@ -268,29 +473,115 @@ fn generate_unique_delimiter(content: &str) -> String {
 /// caller's exact trailing-newline state we strip one trailing newline from the
 /// heredoc body (the heredoc re-adds exactly one) and, when the original content
 /// had no trailing newline at all, drop the heredoc's extra byte afterwards.
-fn write_content_command(quoted_path: &str, content: &str) -> String {
+fn write_content_command(quoted_path: &str, content: &str, append: bool) -> String {
+    heredoc_write_command(quoted_path, content, append, "")
+}
+
+/// Suffix for the temp file an atomic write renames into place.
+const ATOMIC_WRITE_SUFFIX: &str = ".homeboy-tmp";
+
+/// Write through `<path>.homeboy-tmp`, then `mv` it over the target only once
+/// the heredoc succeeded; any failure removes the temp file instead. A
+/// symlinked target is resolved first so the write lands on the real file
+/// and the link survives. An existing target is copied first with `cp -p`
+/// and `chown --reference` so its mode and ownership survive the rename and
+/// an append still sees the current content. When the directory is not
+/// writable or ownership cannot be kept, the target is written in place.
+fn atomic_write_command(full_path: &str, content: &str, append: bool) -> String {
+    let quoted_path = shell::quote_path(full_path);
+    let write = heredoc_write_command(
+        "\"$dest\"",
+        content,
+        append,
+        " && { [ \"$dest\" = \"$target\" ] || mv -f \"$tmp\" \"$target\"; } || { rm -f \"$tmp\"; exit 1; }",
+    );
+
+    format!(
+        "target={quoted_path}; \
+         if [ -L \"$target\" ]; then target=$(readlink -f \"$target\") || exit 1; fi; \
+         tmp=\"$target{ATOMIC_WRITE_SUFFIX}\"; dest=\"$tmp\"; \
+         if [ -e \"$target\" ]; then \
+         if [ -w \"$(dirname \"$target\")\" ] && cp -p \"$target\" \"$tmp\" && \
+         {{ chown --reference=\"$target\" \"$tmp\" 2>/dev/null || \
+         chown \"$(stat -f '%u:%g' \"$target\" 2>/dev/null)\" \"$tmp\" 2>/dev/null; }}; \
+         then :; else rm -f \"$tmp\"; dest=\"$target\"; fi; \
+         fi; {write}"
+    )
+}
+
+/// `cat` the heredoc into `quoted_path`, chaining `then` (shell operators
+/// included) after the write so it only runs once the content landed.
+fn heredoc_write_command(quoted_path: &str, content: &str, append: bool, then: &str) -> String {
     let delimiter = generate_unique_delimiter(content);
     // The heredoc body emits `body` + a single trailing newline. Removing one
     // trailing newline from `content` keeps multi-newline endings intact while
     // letting the heredoc supply the final newline for content that ends in one.
     let body = content.strip_suffix('\n').unwrap_or(content);
-    let mut command = format!("cat > {quoted_path} << '{delimiter}'\n{body}\n{delimiter}");
+    let redirect = if append { ">>" } else { ">" };
+    // Heredoc adds a trailing newline the caller never wanted when `content`
+    // lacks one; drop it so the written file matches `content` exactly.
+    let trim = if content.ends_with('\n') {
+        String::new()
+    } else {
+        format!(" && truncate -s -1 {quoted_path}")
+    };
+
+    format!("cat {redirect} {quoted_path} << '{delimiter}'{trim}{then}\n{body}\n{delimiter}")
+}
 
-    if !content.ends_with('\n') {
-        // Heredoc added a trailing newline the caller never wanted; drop it so the
-        // written file matches `content` exactly.
-        command.push_str(&format!("\ntruncate -s -1 {quoted_path}"));
+const PARENTS_CREATED_MARKER: &str = "__HOMEBOY_PARENTS_CREATED__";
+
+/// Prefix a write with `mkdir -p` of the parent directory, in the same remote
+/// command. The marker on stdout reports whether the directory was missing.
+fn create_parents_prefix(full_path: &str) -> Option<String> {
+    let parent = full_path.rsplit_once('/')?.0;
+    if parent.is_empty() {
+        return None;
     }
+    let quoted_parent = shell::quote_path(parent);
+    Some(format!(
+        "if [ ! -d {quoted_parent} ]; then mkdir -p {quoted_parent} && echo {PARENTS_CREATED_MARKER} || exit 1; fi\n"
+    ))
+}
 
-    command
+/// Write content to file, optionally creating missing parent directories.
+pub fn write(
+    project_id: &str,
+    path: &str,
+    content: &str,
+    create_parents: bool,
+) -> Result<WriteResult> {
+    write_with_options(
+        project_id,
+        path,
+        content,
+        WriteOptions {
+            create_parents,
+            ..Default::default()
+        },
+    )
 }
 
-/// Write content to file.
-pub fn write(project_id: &str, path: &str, content: &str) -> Result<WriteResult> {
+/// Write content to a file, optionally appending instead of overwriting.
+pub fn write_with_options(
+    project_id: &str,
+    path: &str,
+    content: &str,
+    options: WriteOptions,
+) -> Result<WriteResult> {
     let project = project::load(project_id)?;
     let project_base_path = require_project_base_path(project_id, &project)?;
     let full_path = resolve_remote_path(&project, &project_base_path, path)?;
-    let command = write_content_command(&shell::quote_path(&full_path), content);
+    let mut command = if options.atomic {
+        atomic_write_command(&full_path, content, options.append)
+    } else {
+        write_content_command(&shell::quote_path(&full_path), content, options.append)
+    };
+    if options.create_parents {
+        if let Some(prefix) = create_parents_prefix(&full_path) {
+            command.insert_str(0, &prefix);
+        }
+    }
     let output = execute_for_project(&project, &command)?;
     command::require_success(output.success, &output.stderr, "WRITE")?;
 
@@ -298,6 +589,41 @@ pub fn write(project_id: &str, path: &str, content: &str) -> Result<WriteResult>
         base_path: Some(project_base_path),
         path: full_path,
         bytes_written: content.len(),
+        parents_created: output.stdout.contains(PARENTS_CREATED_MARKER),
+        appended: options.append,
+        atomic: options.atomic,
+    })
+}
+
+/// Create a directory. Without `parents`, a missing parent fails like `mkdir`.
+pub fn mkdir(project_id: &str, path: &str, parents: bool) -> Result<MkdirResult> {
+    let project = project::load(project_id)?;
+    let project_base_path = require_project_base_path(project_id, &project)?;
+    let full_path = resolve_remote_path(&project, &project_base_path, path)?;
+    let flags = if parents { "-p " } else { "" };
+    let command = format!("mkdir {}{}", flags, shell::quote_path(&full_path));
+    let output = execute_for_project(&project, &command)?;
+    require_file_command_success(&output, "MKDIR", &full_path)?;
+
+    Ok(MkdirResult {
+        base_path: Some(project_base_path),
+        path: full_path,
+        parents,
+    })
+}
+
+/// Create an empty file, or update the timestamps of an existing one.
+pub fn touch(project_id: &str, path: &str) -> Result<TouchResult> {
+    let project = project::load(project_id)?;
+    let project_base_path = require_project_base_path(project_id, &project)?;
+    let full_path = resolve_remote_path(&project, &project_base_path, path)?;
+    let command = format!("touch {}", shell::quote_path(&full_path));
+    let output = execute_for_project(&project, &command)?;
+    require_file_command_success(&output, "TOUCH", &full_path)?;
+
+    Ok(TouchResult {
+        base_path: Some(project_base_path),
+        path: full_path,
     })
 }
 
@@ -339,6 +665,40 @@ pub fn rename(project_id: &str, old_path: &str, new_path: &str) -> Result<Rename
     })
 }
 
+/// Copy a file, or a directory tree when `recursive`, within the project.
+/// An existing destination file is overwritten.
+pub fn copy(
+    project_id: &str,
+    source_path: &str,
+    destination_path: &str,
+    recursive: bool,
+) -> Result<CopyResult> {
+    let project = project::load(project_id)?;
+    let project_base_path = require_project_base_path(project_id, &project)?;
+    let full_source = resolve_remote_path(&project, &project_base_path, source_path)?;
+    let full_destination = resolve_remote_path(&project, &project_base_path, destination_path)?;
+    let command = copy_command(&full_source, &full_destination, recursive);
+    let output = execute_for_project(&project, &command)?;
+    require_file_command_success(&output, "COPY", &full_source)?;
+
+    Ok(CopyResult {
+        base_path: Some(project_base_path),
+        source_path: full_source,
+        destination_path: full_destination,
+        recursive,
+    })
+}
+
+fn copy_command(full_source: &str, full_destination: &str, recursive: bool) -> String {
+    let flags = if recursive { "-r " } else { "" };
+    format!(
+        "cp {}{} {}",
+        flags,
+        shell::quote_path(full_source),
+        shell::quote_path(full_destination)
+    )
+}
+
 #[derive(Debug, Clone, Serialize)]
 
 pub struct FindResult {
@@ -354,6 +714,8 @@ pub struct GrepMatch {
     pub file: String,
     pub line: u32,
     pub content: String,
+    /// A surrounding line requested with `before`/`after`, not a match.
+    pub is_context: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -370,25 +732,47 @@ fn parse_find_output(output: &str) -> Vec<String> {
     text::lines(output).map(|s| s.to_string()).collect()
 }
 
-/// Parse grep output into structured matches.
+/// Parse `grep -Hn` output into structured matches.
+///
+/// Matches look like `file:line:content`; with `-A`/`-B`/`-C`, context lines
+/// look like `file-line-content` and groups are split by `--`. Since file
+/// names may themselves contain dashes, context lines are attributed to a
+/// file already seen on a match line.
 fn parse_grep_output(output: &str) -> Vec<GrepMatch> {
-    let mut matches = Vec::new();
+    let match_files: Vec<&str> = output
+        .lines()
+        .filter_map(|line| {
+            let (file, rest) = line.split_once(':')?;
+            let (line_num, _) = rest.split_once(':')?;
+            line_num.parse::<u32>().ok().map(|_| file)
+        })
+        .collect();
 
+    let mut matches = Vec::new();
     for line in output.lines() {
-        if line.is_empty() {
+        if line.is_empty() || line == "--" {
             continue;
         }
 
-        // grep -n format: "filename:line_number:content"
-        let parts: Vec<&str> = line.splitn(3, ':').collect();
-        if parts.len() >= 3 {
-            if let Ok(line_num) = parts[1].parse::<u32>() {
-                matches.push(GrepMatch {
-                    file: parts[0].to_string(),
-                    line: line_num,
-                    content: parts[2].to_string(),
-                });
-            }
+        // The shortest known file wins, so `a.php-5-x:1:y` reads as context
+        // of `a.php` rather than a match in a file named `a.php-5-x`.
+        let parsed = match_files
+            .iter()
+            .filter_map(|file| {
+                let rest = line.strip_prefix(file)?;
+                let separator = rest.chars().next().filter(|c| *c == ':' || *c == '-')?;
+                let (line_num, content) = rest[1..].split_once(separator)?;
+                let line_num = line_num.parse::<u32>().ok()?;
+                Some((file, line_num, content, separator == '-'))
+            })
+            .min_by_key(|(file, ..)| file.len());
+        if let Some((file, line_num, content, is_context)) = parsed {
+            matches.push(GrepMatch {
+                file: file.to_string(),
+                line: line_num,
+                content: content.to_string(),
+                is_context,
+            });
         }
     }
 
@@ -447,7 +831,10 @@ pub fn find(
     })
 }
 
-/// Search file contents using grep.
+/// Search file contents using grep. `fixed_strings` matches `pattern`
+/// literally (`-F`) instead of as a regex. `before`/`after` add that many
+/// context lines around each match (`-B`/`-A`), returned with `is_context: true`.
+#[allow(clippy::too_many_arguments)]
 pub fn grep(
     project_id: &str,
     path: &str,
@@ -455,6 +842,9 @@ pub fn grep(
     name_filter: Option<&str>,
     max_depth: Option<u32>,
     case_insensitive: bool,
+    fixed_strings: bool,
+    before: Option<u32>,
+    after: Option<u32>,
 ) -> Result<GrepResult> {
     let project = project::load(project_id)?;
     let project_base_path = require_project_base_path(project_id, &project)?;
@@ -474,11 +864,62 @@ pub fn grep(
     let check_output = execute_for_project(&project, &is_dir_cmd)?;
     let is_directory = check_output.stdout.trim() == "dir";
 
-    // Build grep command based on path type and options
-    let cmd = if is_directory && (max_depth.is_some() || name_filter.is_some()) {
+    let cmd = build_grep_command(
+        &full_path,
+        is_directory,
+        pattern,
+        name_filter,
+        max_depth,
+        case_insensitive,
+        fixed_strings,
+        before,
+        after,
+    );
+
+    let output = execute_for_project(&project, &cmd)?;
+
+    // grep returns exit code 1 when no matches found, which is not an error
+    let matches = parse_grep_output(&output.stdout);
+
+    Ok(GrepResult {
+        base_path: Some(project_base_path),
+        path: full_path,
+        pattern: pattern.to_string(),
+        matches,
+    })
+}
+
+/// Build the remote grep command. `-H` is always passed so single-file and
+/// single-batch output still carries the file name the parser keys on.
+#[allow(clippy::too_many_arguments)]
+fn build_grep_command(
+    full_path: &str,
+    is_directory: bool,
+    pattern: &str,
+    name_filter: Option<&str>,
+    max_depth: Option<u32>,
+    case_insensitive: bool,
+    fixed_strings: bool,
+    before: Option<u32>,
+    after: Option<u32>,
+) -> String {
+    let mut flags = String::from("-Hn");
+    if case_insensitive {
+        flags.push('i');
+    }
+    if fixed_strings {
+        flags.push('F');
+    }
+    if let Some(lines) = before {
+        flags.push_str(&format!(" -B {}", lines));
+    }
+    if let Some(lines) = after {
+        flags.push_str(&format!(" -A {}", lines));
+    }
+
+    if is_directory && (max_depth.is_some() || name_filter.is_some()) {
         // Use find + xargs for portable depth limiting and name filtering
-        let case_flag = if case_insensitive { "-i" } else { "" };
-        let mut find_cmd = format!("find {}", shell::quote_path(&full_path));
+        let mut find_cmd = format!("find {}", shell::quote_path(full_path));
 
         if let Some(depth) = max_depth {
             find_cmd.push_str(&format!(" -maxdepth {}", depth));
@@ -491,41 +932,93 @@ pub fn grep(
         }
 
         format!(
-            "{} -print0 2>/dev/null | xargs -0 grep -n {} {} 2>/dev/null",
+            "{} -print0 2>/dev/null | xargs -0 grep {} {} 2>/dev/null",
             find_cmd,
-            case_flag,
-            shell::quote_path(pattern)
-        )
-    } else if is_directory {
-        // Simple recursive grep for directories without depth/name filters
-        let flags = if case_insensitive { "-rni" } else { "-rn" };
-        format!(
-            "grep {} {} {} 2>/dev/null",
             flags,
-            shell::quote_path(pattern),
-            shell::quote_path(&full_path)
+            shell::quote_path(pattern)
         )
     } else {
-        // Single file grep (no -r flag)
-        let flags = if case_insensitive { "-ni" } else { "-n" };
+        // Recursive grep for directories without depth/name filters, plain
+        // grep for a single file
+        let recursive = if is_directory { "-r " } else { "" };
         format!(
-            "grep {} {} {} 2>/dev/null",
+            "grep {}{} {} {} 2>/dev/null",
+            recursive,
             flags,
             shell::quote_path(pattern),
-            shell::quote_path(&full_path)
+            shell::quote_path(full_path)
         )
-    };
+    }
+}
 
-    let output = execute_for_project(&project, &cmd)?;
+#[derive(Debug, Clone, Serialize)]
 
-    // grep returns exit code 1 when no matches found, which is not an error
-    let matches = parse_grep_output(&output.stdout);
+pub struct SearchResult {
+    pub base_path: Option<String>,
+    pub path: String,
+    pub name_pattern: Option<String>,
+    pub pattern: String,
+    pub files: Vec<String>,
+}
 
-    Ok(GrepResult {
+/// Build the single remote pipeline behind `search`: `find` (pruning
+/// `SEARCH_EXCLUDE_DIRS`) feeding `grep -l` through `xargs -0`.
+fn build_search_command(
+    full_path: &str,
+    name_pattern: Option<&str>,
+    pattern: &str,
+    case_insensitive: bool,
+) -> String {
+    let prune = SEARCH_EXCLUDE_DIRS
+        .iter()
+        .map(|dir| format!("-name {}", shell::quote_path(dir)))
+        .collect::<Vec<_>>()
+        .join(" -o ");
+    let mut cmd = format!(
+        "find {} -type d \\( {} \\) -prune -o -type f",
+        shell::quote_path(full_path),
+        prune
+    );
+    if let Some(name) = name_pattern {
+        cmd.push_str(&format!(" -name {}", shell::quote_path(name)));
+    }
+    let grep_flags = if case_insensitive { "-li" } else { "-l" };
+    cmd.push_str(&format!(
+        " -print0 2>/dev/null | xargs -0 grep {} -e {} 2>/dev/null | sort",
+        grep_flags,
+        shell::quote_path(pattern)
+    ));
+    cmd
+}
+
+/// Find files by name whose contents match `pattern`, in one round trip.
+pub fn search(
+    project_id: &str,
+    path: &str,
+    name_pattern: Option<&str>,
+    pattern: &str,
+    case_insensitive: bool,
+) -> Result<SearchResult> {
+    if pattern.trim().is_empty() {
+        return Err(Error::validation_missing_argument(vec![
+            "pattern".to_string()
+        ]));
+    }
+
+    let project = project::load(project_id)?;
+    let project_base_path = require_project_base_path(project_id, &project)?;
+    let full_path = resolve_remote_path(&project, &project_base_path, path)?;
+
+    let cmd = build_search_command(&full_path, name_pattern, pattern, case_insensitive);
+    let output = execute_for_project(&project, &cmd)?;
+
+    // grep exits 1 when nothing matches, which is not an error
+    Ok(SearchResult {
         base_path: Some(project_base_path),
         path: full_path,
+        name_pattern: name_pattern.map(str::to_string),
         pattern: pattern.to_string(),
-        matches,
+        files: parse_find_output(&output.stdout),
     })
 }
 
@@ -533,12 +1026,15 @@ pub struct DownloadResult {
     pub remote_path: String,
     pub local_path: String,
     pub recursive: bool,
+    /// Bytes that landed on local disk; `None` when the download failed.
+    pub bytes: Option<u64>,
     pub success: bool,
     pub exit_code: i32,
     pub error: Option<String>,
 }
 
-/// Download a file or directory from remote server via SCP.
+/// Download a file or directory from remote server via SCP. The transfer is
+/// byte-for-byte, so binary files (databases, images) survive intact.
 pub fn download(
     project_id: &str,
     remote_path: &str,
@@ -561,28 +1057,11 @@ pub fn download(
         }
     }
 
-    let deploy_defaults = defaults::load_defaults().deploy;
-    let mut scp_args: Vec<String> = deploy_defaults.scp_flags.clone();
-
-    if recursive {
-        scp_args.push("-r".to_string());
-    }
-
-    if let Some(identity_file) = &ctx.client.identity_file {
-        scp_args.extend(["-i".to_string(), identity_file.clone()]);
-    }
-
-    if ctx.client.port != deploy_defaults.default_ssh_port {
-        scp_args.extend(["-P".to_string(), ctx.client.port.to_string()]);
-    }
+    let landed = download_target(local, &full_remote_path);
 
+    let mut scp_args = scp_base_args(&ctx.client, recursive);
     // Remote source (reverse of upload)
-    scp_args.push(format!(
-        "{}@{}:{}",
-        ctx.client.user,
-        ctx.client.host,
-        shell::quote_path(&full_remote_path)
-    ));
+    scp_args.push(scp_remote_target(&ctx.client, &full_remote_path));
     scp_args.push(local_path.to_string());
 
     let label = if recursive { "directory" } else { "file" };
@@ -602,6 +1081,7 @@ pub fn download(
             remote_path: full_remote_path,
             local_path: local_path.to_string(),
             recursive,
+            bytes: Some(local_size(&landed)),
             success: true,
             exit_code: 0,
             error: None,
@@ -613,6 +1093,7 @@ pub fn download(
                 remote_path: full_remote_path,
                 local_path: local_path.to_string(),
                 recursive,
+                bytes: None,
                 success: false,
                 exit_code,
                 error: Some(stderr),
@@ -622,6 +1103,7 @@ pub fn download(
             remote_path: full_remote_path,
             local_path: local_path.to_string(),
             recursive,
+            bytes: None,
             success: false,
             exit_code: 1,
             error: Some(err.to_string()),
@@ -629,6 +1111,143 @@ pub fn download(
     }
 }
 
+pub struct UploadResult {
+    pub local_path: String,
+    pub remote_path: String,
+    pub recursive: bool,
+    /// Size of the local source; `None` when the upload failed.
+    pub bytes: Option<u64>,
+    pub success: bool,
+    pub exit_code: i32,
+    pub error: Option<String>,
+}
+
+/// Upload a local file or directory to the project's server via SCP. The
+/// transfer is byte-for-byte, unlike `write`, which sends text through a
+/// heredoc.
+pub fn upload(
+    project_id: &str,
+    local_path: &str,
+    remote_path: &str,
+    recursive: bool,
+) -> Result<UploadResult> {
+    let local = Path::new(local_path);
+    let metadata = std::fs::metadata(local).map_err(|e| {
+        Error::validation_invalid_argument(
+            "local_path",
+            format!("Cannot read local path {}: {}", local_path, e),
+            None,
+            None,
+        )
+    })?;
+    if metadata.is_dir() && !recursive {
+        return Err(Error::validation_invalid_argument(
+            "local_path",
+            format!(
+                "{} is a directory; pass --recursive to upload it",
+                local_path
+            ),
+            None,
+            None,
+        ));
+    }
+
+    let (ctx, project_base_path) = resolve_project_ssh_with_base_path(project_id)?;
+    let full_remote_path = resolve_remote_path(&ctx.project, &project_base_path, remote_path)?;
+
+    let mut scp_args = scp_base_args(&ctx.client, recursive);
+    scp_args.push(local_path.to_string());
+    scp_args.push(scp_remote_target(&ctx.client, &full_remote_path));
+
+    let label = if recursive { "directory" } else { "file" };
+    log_status!(
+        "upload",
+        "Uploading {}: {} -> {}@{}:{}",
+        label,
+        local_path,
+        ctx.client.user,
+        ctx.client.host,
+        full_remote_path
+    );
+
+    let (success, exit_code, error) = match Command::new("scp").args(&scp_args).output() {
+        Ok(output) if output.status.success() => (true, 0, None),
+        Ok(output) => (
+            false,
+            output.status.code().unwrap_or(1),
+            Some(String::from_utf8_lossy(&output.stderr).to_string()),
+        ),
+        Err(err) => (false, 1, Some(err.to_string())),
+    };
+
+    Ok(UploadResult {
+        local_path: local_path.to_string(),
+        remote_path: full_remote_path,
+        recursive,
+        bytes: success.then(|| local_size(local)),
+        success,
+        exit_code,
+        error,
+    })
+}
+
+/// scp flags shared by `download` and `upload`: configured defaults, `-r`,
+/// and the client's identity file and non-default port.
+fn scp_base_args(client: &SshClient, recursive: bool) -> Vec<String> {
+    let deploy_defaults = defaults::load_defaults().deploy;
+    let mut scp_args: Vec<String> = deploy_defaults.scp_flags.clone();
+
+    if recursive {
+        scp_args.push("-r".to_string());
+    }
+
+    if let Some(identity_file) = &client.identity_file {
+        scp_args.extend(["-i".to_string(), identity_file.clone()]);
+    }
+
+    if client.port != deploy_defaults.default_ssh_port {
+        scp_args.extend(["-P".to_string(), client.port.to_string()]);
+    }
+
+    scp_args
+}
+
+fn scp_remote_target(client: &SshClient, full_remote_path: &str) -> String {
+    format!(
+        "{}@{}:{}",
+        client.user,
+        client.host,
+        shell::quote_path(full_remote_path)
+    )
+}
+
+/// Where scp puts the download: inside `local` when it is an existing
+/// directory, otherwise at `local` itself.
+fn download_target(local: &Path, remote_path: &str) -> std::path::PathBuf {
+    match Path::new(remote_path.trim_end_matches('/')).file_name() {
+        Some(name) if local.is_dir() => local.join(name),
+        _ => local.to_path_buf(),
+    }
+}
+
+/// Size of a local file, or the total size of the files under a directory.
+fn local_size(path: &Path) -> u64 {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| local_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -667,6 +1286,98 @@ mod tests {
         assert_eq!(matches, vec!["/tmp/a", "/tmp/b"]);
     }
 
+    #[test]
+    fn stat_reports_metadata_and_flags_missing_paths() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("wp-config.php");
+        std::fs::write(&path, "<?php\n").expect("write file");
+        let full_path = path.to_string_lossy().to_string();
+        let run = |full_path: &str| {
+            std::process::Command::new("sh")
+                .args(["-c", &stat_command(full_path)])
+                .output()
+                .expect("run stat")
+        };
+
+        let output = run(&full_path);
+        assert!(output.status.success());
+        let stat = parse_stat_output(
+            &String::from_utf8_lossy(&output.stdout),
+            &dir.path().to_string_lossy(),
+            &full_path,
+        )
+        .expect("parse stat output");
+        assert_eq!(stat.file_type, "regular file");
+        assert_eq!(stat.size, 6);
+        assert!(stat.permissions.starts_with('-'));
+        assert!(!stat.owner.is_empty());
+        assert!(stat.modified.ends_with("+00:00"));
+
+        let missing = run(&dir.path().join("missing.php").to_string_lossy());
+        assert_eq!(missing.status.code(), Some(STAT_MISSING_EXIT_CODE));
+    }
+
+    #[test]
+    fn stat_output_parses_fields_and_rejects_garbage() {
+        let stat = parse_stat_output(
+            "directory|4096|drwxr-xr-x|755|www-data|www-data|1700000000\n",
+            "/srv/site",
+            "/srv/site/wp-content",
+        )
+        .expect("parse");
+        assert_eq!(stat.file_type, "directory");
+        assert_eq!(stat.mode, "755");
+        assert_eq!(stat.owner, "www-data");
+        assert_eq!(stat.modified, "2023-11-14T22:13:20+00:00");
+
+        let bsd = parse_stat_output(
+            "Regular File|6|-rw-r--r--|644|deploy|staff|1700000000\n",
+            "/srv/site",
+            "/srv/site/index.php",
+        )
+        .expect("parse BSD stat output");
+        assert_eq!(bsd.file_type, "regular file");
+        assert_eq!(bsd.size, 6);
+
+        assert!(parse_stat_output("stat: cannot stat", "/srv/site", "/srv/site/x").is_none());
+    }
+
+    #[test]
+    fn copy_overwrites_files_and_rejects_directory_onto_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("source.txt");
+        let destination = dir.path().join("destination.txt");
+        let tree = dir.path().join("plugins");
+        std::fs::write(&source, "new").expect("write source");
+        std::fs::write(&destination, "old").expect("write destination");
+        std::fs::create_dir(&tree).expect("create dir");
+        std::fs::write(tree.join("plugin.php"), "<?php\n").expect("write nested file");
+        let run = |source: &Path, destination: &Path, recursive: bool| {
+            std::process::Command::new("sh")
+                .args([
+                    "-c",
+                    &copy_command(
+                        &source.to_string_lossy(),
+                        &destination.to_string_lossy(),
+                        recursive,
+                    ),
+                ])
+                .output()
+                .expect("run cp")
+        };
+
+        assert!(run(&source, &destination, false).status.success());
+        assert_eq!(std::fs::read_to_string(&destination).unwrap(), "new");
+
+        let tree_copy = dir.path().join("plugins-copy");
+        assert!(run(&tree, &tree_copy, true).status.success());
+        assert!(tree_copy.join("plugin.php").exists());
+
+        let onto_file = run(&tree, &destination, true);
+        assert!(!onto_file.status.success());
+        assert!(!onto_file.stderr.is_empty());
+    }
+
     #[test]
     fn test_grep() {
         let matches = parse_grep_output("/tmp/file.txt:3:needle\n");
@@ -675,6 +1386,364 @@ mod tests {
         assert_eq!(matches[0].file, "/tmp/file.txt");
         assert_eq!(matches[0].line, 3);
         assert_eq!(matches[0].content, "needle");
+        assert!(!matches[0].is_context);
+    }
+
+    #[test]
+    fn grep_output_with_context_marks_context_lines_and_skips_separators() {
+        let output = "\
+inc/hooks-2.php-9-function boot() {
+inc/hooks-2.php:10:    add_action('init', 'boot');
+inc/hooks-2.php-11-}
+--
+inc/hooks-2.php-40-// later: 12:00
+inc/hooks-2.php:41:add_action('init', 'late');
+";
+        let matches = parse_grep_output(output);
+
+        assert_eq!(matches.len(), 5);
+        assert!(matches.iter().all(|m| m.file == "inc/hooks-2.php"));
+        assert_eq!(
+            matches
+                .iter()
+                .map(|m| (m.line, m.is_context))
+                .collect::<Vec<_>>(),
+            vec![(9, true), (10, false), (11, true), (40, true), (41, false)]
+        );
+        assert_eq!(matches[0].content, "function boot() {");
+        assert_eq!(matches[3].content, "// later: 12:00");
+    }
+
+    #[test]
+    fn grep_command_always_names_files_and_adds_context_flags() {
+        let cmd = build_grep_command(
+            "/srv/site/wp-config.php",
+            false,
+            "DB_NAME",
+            None,
+            None,
+            true,
+            false,
+            Some(2),
+            Some(3),
+        );
+        assert_eq!(
+            cmd,
+            "grep -Hni -B 2 -A 3 'DB_NAME' '/srv/site/wp-config.php' 2>/dev/null"
+        );
+
+        let cmd = build_grep_command(
+            "/srv/site",
+            true,
+            "add_action",
+            Some("*.php"),
+            None,
+            false,
+            false,
+            None,
+            None,
+        );
+        assert!(cmd.ends_with("| xargs -0 grep -Hn 'add_action' 2>/dev/null"));
+    }
+
+    #[test]
+    fn fixed_string_grep_matches_pattern_literally() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("config.php");
+        std::fs::write(
+            &path,
+            "$config['api.key'] = 1;\n$config['apixkey'] = 2;\n$config['a'] = 3;\n",
+        )
+        .expect("write config");
+        let path = path.to_string_lossy();
+        let run = |fixed_strings| {
+            let cmd = build_grep_command(
+                &path,
+                false,
+                "$config['api.key']",
+                None,
+                None,
+                false,
+                fixed_strings,
+                None,
+                None,
+            );
+            let output = std::process::Command::new("sh")
+                .args(["-c", &cmd])
+                .output()
+                .expect("run grep");
+            parse_grep_output(&String::from_utf8_lossy(&output.stdout))
+        };
+
+        let literal = run(true);
+        assert_eq!(literal.len(), 1);
+        assert_eq!(literal[0].line, 1);
+
+        // As a regex, `['api.key']` is a one-character class, so the literal
+        // key is never found.
+        assert!(run(false).is_empty());
+    }
+
+    #[test]
+    fn search_command_prunes_excluded_dirs_and_lists_matching_files() {
+        let cmd = build_search_command("/srv/site/inc", Some("*.php"), "add_action", false);
+
+        assert!(cmd.starts_with("find '/srv/site/inc' -type d \\( -name '.git' -o -name 'node_modules' -o -name 'vendor' \\) -prune -o -type f -name '*.php' -print0"));
+        assert!(cmd.contains("| xargs -0 grep -l -e 'add_action'"));
+
+        let insensitive = build_search_command("/srv/site", None, "it's", true);
+        assert!(insensitive.contains("grep -li -e 'it'\\''s'"));
+        assert!(!insensitive.contains(" -name '*"));
+    }
+
+    #[test]
+    fn write_content_command_appends_without_touching_existing_content() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("wp-config.php");
+        std::fs::write(&path, "<?php\n").expect("write config");
+        let quoted = shell::quote_path(&path.to_string_lossy());
+        let run = |content: &str, append: bool| {
+            let status = std::process::Command::new("sh")
+                .args(["-c", &write_content_command(&quoted, content, append)])
+                .status()
+                .expect("run write");
+            assert!(status.success());
+            std::fs::read_to_string(&path).expect("read config")
+        };
+
+        assert_eq!(
+            run("define('WP_DEBUG', true);\n", true),
+            "<?php\ndefine('WP_DEBUG', true);\n"
+        );
+        assert_eq!(
+            run("// end", true),
+            "<?php\ndefine('WP_DEBUG', true);\n// end"
+        );
+        assert_eq!(run("<?php\n", false), "<?php\n");
+    }
+
+    #[test]
+    fn download_target_and_size_follow_scp_placement() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let landed = download_target(dir.path(), "/srv/site/wp-content/db.sqlite");
+        assert_eq!(landed, dir.path().join("db.sqlite"));
+        let explicit = dir.path().join("copy.sqlite");
+        assert_eq!(download_target(&explicit, "/srv/db.sqlite"), explicit);
+
+        std::fs::write(&landed, [0u8, 159, 146, 150]).expect("write binary");
+        std::fs::create_dir(dir.path().join("uploads")).expect("mkdir");
+        std::fs::write(dir.path().join("uploads/a.png"), [1u8; 10]).expect("write image");
+        assert_eq!(local_size(&landed), 4);
+        assert_eq!(local_size(&dir.path().join("uploads")), 10);
+        assert_eq!(local_size(&dir.path().join("missing")), 0);
+    }
+
+    #[test]
+    fn upload_validates_the_local_source_before_connecting() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let local_dir = dir.path().to_string_lossy().to_string();
+        let missing = dir.path().join("missing.mo").to_string_lossy().to_string();
+
+        let err = upload("unknown-project", &local_dir, "languages", false)
+            .err()
+            .expect("directory without recursive");
+        assert!(err.message.contains("--recursive"), "{}", err.message);
+        let err = upload("unknown-project", &missing, "languages/de_DE.mo", false)
+            .err()
+            .expect("missing source");
+        assert!(
+            err.message.contains("Cannot read local path"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn write_content_command_keeps_lines_matching_the_default_delimiter() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("deploy.sh");
+        let content = "cat <<HOMEBOYEOF\nhi\nHOMEBOYEOF\nHOMEBOYEOF_1\necho done\n";
+        let status = std::process::Command::new("sh")
+            .args([
+                "-c",
+                &write_content_command(&shell::quote_path(&path.to_string_lossy()), content, false),
+            ])
+            .status()
+            .expect("run write");
+
+        assert!(status.success());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+        assert_eq!(generate_unique_delimiter(content), "HOMEBOYEOF_2");
+    }
+
+    #[test]
+    fn write_commands_handle_single_quotes_in_paths() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("it's \"here\".txt");
+        let full_path = path.to_string_lossy().to_string();
+        let run = |command: String| {
+            let status = std::process::Command::new("sh")
+                .args(["-c", &command])
+                .status()
+                .expect("run write");
+            assert!(status.success(), "{command}");
+            std::fs::read_to_string(&path).expect("read file")
+        };
+
+        assert_eq!(
+            run(write_content_command(
+                &shell::quote_path(&full_path),
+                "a'b\n",
+                false
+            )),
+            "a'b\n"
+        );
+        assert_eq!(run(atomic_write_command(&full_path, "c", true)), "a'b\nc");
+    }
+
+    #[test]
+    fn atomic_write_command_renames_temp_file_and_keeps_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join(".htaccess");
+        let full_path = path.to_string_lossy().to_string();
+        let tmp = dir.path().join(".htaccess.homeboy-tmp");
+        std::fs::write(&path, "RewriteEngine On\n").expect("write htaccess");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640)).expect("chmod");
+        let run = |content: &str, append: bool| {
+            std::process::Command::new("sh")
+                .args(["-c", &atomic_write_command(&full_path, content, append)])
+                .status()
+                .expect("run write")
+                .success()
+        };
+
+        assert!(run("# BEGIN WordPress", true));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "RewriteEngine On\n# BEGIN WordPress"
+        );
+        assert!(run("Options -Indexes\n", false));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "Options -Indexes\n"
+        );
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o640);
+        assert!(!tmp.exists());
+
+        let missing_dir = dir.path().join("missing/config.php");
+        let status = std::process::Command::new("sh")
+            .args([
+                "-c",
+                &atomic_write_command(&missing_dir.to_string_lossy(), "x", false),
+            ])
+            .status()
+            .expect("run write");
+        assert!(!status.success());
+        assert!(!dir.path().join("missing/config.php.homeboy-tmp").exists());
+    }
+
+    #[test]
+    fn atomic_write_command_writes_through_symlinks() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let real = dir.path().join("shared/wp-config.php");
+        std::fs::create_dir_all(real.parent().unwrap()).expect("mkdir");
+        std::fs::write(&real, "old\n").expect("write real file");
+        let link = dir.path().join("wp-config.php");
+        std::os::unix::fs::symlink(&real, &link).expect("symlink");
+
+        let status = std::process::Command::new("sh")
+            .args([
+                "-c",
+                &atomic_write_command(&link.to_string_lossy(), "new\n", false),
+            ])
+            .status()
+            .expect("run write");
+        assert!(status.success());
+        assert!(std::fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(std::fs::read_to_string(&real).unwrap(), "new\n");
+        assert!(!dir.path().join("shared/wp-config.php.homeboy-tmp").exists());
+    }
+
+    #[test]
+    fn atomic_write_command_keeps_ownership_or_writes_in_place() {
+        let command = atomic_write_command("/srv/site/.htaccess", "x", false);
+        assert!(command.contains("chown --reference=\"$target\" \"$tmp\""));
+        assert!(command.contains("[ -w \"$(dirname \"$target\")\" ]"));
+        assert!(command.contains("dest=\"$target\""));
+    }
+
+    #[test]
+    fn create_parents_prefix_runs_mkdir_in_the_write_command() {
+        let prefix = create_parents_prefix("/srv/site/new dir/config.php").expect("parent");
+        assert!(prefix.contains("mkdir -p '/srv/site/new dir'"));
+        assert!(prefix.contains(PARENTS_CREATED_MARKER));
+        assert_eq!(create_parents_prefix("/config.php"), None);
+    }
+
+    #[test]
+    fn read_command_limits_lines_and_bytes() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("debug.log");
+        std::fs::write(&path, "one\ntwo\nthree\n").expect("write log");
+        let full_path = path.to_string_lossy().to_string();
+        let run = |options: ReadOptions| {
+            let output = std::process::Command::new("sh")
+                .args(["-c", &read_command(&full_path, options)])
+                .output()
+                .expect("run read");
+            (
+                output.status.success(),
+                String::from_utf8_lossy(&output.stdout).to_string(),
+            )
+        };
+
+        let head = ReadOptions {
+            head: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(run(head), (true, "one\n".to_string()));
+        let tail = ReadOptions {
+            tail: Some(2),
+            max_bytes: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(run(tail), (true, "two\nt".to_string()));
+        let bytes = ReadOptions {
+            max_bytes: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(run(bytes), (true, "on".to_string()));
+
+        let missing = dir.path().join("missing.log").to_string_lossy().to_string();
+        let status = std::process::Command::new("sh")
+            .args(["-c", &read_command(&missing, tail)])
+            .status()
+            .expect("run read");
+        assert!(!status.success());
+    }
+
+    #[test]
+    fn read_truncation_compares_content_with_file_size() {
+        let head = ReadOptions {
+            head: Some(10),
+            ..Default::default()
+        };
+        assert!(!is_read_truncated(Some(4), "one\n", head));
+        assert!(is_read_truncated(Some(14), "one\n", head));
+        assert!(!is_read_truncated(None, "one\n", head));
+
+        let bytes = ReadOptions {
+            max_bytes: Some(2),
+            ..Default::default()
+        };
+        assert!(is_read_truncated(Some(3), "o\u{fffd}", bytes));
+        assert!(!is_read_truncated(Some(2), "on", bytes));
     }
 
     #[test]
@@ -697,6 +1766,7 @@ mod tests {
             exit_code: 126,
             timed_out: false,
             child_resource: None,
+            combined: None,
         };
 
         let error = require_file_command_success(&output, "READ", "/srv/site/blocked.txt")
@@ -720,6 +1790,7 @@ mod tests {
             exit_code: 2,
             timed_out: false,
             child_resource: None,
+            combined: None,
         };
 
         let error = require_file_command_success(&output, "LIST", "/srv/site/private")
@@ -24,7 +24,7 @@ fn write_modified_content(
         return Ok(());
     }
 
-    write(project_id, path, content).map(|_| ())
+    write(project_id, path, content, false).map(|_| ())
 }
 
 #[derive(Debug, Clone, Serialize)]
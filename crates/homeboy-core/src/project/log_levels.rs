@@ -0,0 +1,213 @@
+//! Log severity detection for `homeboy logs`.
+//!
+//! Each line is matched against level patterns: those contributed by the
+//! project's extensions (`logs.level_patterns` in the manifest) first, then a
+//! small built-in set. Matching is deliberately conservative — a line with no
+//! recognizable severity marker gets no level rather than a guess.
+
+use regex::Regex;
+use serde::Serialize;
+
+use crate::extension_store;
+use crate::project::Project;
+use homeboy_extension_contract::LogLevelPatterns;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Error,
+    Warning,
+    Notice,
+}
+
+impl LogLevel {
+    /// ANSI SGR color used when highlighting a line on a terminal.
+    fn ansi_color(self) -> &'static str {
+        match self {
+            LogLevel::Error => "31",
+            LogLevel::Warning => "33",
+            LogLevel::Notice => "36",
+        }
+    }
+}
+
+/// A log line paired with its detected severity.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<LogLevel>,
+}
+
+const DEFAULT_ERROR_PATTERNS: &[&str] = &[
+    r"\b(ERROR|FATAL|CRITICAL|CRIT|ALERT|EMERGENCY|EMERG)\b",
+    r"\bPHP (Fatal|Parse|Recoverable fatal) error\b",
+    r"\[(error|crit|alert|emerg)\]",
+    r"(?i)\bUncaught (exception|error)\b",
+];
+
+const DEFAULT_WARNING_PATTERNS: &[&str] =
+    &[r"\b(WARN|WARNING)\b", r"\bPHP Warning\b", r"\[warn(ing)?\]"];
+
+const DEFAULT_NOTICE_PATTERNS: &[&str] = &[
+    r"\b(NOTICE|DEPRECATED)\b",
+    r"\bPHP (Notice|Deprecated)\b",
+    r"\[notice\]",
+];
+
+/// Compiled level patterns: extension patterns, then the built-in set, each
+/// in severity order (error, warning, notice).
+pub struct LogLevelMatcher {
+    rules: Vec<(LogLevel, Regex)>,
+}
+
+impl LogLevelMatcher {
+    /// Matcher with only the built-in patterns.
+    pub fn builtin() -> Self {
+        Self::with_patterns(&[])
+    }
+
+    /// Matcher for a project: patterns from each of its extensions that
+    /// declare `logs.level_patterns`, followed by the built-in set. Extensions
+    /// that fail to load are skipped.
+    pub fn for_project(project: &Project) -> Self {
+        let mut extension_ids: Vec<&String> = project
+            .extensions
+            .as_ref()
+            .map(|extensions| extensions.keys().collect())
+            .unwrap_or_default();
+        extension_ids.sort();
+
+        let patterns: Vec<LogLevelPatterns> = extension_ids
+            .into_iter()
+            .filter_map(|id| extension_store::load_extension(id).ok())
+            .filter_map(|manifest| manifest.logs.map(|logs| logs.level_patterns))
+            .collect();
+        Self::with_patterns(&patterns)
+    }
+
+    /// Build a matcher from extension-declared patterns plus the built-in set.
+    /// Extension patterns are tried first so a module can reclassify lines the
+    /// defaults would otherwise catch. Invalid extension regexes are ignored
+    /// rather than failing the command.
+    pub fn with_patterns(extension_patterns: &[LogLevelPatterns]) -> Self {
+        let mut rules = Vec::new();
+        for patterns in extension_patterns {
+            for (level, sources) in [
+                (LogLevel::Error, &patterns.error),
+                (LogLevel::Warning, &patterns.warning),
+                (LogLevel::Notice, &patterns.notice),
+            ] {
+                rules.extend(
+                    sources
+                        .iter()
+                        .filter_map(|p| Regex::new(p).ok().map(|re| (level, re))),
+                );
+            }
+        }
+        for (level, defaults) in [
+            (LogLevel::Error, DEFAULT_ERROR_PATTERNS),
+            (LogLevel::Warning, DEFAULT_WARNING_PATTERNS),
+            (LogLevel::Notice, DEFAULT_NOTICE_PATTERNS),
+        ] {
+            rules.extend(
+                defaults
+                    .iter()
+                    .filter_map(|p| Regex::new(p).ok().map(|re| (level, re))),
+            );
+        }
+        Self { rules }
+    }
+
+    /// Level of the first pattern matching `line`, if any.
+    pub fn detect(&self, line: &str) -> Option<LogLevel> {
+        self.rules
+            .iter()
+            .find(|(_, re)| re.is_match(line))
+            .map(|(level, _)| *level)
+    }
+
+    /// Split `content` into lines annotated with their detected level.
+    pub fn annotate(&self, content: &str) -> Vec<LogLine> {
+        content
+            .lines()
+            .map(|line| LogLine {
+                content: line.to_string(),
+                level: self.detect(line),
+            })
+            .collect()
+    }
+
+    /// `line` wrapped in the ANSI color for its level, or unchanged when no
+    /// level is detected.
+    pub fn colorize(&self, line: &str) -> String {
+        match self.detect(line) {
+            Some(level) => format!("\x1b[{}m{}\x1b[0m", level.ansi_color(), line),
+            None => line.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_patterns_detect_common_levels_conservatively() {
+        let matcher = LogLevelMatcher::builtin();
+
+        assert_eq!(
+            matcher.detect("[17-Oct-2026 10:00:00 UTC] PHP Fatal error:  Uncaught Error"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(
+            matcher.detect("2026/10/17 10:00:00 [error] 123#0: *1 upstream timed out"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(
+            matcher.detect("PHP Warning:  Undefined array key \"id\""),
+            Some(LogLevel::Warning)
+        );
+        assert_eq!(
+            matcher.detect("PHP Deprecated:  Creation of dynamic property"),
+            Some(LogLevel::Notice)
+        );
+        assert_eq!(matcher.detect("GET /wp-json/ 200 OK"), None);
+        assert_eq!(matcher.detect("no errors found in 3 files"), None);
+    }
+
+    #[test]
+    fn extension_patterns_take_precedence_and_invalid_ones_are_skipped() {
+        let matcher = LogLevelMatcher::with_patterns(&[LogLevelPatterns {
+            error: vec!["^E ".to_string(), "(".to_string()],
+            warning: Vec::new(),
+            notice: vec!["^N ".to_string(), "ERROR_LOG rotated".to_string()],
+        }]);
+
+        assert_eq!(matcher.detect("E disk full"), Some(LogLevel::Error));
+        assert_eq!(
+            matcher.detect("ERROR_LOG rotated: ERROR count reset"),
+            Some(LogLevel::Notice)
+        );
+        assert_eq!(matcher.detect("N cache warmed"), Some(LogLevel::Notice));
+        assert_eq!(
+            matcher.detect("WARNING: low memory"),
+            Some(LogLevel::Warning)
+        );
+    }
+
+    #[test]
+    fn annotate_and_colorize_leave_unmatched_lines_plain() {
+        let matcher = LogLevelMatcher::builtin();
+        let lines = matcher.annotate("booting\nERROR failed to bind\n");
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].level, None);
+        assert_eq!(lines[1].level, Some(LogLevel::Error));
+        assert_eq!(matcher.colorize("booting"), "booting");
+        assert_eq!(
+            matcher.colorize("ERROR failed"),
+            "\x1b[31mERROR failed\x1b[0m"
+        );
+    }
+}
@@ -4,14 +4,19 @@
 //! Routes to local or SSH execution based on project configuration.
 //! Pass `local: true` to bypass SSH and execute commands directly on the
 //! current machine (useful when homeboy runs on the target server itself).
+//! Captured lines are annotated with a detected severity (see [`super::log_levels`]).
 
 use crate::context::require_project_base_path;
-use crate::engine::executor::{execute_for_project, execute_for_project_interactive};
+use crate::engine::executor::{
+    execute_for_project, execute_for_project_interactive, execute_for_project_lines,
+};
 use crate::engine::shell;
 use crate::error::{Error, Result};
 use crate::paths as base_path;
+use crate::project::log_levels::{LogLevel, LogLevelMatcher, LogLine};
 use crate::project::{self, Project};
 use serde::Serialize;
+use std::io::Write;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct LogEntry {
@@ -25,6 +30,7 @@ pub struct LogContent {
     pub path: String,
     pub lines: u32,
     pub content: String,
+    pub annotated_lines: Vec<LogLine>,
     pub evidence: LogEvidenceMetadata,
 }
 
@@ -54,6 +60,8 @@ pub struct LogEvidenceMetadata {
 pub struct LogSearchMatch {
     pub line_number: u32,
     pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<LogLevel>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -71,6 +79,7 @@ pub struct PinnedLogContent {
     pub label: Option<String>,
     pub lines: u32,
     pub content: String,
+    pub annotated_lines: Vec<LogLine>,
     pub evidence: LogEvidenceMetadata,
 }
 
@@ -103,25 +112,30 @@ pub fn list(project_id: &str) -> Result<Vec<LogEntry>> {
         .collect())
 }
 
+fn no_pinned_logs_error(project_id: &str) -> Error {
+    Error::validation_invalid_argument(
+        "pinned_logs",
+        "No pinned logs configured for this project",
+        None,
+        Some(vec![
+            format!(
+                "Pin a log: homeboy project set {} --pin-log /path/to/app.log",
+                project_id
+            ),
+            format!("List pinned logs: homeboy logs list {}", project_id),
+        ]),
+    )
+}
+
 pub fn show_pinned(project_id: &str, lines: u32, local: bool) -> Result<PinnedLogsContent> {
     let project = load_project(project_id, local)?;
 
     if project.remote_logs.pinned_logs.is_empty() {
-        return Err(Error::validation_invalid_argument(
-            "pinned_logs",
-            "No pinned logs configured for this project",
-            None,
-            Some(vec![
-                format!(
-                    "Pin a log: homeboy project set {} --pin-log /path/to/app.log",
-                    project_id
-                ),
-                format!("List pinned logs: homeboy logs list {}", project_id),
-            ]),
-        ));
+        return Err(no_pinned_logs_error(project_id));
     }
 
     let base_path = require_project_base_path(project_id, &project)?;
+    let matcher = LogLevelMatcher::for_project(&project);
 
     let mut logs = Vec::new();
     for pinned_log in &project.remote_logs.pinned_logs {
@@ -146,6 +160,7 @@ pub fn show_pinned(project_id: &str, lines: u32, local: bool) -> Result<PinnedLo
             label: pinned_log.label.clone(),
             lines: log_lines,
             evidence,
+            annotated_lines: matcher.annotate(&output.stdout),
             content: output.stdout,
         });
     }
@@ -154,10 +169,48 @@ pub fn show_pinned(project_id: &str, lines: u32, local: bool) -> Result<PinnedLo
     Ok(PinnedLogsContent { logs, total_logs })
 }
 
+/// Resolve a log argument: a pinned log's label selects that log's path,
+/// anything else is taken as a path relative to the project base path.
+fn resolve_log_path<'a>(project: &'a Project, path: &'a str) -> &'a str {
+    project
+        .remote_logs
+        .pinned_logs
+        .iter()
+        .find(|log| log.label.as_deref() == Some(path))
+        .map(|log| log.path.as_str())
+        .unwrap_or(path)
+}
+
+/// `tail -f` with an initial backlog of `lines`.
+fn follow_command(full_path: &str, lines: u32) -> String {
+    format!("tail -n {} -f {}", lines, shell::quote_path(full_path))
+}
+
+/// Follow several logs at once. Each log gets its own `tail -f` piped through
+/// a `read` loop that prefixes `[name] `, so every line reaches the shared
+/// stdout in one write and lines from different logs interleave in arrival
+/// order without being spliced together. (`awk` would be shorter, but mawk
+/// block-buffers its input and stalls on a live `tail -f`.)
+fn merged_follow_command(logs: &[(String, &str)], lines: u32) -> String {
+    let tails = logs
+        .iter()
+        .map(|(full_path, name)| {
+            format!(
+                "{} | while IFS= read -r line; do printf '%s%s\\n' {} \"$line\"; done &",
+                follow_command(full_path, lines),
+                shell::quote_arg(&format!("[{}] ", name))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("{} wait", tails)
+}
+
 pub fn show(project_id: &str, path: &str, lines: u32, local: bool) -> Result<LogContent> {
     let project = load_project(project_id, local)?;
     let base_path = require_project_base_path(project_id, &project)?;
-    let full_path = base_path::join_remote_path(Some(&base_path), path)?;
+    let full_path =
+        base_path::join_remote_path(Some(&base_path), resolve_log_path(&project, path))?;
 
     let command = format!("tail -n {} {}", lines, shell::quote_path(&full_path));
     let output = execute_for_project(&project, &command)?;
@@ -167,17 +220,69 @@ pub fn show(project_id: &str, path: &str, lines: u32, local: bool) -> Result<Log
         path: full_path,
         lines,
         evidence,
+        annotated_lines: LogLevelMatcher::for_project(&project).annotate(&output.stdout),
         content: output.stdout,
     })
 }
 
-pub fn follow(project_id: &str, path: &str, local: bool) -> Result<i32> {
+/// Follow a log with `tail -f`, starting from the last `lines` lines. With
+/// `colorize`, lines are streamed through the project's level matcher and
+/// highlighted by severity; otherwise output passes through untouched. The
+/// returned code is the remote command's (or SSH's, if the connection drops).
+pub fn follow(
+    project_id: &str,
+    path: &str,
+    lines: u32,
+    local: bool,
+    colorize: bool,
+) -> Result<i32> {
     let project = load_project(project_id, local)?;
     let base_path = require_project_base_path(project_id, &project)?;
-    let full_path = base_path::join_remote_path(Some(&base_path), path)?;
+    let full_path =
+        base_path::join_remote_path(Some(&base_path), resolve_log_path(&project, path))?;
+
+    let tail_cmd = follow_command(&full_path, lines);
+    if !colorize {
+        return execute_for_project_interactive(&project, &tail_cmd);
+    }
 
-    let tail_cmd = format!("tail -f {}", shell::quote_path(&full_path));
-    execute_for_project_interactive(&project, &tail_cmd)
+    let matcher = LogLevelMatcher::for_project(&project);
+    let mut stdout = std::io::stdout();
+    execute_for_project_lines(&project, &tail_cmd, &mut |line| {
+        let _ = writeln!(stdout, "{}", matcher.colorize(line));
+        let _ = stdout.flush();
+    })
+}
+
+/// Follow every pinned log together, each line prefixed with its log's
+/// display name. Colorizing works as in [`follow`].
+pub fn follow_pinned(project_id: &str, lines: u32, local: bool, colorize: bool) -> Result<i32> {
+    let project = load_project(project_id, local)?;
+    if project.remote_logs.pinned_logs.is_empty() {
+        return Err(no_pinned_logs_error(project_id));
+    }
+    let base_path = require_project_base_path(project_id, &project)?;
+    let logs = project
+        .remote_logs
+        .pinned_logs
+        .iter()
+        .map(|log| {
+            base_path::join_remote_path(Some(&base_path), &log.path)
+                .map(|full_path| (full_path, log.display_name()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let command = merged_follow_command(&logs, lines);
+    if !colorize {
+        return execute_for_project_interactive(&project, &command);
+    }
+
+    let matcher = LogLevelMatcher::for_project(&project);
+    let mut stdout = std::io::stdout();
+    execute_for_project_lines(&project, &command, &mut |line| {
+        let _ = writeln!(stdout, "{}", matcher.colorize(line));
+        let _ = stdout.flush();
+    })
 }
 
 pub fn clear(project_id: &str, path: &str, local: bool) -> Result<String> {
@@ -230,7 +335,7 @@ pub fn search(
     };
 
     let output = execute_for_project(&project, &command)?;
-    let matches = parse_grep_output(&output.stdout);
+    let matches = parse_grep_output(&output.stdout, &LogLevelMatcher::for_project(&project));
     let match_count = matches.len();
     let evidence = LogEvidenceMetadata::search(
         &full_path,
@@ -335,9 +440,67 @@ mod tests {
         assert_eq!(evidence.match_count, Some(1));
         assert_eq!(evidence.captured_lines, 2);
     }
+
+    #[test]
+    fn follow_command_starts_from_requested_backlog() {
+        assert_eq!(
+            follow_command("/srv/site/wp-content/debug.log", 50),
+            format!(
+                "tail -n 50 -f {}",
+                shell::quote_path("/srv/site/wp-content/debug.log")
+            )
+        );
+    }
+
+    #[test]
+    fn merged_follow_prefixes_each_log_and_waits_for_all() {
+        let command = merged_follow_command(
+            &[
+                ("/srv/logs/error.log".to_string(), "error.log"),
+                ("/srv/logs/access.log".to_string(), "access"),
+            ],
+            20,
+        );
+
+        assert!(command.contains(&follow_command("/srv/logs/error.log", 20)));
+        assert!(command.contains(&follow_command("/srv/logs/access.log", 20)));
+        assert!(command.contains(&shell::quote_arg("[error.log] ")));
+        assert!(command.contains(&shell::quote_arg("[access] ")));
+        assert_eq!(command.matches("while IFS= read -r line").count(), 2);
+        assert!(command.ends_with("& wait"));
+    }
+
+    #[test]
+    fn pinned_log_label_resolves_to_its_path() {
+        let mut project = Project::default();
+        project
+            .remote_logs
+            .pinned_logs
+            .push(project::PinnedRemoteLog {
+                path: "wp-content/debug.log".to_string(),
+                label: Some("debug".to_string()),
+                tail_lines: 100,
+            });
+
+        assert_eq!(resolve_log_path(&project, "debug"), "wp-content/debug.log");
+        assert_eq!(resolve_log_path(&project, "logs/app.log"), "logs/app.log");
+    }
+
+    #[test]
+    fn grep_matches_carry_detected_level() {
+        let matches = parse_grep_output(
+            "12:PHP Fatal error: boom\n13-request finished\n",
+            &LogLevelMatcher::builtin(),
+        );
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].line_number, 12);
+        assert_eq!(matches[0].level, Some(LogLevel::Error));
+        assert_eq!(matches[1].level, None);
+    }
 }
 
-fn parse_grep_output(output: &str) -> Vec<LogSearchMatch> {
+fn parse_grep_output(output: &str, matcher: &LogLevelMatcher) -> Vec<LogSearchMatch> {
     let mut matches = Vec::new();
 
     for line in output.lines() {
@@ -347,16 +510,20 @@ fn parse_grep_output(output: &str) -> Vec<LogSearchMatch> {
 
         if let Some(colon_pos) = line.find(':') {
             if let Ok(line_num) = line[..colon_pos].parse::<u32>() {
+                let content = &line[colon_pos + 1..];
                 matches.push(LogSearchMatch {
                     line_number: line_num,
-                    content: line[colon_pos + 1..].to_string(),
+                    content: content.to_string(),
+                    level: matcher.detect(content),
                 });
             }
         } else if let Some(dash_pos) = line.find('-') {
             if let Ok(line_num) = line[..dash_pos].parse::<u32>() {
+                let content = &line[dash_pos + 1..];
                 matches.push(LogSearchMatch {
                     line_number: line_num,
-                    content: line[dash_pos + 1..].to_string(),
+                    content: content.to_string(),
+                    level: matcher.detect(content),
                 });
             }
         }
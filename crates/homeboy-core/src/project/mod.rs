@@ -11,9 +11,11 @@ use std::path::{Path, PathBuf};
 
 pub mod component;
 pub mod files;
+pub mod log_levels;
 pub mod logs;
 mod path_resolution;
 pub mod pins;
+pub mod portability;
 mod readiness;
 pub mod report;
 mod status;
@@ -34,18 +36,19 @@ pub use pins::{
     add_pin, list_pins, remove_pin, rename_pin, update_pin, PinUpdateOptions, ProjectPinChange,
     ProjectPinListItem, ProjectPinOutput,
 };
+pub use portability::{ProjectBundle, ProjectImportReport, PROJECT_BUNDLE_SCHEMA};
 pub(crate) use readiness::component_local_path_blockers;
 pub use readiness::{
     calculate_deploy_readiness, validate_component_local_path, validate_component_local_paths,
     validate_deploy_component_local_paths,
 };
 pub use report::{
-    build_components_output, build_create_output, build_delete_output, build_init_output,
-    build_list_output, build_path_resolution_output, build_pin_output, build_remove_output,
-    build_rename_output, build_set_output, build_show_output, build_status_output, list_report,
-    show_report, status_report, ProjectComponentVersion, ProjectListItem, ProjectListReport,
-    ProjectPathResolutionReport, ProjectReportExtra, ProjectReportOutput, ProjectShowReport,
-    ProjectStatusReport,
+    build_components_output, build_create_output, build_delete_output, build_export_output,
+    build_import_output, build_init_output, build_list_output, build_path_resolution_output,
+    build_pin_output, build_remove_output, build_rename_output, build_set_output,
+    build_show_output, build_status_output, list_report, show_report, status_report,
+    ProjectComponentVersion, ProjectListItem, ProjectListReport, ProjectPathResolutionReport,
+    ProjectReportExtra, ProjectReportOutput, ProjectShowReport, ProjectStatusReport,
 };
 pub use status::{collect_status, ProjectComponentStatus, ProjectStatusSnapshot};
 pub use types::*;
@@ -105,6 +108,10 @@ pub struct Project {
 
     #[serde(default)]
     pub sub_targets: Vec<SubTarget>,
+    /// Multisite network: `sub_targets` are the network's sites. Extension CLI
+    /// commands get `--url=<site domain>` injected and accept `--network`.
+    #[serde(default)]
+    pub multisite: bool,
     #[serde(default)]
     pub shared_tables: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -120,6 +127,15 @@ pub struct Project {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub services: Vec<String>,
 
+    /// Remote directory where archive artifacts are uploaded and extracted from.
+    ///
+    /// Defaults to the component's `remote_path` itself (uploaded with the
+    /// `.homeboy-` prefix). Set this (e.g. `/tmp`) when the deploy target is
+    /// read-only or space-constrained; stray archives then never land in the
+    /// deploy target. Falls back to the server's `remote_temp_dir`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_temp_dir: Option<String>,
+
     /// Post-deploy front-end smoke check (opt-in, config-driven).
     ///
     /// When enabled, homeboy fetches the configured URL as a fresh visitor after
@@ -129,6 +145,13 @@ pub struct Project {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub smoke_check: Option<SmokeCheckConfig>,
 
+    /// Health check path (or absolute URL) polled by `deploy --wait-healthy`.
+    ///
+    /// A path is resolved against `https://<domain>`; defaults to `/` when
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_path: Option<String>,
+
     /// Optional fail-closed source-provenance policy for deployments.
     /// Omission preserves the legacy deployment behavior.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -444,6 +467,35 @@ mod config_layout_tests {
         });
     }
 
+    #[test]
+    fn commented_project_config_loads_and_saves_as_strict_json() {
+        with_isolated_home(|_| {
+            let dir = paths::projects().expect("projects path").join("annotated");
+            std::fs::create_dir_all(&dir).expect("project dir");
+            let config_path = dir.join("annotated.json");
+            std::fs::write(
+                &config_path,
+                r#"{
+                    // Shared staging box
+                    "domain": "annotated.test",
+                    /* deploy target */
+                    "base_path": "/var/www/html",
+                }"#,
+            )
+            .expect("commented project config");
+
+            let project = load("annotated").expect("commented config loads");
+            assert_eq!(project.domain.as_deref(), Some("annotated.test"));
+            assert_eq!(project.base_path.as_deref(), Some("/var/www/html"));
+
+            save(&project).expect("save project");
+            let saved = std::fs::read_to_string(&config_path).expect("saved config");
+            let strict: serde_json::Value =
+                serde_json::from_str(&saved).expect("saved config is strict JSON");
+            assert_eq!(strict["domain"], "annotated.test");
+        });
+    }
+
     #[test]
     fn legacy_product_specific_tools_config_is_ignored() {
         let project: Project = serde_json::from_str(
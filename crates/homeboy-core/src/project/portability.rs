@@ -0,0 +1,492 @@
+//! Project export/import bundles.
+//!
+//! A bundle is a self-contained JSON document holding one project, the server
+//! it references, and the components attached to it, so a teammate can
+//! recreate the same setup with a single `homeboy project import`. Secrets are
+//! never embedded: identity files stay as path references, and any inline
+//! secret values (server env entries with sensitive names, config-sourced API
+//! auth variables) are dropped and listed in `omitted_secrets` so the importer
+//! knows what to supply locally.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::component::{self, Component};
+use crate::config;
+use crate::engine::identifier;
+use crate::error::{Error, Result};
+use crate::output::{BatchResult, CreateOutput};
+use crate::redaction::RedactionPolicy;
+use crate::server;
+
+use super::{load, Project};
+
+/// Current bundle schema identifier.
+pub const PROJECT_BUNDLE_SCHEMA: &str = "homeboy/project-bundle/v1";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectBundle {
+    pub schema: String,
+    pub project: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub omitted_secrets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectImportReport {
+    pub project_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<BatchResult>,
+    pub components: BatchResult,
+    pub project: BatchResult,
+}
+
+impl ProjectImportReport {
+    pub fn exit_code(&self) -> i32 {
+        let server_errors = self.server.as_ref().map(|s| s.errors).unwrap_or(0);
+        if server_errors + self.components.errors + self.project.errors > 0 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+/// Build a portable bundle for `project_id`.
+pub fn export(project_id: &str) -> Result<ProjectBundle> {
+    let project = load(project_id)?;
+    let mut omitted_secrets = Vec::new();
+
+    let server = match project.server_id.as_deref() {
+        Some(server_id) => Some(export_server(
+            &server::load(server_id)?,
+            &mut omitted_secrets,
+        )?),
+        None => None,
+    };
+
+    let mut components = Vec::new();
+    for attachment in &project.components {
+        // Attachments without a loadable component registration are still
+        // carried by the project's own `components` list.
+        if let Ok(component) = component::load(&attachment.id) {
+            components.push(entity_value(&component, &component.id)?);
+        }
+    }
+
+    let project_value = export_project(&project, &mut omitted_secrets)?;
+
+    Ok(ProjectBundle {
+        schema: PROJECT_BUNDLE_SCHEMA.to_string(),
+        project: project_value,
+        server,
+        components,
+        omitted_secrets,
+    })
+}
+
+/// Recreate the server, components, and project from a bundle.
+///
+/// Accepts the bare bundle or the full `homeboy project export` JSON envelope.
+/// IDs are slugified before creation. Existing IDs are skipped when
+/// `skip_existing` is set and reported as errors otherwise.
+pub fn import(bundle_spec: &str, skip_existing: bool) -> Result<ProjectImportReport> {
+    let raw = config::read_json_spec_to_string(bundle_spec)?;
+    let value: Value = config::from_str(&raw)?;
+    let value = ["/data/bundle", "/bundle"]
+        .iter()
+        .find_map(|pointer| value.pointer(pointer).cloned())
+        .unwrap_or(value);
+    let bundle: ProjectBundle = serde_json::from_value(value)
+        .map_err(|e| Error::validation_invalid_argument("bundle", e.to_string(), None, None))?;
+    if bundle.schema != PROJECT_BUNDLE_SCHEMA {
+        return Err(Error::validation_invalid_argument(
+            "schema",
+            format!(
+                "Unsupported project bundle schema '{}'; expected '{}'",
+                bundle.schema, PROJECT_BUNDLE_SCHEMA
+            ),
+            Some(bundle.schema.clone()),
+            None,
+        ));
+    }
+
+    let server = match bundle.server {
+        Some(value) => {
+            let value = with_slugified_id(value, "server")?;
+            Some(create_bulk::<server::Server>(&value, skip_existing)?)
+        }
+        None => None,
+    };
+
+    let mut components = BatchResult::new();
+    for value in bundle.components {
+        import_component(value, skip_existing, &mut components);
+    }
+
+    let project_value = with_slugified_references(with_slugified_id(bundle.project, "project")?)?;
+    let project_id = project_value
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let project = create_bulk::<Project>(&project_value, skip_existing)?;
+
+    Ok(ProjectImportReport {
+        project_id,
+        server,
+        components,
+        project,
+    })
+}
+
+fn export_project(project: &Project, omitted: &mut Vec<String>) -> Result<Value> {
+    let mut value = entity_value(project, &project.id)?;
+    if let Some(variables) = value
+        .pointer_mut("/api/auth/variables")
+        .and_then(Value::as_object_mut)
+    {
+        for (name, source) in variables.iter_mut() {
+            let Some(source) = source.as_object_mut() else {
+                continue;
+            };
+            if source.remove("value").is_some() {
+                omitted.push(format!("project.api.auth.variables.{}", name));
+            }
+        }
+    }
+    Ok(value)
+}
+
+fn export_server(server: &server::Server, omitted: &mut Vec<String>) -> Result<Value> {
+    let mut value = entity_value(server, &server.id)?;
+    let policy = RedactionPolicy::default();
+    if let Some(env) = value.get_mut("env").and_then(Value::as_object_mut) {
+        let secret_keys: Vec<String> = env
+            .keys()
+            .filter(|key| policy.is_sensitive_key(key))
+            .cloned()
+            .collect();
+        for key in secret_keys {
+            env.remove(&key);
+            omitted.push(format!("server.env.{}", key));
+        }
+    }
+    Ok(value)
+}
+
+fn import_component(value: Value, skip_existing: bool, summary: &mut BatchResult) {
+    let value = match with_slugified_id(value, "component") {
+        Ok(value) => value,
+        Err(error) => {
+            summary.record_error("unknown".to_string(), error.message);
+            return;
+        }
+    };
+    let id = value
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+
+    if component::exists(&id) {
+        if skip_existing {
+            summary.record_skipped(id);
+        } else {
+            summary.record_error(id.clone(), format!("component '{}' already exists", id));
+        }
+        return;
+    }
+
+    let component: Component = match serde_json::from_value(value) {
+        Ok(component) => component,
+        Err(error) => {
+            summary.record_error(id, format!("Parse error: {}", error));
+            return;
+        }
+    };
+
+    match component::write_standalone_component_config(&component) {
+        Ok(()) => summary.record_created(id),
+        Err(error) => summary.record_error(id, error.message),
+    }
+}
+
+fn create_bulk<T: config::ConfigEntity>(value: &Value, skip_existing: bool) -> Result<BatchResult> {
+    let spec = config::to_json_string(&Value::Array(vec![value.clone()]))?;
+    match config::create::<T>(&spec, skip_existing)? {
+        CreateOutput::Bulk(summary) => Ok(summary),
+        CreateOutput::Single(result) => {
+            let mut summary = BatchResult::new();
+            summary.record_created(result.id);
+            Ok(summary)
+        }
+    }
+}
+
+fn entity_value<T: Serialize>(entity: &T, id: &str) -> Result<Value> {
+    let mut value = serde_json::to_value(entity)
+        .map_err(|e| Error::internal_json(e.to_string(), Some("serialize bundle entity".into())))?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert("id".to_string(), Value::String(id.to_string()));
+    }
+    Ok(value)
+}
+
+fn with_slugified_id(mut value: Value, entity: &str) -> Result<Value> {
+    let raw_id = value
+        .get("id")
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            Error::validation_invalid_argument(
+                format!("{}.id", entity),
+                format!("Bundle {} is missing an id", entity),
+                None,
+                None,
+            )
+        })?
+        .to_string();
+    let id = identifier::slugify_id(&raw_id, "id")?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert("id".to_string(), Value::String(id));
+    }
+    Ok(value)
+}
+
+/// Apply the same slug mapping used for imported entity IDs to the project's
+/// server and component references, so they keep pointing at what was created.
+fn with_slugified_references(mut value: Value) -> Result<Value> {
+    let Some(object) = value.as_object_mut() else {
+        return Ok(value);
+    };
+
+    if let Some(server_id) = object.get_mut("server_id") {
+        if let Some(raw) = server_id.as_str() {
+            *server_id = Value::String(identifier::slugify_id(raw, "server_id")?);
+        }
+    }
+
+    if let Some(attachments) = object.get_mut("components").and_then(Value::as_array_mut) {
+        for attachment in attachments {
+            if let Some(id) = attachment.get_mut("id") {
+                if let Some(raw) = id.as_str() {
+                    *id = Value::String(identifier::slugify_id(raw, "components.id")?);
+                }
+            }
+        }
+    }
+
+    if let Some(overrides) = object
+        .get_mut("component_overrides")
+        .and_then(Value::as_object_mut)
+    {
+        let entries = std::mem::take(overrides);
+        for (raw, entry) in entries {
+            overrides.insert(identifier::slugify_id(&raw, "component_overrides")?, entry);
+        }
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::{self, ApiConfig, AuthConfig, VariableSource};
+    use crate::test_support::with_isolated_home;
+    use std::collections::HashMap;
+
+    fn sample_server() -> server::Server {
+        server::Server {
+            id: "prod-box".to_string(),
+            aliases: Vec::new(),
+            host: "prod.example.test".to_string(),
+            user: "deploy".to_string(),
+            port: 22,
+            identity_file: Some("~/.ssh/prod".to_string()),
+            kind: None,
+            auth: None,
+            env: HashMap::from([
+                ("PATH".to_string(), "/opt/bin:$PATH".to_string()),
+                ("API_TOKEN".to_string(), "abc123".to_string()),
+            ]),
+            runner: None,
+            remote_temp_dir: None,
+        }
+    }
+
+    fn sample_project() -> Project {
+        Project {
+            id: "site".to_string(),
+            domain: Some("site.example.test".to_string()),
+            server_id: Some("prod-box".to_string()),
+            api: ApiConfig {
+                enabled: true,
+                base_url: "https://site.example.test/wp-json".to_string(),
+                proxy_url: None,
+                auth: Some(AuthConfig {
+                    header: "Authorization: Bearer {{token}}".to_string(),
+                    variables: HashMap::from([(
+                        "token".to_string(),
+                        VariableSource {
+                            source: "config".to_string(),
+                            value: Some("hunter2".to_string()),
+                            env_var: None,
+                        },
+                    )]),
+                    login: None,
+                    refresh: None,
+                }),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn export_references_secrets_instead_of_embedding_them() {
+        with_isolated_home(|_| {
+            server::save(&sample_server()).expect("save server");
+            project::save(&sample_project()).expect("save project");
+
+            let bundle = export("site").expect("export bundle");
+            let text = serde_json::to_string(&bundle).expect("serialize bundle");
+
+            assert_eq!(bundle.project["id"], "site");
+            assert_eq!(
+                bundle.server.as_ref().unwrap()["identity_file"],
+                "~/.ssh/prod"
+            );
+            assert_eq!(
+                bundle.server.as_ref().unwrap()["env"]["PATH"],
+                "/opt/bin:$PATH"
+            );
+            assert!(!text.contains("abc123"));
+            assert!(!text.contains("hunter2"));
+            assert!(bundle
+                .omitted_secrets
+                .contains(&"server.env.API_TOKEN".to_string()));
+            assert!(bundle
+                .omitted_secrets
+                .contains(&"project.api.auth.variables.token".to_string()));
+        });
+    }
+
+    #[test]
+    fn import_recreates_exported_project_and_server() {
+        let bundle = with_isolated_home(|_| {
+            server::save(&sample_server()).expect("save server");
+            project::save(&sample_project()).expect("save project");
+            serde_json::to_string(&export("site").expect("export")).expect("serialize")
+        });
+
+        with_isolated_home(|_| {
+            let report = import(&bundle, false).expect("import bundle");
+
+            assert_eq!(report.exit_code(), 0);
+            assert_eq!(report.project_id, "site");
+            assert_eq!(report.server.as_ref().unwrap().created, 1);
+            assert_eq!(report.project.created, 1);
+            assert_eq!(
+                project::load("site").unwrap().server_id.as_deref(),
+                Some("prod-box")
+            );
+            assert_eq!(server::load("prod-box").unwrap().host, "prod.example.test");
+        });
+    }
+
+    #[test]
+    fn import_skips_or_errors_on_existing_ids() {
+        with_isolated_home(|_| {
+            server::save(&sample_server()).expect("save server");
+            project::save(&sample_project()).expect("save project");
+            let bundle = serde_json::to_string(&export("site").unwrap()).unwrap();
+
+            let skipped = import(&bundle, true).expect("import with skip");
+            assert_eq!(skipped.exit_code(), 0);
+            assert_eq!(skipped.project.skipped, 1);
+            assert_eq!(skipped.server.as_ref().unwrap().skipped, 1);
+
+            let rejected = import(&bundle, false).expect("import without skip");
+            assert_eq!(rejected.exit_code(), 1);
+            assert_eq!(rejected.project.errors, 1);
+        });
+    }
+
+    #[test]
+    fn import_slugifies_bundle_ids() {
+        with_isolated_home(|_| {
+            let bundle = serde_json::json!({
+                "schema": PROJECT_BUNDLE_SCHEMA,
+                "project": { "id": "My Site", "domain": "my.example.test" }
+            });
+
+            let report = import(&bundle.to_string(), false).expect("import bundle");
+
+            assert_eq!(report.project_id, "my-site");
+            assert!(project::exists("my-site"));
+        });
+    }
+
+    #[test]
+    fn import_slugifies_server_and_component_references() {
+        with_isolated_home(|_| {
+            let bundle = serde_json::json!({
+                "schema": PROJECT_BUNDLE_SCHEMA,
+                "server": {
+                    "id": "Prod Box",
+                    "host": "prod.example.test",
+                    "user": "deploy"
+                },
+                "project": {
+                    "id": "My Site",
+                    "server_id": "Prod Box",
+                    "components": [{ "id": "My Plugin", "local_path": "/tmp/my-plugin" }],
+                    "component_overrides": { "My Plugin": {} }
+                }
+            });
+
+            let report = import(&bundle.to_string(), false).expect("import bundle");
+            assert_eq!(report.exit_code(), 0);
+
+            let project = project::load("my-site").expect("load project");
+            assert_eq!(project.server_id.as_deref(), Some("prod-box"));
+            assert!(server::exists("prod-box"));
+            assert_eq!(project.components[0].id, "my-plugin");
+            assert!(project.component_overrides.contains_key("my-plugin"));
+        });
+    }
+
+    #[test]
+    fn import_accepts_export_envelope() {
+        with_isolated_home(|_| {
+            let envelope = serde_json::json!({
+                "success": true,
+                "data": {
+                    "command": "project.export",
+                    "bundle": {
+                        "schema": PROJECT_BUNDLE_SCHEMA,
+                        "project": { "id": "wrapped" }
+                    }
+                }
+            });
+
+            let report = import(&envelope.to_string(), false).expect("import envelope");
+
+            assert_eq!(report.project_id, "wrapped");
+            assert!(report.server.is_none());
+        });
+    }
+
+    #[test]
+    fn import_rejects_unknown_schema() {
+        with_isolated_home(|_| {
+            let bundle = serde_json::json!({ "schema": "other/v9", "project": { "id": "x" } });
+            let err = import(&bundle.to_string(), false).expect_err("schema mismatch");
+            assert!(err.message.contains("Unsupported project bundle schema"));
+        });
+    }
+}
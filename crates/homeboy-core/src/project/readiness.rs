@@ -24,12 +24,7 @@ pub fn calculate_deploy_readiness(project: &Project) -> (bool, Vec<String>) {
         _ => {}
     }
 
-    if project
-        .base_path
-        .as_ref()
-        .map(|p| p.is_empty())
-        .unwrap_or(true)
-    {
+    if crate::context::resolve_base_path(project, false).is_err() {
         blockers.push(format!(
             "Missing base_path - set with: homeboy project set {} --json '{{\"base_path\": \"/path/to/webroot\"}}'",
             project.id
@@ -10,6 +10,22 @@ pub struct ProjectListItem {
     pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
+    /// Linked extension IDs, sorted; the `type` list query key.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+}
+
+/// Keys accepted by `project list --sort/--filter`.
+pub const LIST_QUERY_KEYS: &[&str] = &["name", "domain", "type"];
+
+/// Values of a list query key for one project list item.
+pub fn list_query_values(item: &ProjectListItem, key: &str) -> Vec<String> {
+    match key {
+        "name" => vec![item.id.clone()],
+        "domain" => item.domain.iter().cloned().collect(),
+        "type" => item.extensions.clone(),
+        _ => Vec::new(),
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -76,26 +92,52 @@ pub struct ProjectReportExtra {
     pub component_versions: Option<Vec<ProjectComponentVersion>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path_resolution: Option<ProjectPathResolutionReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<crate::project::ProjectBundle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imported: Option<crate::project::ProjectImportReport>,
 }
 
 pub type ProjectReportOutput = EntityCrudOutput<Project, ProjectReportExtra>;
 
-pub fn list_report() -> Result<ProjectListReport> {
+/// Build the project list, shaped by optional `--sort` / `--filter` options.
+pub fn list_report(sort: Option<&str>, filters: &[String]) -> Result<ProjectListReport> {
     let projects = list()?;
+    let configured = projects.len();
 
-    let items: Vec<ProjectListItem> = projects
+    let mut items: Vec<ProjectListItem> = projects
         .into_iter()
-        .map(|p| ProjectListItem {
-            id: p.id,
-            domain: p.domain,
+        .map(|p| {
+            let mut extensions: Vec<String> = p
+                .extensions
+                .into_iter()
+                .flat_map(|e| e.into_keys())
+                .collect();
+            extensions.sort();
+            ProjectListItem {
+                id: p.id,
+                domain: p.domain,
+                extensions,
+            }
         })
         .collect();
-
-    let hint = if items.is_empty() {
+    crate::list_query::apply_list_query(
+        &mut items,
+        sort,
+        filters,
+        LIST_QUERY_KEYS,
+        list_query_values,
+    )?;
+
+    let hint = if configured == 0 {
         Some(
             "No projects configured. Run 'homeboy status --full' to see project context"
                 .to_string(),
         )
+    } else if items.is_empty() {
+        Some(format!(
+            "No projects match the filter ({configured} configured)"
+        ))
     } else {
         None
     };
@@ -331,6 +373,49 @@ pub fn build_path_resolution_output(report: ProjectPathResolutionReport) -> Proj
     }
 }
 
+pub fn build_export_output(
+    project_id: &str,
+    bundle: crate::project::ProjectBundle,
+) -> ProjectReportOutput {
+    let hint = if bundle.omitted_secrets.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "Secrets were not exported; configure them after import: {}",
+            bundle.omitted_secrets.join(", ")
+        ))
+    };
+    ProjectReportOutput {
+        command: "project.export".to_string(),
+        id: Some(project_id.to_string()),
+        hint,
+        extra: ProjectReportExtra {
+            bundle: Some(bundle),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+pub fn build_import_output(
+    report: crate::project::ProjectImportReport,
+) -> (ProjectReportOutput, i32) {
+    let exit_code = report.exit_code();
+    (
+        ProjectReportOutput {
+            command: "project.import".to_string(),
+            id: Some(report.project_id.clone()),
+            entity: load(&report.project_id).ok(),
+            extra: ProjectReportExtra {
+                imported: Some(report),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        exit_code,
+    )
+}
+
 pub fn build_init_output(project_id: &str, dir: &std::path::Path) -> ProjectReportOutput {
     ProjectReportOutput {
         command: "project.init".to_string(),
@@ -20,6 +20,8 @@ pub struct ApiOutput {
 /// ```json
 /// {"projectId": "my-project", "method": "GET", "endpoint": "/wp/v2/posts", "body": null}
 /// ```
+///
+/// Set `"debug": true` to log the redacted request and response to stderr.
 pub fn run(input: &str) -> Result<(ApiOutput, i32)> {
     let parsed: ApiInput = serde_json::from_str(input).map_err(|e| {
         Error::validation_invalid_json(
@@ -30,7 +32,7 @@ pub fn run(input: &str) -> Result<(ApiOutput, i32)> {
     })?;
 
     let proj = project::load(&parsed.project_id)?;
-    let client = ApiClient::new(&parsed.project_id, &proj.api)?;
+    let client = ApiClient::new(&parsed.project_id, &proj.api)?.with_debug(parsed.debug);
 
     let body = parsed
         .body
@@ -88,6 +90,8 @@ struct ApiInput {
     body: Option<Value>,
     #[serde(default, rename = "bodyFormat")]
     body_format: BodyFormat,
+    #[serde(default)]
+    debug: bool,
 }
 
 impl<'de> serde::Deserialize<'de> for BodyFormat {
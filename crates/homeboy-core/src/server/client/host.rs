@@ -89,10 +89,32 @@ fn successful_command_stdout(output: std::process::Output) -> Option<String> {
 
 /// Check if an SSH failure is a transient connection error worth retrying.
 pub fn is_transient_ssh_error(output: &CommandOutput) -> bool {
-    let stderr = output.stderr.to_lowercase();
     // SSH exit code 255 = connection error (not a remote command failure)
-    let is_connection_exit = output.exit_code == 255;
+    output.exit_code == 255 || has_transient_ssh_stderr(output)
+}
 
+/// Stricter check used before re-running a command: ssh must exit 255 *and*
+/// report a failure from before the handshake completed, so the remote
+/// command cannot have started. Drops mid-session ("connection reset",
+/// "broken pipe", "connection closed by remote host") may have run part of
+/// the command and are never retried.
+pub fn is_ssh_connection_failure(output: &CommandOutput) -> bool {
+    let stderr = output.stderr.to_lowercase();
+    let pre_handshake_patterns = [
+        "connection refused",
+        "connection timed out",
+        "no route to host",
+        "network is unreachable",
+        "could not resolve",
+        "kex_exchange_identification",
+        "ssh_exchange_identification",
+    ];
+
+    output.exit_code == 255 && pre_handshake_patterns.iter().any(|p| stderr.contains(p))
+}
+
+fn has_transient_ssh_stderr(output: &CommandOutput) -> bool {
+    let stderr = output.stderr.to_lowercase();
     let transient_patterns = [
         "connection refused",
         "connection reset",
@@ -106,5 +128,5 @@ pub fn is_transient_ssh_error(output: &CommandOutput) -> bool {
         "connection closed by remote host",
     ];
 
-    is_connection_exit || transient_patterns.iter().any(|p| stderr.contains(p))
+    transient_patterns.iter().any(|p| stderr.contains(p))
 }
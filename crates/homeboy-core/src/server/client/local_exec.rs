@@ -1,4 +1,5 @@
-use std::io::{Read, Write};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
 use std::process::{Command, Stdio};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -130,6 +131,7 @@ fn run_local_command(
                 exit_code: -1,
                 timed_out: false,
                 child_resource: None,
+                combined: None,
             };
         }
     };
@@ -203,6 +205,7 @@ fn run_local_command(
             ),
             timed_out,
             child_resource: Some(monitor.finish()),
+            combined: None,
         },
         Err(e) => CommandOutput {
             stdout,
@@ -224,6 +227,7 @@ fn run_local_command(
             ),
             timed_out,
             child_resource: Some(monitor.finish()),
+            combined: None,
         },
     };
     if let Some(cleanup_guard) = cleanup_guard.take() {
@@ -305,6 +309,111 @@ pub fn execute_local_command_interactive(
     }
 }
 
+/// Run a local command with inherited stdin/stderr, handing each stdout line
+/// to `on_line` as it arrives. Used where output is rewritten on the fly (e.g.
+/// colorized log follow) instead of passed through verbatim.
+pub fn execute_local_command_lines(command: &str, on_line: &mut dyn FnMut(&str)) -> i32 {
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    };
+
+    #[cfg(not(windows))]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+
+    stream_stdout_lines(&mut cmd, on_line)
+}
+
+/// Spawn `cmd` with piped stdout and feed each line (without its `\r\n` or
+/// `\n` terminator) to `on_line` until the child exits.
+pub(crate) fn stream_stdout_lines(cmd: &mut Command, on_line: &mut dyn FnMut(&str)) -> i32 {
+    let child = cmd
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(_) => return -1,
+    };
+
+    if let Some(stdout) = child.stdout.take() {
+        let mut reader = BufReader::new(stdout);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buf);
+                    on_line(line.trim_end_matches(['\n', '\r']));
+                }
+            }
+        }
+    }
+
+    match child.wait() {
+        Ok(s) => s.code().unwrap_or(-1),
+        Err(_) => -1,
+    }
+}
+
+/// Run a local command with stdout written straight into `stdout_file`, so
+/// large output (e.g. a database dump) is never buffered in memory.
+pub fn execute_local_command_to_file(command: &str, stdout_file: File) -> CommandOutput {
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    };
+
+    #[cfg(not(windows))]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+
+    run_to_file(&mut cmd, stdout_file)
+}
+
+/// Spawn `cmd` with stdout redirected into `stdout_file` and stderr captured.
+/// `CommandOutput::stdout` is always empty; the bytes live in the file.
+pub(crate) fn run_to_file(cmd: &mut Command, stdout_file: File) -> CommandOutput {
+    let output = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(stdout_file))
+        .stderr(Stdio::piped())
+        .output();
+    match output {
+        Ok(out) => CommandOutput {
+            stdout: String::new(),
+            stderr: String::from_utf8_lossy(&out.stderr).to_string(),
+            success: out.status.success(),
+            exit_code: out.status.code().unwrap_or(-1),
+            timed_out: false,
+            child_resource: None,
+            combined: None,
+        },
+        Err(err) => CommandOutput {
+            stdout: String::new(),
+            stderr: format!("Failed to spawn command: {}", err),
+            success: false,
+            exit_code: -1,
+            timed_out: false,
+            child_resource: None,
+            combined: None,
+        },
+    }
+}
+
 /// Execute local command with stdout/stderr tee'd to terminal *and* captured.
 ///
 /// Originally this function just inherited stdout/stderr and returned empty
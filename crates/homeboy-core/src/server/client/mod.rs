@@ -13,16 +13,24 @@ mod ssh_client;
 mod tests;
 
 pub use delegated::DELEGATED_RUN_STATUS_FILE_ENV;
+pub use host::is_ssh_connection_failure;
 pub use host::is_transient_ssh_error;
 pub use local_exec::{
     execute_local_command, execute_local_command_in_dir, execute_local_command_in_dir_with_timeout,
-    execute_local_command_interactive, execute_local_command_passthrough,
+    execute_local_command_interactive, execute_local_command_lines,
+    execute_local_command_passthrough, execute_local_command_to_file,
 };
 pub use local_exec::{
     execute_local_command_passthrough_with_timeout, execute_local_command_stderr_passthrough,
     execute_local_command_stderr_passthrough_with_timeout,
 };
 
+/// Default seconds allowed for the SSH handshake before giving up on a host.
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u32 = 10;
+/// Default extra attempts after a connection-level SSH failure.
+pub const DEFAULT_SSH_RETRIES: u32 = 2;
+
+#[derive(Clone)]
 pub struct SshClient {
     pub host: String,
     pub user: String,
@@ -32,6 +40,17 @@ pub struct SshClient {
     /// When true, all commands run locally instead of over SSH.
     /// Set automatically when the server host is localhost/127.0.0.1/::1.
     pub is_local: bool,
+    /// When true and no managed session is configured, SSH invocations share
+    /// a short-lived ControlMaster socket so consecutive commands skip the
+    /// handshake. Enable with [`SshClient::with_multiplexing`].
+    pub multiplex: bool,
+    /// Seconds allowed for the SSH handshake (`-o ConnectTimeout=`), so a dead
+    /// host fails fast instead of hanging. `0` leaves ssh's own default.
+    pub connect_timeout: u32,
+    /// Extra attempts, with exponential backoff, after a connection-level
+    /// failure (ssh exit 255 plus a connection error). A command that ran and
+    /// exited non-zero is never retried.
+    pub retries: u32,
     /// Environment variables to inject before remote commands.
     /// Values are passed through the shell, so `$PATH`-style expansion works.
     pub env: HashMap<String, String>,
@@ -44,4 +63,41 @@ pub struct CommandOutput {
     pub exit_code: i32,
     pub timed_out: bool,
     pub child_resource: Option<ExtensionChildResourceSummary>,
+    /// Interleaved stdout+stderr in the order it was written. Only set for
+    /// [`OutputCapture::Combined`] runs.
+    pub combined: Option<String>,
+}
+
+/// How a command's output streams are captured.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputCapture {
+    /// stdout and stderr captured separately (the default).
+    #[default]
+    Separate,
+    /// The command's stderr is redirected into stdout (`2>&1`) so the order of
+    /// interleaved output survives; the result lands in `combined`.
+    Combined,
+}
+
+impl OutputCapture {
+    /// Wrap `command` so its output is captured according to this mode.
+    pub(crate) fn wrap(self, command: &str) -> String {
+        match self {
+            OutputCapture::Separate => command.to_string(),
+            // Newline before `}` so trailing comments or `&` in the command
+            // cannot swallow the group terminator.
+            OutputCapture::Combined => format!("{{ {command}\n}} 2>&1"),
+        }
+    }
+
+    /// Finish an output captured with [`Self::wrap`]. For combined runs
+    /// `stdout` holds the merged stream (it is the command's stdout after the
+    /// redirect) and is mirrored into `combined`; `stderr` keeps only
+    /// transport diagnostics such as SSH connection errors.
+    pub(crate) fn finish(self, mut output: CommandOutput) -> CommandOutput {
+        if self == OutputCapture::Combined {
+            output.combined = Some(output.stdout.clone());
+        }
+        output
+    }
 }
@@ -15,13 +15,16 @@ use super::super::ssh_args::{client_ssh_args, SshArgOptions, SshPortFlag};
 use super::super::{
     ManagedSshSession, ManagedSshSessionOutput, Server, ServerAuthMode, ServerSessionConfig,
 };
-use super::host::{is_local_host, is_transient_ssh_error};
+use super::host::{is_local_host, is_ssh_connection_failure};
 use super::local_exec::{
     execute_local_command, execute_local_command_in_dir_with_timeout,
-    execute_local_command_interactive, execute_local_command_with_stdin,
-    execute_local_command_with_stdin_and_timeout,
+    execute_local_command_interactive, execute_local_command_lines, execute_local_command_to_file,
+    execute_local_command_with_stdin, execute_local_command_with_stdin_and_timeout, run_to_file,
+    stream_stdout_lines,
+};
+use super::{
+    CommandOutput, OutputCapture, SshClient, DEFAULT_CONNECT_TIMEOUT_SECS, DEFAULT_SSH_RETRIES,
 };
-use super::{CommandOutput, SshClient};
 
 /// Sentinel terminating the secret-env block streamed over the SSH channel's
 /// stdin. Chosen to never collide with an env var name or a `NAME=VALUE` line.
@@ -33,7 +36,8 @@ pub(crate) const SECRET_ENV_STDIN_SENTINEL: &str = "__HOMEBOY_SECRET_ENV_END__";
 enum SshStdin<'a> {
     /// No stdin payload — the remote command inherits an empty stdin.
     None,
-    /// Stream a local file as the command's stdin (used by `upload_file`).
+    /// Stream a local file as the command's stdin (used by `upload_file` and
+    /// `execute_with_stdin_file`).
     File(&'a str),
     /// Stream in-memory bytes as the command's stdin (used to deliver the
     /// secret-env block without placing secrets in the command argv).
@@ -81,6 +85,11 @@ pub(crate) fn build_secret_env_stdin_block(secret_env: &BTreeMap<String, String>
     block.into_bytes()
 }
 
+/// Delay before retry `attempt` (1-based): 2s, 4s, 8s, ... capped at 30s.
+pub(crate) fn ssh_retry_backoff(attempt: u32) -> Duration {
+    Duration::from_secs(2u64.saturating_pow(attempt).min(30))
+}
+
 /// Map a finished `ssh` invocation's captured output into a [`CommandOutput`].
 fn map_ssh_output(output: std::io::Result<std::process::Output>) -> CommandOutput {
     match output {
@@ -91,6 +100,7 @@ fn map_ssh_output(output: std::io::Result<std::process::Output>) -> CommandOutpu
             exit_code: out.status.code().unwrap_or(-1),
             timed_out: false,
             child_resource: None,
+            combined: None,
         },
         Err(err) => CommandOutput {
             stdout: String::new(),
@@ -99,6 +109,7 @@ fn map_ssh_output(output: std::io::Result<std::process::Output>) -> CommandOutpu
             exit_code: -1,
             timed_out: false,
             child_resource: None,
+            combined: None,
         },
     }
 }
@@ -142,10 +153,35 @@ impl SshClient {
             identity_file,
             auth,
             is_local,
+            multiplex: false,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT_SECS,
+            retries: DEFAULT_SSH_RETRIES,
             env: server.env.clone(),
         })
     }
 
+    /// Reuse one SSH connection across this client's commands via an
+    /// auto-started ControlMaster (`ControlPersist=60s`). Managed sessions
+    /// already multiplex, and local clients never open SSH, so both are
+    /// returned unchanged. If the control socket directory cannot be created
+    /// the client keeps opening a fresh connection per command.
+    pub fn with_multiplexing(mut self) -> Self {
+        if self.is_local || self.auth.is_some() {
+            return self;
+        }
+        let session = ManagedSshSession::multiplexed();
+        self.multiplex = ensure_control_path_parent(&session.control_path).is_ok();
+        self
+    }
+
+    /// Control socket settings for SSH invocations: the managed session when
+    /// configured, otherwise the multiplexing socket when enabled.
+    pub(crate) fn control_session(&self) -> Option<ManagedSshSession> {
+        self.auth
+            .clone()
+            .or_else(|| self.multiplex.then(ManagedSshSession::multiplexed))
+    }
+
     /// Apply bounded execution only to a sequence of short diagnostic probes.
     /// Ordinary runner commands retain their existing execution semantics.
     pub fn scoped_probe_limits(
@@ -377,6 +413,13 @@ impl SshClient {
         self.execute_with_stdin(&effective, SshStdin::None)
     }
 
+    /// Execute `command` with an explicit output capture mode. `Separate` is
+    /// identical to [`execute`]; `Combined` preserves the interleaving of
+    /// stdout and stderr in `CommandOutput::combined`.
+    pub fn execute_with_capture(&self, command: &str, capture: OutputCapture) -> CommandOutput {
+        capture.finish(self.execute(&capture.wrap(command)))
+    }
+
     /// Execute a short, read-only probe with a hard wall-clock deadline.
     ///
     /// Status/version checks must return partial diagnostics instead of allowing
@@ -512,6 +555,7 @@ impl ProbeLimits {
                 exit_code: 124,
                 timed_out: true,
                 child_resource: None,
+                combined: None,
             };
         }
         let output = client.execute_with_timeout(command, timeout);
@@ -573,6 +617,7 @@ mod bounded_probe_tests {
                 auth: None,
                 env: Default::default(),
                 runner: None,
+                remote_temp_dir: None,
             },
             "local",
         )
@@ -665,6 +710,7 @@ where
                 exit_code: -1,
                 timed_out: false,
                 child_resource: None,
+                combined: None,
             }
         }
     };
@@ -742,6 +788,7 @@ where
         },
         timed_out,
         child_resource: None,
+        combined: None,
     }
 }
 
@@ -807,6 +854,13 @@ impl SshClient {
         self.execute_with_stdin(&remote_command, SshStdin::File(local_path))
     }
 
+    /// Run `command` with a local file streamed as its stdin (e.g. a SQL dump
+    /// piped into an import tool), without staging the file remotely.
+    pub fn execute_with_stdin_file(&self, command: &str, local_path: &str) -> CommandOutput {
+        let effective = self.prepend_env(command);
+        self.execute_with_stdin(&effective, SshStdin::File(local_path))
+    }
+
     pub fn download_file(&self, remote_path: &str, local_path: &str) -> CommandOutput {
         if self.is_local {
             return match std::fs::copy(remote_path, local_path) {
@@ -817,6 +871,7 @@ impl SshClient {
                     exit_code: 0,
                     timed_out: false,
                     child_resource: None,
+                    combined: None,
                 },
                 Err(err) => CommandOutput {
                     stdout: String::new(),
@@ -828,6 +883,7 @@ impl SshClient {
                     exit_code: -1,
                     timed_out: false,
                     child_resource: None,
+                    combined: None,
                 },
             };
         }
@@ -842,75 +898,51 @@ impl SshClient {
                     exit_code: -1,
                     timed_out: false,
                     child_resource: None,
+                    combined: None,
                 };
             }
         };
-        let remote_command = self.prepend_env(&format!("cat {}", shell::quote_path(remote_path)));
-        let args = self.build_ssh_args(Some(&remote_command), false);
-        let output = Command::new("ssh")
-            .args(&args)
-            .stdout(Stdio::from(local_file))
-            .stderr(Stdio::piped())
-            .output();
-        match output {
-            Ok(out) => CommandOutput {
-                stdout: String::new(),
-                stderr: String::from_utf8_lossy(&out.stderr).to_string(),
-                success: out.status.success(),
-                exit_code: out.status.code().unwrap_or(-1),
-                timed_out: false,
-                child_resource: None,
-            },
-            Err(err) => CommandOutput {
-                stdout: String::new(),
-                stderr: format!("SSH download error: {}", err),
-                success: false,
-                exit_code: -1,
-                timed_out: false,
-                child_resource: None,
-            },
-        }
+        self.execute_to_file(
+            &format!("cat {}", shell::quote_path(remote_path)),
+            local_file,
+        )
     }
 
-    fn execute_with_stdin(&self, command: &str, stdin: SshStdin<'_>) -> CommandOutput {
-        self.execute_with_retry(command, stdin, 3)
-    }
+    /// Run `command` with its stdout streamed into `stdout_file` instead of
+    /// captured, for output too large to hold in memory (dumps, downloads).
+    pub fn execute_to_file(&self, command: &str, stdout_file: std::fs::File) -> CommandOutput {
+        let effective = self.prepend_env(command);
 
-    fn execute_with_retry(
-        &self,
-        command: &str,
-        stdin: SshStdin<'_>,
-        max_attempts: u32,
-    ) -> CommandOutput {
-        let backoff_secs = [0, 2, 5]; // delays before retry 1, 2, 3
+        if self.is_local {
+            return execute_local_command_to_file(&effective, stdout_file);
+        }
+
+        let args = self.build_ssh_args(Some(&effective), false);
+        run_to_file(Command::new("ssh").args(&args), stdout_file)
+    }
 
-        for attempt in 0..max_attempts {
+    fn execute_with_stdin(&self, command: &str, stdin: SshStdin<'_>) -> CommandOutput {
+        let max_attempts = if self.is_local { 1 } else { self.retries + 1 };
+        let mut attempt = 1;
+        loop {
             let result = self.execute_once(command, stdin);
 
-            // Only retry on transient connection errors, not command failures
-            if result.success || attempt + 1 >= max_attempts || !is_transient_ssh_error(&result) {
+            // Only retry when ssh never ran the command; re-running a command
+            // that executed and failed could repeat a destructive change.
+            if result.success || attempt >= max_attempts || !is_ssh_connection_failure(&result) {
                 return result;
             }
 
-            let delay = backoff_secs.get(attempt as usize + 1).copied().unwrap_or(5);
+            let delay = ssh_retry_backoff(attempt);
             log_status!(
                 "ssh",
                 "Connection failed (attempt {}/{}), retrying in {}s...",
-                attempt + 1,
+                attempt,
                 max_attempts,
-                delay
+                delay.as_secs()
             );
-            std::thread::sleep(std::time::Duration::from_secs(delay));
-        }
-
-        // Unreachable, but satisfy the compiler
-        CommandOutput {
-            stdout: String::new(),
-            stderr: "SSH retry exhausted".to_string(),
-            success: false,
-            exit_code: -1,
-            timed_out: false,
-            child_resource: None,
+            std::thread::sleep(delay);
+            attempt += 1;
         }
     }
 
@@ -947,6 +979,7 @@ impl SshClient {
                     exit_code: -1,
                     timed_out: false,
                     child_resource: None,
+                    combined: None,
                 },
             },
             SshStdin::Inline(bytes) => self.run_ssh_with_inline_stdin(cmd, bytes),
@@ -974,6 +1007,7 @@ impl SshClient {
                     exit_code: -1,
                     timed_out: false,
                     child_resource: None,
+                    combined: None,
                 };
             }
         };
@@ -1013,4 +1047,36 @@ impl SshClient {
             Err(_) => -1,
         }
     }
+
+    /// Run `command` with ssh allowed to prompt on the terminal (password or
+    /// key passphrase) while still capturing its output. Used for onboarding
+    /// steps that run before key auth works, such as `server copy-id`.
+    pub fn execute_with_terminal_prompt(&self, command: &str) -> CommandOutput {
+        let effective = self.prepend_env(command);
+        if self.is_local {
+            return execute_local_command(&effective);
+        }
+
+        let args = self.build_ssh_args(Some(&effective), true);
+        map_ssh_output(
+            Command::new("ssh")
+                .args(&args)
+                .stdin(Stdio::inherit())
+                .output(),
+        )
+    }
+
+    /// Like [`execute_interactive`](Self::execute_interactive), but stdout is
+    /// piped and handed to `on_line` one line at a time. A TTY is still
+    /// requested so Ctrl-C reaches the remote command.
+    pub fn execute_lines(&self, command: &str, on_line: &mut dyn FnMut(&str)) -> i32 {
+        let effective = self.prepend_env(command);
+
+        if self.is_local {
+            return execute_local_command_lines(&effective, on_line);
+        }
+
+        let args = self.build_ssh_args(Some(&effective), true);
+        stream_stdout_lines(Command::new("ssh").args(&args), on_line)
+    }
 }
@@ -5,17 +5,17 @@ use super::super::{
     ManagedSshSession, ManagedSshSessionOutput, Server, ServerAuthMode, ServerSessionConfig,
 };
 use super::delegated::{DELEGATED_RUN_POLL_MS_ENV, DELEGATED_RUN_STATUS_FILE_ENV};
-use super::host::{get_local_ips, is_local_host};
+use super::host::{get_local_ips, is_local_host, is_ssh_connection_failure};
 use super::local_exec::{
     execute_local_command_in_dir, execute_local_command_interactive,
     execute_local_command_passthrough, execute_local_command_stderr_passthrough,
 };
 use super::ssh_client::{
     build_secret_env_stdin_block, execute_command_with_stdin_timeout,
-    execute_command_with_writer_factory, wrap_command_with_secret_env_read_loop,
+    execute_command_with_writer_factory, ssh_retry_backoff, wrap_command_with_secret_env_read_loop,
     SECRET_ENV_STDIN_SENTINEL,
 };
-use super::{CommandOutput, SshClient};
+use super::{CommandOutput, OutputCapture, SshClient};
 
 #[test]
 fn secret_env_values_stream_over_stdin_not_command_argv() {
@@ -80,6 +80,9 @@ fn secret_env_execution_timeout_terminates_the_blocking_process_group() {
         identity_file: None,
         auth: None,
         is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
         env: HashMap::new(),
     };
     let secret_env = std::collections::BTreeMap::from([(
@@ -306,6 +309,9 @@ fn test_upload_file() {
         identity_file: None,
         auth: None,
         is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
         env: HashMap::new(),
     };
 
@@ -332,6 +338,9 @@ fn test_download_file_copies_large_local_file_without_stdout_payload() {
         identity_file: None,
         auth: None,
         is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
         env: HashMap::new(),
     };
 
@@ -345,6 +354,35 @@ fn test_download_file_copies_large_local_file_without_stdout_payload() {
     );
 }
 
+#[test]
+fn execute_to_file_streams_stdout_into_the_file() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let target = dir.path().join("dump.sql");
+    let client = SshClient {
+        host: "localhost".to_string(),
+        user: "tester".to_string(),
+        port: 22,
+        identity_file: None,
+        auth: None,
+        is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
+        env: HashMap::new(),
+    };
+
+    let file = std::fs::File::create(&target).expect("create target");
+    let output = client.execute_to_file("printf 'CREATE TABLE t;\\n'; echo warn >&2", file);
+
+    assert!(output.success, "export failed: {}", output.stderr);
+    assert!(output.stdout.is_empty());
+    assert_eq!(output.stderr.trim(), "warn");
+    assert_eq!(
+        std::fs::read_to_string(target).expect("read target"),
+        "CREATE TABLE t;\n"
+    );
+}
+
 #[test]
 fn managed_session_config_adds_controlmaster_args() {
     let server = Server {
@@ -364,6 +402,7 @@ fn managed_session_config_adds_controlmaster_args() {
         }),
         env: HashMap::new(),
         runner: None,
+        remote_temp_dir: None,
     };
 
     let client = SshClient::from_server(&server, "bastion").expect("client");
@@ -377,6 +416,158 @@ fn managed_session_config_adds_controlmaster_args() {
     assert_eq!(args.last().map(String::as_str), Some("uptime"));
 }
 
+#[test]
+fn multiplexed_client_shares_a_control_socket() {
+    let client = SshClient {
+        host: "slow.example.test".to_string(),
+        user: "deploy".to_string(),
+        port: 22,
+        identity_file: None,
+        auth: None,
+        is_local: false,
+        multiplex: true,
+        connect_timeout: 10,
+        retries: 2,
+        env: HashMap::new(),
+    };
+
+    let args = client.build_ssh_args(Some("uptime"), false);
+    let control_path = ManagedSshSession::multiplexed().control_path;
+
+    assert!(args.contains(&"ControlMaster=auto".to_string()));
+    assert!(args.contains(&format!("ControlPath={control_path}")));
+    assert!(args.contains(&"ControlPersist=60s".to_string()));
+
+    let plain = SshClient {
+        multiplex: false,
+        ..client
+    }
+    .build_ssh_args(Some("uptime"), false);
+    assert!(!plain.contains(&"ControlMaster=auto".to_string()));
+}
+
+fn failed_output(exit_code: i32, stderr: &str) -> CommandOutput {
+    CommandOutput {
+        stdout: String::new(),
+        stderr: stderr.to_string(),
+        success: false,
+        exit_code,
+        timed_out: false,
+        child_resource: None,
+        combined: None,
+    }
+}
+
+#[test]
+fn connection_failure_requires_exit_255_and_connection_error() {
+    assert!(is_ssh_connection_failure(&failed_output(
+        255,
+        "ssh: connect to host dead.example.test port 22: Connection timed out"
+    )));
+    // The remote command ran and failed with connection-like output.
+    assert!(!is_ssh_connection_failure(&failed_output(
+        7,
+        "curl: (7) Failed to connect: Connection refused"
+    )));
+    // The remote command itself exited 255.
+    assert!(!is_ssh_connection_failure(&failed_output(
+        255,
+        "fatal error"
+    )));
+    // Authentication failures will not succeed on retry.
+    assert!(!is_ssh_connection_failure(&failed_output(
+        255,
+        "deploy@host: Permission denied (publickey)."
+    )));
+    assert!(is_ssh_connection_failure(&failed_output(
+        255,
+        "kex_exchange_identification: read: Connection reset by peer"
+    )));
+    // Drops after the handshake may have run part of the command.
+    for stderr in [
+        "client_loop: send disconnect: Broken pipe",
+        "Connection reset by 203.0.113.7 port 22",
+        "Connection closed by remote host",
+    ] {
+        assert!(!is_ssh_connection_failure(&failed_output(255, stderr)));
+    }
+}
+
+#[test]
+fn ssh_retry_backoff_doubles_and_caps() {
+    assert_eq!(ssh_retry_backoff(1), Duration::from_secs(2));
+    assert_eq!(ssh_retry_backoff(2), Duration::from_secs(4));
+    assert_eq!(ssh_retry_backoff(3), Duration::from_secs(8));
+    assert_eq!(ssh_retry_backoff(10), Duration::from_secs(30));
+}
+
+#[test]
+fn connect_timeout_is_rendered_from_the_client() {
+    let mut client = SshClient {
+        host: "dead.example.test".to_string(),
+        user: "deploy".to_string(),
+        port: 22,
+        identity_file: None,
+        auth: None,
+        is_local: false,
+        multiplex: false,
+        connect_timeout: 3,
+        retries: 2,
+        env: HashMap::new(),
+    };
+    assert!(client
+        .build_ssh_args(Some("true"), false)
+        .contains(&"ConnectTimeout=3".to_string()));
+
+    client.connect_timeout = 0;
+    assert!(!client
+        .build_ssh_args(Some("true"), false)
+        .iter()
+        .any(|arg| arg.starts_with("ConnectTimeout=")));
+}
+
+#[test]
+fn failed_commands_are_never_rerun() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let marker = dir.path().join("runs");
+    let client = SshClient {
+        retries: 3,
+        ..local_managed_session_client()
+    };
+
+    let output = client.execute(&format!(
+        "echo run >> '{}'; echo 'Connection refused' >&2; exit 255",
+        marker.display()
+    ));
+
+    assert_eq!(output.exit_code, 255);
+    let runs = std::fs::read_to_string(&marker).expect("marker");
+    assert_eq!(runs.lines().count(), 1);
+}
+
+#[test]
+fn with_multiplexing_leaves_local_and_managed_clients_unchanged() {
+    let local = local_managed_session_client();
+    assert!(!local.with_multiplexing().multiplex);
+
+    let managed = SshClient {
+        host: "bastion.example.test".to_string(),
+        user: "deploy".to_string(),
+        port: 22,
+        identity_file: None,
+        auth: Some(ManagedSshSession {
+            control_path: "/tmp/homeboy-test-control".to_string(),
+            persist: "10m".to_string(),
+        }),
+        is_local: false,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
+        env: HashMap::new(),
+    };
+    assert!(!managed.with_multiplexing().multiplex);
+}
+
 #[test]
 fn managed_session_connect_builds_master_command() {
     let client = SshClient {
@@ -389,6 +580,9 @@ fn managed_session_connect_builds_master_command() {
             persist: "10m".to_string(),
         }),
         is_local: false,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
         env: HashMap::new(),
     };
 
@@ -425,6 +619,7 @@ fn test_from_server() {
         }),
         env: HashMap::new(),
         runner: None,
+        remote_temp_dir: None,
     };
 
     let client = SshClient::from_server(&server, "local").expect("client");
@@ -491,6 +686,7 @@ fn managed_session_connect_reports_per_command_fallback() {
             exit_code: 0,
             timed_out: false,
             child_resource: None,
+            combined: None,
         },
     );
 
@@ -509,6 +705,9 @@ fn test_execute_interactive() {
         identity_file: None,
         auth: None,
         is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
         env: HashMap::new(),
     };
 
@@ -539,6 +738,9 @@ fn local_managed_session_client() -> SshClient {
             persist: "10m".to_string(),
         }),
         is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
         env: HashMap::new(),
     }
 }
@@ -597,3 +799,29 @@ fn pid_is_alive(pid: libc::pid_t) -> bool {
         .map(crate::process::pid_is_running)
         .unwrap_or(false)
 }
+
+#[test]
+fn combined_capture_preserves_stream_order_only_when_requested() {
+    let client = SshClient {
+        host: "localhost".to_string(),
+        user: "tester".to_string(),
+        port: 22,
+        identity_file: None,
+        auth: None,
+        is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
+        env: HashMap::new(),
+    };
+    let command = "echo out; echo err 1>&2; echo out2";
+
+    let combined = client.execute_with_capture(command, OutputCapture::Combined);
+    assert!(combined.success);
+    assert_eq!(combined.combined.as_deref(), Some("out\nerr\nout2\n"));
+
+    let separate = client.execute_with_capture(command, OutputCapture::Separate);
+    assert_eq!(separate.combined, None);
+    assert_eq!(separate.stdout, "out\nout2\n");
+    assert_eq!(separate.stderr, "err\n");
+}
@@ -6,7 +6,7 @@ use super::Server;
 /// Arguments for SSH context resolution
 #[derive(Default)]
 pub struct SshResolveArgs {
-    /// Bare ID (tries project first, then server)
+    /// Bare ID (tries project, then server, then project domain / server host)
     pub id: Option<String>,
     /// Force project resolution
     pub project: Option<String>,
@@ -92,6 +92,23 @@ fn resolve_internal(
         return Ok(("server".to_string(), None, id.clone(), server, None));
     }
 
+    // Last resort: match project domains, then server hosts
+    if let Some(project) = unique_match(id, "domain", project_ids_by_domain(id)?)? {
+        crate::log_status!(
+            "ssh",
+            "Resolved '{}' by domain to project '{}'",
+            id,
+            project
+        );
+        return resolve_project_context(project::load(&project)?);
+    }
+
+    if let Some(server_id) = unique_match(id, "host", server_ids_by_host(id)?)? {
+        crate::log_status!("ssh", "Resolved '{}' by host to server '{}'", id, server_id);
+        let server = super::load(&server_id)?;
+        return Ok(("server".to_string(), None, server_id, server, None));
+    }
+
     Err(Error::validation_invalid_argument(
         "id",
         "No matching project or server",
@@ -100,10 +117,53 @@ fn resolve_internal(
     ))
 }
 
+fn project_ids_by_domain(id: &str) -> Result<Vec<String>> {
+    Ok(project::list()?
+        .into_iter()
+        .filter(|project| {
+            project
+                .domain
+                .as_deref()
+                .is_some_and(|domain| domain.eq_ignore_ascii_case(id))
+        })
+        .map(|project| project.id)
+        .collect())
+}
+
+fn server_ids_by_host(id: &str) -> Result<Vec<String>> {
+    Ok(super::list()?
+        .into_iter()
+        .filter(|server| server.host.eq_ignore_ascii_case(id))
+        .map(|server| server.id)
+        .collect())
+}
+
+fn unique_match(id: &str, field: &str, mut matches: Vec<String>) -> Result<Option<String>> {
+    match matches.len() {
+        0 => Ok(None),
+        1 => Ok(matches.pop()),
+        _ => Err(Error::validation_invalid_argument(
+            "id",
+            format!(
+                "'{}' matches the {} of multiple entries: {}",
+                id,
+                field,
+                matches.join(", ")
+            ),
+            Some(id.to_string()),
+            Some(vec![
+                "Pass the id explicitly with --project or --server".to_string()
+            ]),
+        )),
+    }
+}
+
 fn resolve_project_context(
     project: Project,
 ) -> Result<(String, Option<String>, String, Server, Option<String>)> {
-    let base_path = project.base_path.clone();
+    // A project without a base path still resolves; the shell just skips the
+    // auto-`cd`.
+    let base_path = crate::context::require_project_base_path(&project.id, &project).ok();
     let (server_id, server) = resolve_from_project(&project)?;
     Ok((
         "project".to_string(),
@@ -126,3 +186,109 @@ fn resolve_from_project(project: &Project) -> Result<(String, Server)> {
     let server = super::load(&server_id)?;
     Ok((server_id, server))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server;
+    use crate::test_support::with_isolated_home;
+
+    fn save_server(id: &str, host: &str) {
+        server::save(&Server {
+            id: id.to_string(),
+            aliases: Vec::new(),
+            host: host.to_string(),
+            user: "deploy".to_string(),
+            port: 22,
+            identity_file: None,
+            kind: None,
+            auth: None,
+            env: Default::default(),
+            runner: None,
+            remote_temp_dir: None,
+        })
+        .expect("save server");
+    }
+
+    fn bare(id: &str) -> SshResolveArgs {
+        SshResolveArgs {
+            id: Some(id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resolves_bare_host_case_insensitively() {
+        with_isolated_home(|_| {
+            save_server("prod-box", "prod.example.com");
+
+            let result = resolve_context(&bare("PROD.example.com")).expect("resolve by host");
+
+            assert_eq!(result.resolved_type, "server");
+            assert_eq!(result.server_id, "prod-box");
+        });
+    }
+
+    #[test]
+    fn resolves_bare_domain_to_project() {
+        with_isolated_home(|_| {
+            save_server("prod-box", "10.0.0.5");
+            project::save(&Project {
+                id: "site".to_string(),
+                domain: Some("example.com".to_string()),
+                server_id: Some("prod-box".to_string()),
+                ..Default::default()
+            })
+            .expect("save project");
+
+            let result = resolve_context(&bare("example.com")).expect("resolve by domain");
+
+            assert_eq!(result.resolved_type, "project");
+            assert_eq!(result.project_id.as_deref(), Some("site"));
+            assert_eq!(result.server_id, "prod-box");
+        });
+    }
+
+    #[test]
+    fn blank_project_base_path_resolves_without_base_path() {
+        with_isolated_home(|_| {
+            save_server("prod-box", "10.0.0.5");
+            project::save(&Project {
+                id: "site".to_string(),
+                server_id: Some("prod-box".to_string()),
+                base_path: Some("   ".to_string()),
+                ..Default::default()
+            })
+            .expect("save project");
+
+            let result = resolve_context(&bare("site")).expect("resolve project");
+
+            assert_eq!(result.project_id.as_deref(), Some("site"));
+            assert!(result.base_path.is_none());
+        });
+    }
+
+    #[test]
+    fn ambiguous_host_match_names_candidates() {
+        with_isolated_home(|_| {
+            save_server("box-a", "shared.example.com");
+            save_server("box-b", "shared.example.com");
+
+            let err = resolve_context(&bare("shared.example.com")).expect_err("ambiguous host");
+
+            assert!(err.message.contains("box-a, box-b"));
+        });
+    }
+
+    #[test]
+    fn id_match_takes_precedence_over_host() {
+        with_isolated_home(|_| {
+            save_server("example.com", "10.0.0.1");
+            save_server("other", "example.com");
+
+            let result = resolve_context(&bare("example.com")).expect("resolve by id");
+
+            assert_eq!(result.server_id, "example.com");
+        });
+    }
+}
@@ -0,0 +1,311 @@
+//! Connectivity check for a configured server.
+//!
+//! Runs one trivial remote command and reports whether it came back, how long
+//! the round trip took, and what the remote system looks like. When ssh itself
+//! fails (exit 255), stderr is classified so misconfiguration is obvious.
+
+use serde::Serialize;
+use std::time::Instant;
+
+use super::{load, Server, SshClient};
+use crate::error::Result;
+
+/// First line printed by the probe; proves the command actually ran remotely.
+const PROBE_MARKER: &str = "homeboy-ok";
+/// Servers probed at once by [`check_reachability`].
+const REACHABILITY_CONCURRENCY: usize = 8;
+/// Handshake budget for fleet-wide checks, shorter than the default so one
+/// dead host doesn't dominate the listing.
+const REACHABILITY_CONNECT_TIMEOUT_SECS: u32 = 5;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionFailureKind {
+    /// The host name did not resolve.
+    Dns,
+    /// The host answered but rejected the key or password.
+    Auth,
+    /// No answer within the connect timeout.
+    Timeout,
+    /// Nothing is listening on the SSH port.
+    Refused,
+    /// No network route to the host.
+    Unreachable,
+    /// The host key is unknown or changed.
+    HostKey,
+    /// ssh failed for a reason not recognised above.
+    Unknown,
+}
+
+/// Reachability annotation for one server in `server list --check`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerReachability {
+    pub server_id: String,
+    /// `None` when the probe could not run (e.g. a missing identity file).
+    pub reachable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<ConnectionFailureKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionTestResult {
+    pub server_id: String,
+    pub host: String,
+    pub user: String,
+    pub port: u16,
+    pub success: bool,
+    /// Wall-clock time for the whole probe, including the SSH handshake.
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<ConnectionFailureKind>,
+    pub exit_code: i32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub stderr: String,
+}
+
+/// Run `echo homeboy-ok` (plus `uname` and the login shell) on the server.
+/// Retries are disabled so a failing host is reported immediately.
+pub fn test_connection(server_id: &str) -> Result<ConnectionTestResult> {
+    let server = load(server_id)?;
+    let mut client = SshClient::from_server(&server, server_id)?;
+    client.retries = 0;
+    Ok(probe(server_id, server, &client))
+}
+
+/// Probe every server, [`REACHABILITY_CONCURRENCY`] at a time, returning one
+/// annotation per server in input order.
+pub fn check_reachability(servers: &[Server]) -> Vec<ServerReachability> {
+    let mut results = Vec::with_capacity(servers.len());
+    for chunk in servers.chunks(REACHABILITY_CONCURRENCY) {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = chunk
+                .iter()
+                .map(|server| scope.spawn(move || server_reachability(server)))
+                .collect();
+            for (handle, server) in handles.into_iter().zip(chunk) {
+                results.push(handle.join().unwrap_or_else(|_| ServerReachability {
+                    server_id: server.id.clone(),
+                    reachable: None,
+                    latency_ms: None,
+                    failure: None,
+                    error: Some("reachability probe panicked".to_string()),
+                }));
+            }
+        });
+    }
+    results
+}
+
+fn server_reachability(server: &Server) -> ServerReachability {
+    let mut client = match SshClient::from_server(server, &server.id) {
+        Ok(client) => client,
+        Err(err) => {
+            return ServerReachability {
+                server_id: server.id.clone(),
+                reachable: None,
+                latency_ms: None,
+                failure: None,
+                error: Some(err.message),
+            }
+        }
+    };
+    client.retries = 0;
+    client.connect_timeout = REACHABILITY_CONNECT_TIMEOUT_SECS;
+
+    let result = probe(&server.id, server.clone(), &client);
+    ServerReachability {
+        server_id: result.server_id,
+        reachable: Some(result.success),
+        latency_ms: result.success.then_some(result.latency_ms),
+        failure: result.failure,
+        error: None,
+    }
+}
+
+fn probe(server_id: &str, server: Server, client: &SshClient) -> ConnectionTestResult {
+    let started = Instant::now();
+    let output = client.execute(&format!(
+        "echo {PROBE_MARKER}; uname -srm 2>/dev/null; printf '%s\\n' \"${{SHELL:-}}\""
+    ));
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let mut lines = output.stdout.lines().map(str::trim);
+    let ran = lines.next() == Some(PROBE_MARKER);
+    let mut next_value = || {
+        lines
+            .next()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+    };
+    let (uname, shell) = if ran {
+        (next_value(), next_value())
+    } else {
+        (None, None)
+    };
+
+    let success = output.success && ran;
+    ConnectionTestResult {
+        server_id: server_id.to_string(),
+        host: server.host,
+        user: server.user,
+        port: server.port,
+        success,
+        latency_ms,
+        uname,
+        shell,
+        failure: if success {
+            None
+        } else {
+            Some(
+                classify_connection_failure(output.exit_code, &output.stderr)
+                    .unwrap_or(ConnectionFailureKind::Unknown),
+            )
+        },
+        exit_code: output.exit_code,
+        stderr: output.stderr.trim().to_string(),
+    }
+}
+
+/// Classify an ssh connection failure. Only exit code 255 is ssh's own; any
+/// other code means the remote command ran, so there is nothing to classify.
+pub fn classify_connection_failure(exit_code: i32, stderr: &str) -> Option<ConnectionFailureKind> {
+    if exit_code != 255 {
+        return None;
+    }
+
+    let stderr = stderr.to_lowercase();
+    let matches = |patterns: &[&str]| patterns.iter().any(|p| stderr.contains(p));
+    Some(
+        if matches(&[
+            "could not resolve hostname",
+            "name or service not known",
+            "temporary failure in name resolution",
+            "nodename nor servname",
+        ]) {
+            ConnectionFailureKind::Dns
+        } else if matches(&[
+            "host key verification failed",
+            "remote host identification has changed",
+        ]) {
+            ConnectionFailureKind::HostKey
+        } else if matches(&[
+            "permission denied",
+            "too many authentication failures",
+            "no more authentication methods",
+        ]) {
+            ConnectionFailureKind::Auth
+        } else if matches(&["timed out"]) {
+            ConnectionFailureKind::Timeout
+        } else if matches(&["connection refused"]) {
+            ConnectionFailureKind::Refused
+        } else if matches(&["no route to host", "network is unreachable"]) {
+            ConnectionFailureKind::Unreachable
+        } else {
+            ConnectionFailureKind::Unknown
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{save, Server};
+    use crate::test_support::with_isolated_home;
+
+    #[test]
+    fn classify_connection_failure_reads_ssh_stderr() {
+        let cases = [
+            (
+                "ssh: Could not resolve hostname nope.invalid: Name or service not known",
+                ConnectionFailureKind::Dns,
+            ),
+            (
+                "deploy@web: Permission denied (publickey,password).",
+                ConnectionFailureKind::Auth,
+            ),
+            (
+                "ssh: connect to host 10.0.0.9 port 22: Connection timed out",
+                ConnectionFailureKind::Timeout,
+            ),
+            (
+                "ssh: connect to host web port 22: Connection refused",
+                ConnectionFailureKind::Refused,
+            ),
+            (
+                "ssh: connect to host web port 22: No route to host",
+                ConnectionFailureKind::Unreachable,
+            ),
+            (
+                "Host key verification failed.",
+                ConnectionFailureKind::HostKey,
+            ),
+            (
+                "kex_exchange_identification: read: reset",
+                ConnectionFailureKind::Unknown,
+            ),
+        ];
+        for (stderr, kind) in cases {
+            assert_eq!(
+                classify_connection_failure(255, stderr),
+                Some(kind),
+                "{stderr}"
+            );
+        }
+        assert_eq!(classify_connection_failure(1, "Connection refused"), None);
+    }
+
+    fn local_server(id: &str) -> Server {
+        Server {
+            id: id.to_string(),
+            aliases: Vec::new(),
+            host: "localhost".to_string(),
+            user: "tester".to_string(),
+            port: 22,
+            identity_file: None,
+            kind: None,
+            auth: None,
+            env: Default::default(),
+            runner: None,
+            remote_temp_dir: None,
+        }
+    }
+
+    #[test]
+    fn check_reachability_keeps_order_and_reports_config_errors() {
+        let missing_key = Server {
+            identity_file: Some("/nonexistent/homeboy-test-key".to_string()),
+            ..local_server("broken")
+        };
+        let servers = vec![local_server("a"), missing_key, local_server("b")];
+
+        let results = check_reachability(&servers);
+
+        let ids: Vec<&str> = results.iter().map(|r| r.server_id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "broken", "b"]);
+        assert_eq!(results[0].reachable, Some(true));
+        assert!(results[0].latency_ms.is_some());
+        assert_eq!(results[1].reachable, None);
+        assert!(results[1].error.is_some());
+    }
+
+    #[test]
+    fn test_connection_reports_local_shell_and_uname() {
+        with_isolated_home(|_| {
+            save(&local_server("local")).expect("save server");
+
+            let result = test_connection("local").expect("test connection");
+
+            assert!(result.success, "{}", result.stderr);
+            assert!(result.uname.is_some());
+            assert_eq!(result.failure, None);
+        });
+    }
+}
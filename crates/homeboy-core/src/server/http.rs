@@ -6,8 +6,10 @@
 use crate::error::{Error, ErrorCode, Result};
 use crate::keychain;
 use crate::project::{ApiConfig, AuthConfig, AuthFlowConfig, VariableSource};
+use crate::redaction::RedactionPolicy;
 use homeboy_extension_contract::HttpMethod;
-use reqwest::blocking::{Client, ClientBuilder, RequestBuilder, Response};
+use reqwest::blocking::{Client, ClientBuilder, Request, RequestBuilder, Response};
+use reqwest::header::HeaderMap;
 use reqwest::Proxy;
 use serde_json::{json, Value};
 use std::collections::HashMap;
@@ -46,6 +48,7 @@ pub struct ApiClient {
     base_url: String,
     project_id: String,
     auth: Option<AuthConfig>,
+    debug: bool,
 }
 
 impl ApiClient {
@@ -66,9 +69,16 @@ impl ApiClient {
             base_url: api_config.base_url.clone(),
             project_id: project_id.to_string(),
             auth: api_config.auth.clone(),
+            debug: false,
         })
     }
 
+    /// Enables redacted request/response logging to stderr.
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
     /// Executes an HTTP request with optional body and authentication.
     fn execute_request(
         &self,
@@ -103,7 +113,7 @@ impl ApiClient {
             request
         };
 
-        let response = request.send().map_err(http_error)?;
+        let response = send_request(&self.client, request, self.debug)?;
         parse_json_response(response)
     }
 
@@ -150,12 +160,7 @@ impl ApiClient {
     /// Makes a POST request without auth (for login flows).
     pub fn post_unauthenticated(&self, endpoint: &str, body: &Value) -> Result<Value> {
         let url = format!("{}{}", self.base_url, endpoint);
-        let response = self
-            .client
-            .post(&url)
-            .json(body)
-            .send()
-            .map_err(http_error)?;
+        let response = send_request(&self.client, self.client.post(&url).json(body), self.debug)?;
         parse_json_response(response)
     }
 
@@ -354,6 +359,73 @@ fn parse_header(header: &str) -> Result<(&str, &str)> {
     Ok((parts[0].trim(), parts[1].trim()))
 }
 
+/// Sends a request, logging the redacted request and response to stderr when
+/// `debug` is set. Stdout stays reserved for the command's JSON output.
+pub(crate) fn send_request(
+    client: &Client,
+    request: RequestBuilder,
+    debug: bool,
+) -> Result<Response> {
+    if !debug {
+        return request.send().map_err(http_error);
+    }
+
+    let request = request.build().map_err(http_error)?;
+    let policy = RedactionPolicy::default();
+    for line in debug_request_lines(&request, &policy) {
+        eprintln!("[api] {}", line);
+    }
+    let response = client.execute(request).map_err(http_error)?;
+    for line in debug_response_lines(&response, &policy) {
+        eprintln!("[api] {}", line);
+    }
+    Ok(response)
+}
+
+fn debug_request_lines(request: &Request, policy: &RedactionPolicy) -> Vec<String> {
+    let mut lines = vec![format!(
+        "> {} {}",
+        request.method(),
+        policy.redact_url(request.url().as_str())
+    )];
+    lines.extend(debug_header_lines(">", request.headers(), policy));
+    if let Some(bytes) = request.body().and_then(|body| body.as_bytes()) {
+        lines.push(format!("> body: {}", debug_body(bytes, policy)));
+    }
+    lines
+}
+
+fn debug_response_lines(response: &Response, policy: &RedactionPolicy) -> Vec<String> {
+    let mut lines = vec![format!("< {}", response.status())];
+    lines.extend(debug_header_lines("<", response.headers(), policy));
+    lines
+}
+
+fn debug_header_lines(
+    direction: &str,
+    headers: &HeaderMap,
+    policy: &RedactionPolicy,
+) -> Vec<String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if policy.is_sensitive_header(name.as_str()) {
+                policy.replacement().to_string()
+            } else {
+                policy.redact_string(value.to_str().unwrap_or("<non-utf8>"))
+            };
+            format!("{} {}: {}", direction, name, value)
+        })
+        .collect()
+}
+
+fn debug_body(bytes: &[u8], policy: &RedactionPolicy) -> String {
+    match serde_json::from_slice::<Value>(bytes) {
+        Ok(value) => policy.redact_json(&value).to_string(),
+        Err(_) => policy.redact_string(&String::from_utf8_lossy(bytes)),
+    }
+}
+
 fn parse_json_response(response: Response) -> Result<Value> {
     let status = response.status();
     let body = response.text().map_err(http_error)?;
@@ -654,6 +726,55 @@ mod tests {
             .expect("login response");
     }
 
+    #[test]
+    fn debug_request_lines_redact_auth_headers_and_body_secrets() {
+        let client = Client::new();
+        let request = client
+            .post("https://api.example.test/items?token=abc123&page=2")
+            .header("Authorization", "Bearer super-secret")
+            .header("X-Trace", "trace-1")
+            .json(&serde_json::json!({ "name": "soup", "password": "hunter2" }))
+            .build()
+            .expect("build request");
+
+        let lines = debug_request_lines(&request, &RedactionPolicy::default());
+        let joined = lines.join("\n");
+
+        assert!(lines[0].starts_with("> POST https://api.example.test/items?"));
+        assert!(joined.contains("page=2"));
+        assert!(joined.contains("> authorization: [REDACTED]"));
+        assert!(joined.contains("> x-trace: trace-1"));
+        assert!(joined.contains("\"name\":\"soup\""));
+        assert!(!joined.contains("super-secret"));
+        assert!(!joined.contains("hunter2"));
+        assert!(!joined.contains("abc123"));
+    }
+
+    #[test]
+    fn debug_request_lines_honor_custom_sensitive_headers() {
+        let client = Client::new();
+        let request = client
+            .get("https://api.example.test/items")
+            .header("X-Site-Secret", "shh")
+            .build()
+            .expect("build request");
+        let policy = RedactionPolicy::default().with_sensitive_header("x-site-secret");
+
+        let lines = debug_request_lines(&request, &policy);
+
+        assert!(lines.contains(&"> x-site-secret: [REDACTED]".to_string()));
+    }
+
+    #[test]
+    fn debug_client_still_returns_response() {
+        let base_url = with_test_server(|request| assert_method_path(request, "GET", "/items"));
+        let response = test_client(base_url)
+            .with_debug(true)
+            .get("/items")
+            .expect("get response");
+        assert_eq!(response["ok"], true);
+    }
+
     #[test]
     fn test_refresh_if_needed() {
         let client = test_client("http://127.0.0.1:1".to_string());
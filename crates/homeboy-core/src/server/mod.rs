@@ -3,6 +3,7 @@ pub mod auth;
 pub mod auth_profiles;
 pub mod client;
 mod connection;
+mod connectivity;
 pub mod health;
 pub mod http;
 mod keys;
@@ -14,17 +15,23 @@ pub mod transfer;
 pub use client::DELEGATED_RUN_STATUS_FILE_ENV;
 pub use client::{
     execute_local_command, execute_local_command_in_dir, execute_local_command_in_dir_with_timeout,
-    execute_local_command_interactive, execute_local_command_passthrough, is_transient_ssh_error,
-    CommandOutput, SshClient,
+    execute_local_command_interactive, execute_local_command_lines,
+    execute_local_command_passthrough, execute_local_command_to_file, is_ssh_connection_failure,
+    is_transient_ssh_error, CommandOutput, OutputCapture, SshClient, DEFAULT_CONNECT_TIMEOUT_SECS,
+    DEFAULT_SSH_RETRIES,
 };
 pub use client::{
     execute_local_command_passthrough_with_timeout, execute_local_command_stderr_passthrough,
     execute_local_command_stderr_passthrough_with_timeout,
 };
 pub use connection::{resolve_context, SshResolveArgs, SshResolveResult};
+pub use connectivity::{
+    check_reachability, classify_connection_failure, test_connection, ConnectionFailureKind,
+    ConnectionTestResult, ServerReachability,
+};
 pub use keys::{
-    generate_key, get_public_key, import_key, unset_key, use_key, KeyGenerateResult,
-    KeyImportResult,
+    copy_id, generate_key, get_public_key, import_key, unset_key, use_key, KeyCopyIdResult,
+    KeyGenerateResult, KeyImportResult,
 };
 pub use session::{ManagedSshSession, ManagedSshSessionOutput};
 
@@ -60,6 +67,11 @@ pub struct Server {
     pub env: HashMap<String, String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub runner: Option<ServerRunner>,
+    /// Remote directory where archive artifacts are staged before extraction,
+    /// for every project on this server. A project's own `remote_temp_dir`
+    /// takes precedence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_temp_dir: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -249,6 +261,20 @@ impl ConfigEntity for Server {
 
 entity_crud!(Server; merge);
 
+/// Keys accepted by `server list --sort/--filter`.
+pub const LIST_QUERY_KEYS: &[&str] = &["name", "host", "user", "type"];
+
+/// Values of a list query key for one server (`type` is the server `kind`).
+pub fn list_query_values(server: &Server, key: &str) -> Vec<String> {
+    match key {
+        "name" => vec![server.id.clone()],
+        "host" => vec![server.host.clone()],
+        "user" => vec![server.user.clone()],
+        "type" => server.kind.iter().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
 pub fn find_by_host(host: &str) -> Option<Server> {
     list().ok()?.into_iter().find(|s| s.host == host)
 }
@@ -8,7 +8,22 @@ pub struct ManagedSshSession {
     pub persist: String,
 }
 
+/// Control socket for per-client multiplexing. `%C` hashes host, port, and
+/// user so the path stays under the Unix socket length limit.
+const MULTIPLEX_CONTROL_PATH: &str = "~/.ssh/controlmasters/homeboy-%C";
+/// Keep multiplexed masters alive just long enough to span one multi-step
+/// operation.
+const MULTIPLEX_PERSIST: &str = "60s";
+
 impl ManagedSshSession {
+    /// Session settings for [`super::SshClient::with_multiplexing`].
+    pub fn multiplexed() -> Self {
+        Self {
+            control_path: expand_control_path(MULTIPLEX_CONTROL_PATH),
+            persist: MULTIPLEX_PERSIST.to_string(),
+        }
+    }
+
     pub fn from_auth(auth: &ServerAuth) -> Self {
         Self {
             control_path: expand_control_path(
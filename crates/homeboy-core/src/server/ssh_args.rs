@@ -27,7 +27,8 @@ pub(crate) fn client_ssh_args(client: &SshClient, options: SshArgOptions<'_>) ->
         &client.host,
         client.port,
         client.identity_file.as_deref(),
-        client.auth.as_ref(),
+        client.control_session().as_ref(),
+        client.connect_timeout,
         options,
     );
     args.push(format!("{}@{}", client.user, client.host));
@@ -43,12 +44,20 @@ pub fn client_option_args(client: &SshClient, options: SshArgOptions<'_>) -> Vec
         &client.host,
         client.port,
         client.identity_file.as_deref(),
-        client.auth.as_ref(),
+        client.control_session().as_ref(),
+        client.connect_timeout,
         options,
     )
 }
 
-pub fn server_option_args(server: &Server, options: SshArgOptions<'_>) -> Vec<String> {
+/// Options for a raw `ssh` to `server`. `connect_timeout_secs` should come
+/// from the [`SshClient`] built for the same server, so the configured
+/// handshake timeout applies here too.
+pub fn server_option_args(
+    server: &Server,
+    connect_timeout_secs: u32,
+    options: SshArgOptions<'_>,
+) -> Vec<String> {
     let auth = server
         .auth
         .as_ref()
@@ -63,6 +72,7 @@ pub fn server_option_args(server: &Server, options: SshArgOptions<'_>) -> Vec<St
             .as_deref()
             .filter(|path| !path.is_empty()),
         auth.as_ref(),
+        connect_timeout_secs,
         options,
     )
 }
@@ -80,6 +90,7 @@ fn client_connection_args(
     port: u16,
     identity_file: Option<&str>,
     auth: Option<&ManagedSshSession>,
+    connect_timeout_secs: u32,
     options: SshArgOptions<'_>,
 ) -> Vec<String> {
     let mut args = Vec::new();
@@ -119,8 +130,8 @@ fn client_connection_args(
     if options.exit_on_forward_failure {
         push_option(&mut args, "ExitOnForwardFailure=yes");
     }
-    if options.connect_timeout && !options.interactive {
-        push_option(&mut args, "ConnectTimeout=10");
+    if options.connect_timeout && !options.interactive && connect_timeout_secs > 0 {
+        push_option(&mut args, format!("ConnectTimeout={connect_timeout_secs}"));
     }
     if options.keepalive && !options.interactive {
         push_option(&mut args, "ServerAliveInterval=15");
@@ -153,6 +164,9 @@ mod tests {
                 persist: "4h".to_string(),
             }),
             is_local: false,
+            multiplex: false,
+            connect_timeout: 10,
+            retries: 2,
             env: HashMap::new(),
         };
 
@@ -485,6 +485,7 @@ mod tests {
             auth: None,
             env: HashMap::new(),
             runner: None,
+            remote_temp_dir: None,
         })
         .expect("save server");
     }
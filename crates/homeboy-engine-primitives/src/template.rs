@@ -13,6 +13,10 @@ impl TemplateVars {
     pub const TABLE: &'static str = "table";
     pub const QUERY: &'static str = "query";
     pub const FORMAT: &'static str = "format";
+    pub const SEARCH: &'static str = "search";
+    pub const REPLACE: &'static str = "replace";
+    pub const TABLES: &'static str = "tables";
+    pub const DRY_RUN: &'static str = "dryRun";
     pub const TARGET_DIR: &'static str = "targetDir";
     pub const TARGET_BASENAME: &'static str = "targetBasename";
     pub const TARGET_PARENT_DIR: &'static str = "targetParentDir";
@@ -3,8 +3,9 @@
 use homeboy_error::{Error, Result};
 use regex::Regex;
 use std::cmp::Ordering;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::hash::Hash;
+use std::sync::{Mutex, OnceLock};
 
 pub(crate) fn normalize_identifier(input: &str) -> String {
     input.trim().to_lowercase()
@@ -64,9 +65,43 @@ pub fn ensure_multiline(pattern: &str) -> String {
     }
 }
 
+/// Distinct patterns kept compiled before the cache is cleared.
+const REGEX_CACHE_CAPACITY: usize = 128;
+
+/// Compile a multiline regex once per distinct pattern.
+///
+/// Version files are parsed with a handful of patterns across many components,
+/// so compiled regexes are cached. Clones share the compiled program. Invalid
+/// patterns are not cached. Patterns can come from extension manifests and
+/// user input, so the cache is cleared once it reaches
+/// [`REGEX_CACHE_CAPACITY`] to keep long-running processes bounded.
+fn cached_regex(pattern: &str) -> Option<Regex> {
+    let mut cache = regex_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(re) = cache.get(pattern) {
+        return Some(re.clone());
+    }
+    let re = Regex::new(&ensure_multiline(pattern)).ok()?;
+    insert_bounded(&mut cache, pattern, re.clone());
+    Some(re)
+}
+
+fn insert_bounded(cache: &mut HashMap<String, Regex>, pattern: &str, re: Regex) {
+    if cache.len() >= REGEX_CACHE_CAPACITY {
+        cache.clear();
+    }
+    cache.insert(pattern.to_string(), re);
+}
+
+fn regex_cache() -> &'static Mutex<HashMap<String, Regex>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 /// Extract first match from content using regex pattern with capture group.
 pub fn extract_first(content: &str, pattern: &str) -> Option<String> {
-    let re = Regex::new(&ensure_multiline(pattern)).ok()?;
+    let re = cached_regex(pattern)?;
     re.captures(content.trim())
         .and_then(|caps| caps.get(1))
         .map(|m| m.as_str().to_string())
@@ -74,7 +109,7 @@ pub fn extract_first(content: &str, pattern: &str) -> Option<String> {
 
 /// Extract all matches from content using regex pattern with capture group.
 pub fn extract_all(content: &str, pattern: &str) -> Option<Vec<String>> {
-    let re = Regex::new(&ensure_multiline(pattern)).ok()?;
+    let re = cached_regex(pattern)?;
     let matches: Vec<String> = re
         .captures_iter(content.trim())
         .filter_map(|caps| caps.get(1).map(|m| m.as_str().to_string()))
@@ -83,26 +118,29 @@ pub fn extract_all(content: &str, pattern: &str) -> Option<Vec<String>> {
 }
 
 /// Replace all matches of capture group with new value.
+///
+/// Only the bytes of capture group 1 change: the replacement is spliced in at
+/// the group's position, so text elsewhere in the match (or elsewhere in the
+/// file) that happens to equal the old value is left alone. Matching runs on
+/// the trimmed content, like [`extract_all`], but the surrounding whitespace
+/// is kept as-is.
 pub fn replace_all(content: &str, pattern: &str, replacement: &str) -> Option<(String, usize)> {
-    let re = Regex::new(&ensure_multiline(pattern)).ok()?;
-    let mut count = 0usize;
-    let had_trailing_newline = content.ends_with('\n');
+    let re = cached_regex(pattern)?;
     let trimmed = content.trim();
+    let offset = trimmed.as_ptr() as usize - content.as_ptr() as usize;
 
-    let replaced = re
-        .replace_all(trimmed, |caps: &regex::Captures| {
-            count += 1;
-            let full_match = caps.get(0).map(|m| m.as_str()).unwrap_or("");
-            let captured = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-            full_match.replacen(captured, replacement, 1)
-        })
-        .to_string();
-
-    let result = if had_trailing_newline && !replaced.ends_with('\n') {
-        format!("{}\n", replaced)
-    } else {
-        replaced
-    };
+    let mut count = 0usize;
+    let mut result = String::with_capacity(content.len());
+    let mut last = 0;
+    for caps in re.captures_iter(trimmed) {
+        count += 1;
+        if let Some(group) = caps.get(1) {
+            result.push_str(&content[last..offset + group.start()]);
+            result.push_str(replacement);
+            last = offset + group.end();
+        }
+    }
+    result.push_str(&content[last..]);
 
     Some((result, count))
 }
@@ -244,6 +282,45 @@ mod tests {
         assert_eq!(extract_first(content, pattern), Some("1.2.3".to_string()));
     }
 
+    #[test]
+    fn cached_regex_reuses_compiled_pattern() {
+        let pattern = r"Cached-Version:\s*(\d+\.\d+\.\d+)";
+        assert_eq!(
+            extract_first("Cached-Version: 4.5.6", pattern),
+            Some("4.5.6".to_string())
+        );
+        let cached = regex_cache()
+            .lock()
+            .unwrap()
+            .get(pattern)
+            .map(|re| re.as_str().to_string());
+        assert_eq!(
+            cached.as_deref(),
+            Some("(?m)Cached-Version:\\s*(\\d+\\.\\d+\\.\\d+)")
+        );
+
+        assert_eq!(
+            extract_first("Cached-Version: 7.8.9", pattern),
+            Some("7.8.9".to_string())
+        );
+        assert!(cached_regex("(unclosed").is_none());
+        assert!(!regex_cache().lock().unwrap().contains_key("(unclosed"));
+    }
+
+    #[test]
+    fn regex_cache_is_cleared_at_capacity() {
+        let mut cache = HashMap::new();
+        let re = Regex::new("x").unwrap();
+        for index in 0..REGEX_CACHE_CAPACITY {
+            insert_bounded(&mut cache, &index.to_string(), re.clone());
+        }
+        assert_eq!(cache.len(), REGEX_CACHE_CAPACITY);
+
+        insert_bounded(&mut cache, "overflow", Regex::new("y").unwrap());
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains_key("overflow"));
+    }
+
     #[test]
     fn extract_all_finds_multiple() {
         let content = "v1.0.0 and v2.0.0";
@@ -261,6 +338,18 @@ mod tests {
         assert_eq!(count, 2);
     }
 
+    #[test]
+    fn replace_all_splices_only_the_captured_group() {
+        let content = "\n * Plugin_1 Version: 1\n * Requires at least: 1\n\n";
+        let pattern = r"Plugin_1 Version: (\d+)";
+        let (replaced, count) = replace_all(content, pattern, "2").unwrap();
+        assert_eq!(
+            replaced,
+            "\n * Plugin_1 Version: 2\n * Requires at least: 1\n\n"
+        );
+        assert_eq!(count, 1);
+    }
+
     #[test]
     fn require_identical_passes_duplicates() {
         let values = vec!["1.0.0".to_string(), "1.0.0".to_string()];
@@ -29,6 +29,7 @@ pub enum ErrorCode {
     FleetNotFound,
     ExtensionNotFound,
     ExtensionUnsupported,
+    CliToolMissing,
     DocsTopicNotFound,
     RigNotFound,
     RunnerNotFound,
@@ -89,6 +90,7 @@ impl ErrorCode {
             ErrorCode::FleetNotFound => "fleet.not_found",
             ErrorCode::ExtensionNotFound => "extension.not_found",
             ErrorCode::ExtensionUnsupported => "extension.unsupported",
+            ErrorCode::CliToolMissing => "cli.tool_missing",
             ErrorCode::DocsTopicNotFound => "docs.topic_not_found",
             ErrorCode::RigNotFound => "rig.not_found",
             ErrorCode::RunnerNotFound => "runner.not_found",
@@ -736,6 +738,33 @@ impl Error {
         Self::entity_not_found(ErrorCode::ExtensionNotFound, "Extension", id, suggestions)
     }
 
+    /// An extension CLI tool's resolved binary is not installed where the
+    /// command would run. `location` names the host (`local` or
+    /// `server '<id>'`) and `working_dir` the directory it was resolved from.
+    pub fn cli_tool_missing(
+        tool: impl Into<String>,
+        cli_path: impl Into<String>,
+        location: impl Into<String>,
+        working_dir: Option<String>,
+    ) -> Self {
+        let tool = tool.into();
+        let cli_path = cli_path.into();
+        let location = location.into();
+        Self::new(
+            ErrorCode::CliToolMissing,
+            format!(
+                "CLI tool '{}' is not installed: '{}' was not found on {}",
+                tool, cli_path, location
+            ),
+            serde_json::json!({
+                "tool": tool,
+                "cli_path": cli_path,
+                "location": location,
+                "working_dir": working_dir,
+            }),
+        )
+    }
+
     pub fn fleet_not_found(id: impl Into<String>, suggestions: Vec<String>) -> Self {
         Self::entity_not_found(ErrorCode::FleetNotFound, "Fleet", id, suggestions)
     }
@@ -967,6 +996,30 @@ impl Error {
         .with_hint("Topics use path format: 'commands/deploy', 'architecture/hooks'")
     }
 
+    /// Topic-not-found error that carries close matches and the full topic list
+    /// so scripts can recover without a second `self docs list` call.
+    pub fn docs_topic_not_found_with_topics(
+        topic: impl Into<String>,
+        suggestions: Vec<String>,
+        available_topics: Vec<String>,
+    ) -> Self {
+        let mut error = Self::new(
+            ErrorCode::DocsTopicNotFound,
+            "Documentation topic not found",
+            serde_json::json!({
+                "topic": topic.into(),
+                "suggestions": suggestions,
+                "available_topics": available_topics,
+            }),
+        );
+        if !suggestions.is_empty() {
+            error = error.with_hint(format_suggestions(&suggestions));
+        }
+        error
+            .with_hint("Run 'homeboy self docs list' to see available topics")
+            .with_hint("Topics use path format: 'commands/deploy', 'architecture/hooks'")
+    }
+
     fn not_found(code: ErrorCode, message: &str, id: impl Into<String>) -> Self {
         let details = to_details(NotFoundDetails { id: id.into() });
         Self::new(code, message, details)
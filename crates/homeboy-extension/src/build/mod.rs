@@ -103,9 +103,9 @@ pub(crate) fn resolve_build_command(component: &Component) -> Result<ResolvedBui
             }
 
             // Priority 2: Local script matching the extension's script_names pattern
-            let local_path = PathBuf::from(&component.local_path);
+            let build_root = component.build_root(&PathBuf::from(&component.local_path))?;
             for script_name in &build.script_names {
-                let local_script = local_path.join(script_name);
+                let local_script = build_root.join(script_name);
                 if local_script.exists() {
                     let command = build
                         .command_template
@@ -430,6 +430,8 @@ fn execute_build_component(
     // Validate local_path before attempting build
     let validated_path = component::validate_local_path(comp)?;
     let local_path_str = validated_path.to_string_lossy().to_string();
+    let build_root = comp.build_root(&validated_path)?;
+    let build_root_str = build_root.to_string_lossy().to_string();
 
     // Warn when HEAD is ahead of the latest tag — the build will include
     // unreleased commits that won't be deployed unless using `deploy --head`.
@@ -528,7 +530,7 @@ fn execute_build_component(
     } else if let Some(context) = build_context {
         let mut runner = extension::ExtensionRunner::for_context(context.clone())
             .component(comp.clone())
-            .working_dir(&local_path_str)
+            .working_dir(&build_root_str)
             .command_override(build_cmd.clone())
             .with_run_dir(&run_dir)
             .timeout(Some(build_timeout))
@@ -543,7 +545,7 @@ fn execute_build_component(
             extension::resolve_execution_context(comp, extension::ExtensionCapability::Build)?;
         let mut runner = extension::ExtensionRunner::for_context(context)
             .component(comp.clone())
-            .working_dir(&local_path_str)
+            .working_dir(&build_root_str)
             .command_override(build_cmd.clone())
             .with_run_dir(&run_dir)
             .timeout(Some(build_timeout))
@@ -768,8 +770,8 @@ fn apply_artifact_inputs(comp: &Component) -> Result<Vec<ResolvedArtifactInput>>
             None,
         )
     })?;
-    let artifact_path =
-        resolve_artifact_path_from_root(&artifact_pattern, Some(Path::new(&comp.local_path)))?;
+    let build_root = comp.build_root(Path::new(&comp.local_path))?;
+    let artifact_path = resolve_artifact_path_from_root(&artifact_pattern, Some(&build_root))?;
     artifact_inputs::apply_to_component_artifact(comp, &artifact_path)
 }
 
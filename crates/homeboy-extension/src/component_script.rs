@@ -86,6 +86,11 @@ fn run_component_scripts_with_env_and_timeout(
     let mut timed_out = false;
     let mut child_resource = None;
     let env = component_script_env(component, source_path, extra_env)?;
+    let working_dir = if capability == ExtensionCapability::Build {
+        component.build_root(source_path)?
+    } else {
+        source_path.to_path_buf()
+    };
 
     for command in commands {
         if passthrough {
@@ -104,7 +109,7 @@ fn run_component_scripts_with_env_and_timeout(
             "",
             &[],
             &env,
-            Some(&source_path.to_string_lossy()),
+            Some(&working_dir.to_string_lossy()),
             Some(&command),
             super::execution::CapabilityScriptOptions {
                 passthrough,
@@ -209,14 +209,15 @@ pub fn run_extension(
     })
 }
 
-/// Execute a extension action (API call).
+/// Execute a extension action directly, outside any release step.
 pub fn run_action(
     extension_id: &str,
     action_id: &str,
     project_id: Option<&str>,
     data: Option<&str>,
+    payload: Option<&serde_json::Value>,
 ) -> Result<serde_json::Value> {
-    execute_action(extension_id, action_id, project_id, data, None)
+    execute_action(extension_id, action_id, project_id, data, payload)
 }
 
 fn extension_runtime(extension: &ExtensionManifest) -> Result<&RuntimeConfig> {
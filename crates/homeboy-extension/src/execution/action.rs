@@ -52,6 +52,8 @@ pub fn execute_action(
             )
         })?;
 
+    require_action_credentials(action, project_id)?;
+
     let selected: Vec<serde_json::Value> = if let Some(data_str) = data {
         serde_json::from_str(data_str).map_err(|e| {
             Error::internal_json(e.to_string(), Some("parse action data".to_string()))
@@ -71,15 +73,6 @@ pub fn execute_action(
             let project = project::load(pid)?;
             let client = ApiClient::new(pid, &project.api)?;
 
-            if action.requires_auth.unwrap_or(false) && !client.is_authenticated() {
-                return Err(Error::validation_invalid_argument(
-                    "auth",
-                    "Not authenticated",
-                    None,
-                    Some(vec!["Run 'homeboy api auth login --project <id>' first.".to_string()]),
-                ));
-            }
-
             let endpoint = validation::require(
                 action.endpoint.as_ref(),
                 "endpoint",
@@ -154,6 +147,32 @@ pub fn execute_action(
     }
 }
 
+/// Actions marked `requires_auth` need an authenticated project API client,
+/// whatever their type, so a command action cannot run without credentials.
+fn require_action_credentials(action: &ActionConfig, project_id: Option<&str>) -> Result<()> {
+    if !action.requires_auth.unwrap_or(false) {
+        return Ok(());
+    }
+    let pid = validation::require(
+        project_id,
+        "project",
+        "--project is required for actions that require auth",
+    )?;
+    let project = project::load(pid)?;
+    if !ApiClient::new(pid, &project.api)?.is_authenticated() {
+        return Err(Error::validation_invalid_argument(
+            "auth",
+            "Not authenticated",
+            None,
+            Some(vec![format!(
+                "Run 'homeboy api auth login --project {}' first.",
+                pid
+            )]),
+        ));
+    }
+    Ok(())
+}
+
 fn interpolate_action_payload(
     action: &ActionConfig,
     selected: &[serde_json::Value],
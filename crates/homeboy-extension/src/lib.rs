@@ -111,13 +111,13 @@ pub use manifest::{
     ExtensionMaterializationHelperManifestRef, ExtensionMaterializationSourceContract,
     ExtensionMaterializationSourceKind, ExtensionToolDiagnosticDeclaration, FeatureContextRule,
     FileContainsCondition, FuzzConfig, HttpMethod, IncludeWrapperPolicy, InputConfig,
-    LintChangedFileRoute, LintConfig, NotificationTransportConfig, PackageNameSource,
-    PlatformCapability, ProvidesConfig, ReleasePreflightConfig, RemotePathInferenceRule,
-    RemotePathRootRule, RequirementsConfig, RuntimeConfig, RuntimeRequirementsConfig,
-    ScriptsConfig, SelectOption, SettingConfig, SinceTagConfig, SourceSnapshotConfig,
-    StructuredSidecarDeclaration, TestChangedFileExclusiveEnv, TestChangedFileRouting,
-    TestChangedFileRoutingStrategy, TestConfig, TestDriftConfig, TestMappingConfig,
-    TestPassthroughFilter, TestPassthroughFilterStrategy, TestVacuityPolicy,
+    LintChangedFileRoute, LintConfig, LogLevelPatterns, LogsConfig, NotificationTransportConfig,
+    PackageNameSource, PlatformCapability, ProvidesConfig, ReleasePreflightConfig,
+    RemotePathInferenceRule, RemotePathRootRule, RequirementsConfig, RuntimeConfig,
+    RuntimeRequirementsConfig, ScriptsConfig, SelectOption, SettingConfig, SinceTagConfig,
+    SourceSnapshotConfig, StructuredSidecarDeclaration, TestChangedFileExclusiveEnv,
+    TestChangedFileRouting, TestChangedFileRoutingStrategy, TestConfig, TestDriftConfig,
+    TestMappingConfig, TestPassthroughFilter, TestPassthroughFilterStrategy, TestVacuityPolicy,
     TraceBrowserArtifactMapConfig, TraceBrowserEvidenceAdapterConfig,
     TraceBrowserMetricAliasConfig, TraceBrowserSummaryAliasConfig, TraceConfig,
     VersionPatternConfig, EXTENSION_CONTRACT_PRODUCER_SCHEMA,
@@ -128,7 +128,9 @@ pub use refactor_protocol::{
     RefactorScriptFailure, RefactorScriptFailureKind, RelatedTests, ResolvedImports,
     RewrittenImport,
 };
-pub use repair::{relink, replace, replace_with_revision, ReplaceResult};
+pub use repair::{
+    link, relink, replace, replace_with_revision, unlink, LinkResult, ReplaceResult, UnlinkResult,
+};
 pub use runner::{ExtensionRunner, RunnerOutput};
 pub use runtime_helper::{
     helper_path, BASH_PREFLIGHT_ENV, COMMAND_CAPTURE_ENV, RUNNER_PRELUDE_ENV, RUNNER_STEPS_ENV,
@@ -32,6 +32,7 @@ pub use homeboy_extension_contract::ci_config::{
     CiCapability, CiJobFidelity, CiJobMapping, CiJobSpec, CiLocalContext, CiProfileSpec,
 };
 pub use homeboy_extension_contract::fuzz_config::{FuzzConfig, FuzzWorkloadConfig};
+pub use homeboy_extension_contract::logs_config::{LogLevelPatterns, LogsConfig};
 pub use homeboy_extension_contract::manifest_action_config::{
     ActionConfig, InputConfig, RuntimeConfig, SelectOption, SettingConfig,
 };
@@ -9,8 +9,9 @@ use std::path::{Path, PathBuf};
 
 use super::execution::run_setup;
 use super::lifecycle::{
-    derive_id_from_url, install_linked_shared_assets, rename_dir, resolve_cloned_extension,
-    slugify_id, write_requested_source_ref, write_source_metadata,
+    derive_id_from_url, install, install_linked_shared_assets, rename_dir,
+    resolve_cloned_extension, slugify_id, uninstall, write_requested_source_ref,
+    write_source_metadata,
 };
 use super::manifest::ExtensionManifest;
 
@@ -47,6 +48,157 @@ pub fn relink(extension_id: &str, source: &str) -> Result<ReplaceResult> {
     replace_from_path(source, Some(extension_id), true, None)
 }
 
+#[derive(Debug, Clone)]
+pub struct LinkResult {
+    pub extension_id: String,
+    pub path: PathBuf,
+    pub source: String,
+    pub manifest_path: PathBuf,
+    pub source_revision: Option<String>,
+    /// Install path that was present under the same id before linking.
+    pub previous_path: Option<PathBuf>,
+    /// True when the link shadows a copied (non-linked) install.
+    pub shadowed_install: bool,
+    /// Where the shadowed install was moved. `None` when there was nothing to
+    /// keep or `--force` discarded it.
+    pub backup_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnlinkResult {
+    pub source: PathBuf,
+    /// Shadowed install moved back into place by the unlink.
+    pub restored_install: Option<PathBuf>,
+}
+
+/// Link a local extension directory for development.
+///
+/// Unlike `install`, an existing install under the same id is replaced by the
+/// link rather than rejected. A copied install is never deleted: it is moved
+/// to a backup path that `unlink` restores, and only removed when `force` is
+/// set.
+pub fn link(source: &str, id_override: Option<&str>, force: bool) -> Result<LinkResult> {
+    if is_git_url(source) {
+        return Err(Error::validation_invalid_argument(
+            "source",
+            "Extension link requires a local directory",
+            Some(source.to_string()),
+            Some(vec![format!(
+                "Install from git instead: homeboy extension install {}",
+                source
+            )]),
+        ));
+    }
+
+    let local_source = resolve_local_source(source)?;
+    let extension_id = local_extension_id(&local_source, source, id_override)?;
+    let extension_dir = paths::extension(&extension_id)?;
+
+    if extension_dir.is_symlink() {
+        let result = replace_from_path(source, Some(&extension_id), false, None)?;
+        return Ok(LinkResult {
+            extension_id: result.extension_id,
+            path: extension_dir,
+            source: result.source,
+            manifest_path: result.manifest_path,
+            source_revision: result.source_revision,
+            previous_path: Some(result.old_path),
+            shadowed_install: false,
+            backup_path: None,
+        });
+    }
+
+    let shadowed_install = path_exists_or_symlink(&extension_dir);
+    let backup_dir = shadowed_install_path(&extension_dir, &extension_id);
+    if shadowed_install {
+        if path_exists_or_symlink(&backup_dir) {
+            return Err(Error::validation_invalid_argument(
+                "extension_id",
+                format!(
+                    "Extension '{}' already has a shadowed install at {}",
+                    extension_id,
+                    backup_dir.display()
+                ),
+                Some(extension_id),
+                Some(vec![
+                    "Restore or remove the shadowed install before linking again".to_string(),
+                ]),
+            ));
+        }
+        move_existing_install(&extension_dir, &backup_dir)?;
+    }
+
+    let result = match install(source, id_override) {
+        Ok(result) => result,
+        Err(err) => {
+            if shadowed_install {
+                let _ = restore_existing_install(&backup_dir, &extension_dir);
+            }
+            return Err(err);
+        }
+    };
+
+    let backup_path = if shadowed_install && force {
+        remove_existing_install(&backup_dir)?;
+        None
+    } else {
+        shadowed_install.then_some(backup_dir)
+    };
+
+    Ok(LinkResult {
+        extension_id: result.extension_id,
+        path: result.path,
+        source: result.url,
+        manifest_path: result.manifest_path,
+        source_revision: result.source_revision,
+        previous_path: shadowed_install.then_some(extension_dir),
+        shadowed_install,
+        backup_path,
+    })
+}
+
+/// Remove a linked extension, leaving its source directory untouched.
+///
+/// Copied installs are rejected so `unlink` never deletes extension files. An
+/// install shadowed by `link` is moved back into place.
+pub fn unlink(extension_id: &str) -> Result<UnlinkResult> {
+    let extension_dir = paths::extension(extension_id)?;
+    if !path_exists_or_symlink(&extension_dir) {
+        return Err(Error::extension_not_found(extension_id.to_string(), vec![]));
+    }
+    if !extension_dir.is_symlink() {
+        return Err(Error::validation_invalid_argument(
+            "extension_id",
+            format!("Extension '{}' is installed, not linked", extension_id),
+            Some(extension_id.to_string()),
+            Some(vec![format!(
+                "Remove the install with: homeboy extension uninstall {}",
+                extension_id
+            )]),
+        ));
+    }
+
+    let source = installed_source_path(&extension_dir);
+    uninstall(extension_id)?;
+
+    let backup_dir = shadowed_install_path(&extension_dir, extension_id);
+    let restored_install = if path_exists_or_symlink(&backup_dir) {
+        restore_existing_install(&backup_dir, &extension_dir)?;
+        Some(extension_dir)
+    } else {
+        None
+    };
+
+    Ok(UnlinkResult {
+        source,
+        restored_install,
+    })
+}
+
+fn shadowed_install_path(extension_dir: &Path, extension_id: &str) -> PathBuf {
+    extension_dir.with_file_name(format!(".link-shadowed-{}", extension_id))
+}
+
 fn replace_from_url(
     url: &str,
     id_override: Option<&str>,
@@ -437,8 +589,8 @@ fn clean_stale_replace_clone_temps(extensions_dir: &Path, extension_id: &str) ->
 #[cfg(test)]
 mod tests {
     use super::{
-        clean_stale_replace_clone_temps, relink, replace, replace_with_revision,
-        unique_replace_clone_temp,
+        clean_stale_replace_clone_temps, link, relink, replace, replace_with_revision,
+        unique_replace_clone_temp, unlink,
     };
     use crate::{install, load_extension};
     use homeboy_core::test_support::with_isolated_home;
@@ -724,6 +876,102 @@ mod tests {
         });
     }
 
+    #[test]
+    fn link_creates_symlink_for_new_extension() {
+        with_isolated_home(|home| {
+            let home = home.path();
+            let source = home.join("dev-source");
+            write_extension_fixture(&source, "swift");
+
+            let result =
+                link(&source.join("swift").to_string_lossy(), None, false).expect("link extension");
+
+            assert_eq!(result.extension_id, "swift");
+            assert!(result.path.is_symlink());
+            assert!(result.previous_path.is_none());
+            assert!(!result.shadowed_install);
+        });
+    }
+
+    #[test]
+    fn link_keeps_shadowed_install_and_unlink_restores_it() {
+        with_isolated_home(|home| {
+            let home = home.path();
+            let installed = home.join("installed");
+            let source = home.join("dev-source");
+            write_extension_fixture(&installed, "swift");
+            write_extension_fixture_with_version(&source, "swift", "2.0.0");
+
+            let extensions_dir = home.join(".config/homeboy/extensions");
+            fs::create_dir_all(&extensions_dir).expect("extensions dir");
+            fs::rename(installed.join("swift"), extensions_dir.join("swift"))
+                .expect("seed copied install");
+
+            let result = link(&source.join("swift").to_string_lossy(), None, false)
+                .expect("link over install");
+            assert!(result.shadowed_install);
+            let backup = result.backup_path.expect("shadowed install is kept");
+            assert!(backup.join("swift.json").exists());
+            assert_eq!(
+                load_extension("swift").expect("load linked").version,
+                "2.0.0"
+            );
+
+            let unlinked = unlink("swift").expect("unlink extension");
+            assert_eq!(unlinked.source, source.join("swift"));
+            assert_eq!(
+                unlinked.restored_install,
+                Some(extensions_dir.join("swift"))
+            );
+            assert!(!extensions_dir.join("swift").is_symlink());
+            assert!(!backup.exists());
+            assert!(source.join("swift/swift.json").exists());
+            assert_ne!(
+                load_extension("swift").expect("load restored").version,
+                "2.0.0"
+            );
+        });
+    }
+
+    #[test]
+    fn link_force_discards_shadowed_install() {
+        with_isolated_home(|home| {
+            let home = home.path();
+            let source = home.join("dev-source");
+            let extensions_dir = home.join(".config/homeboy/extensions");
+            write_extension_fixture(&extensions_dir, "swift");
+            write_extension_fixture_with_version(&source, "swift", "2.0.0");
+
+            let result = link(&source.join("swift").to_string_lossy(), None, true)
+                .expect("force link over install");
+
+            assert!(result.shadowed_install);
+            assert!(result.backup_path.is_none());
+            assert!(!extensions_dir.join(".link-shadowed-swift").exists());
+            assert!(result.path.is_symlink());
+        });
+    }
+
+    #[test]
+    fn unlink_rejects_copied_install() {
+        with_isolated_home(|home| {
+            let extensions_dir = home.path().join(".config/homeboy/extensions");
+            write_extension_fixture(&extensions_dir, "swift");
+
+            let err = unlink("swift").expect_err("copied install is not a link");
+
+            assert!(err.message.contains("not linked"));
+            assert!(extensions_dir.join("swift/swift.json").exists());
+        });
+    }
+
+    #[test]
+    fn link_rejects_git_urls() {
+        let err =
+            link("https://github.com/example/swift.git", None, false).expect_err("url source");
+        assert!(err.message.contains("local directory"));
+    }
+
     #[test]
     fn replace_fails_and_restores_existing_install_when_declared_provider_is_not_discoverable() {
         with_isolated_home(|home| {
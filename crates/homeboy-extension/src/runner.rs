@@ -721,6 +721,7 @@ mod tests {
             exit_code: 2,
             timed_out: false,
             child_resource: None,
+            combined: None,
         };
 
         write_structured_failure_sidecar(
@@ -755,6 +756,7 @@ mod tests {
             exit_code: 127,
             timed_out: false,
             child_resource: None,
+            combined: None,
         };
 
         write_structured_failure_sidecar(
@@ -133,6 +133,7 @@ pub(super) struct SshTunnelOutput {
 
 pub(super) fn open_loopback_tunnel(
     server: &Server,
+    connect_timeout_secs: u32,
     local_port: u16,
     remote_host: &str,
     remote_port: u16,
@@ -147,6 +148,7 @@ pub(super) fn open_loopback_tunnel(
 
     let mut args = homeboy_core::server::ssh_args::server_option_args(
         server,
+        connect_timeout_secs,
         homeboy_core::server::ssh_args::SshArgOptions {
             batch_mode: true,
             connect_timeout: true,
@@ -31,6 +31,7 @@ pub(super) fn command_output(
         exit_code: if success { 0 } else { 1 },
         timed_out,
         child_resource: None,
+        combined: None,
     }
 }
 
@@ -585,6 +585,7 @@ fn remote_leaseless_recovery_timeout_is_actionable() {
         exit_code: 124,
         timed_out: true,
         child_resource: None,
+        combined: None,
     });
     assert!(message.contains("daemon status"));
 }
@@ -1163,9 +1163,10 @@ fn test_open_loopback_tunnel_noops_for_local_runner() {
         auth: None,
         env: HashMap::new(),
         runner: None,
+        remote_temp_dir: None,
     };
 
-    let tunnel = open_loopback_tunnel(&server, 49100, "127.0.0.1", 49200);
+    let tunnel = open_loopback_tunnel(&server, 10, 49100, "127.0.0.1", 49200);
 
     assert!(tunnel.success);
     assert_eq!(tunnel.pid, None);
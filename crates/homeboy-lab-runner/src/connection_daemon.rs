@@ -31,7 +31,7 @@ struct DaemonHealthReport {
 
 pub(super) fn connect_remote_daemon(
     server: &Server,
-    _client: &SshClient,
+    client: &SshClient,
     _homeboy: &str,
     daemon: RemoteDaemon,
     _expected_version: &str,
@@ -51,7 +51,7 @@ pub(super) fn connect_remote_daemon(
         )
     };
     let (local_port, tunnel_pid, local_url) =
-        open_daemon_tunnel(server, &daemon, runner_id, session_path)?;
+        open_daemon_tunnel(server, client, &daemon, runner_id, session_path)?;
     match probe_daemon_health_until_ready(&local_url, &daemon) {
         Ok(()) => Ok((local_port, tunnel_pid, local_url, daemon)),
         Err(DaemonHealthProbeFailure::IdentityMismatch(report)) => Err(failed_after_tunnel(
@@ -129,6 +129,7 @@ fn health_identity_matches(report: &DaemonHealthReport, daemon: &RemoteDaemon) -
 
 fn open_daemon_tunnel(
     server: &Server,
+    client: &SshClient,
     daemon: &RemoteDaemon,
     runner_id: &str,
     session_path: &Path,
@@ -152,6 +153,7 @@ fn open_daemon_tunnel(
     })?;
     let tunnel = open_loopback_tunnel(
         server,
+        client.connect_timeout,
         local_port,
         &remote_addr.ip().to_string(),
         remote_addr.port(),
@@ -968,6 +968,7 @@ mod tests {
             exit_code: 124,
             timed_out: true,
             child_resource: None,
+            combined: None,
         };
         let timeout_error = validate_remote_lease_bound_daemon_stop_output(&timeout)
             .expect_err("timed out SSH stop must fail closed");
@@ -980,6 +981,7 @@ mod tests {
             exit_code: 0,
             timed_out: false,
             child_resource: None,
+            combined: None,
         };
         let malformed_error = validate_remote_lease_bound_daemon_stop_output(&malformed)
             .expect_err("malformed SSH stop output must fail closed");
@@ -995,6 +997,7 @@ mod tests {
             exit_code: 0,
             timed_out: false,
             child_resource: None,
+            combined: None,
         };
 
         let error = validate_remote_lease_bound_daemon_stop_output(&output)
@@ -1014,6 +1017,7 @@ mod tests {
             exit_code: 0,
             timed_out: false,
             child_resource: None,
+            combined: None,
         };
 
         validate_remote_lease_bound_daemon_stop_output(&output)
@@ -1461,6 +1461,7 @@ mod metadata_write_tests {
             exit_code: -1,
             timed_out: false,
             child_resource: None,
+            combined: None,
         });
 
         assert_eq!(error.code, ErrorCode::RunnerLabTransportFailure);
@@ -2076,6 +2077,7 @@ mod tests {
             exit_code,
             timed_out: false,
             child_resource: None,
+            combined: None,
         }
     }
 
@@ -383,6 +383,75 @@ impl<T: Serialize, E: Serialize + Default> Default for EntityCrudOutput<T, E> {
     }
 }
 
+// ============================================================================
+// Env Output
+// ============================================================================
+
+/// Default key prefix for `--format env` output.
+pub const DEFAULT_ENV_PREFIX: &str = "HOMEBOY_";
+
+/// Render a flat JSON object as shell-evaluable `KEY=value` lines.
+///
+/// Keys are the prefixed, upper-cased field names with any non-alphanumeric
+/// character replaced by `_`. Null fields are omitted. Nested objects and
+/// arrays have no sensible `KEY=value` form, so they are rejected instead of
+/// being silently dropped; callers pass a flat projection of their result.
+pub fn render_env(value: &serde_json::Value, prefix: &str) -> Result<String, String> {
+    let serde_json::Value::Object(fields) = value else {
+        return Err("env output requires a flat object result".to_string());
+    };
+    let nested: Vec<&str> = fields
+        .iter()
+        .filter(|(_, value)| value.is_object() || value.is_array())
+        .map(|(key, _)| key.as_str())
+        .collect();
+    if !nested.is_empty() {
+        return Err(format!(
+            "env output requires a flat object result; nested fields: {}",
+            nested.join(", ")
+        ));
+    }
+
+    let mut out = String::new();
+    for (key, value) in fields {
+        let rendered = match value {
+            serde_json::Value::Null => continue,
+            serde_json::Value::String(text) => env_quote(text),
+            other => other.to_string(),
+        };
+        out.push_str(&env_key(prefix, key));
+        out.push('=');
+        out.push_str(&rendered);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn env_key(prefix: &str, key: &str) -> String {
+    format!("{prefix}{key}")
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn env_quote(value: &str) -> String {
+    let safe = !value.is_empty()
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || "._-/:@%+,".contains(ch));
+    if safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -530,4 +599,32 @@ mod tests {
         assert_eq!(output.summary.failed, 0);
         assert!(output.results.is_empty());
     }
+
+    #[test]
+    fn render_env_flattens_scalars_with_prefix_and_quotes_unsafe_values() {
+        let value = json!({
+            "version": "1.2.3",
+            "component_id": "my-plugin",
+            "dirty": false,
+            "count": 3,
+            "note": "it's here",
+            "missing": null,
+        });
+
+        let rendered = render_env(&value, DEFAULT_ENV_PREFIX).expect("flat object renders");
+
+        assert_eq!(
+            rendered,
+            "HOMEBOY_COMPONENT_ID=my-plugin\nHOMEBOY_COUNT=3\nHOMEBOY_DIRTY=false\nHOMEBOY_NOTE='it'\\''s here'\nHOMEBOY_VERSION=1.2.3\n"
+        );
+    }
+
+    #[test]
+    fn render_env_rejects_nested_and_non_object_results() {
+        let nested = render_env(&json!({ "version": "1", "targets": [] }), "X_")
+            .expect_err("arrays are not flat");
+        assert!(nested.contains("targets"));
+
+        assert!(render_env(&json!(["a"]), "X_").is_err());
+    }
 }
@@ -0,0 +1,463 @@
+//! Remote backups of deployed components and `deploy --rollback`.
+//!
+//! Backups are opt-in. When enabled, an artifact deploy copies the
+//! component's current remote directory to
+//! `~/.homeboy/backups/<project>/<component>/<stamp>` before replacing it,
+//! outside the web root so old code is never web-reachable. The stamp is a
+//! UTC timestamp, suffixed with the replaced version when it is known, so
+//! names sort chronologically and carry the version a rollback restores. Only
+//! the newest `keep` backups are retained.
+//!
+//! Rollback consumes the newest backup: it is copied next to the target,
+//! swapped into place, and then removed, so a second rollback steps back one
+//! more release. The component's deploy manifest is dropped, since it
+//! describes the release that was rolled back.
+
+use homeboy_core::context::resolve_project_ssh_with_base_path;
+use homeboy_core::engine::shell;
+use homeboy_core::error::{Error, Result};
+use homeboy_core::project;
+use homeboy_core::server::SshClient;
+
+use super::manifest;
+use super::path_roots::{project_with_detected_path_roots, resolve_effective_remote_path};
+use super::policy::{protected_path_suffixes, validate_deploy_target};
+use super::types::{
+    ComponentDeployResult, DeployOrchestrationResult, DeployRollback, DeploySummary,
+};
+
+/// Backups kept per component when the caller does not choose a limit.
+/// Backups are opt-in, so none are taken by default.
+pub const DEFAULT_KEEP_BACKUPS: u32 = 0;
+
+const BACKUP_DIR: &str = ".homeboy/backups";
+
+/// Directory holding every backup of one component, under `home`.
+fn backup_root(home: &str, project_id: &str, component_id: &str) -> String {
+    format!(
+        "{}/{}/{}/{}",
+        home.trim_end_matches('/'),
+        BACKUP_DIR,
+        project_id,
+        component_id
+    )
+}
+
+/// Home directory of the remote user, where backups live.
+fn remote_home(client: &SshClient) -> Result<String> {
+    let output = client.execute("printf '%s' \"$HOME\"");
+    let home = output.stdout.trim();
+    if !output.success || home.is_empty() {
+        return Err(Error::internal_io(
+            format!(
+                "Could not resolve the remote home directory for backups: {}",
+                output.stderr.trim()
+            ),
+            None,
+        ));
+    }
+    Ok(home.to_string())
+}
+
+/// Backup directory name: sortable UTC stamp plus the replaced version.
+fn backup_name(stamp: &str, version: Option<&str>) -> String {
+    let version = version
+        .map(|version| {
+            version
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-') {
+                        c
+                    } else {
+                        '-'
+                    }
+                })
+                .collect::<String>()
+        })
+        .filter(|version| !version.is_empty());
+    match version {
+        Some(version) => format!("{}_{}", stamp, version),
+        None => stamp.to_string(),
+    }
+}
+
+/// Version recorded in a backup directory name, if any.
+fn backup_version(name: &str) -> Option<String> {
+    name.split_once('_')
+        .map(|(_, version)| version.to_string())
+        .filter(|version| !version.is_empty())
+}
+
+/// Backup names under `root`, oldest first.
+fn list_backups(client: &SshClient, root: &str) -> Vec<String> {
+    let output = client.execute(&format!(
+        "cd {} 2>/dev/null && ls -1",
+        shell::quote_path(root)
+    ));
+    if !output.success {
+        return Vec::new();
+    }
+    let mut names: Vec<String> = output
+        .stdout
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty() && !name.starts_with('.'))
+        .map(str::to_string)
+        .collect();
+    names.sort();
+    names
+}
+
+/// Names to delete so that only the newest `keep` remain.
+fn backups_to_prune(names: &[String], keep: u32) -> &[String] {
+    let excess = names.len().saturating_sub(keep as usize);
+    &names[..excess]
+}
+
+/// Copy the component's current remote directory into a new backup and prune
+/// older ones. Returns the backup path, or `None` when backups are disabled
+/// (`keep == 0`) or nothing is deployed yet.
+pub(super) fn backup_before_deploy(
+    client: &SshClient,
+    project_id: &str,
+    component_id: &str,
+    install_dir: &str,
+    version: Option<&str>,
+    keep: u32,
+) -> Result<Option<String>> {
+    if keep == 0 {
+        return Ok(None);
+    }
+    if !client
+        .execute(&format!("test -d {}", shell::quote_path(install_dir)))
+        .success
+    {
+        return Ok(None);
+    }
+
+    let root = backup_root(&remote_home(client)?, project_id, component_id);
+    backup_into(client, &root, install_dir, version, keep).map(Some)
+}
+
+fn backup_into(
+    client: &SshClient,
+    root: &str,
+    install_dir: &str,
+    version: Option<&str>,
+    keep: u32,
+) -> Result<String> {
+    let quoted_install = shell::quote_path(install_dir);
+    let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ").to_string();
+    let backup_path = format!("{}/{}", root, backup_name(&stamp, version));
+    homeboy_core::log_status!("deploy", "Backing up {} -> {}", install_dir, backup_path);
+
+    let output = client.execute(&format!(
+        "mkdir -p {} && cp -a {} {}",
+        shell::quote_path(root),
+        quoted_install,
+        shell::quote_path(&backup_path)
+    ));
+    if !output.success {
+        return Err(Error::internal_io(
+            format!(
+                "Failed to back up {}: {}",
+                install_dir,
+                output.stderr.trim()
+            ),
+            Some(backup_path),
+        ));
+    }
+
+    let names = list_backups(client, root);
+    for name in backups_to_prune(&names, keep) {
+        let _ = client.execute(&format!(
+            "rm -rf {}",
+            shell::quote_path(&format!("{}/{}", root, name))
+        ));
+    }
+
+    Ok(backup_path)
+}
+
+/// Restore the newest backup of each component into its remote path.
+pub fn rollback(project_id: &str, component_ids: &[String]) -> Result<DeployOrchestrationResult> {
+    if component_ids.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "component",
+            "--rollback requires at least one component ID",
+            None,
+            Some(vec![format!(
+                "homeboy deploy {} <component-id> --rollback",
+                project_id
+            )]),
+        ));
+    }
+
+    let project = project::load(project_id)?;
+    let components = component_ids
+        .iter()
+        .map(|component_id| project::resolve_project_component(&project, component_id))
+        .collect::<Result<Vec<_>>>()?;
+    let (ctx, base_path) = resolve_project_ssh_with_base_path(project_id)?;
+    let project = project_with_detected_path_roots(&project, &components, &base_path, &ctx.client);
+
+    let mut results = Vec::with_capacity(components.len());
+    for component in &components {
+        let result = match rollback_component(&ctx.client, &project, component, &base_path) {
+            Ok(rollback) => {
+                // The manifest describes the release that was just replaced.
+                manifest::forget(project_id, &component.id);
+                ComponentDeployResult::new_for_project(component, &project, &base_path)
+                    .with_status("rolled_back")
+                    .with_versions(None, rollback.restored_version.clone())
+                    .with_rollback(rollback)
+            }
+            Err(error) => {
+                let mut result =
+                    ComponentDeployResult::new_for_project(component, &project, &base_path)
+                        .with_status("failed");
+                result.error = Some(error.message);
+                result
+            }
+        };
+        results.push(result);
+    }
+
+    let succeeded = results
+        .iter()
+        .filter(|result| result.status == "rolled_back")
+        .count() as u32;
+    let failed = results.len() as u32 - succeeded;
+    Ok(DeployOrchestrationResult {
+        results,
+        summary: DeploySummary {
+            total: succeeded + failed,
+            succeeded,
+            failed,
+            skipped: 0,
+        },
+        deploy_order: Vec::new(),
+    })
+}
+
+fn rollback_component(
+    client: &SshClient,
+    project: &project::Project,
+    component: &homeboy_core::component::Component,
+    base_path: &str,
+) -> Result<DeployRollback> {
+    let install_dir = resolve_effective_remote_path(project, component, base_path)?;
+    validate_deploy_target(
+        &install_dir,
+        base_path,
+        &component.id,
+        &protected_path_suffixes(component),
+    )?;
+
+    let root = backup_root(&remote_home(client)?, &project.id, &component.id);
+    restore_newest_backup(client, &root, &install_dir, &component.id)
+}
+
+fn restore_newest_backup(
+    client: &SshClient,
+    root: &str,
+    install_dir: &str,
+    component_id: &str,
+) -> Result<DeployRollback> {
+    let Some(name) = list_backups(client, root).pop() else {
+        return Err(Error::validation_invalid_argument(
+            "rollback",
+            format!(
+                "No backup found for component '{}' in {}",
+                component_id, root
+            ),
+            Some(component_id.to_string()),
+            Some(vec![
+                "Backups are only taken by deploys run with --keep-backups <n>".to_string(),
+            ]),
+        ));
+    };
+    let backup_path = format!("{}/{}", root, name);
+    homeboy_core::log_status!("deploy", "Restoring {} -> {}", backup_path, install_dir);
+
+    // Stage the copy beside the target so the swap is two renames, then drop
+    // the replaced tree and the consumed backup.
+    let staged = format!("{}.homeboy-rollback", install_dir.trim_end_matches('/'));
+    let replaced = format!("{}.homeboy-replaced", install_dir.trim_end_matches('/'));
+    let (backup_q, install_q, staged_q, replaced_q) = (
+        shell::quote_path(&backup_path),
+        shell::quote_path(install_dir),
+        shell::quote_path(&staged),
+        shell::quote_path(&replaced),
+    );
+    let swap_cmd = format!(
+        "rm -rf {staged_q} {replaced_q} && cp -a {backup_q} {staged_q} && \
+         {{ if [ -e {install_q} ]; then mv {install_q} {replaced_q}; fi; }} && \
+         mv {staged_q} {install_q} && rm -rf {replaced_q} {backup_q}"
+    );
+    let output = client.execute(&swap_cmd);
+    if !output.success {
+        return Err(Error::internal_io(
+            format!(
+                "Failed to restore backup for '{}': {}",
+                component_id,
+                output.stderr.trim()
+            ),
+            Some(backup_path),
+        ));
+    }
+
+    Ok(DeployRollback {
+        restored_version: backup_version(&name),
+        backup_path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deploy::local_test_client;
+    use std::fs;
+
+    #[test]
+    fn backup_names_carry_sanitized_version() {
+        assert_eq!(
+            backup_name("20261017T101500.000Z", Some("1.2.3")),
+            "20261017T101500.000Z_1.2.3"
+        );
+        assert_eq!(
+            backup_name("20261017T101500.000Z", Some("v1/beta 2")),
+            "20261017T101500.000Z_v1-beta-2"
+        );
+        assert_eq!(
+            backup_name("20261017T101500.000Z", None),
+            "20261017T101500.000Z"
+        );
+        assert_eq!(
+            backup_version("20261017T101500.000Z_1.2.3").as_deref(),
+            Some("1.2.3")
+        );
+        assert_eq!(backup_version("20261017T101500.000Z"), None);
+    }
+
+    #[test]
+    fn prune_keeps_newest_backups() {
+        let names: Vec<String> = ["a", "b", "c", "d"].iter().map(|n| n.to_string()).collect();
+        assert_eq!(backups_to_prune(&names, 3), &names[..1]);
+        assert!(backups_to_prune(&names, 5).is_empty());
+    }
+
+    #[test]
+    fn backup_root_lives_outside_the_web_root() {
+        assert_eq!(
+            backup_root("/home/deploy/", "site", "demo"),
+            "/home/deploy/.homeboy/backups/site/demo"
+        );
+    }
+
+    #[test]
+    fn backup_into_copies_target_and_prunes_old_backups() {
+        let temp = tempfile::tempdir().expect("create tempdir");
+        let home = temp.path().join("home");
+        let root = backup_root(home.to_str().unwrap(), "site", "demo");
+        let install = temp.path().join("plugins/demo");
+        fs::create_dir_all(&install).expect("create install dir");
+        fs::write(install.join("demo.php"), "v1").expect("write plugin");
+
+        for version in ["1.0.0", "1.1.0", "1.2.0"] {
+            let backup = backup_into(
+                &local_test_client(),
+                &root,
+                install.to_str().unwrap(),
+                Some(version),
+                2,
+            )
+            .expect("backup");
+            assert!(backup.ends_with(&format!("_{}", version)));
+        }
+
+        let names = list_backups(&local_test_client(), &root);
+        assert_eq!(names.len(), 2);
+        assert!(names[0].ends_with("_1.1.0"));
+        assert!(names[1].ends_with("_1.2.0"));
+        let newest = home
+            .join(".homeboy/backups/site/demo")
+            .join(&names[1])
+            .join("demo.php");
+        assert_eq!(fs::read_to_string(newest).expect("read backup"), "v1");
+    }
+
+    #[test]
+    fn rollback_restores_newest_backup_and_consumes_it() {
+        let temp = tempfile::tempdir().expect("create tempdir");
+        let root = backup_root(temp.path().join("home").to_str().unwrap(), "site", "demo");
+        let install = temp.path().join("plugins/demo");
+        fs::create_dir_all(&install).expect("create install dir");
+
+        for (version, contents) in [("1.0.0", "one"), ("1.1.0", "two")] {
+            fs::write(install.join("demo.php"), contents).expect("write plugin");
+            backup_into(
+                &local_test_client(),
+                &root,
+                install.to_str().unwrap(),
+                Some(version),
+                3,
+            )
+            .expect("backup");
+        }
+        fs::write(install.join("demo.php"), "broken").expect("write bad deploy");
+        fs::write(install.join("new-file.php"), "added").expect("write new file");
+
+        let rollback = restore_newest_backup(
+            &local_test_client(),
+            &root,
+            install.to_str().unwrap(),
+            "demo",
+        )
+        .expect("rollback");
+
+        assert_eq!(rollback.restored_version.as_deref(), Some("1.1.0"));
+        assert_eq!(
+            fs::read_to_string(install.join("demo.php")).expect("read restored"),
+            "two"
+        );
+        assert!(!install.join("new-file.php").exists());
+        assert!(!std::path::Path::new(&rollback.backup_path).exists());
+        assert_eq!(list_backups(&local_test_client(), &root).len(), 1);
+    }
+
+    #[test]
+    fn backup_before_deploy_skips_first_deploy_and_disabled_retention() {
+        let temp = tempfile::tempdir().expect("create tempdir");
+        let missing = temp.path().join("plugins/new");
+        assert_eq!(
+            backup_before_deploy(
+                &local_test_client(),
+                "site",
+                "new",
+                missing.to_str().unwrap(),
+                None,
+                3
+            )
+            .expect("backup"),
+            None
+        );
+
+        fs::create_dir_all(&missing).expect("create install dir");
+        assert_eq!(
+            backup_before_deploy(
+                &local_test_client(),
+                "site",
+                "new",
+                missing.to_str().unwrap(),
+                None,
+                0
+            )
+            .expect("backup"),
+            None
+        );
+        assert!(fs::read_dir(temp.path().join("plugins"))
+            .expect("read plugins")
+            .all(|entry| entry.expect("entry").file_name() == "new"));
+    }
+}
@@ -0,0 +1,385 @@
+//! Component-level deployability checks (`homeboy component doctor`).
+//!
+//! Narrower than the global doctor: validates one component's local path,
+//! version targets, build configuration, and artifact shape against the same
+//! helpers deploy uses, without building or touching a remote.
+
+use std::fs;
+use std::path::Path;
+
+use homeboy_core::component::{self, Component};
+use serde::Serialize;
+
+use super::execution::artifact_requires_extract_command;
+use crate::release::version::read_component_version;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DoctorCheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentDoctorCheck {
+    pub id: &'static str,
+    pub status: DoctorCheckStatus,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentDoctorReport {
+    pub component_id: String,
+    pub status: DoctorCheckStatus,
+    pub checks: Vec<ComponentDoctorCheck>,
+}
+
+impl ComponentDoctorReport {
+    pub fn has_failures(&self) -> bool {
+        self.status == DoctorCheckStatus::Fail
+    }
+}
+
+const KNOWN_DEPLOY_STRATEGIES: &[&str] = &["rsync", "git", "file"];
+
+/// Run every deployability check for `component`.
+pub fn component_doctor(component: &Component) -> ComponentDoctorReport {
+    let local_path = check_local_path(component);
+    let local_path_ok = local_path.status != DoctorCheckStatus::Fail;
+
+    let mut checks = vec![local_path];
+    if local_path_ok {
+        checks.push(check_version(component));
+    } else {
+        checks.push(skipped("version", "local_path check failed"));
+    }
+    checks.push(check_build(component));
+    if local_path_ok {
+        checks.push(check_artifact(component));
+    } else {
+        checks.push(skipped("artifact", "local_path check failed"));
+    }
+
+    let status = checks
+        .iter()
+        .map(|check| check.status)
+        .max()
+        .unwrap_or(DoctorCheckStatus::Pass);
+
+    ComponentDoctorReport {
+        component_id: component.id.clone(),
+        status,
+        checks,
+    }
+}
+
+fn check(
+    id: &'static str,
+    status: DoctorCheckStatus,
+    message: impl Into<String>,
+) -> ComponentDoctorCheck {
+    ComponentDoctorCheck {
+        id,
+        status,
+        message: message.into(),
+    }
+}
+
+fn skipped(id: &'static str, reason: &str) -> ComponentDoctorCheck {
+    check(id, DoctorCheckStatus::Warn, format!("skipped: {reason}"))
+}
+
+fn check_local_path(component: &Component) -> ComponentDoctorCheck {
+    let path = match component::validate_local_path(component) {
+        Ok(path) => path,
+        Err(error) => return check("local_path", DoctorCheckStatus::Fail, error.message),
+    };
+
+    let readable = if path.is_dir() {
+        fs::read_dir(&path).map(|_| ())
+    } else {
+        fs::File::open(&path).map(|_| ())
+    };
+
+    match readable {
+        Ok(()) => check(
+            "local_path",
+            DoctorCheckStatus::Pass,
+            format!("{} exists and is readable", path.display()),
+        ),
+        Err(error) => check(
+            "local_path",
+            DoctorCheckStatus::Fail,
+            format!("{} is not readable: {error}", path.display()),
+        ),
+    }
+}
+
+fn check_version(component: &Component) -> ComponentDoctorCheck {
+    if component
+        .version_targets
+        .as_ref()
+        .is_none_or(|targets| targets.is_empty())
+    {
+        return check(
+            "version",
+            DoctorCheckStatus::Warn,
+            "no version_targets configured; deploy cannot compare local and remote versions",
+        );
+    }
+
+    match read_component_version(component) {
+        Ok(info) => {
+            let warnings: Vec<String> = info
+                .targets
+                .iter()
+                .filter_map(|target| target.warning.clone())
+                .collect();
+            if warnings.is_empty() {
+                check(
+                    "version",
+                    DoctorCheckStatus::Pass,
+                    format!(
+                        "version {} parsed from {} target(s)",
+                        info.version,
+                        info.targets.len()
+                    ),
+                )
+            } else {
+                check(
+                    "version",
+                    DoctorCheckStatus::Warn,
+                    format!("version {} parsed; {}", info.version, warnings.join("; ")),
+                )
+            }
+        }
+        Err(error) => check("version", DoctorCheckStatus::Fail, error.message),
+    }
+}
+
+fn check_build(component: &Component) -> ComponentDoctorCheck {
+    if let Err(error) = component.validate_supported_build_config() {
+        return check("build", DoctorCheckStatus::Fail, error.message);
+    }
+
+    let deploy_config = component.deploy_config();
+    if deploy_config.is_git_deploy() || deploy_config.is_file_deploy() {
+        return if component.build_artifact.is_some() {
+            check(
+                "build",
+                DoctorCheckStatus::Warn,
+                "build_artifact is ignored by git and file deploy strategies",
+            )
+        } else {
+            check(
+                "build",
+                DoctorCheckStatus::Pass,
+                "no build step for this deploy strategy",
+            )
+        };
+    }
+
+    let has_script = component
+        .scripts
+        .as_ref()
+        .is_some_and(|scripts| !scripts.build.is_empty());
+    let has_extension = component
+        .extensions
+        .as_ref()
+        .is_some_and(|extensions| !extensions.is_empty());
+
+    let build_source = if has_script {
+        "scripts.build"
+    } else {
+        "the linked extension build"
+    };
+    match (component.build_artifact.as_deref(), has_script || has_extension) {
+        (Some(artifact), true) => check(
+            "build",
+            DoctorCheckStatus::Pass,
+            format!("build_artifact '{artifact}' is produced by {build_source}"),
+        ),
+        (None, true) => check(
+            "build",
+            DoctorCheckStatus::Pass,
+            format!("build runs via {build_source}; artifact path comes from the extension default"),
+        ),
+        (Some(_), false) => check(
+            "build",
+            DoctorCheckStatus::Warn,
+            "build_artifact is set but no scripts.build or extension provides a build; deploy will only work with --skip-build or a release asset",
+        ),
+        (None, false) => check("build", DoctorCheckStatus::Pass, "no build configured"),
+    }
+}
+
+fn check_artifact(component: &Component) -> ComponentDoctorCheck {
+    let deploy_config = component.deploy_config();
+    let strategy = deploy_config.deploy_strategy.unwrap_or("rsync");
+    if !KNOWN_DEPLOY_STRATEGIES.contains(&strategy) {
+        return check(
+            "artifact",
+            DoctorCheckStatus::Fail,
+            format!(
+                "unsupported deploy_strategy '{strategy}' (expected one of: {})",
+                KNOWN_DEPLOY_STRATEGIES.join(", ")
+            ),
+        );
+    }
+
+    if deploy_config.is_git_deploy() {
+        return match component.git_deploy_config() {
+            Some(_) => check("artifact", DoctorCheckStatus::Pass, "git deploy configured"),
+            None => check(
+                "artifact",
+                DoctorCheckStatus::Warn,
+                "deploy_strategy is git but git_deploy is not configured",
+            ),
+        };
+    }
+
+    if deploy_config.is_file_deploy() {
+        return if Path::new(&component.local_path).is_file() {
+            check(
+                "artifact",
+                DoctorCheckStatus::Pass,
+                "file deploy of local_path",
+            )
+        } else {
+            check(
+                "artifact",
+                DoctorCheckStatus::Fail,
+                "deploy_strategy is file but local_path is not a file",
+            )
+        };
+    }
+
+    let Some(artifact) = component.build_artifact.as_deref() else {
+        let has_extension = component
+            .extensions
+            .as_ref()
+            .is_some_and(|extensions| !extensions.is_empty());
+        return if has_extension {
+            check(
+                "artifact",
+                DoctorCheckStatus::Pass,
+                "artifact comes from the linked extension default",
+            )
+        } else {
+            check(
+                "artifact",
+                DoctorCheckStatus::Warn,
+                "no build_artifact and no linked extension; nothing defines what to upload",
+            )
+        };
+    };
+
+    let artifact_path = Path::new(artifact);
+    if artifact_path.file_name().is_none() {
+        return check(
+            "artifact",
+            DoctorCheckStatus::Fail,
+            format!("build_artifact '{artifact}' must include a file or directory name"),
+        );
+    }
+
+    if artifact_requires_extract_command(artifact_path) && component.extract_command.is_none() {
+        return check(
+            "artifact",
+            DoctorCheckStatus::Warn,
+            format!(
+                "archive artifact '{artifact}' needs an extract_command unless the linked extension provides a deploy override"
+            ),
+        );
+    }
+
+    check(
+        "artifact",
+        DoctorCheckStatus::Pass,
+        format!("artifact '{artifact}' is supported by {strategy} deploy"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use homeboy_core::component::VersionTarget;
+
+    fn fixture(dir: &Path) -> Component {
+        Component {
+            id: "fixture".to_string(),
+            local_path: dir.display().to_string(),
+            ..Component::default()
+        }
+    }
+
+    fn status_of(report: &ComponentDoctorReport, id: &str) -> DoctorCheckStatus {
+        report
+            .checks
+            .iter()
+            .find(|check| check.id == id)
+            .map(|check| check.status)
+            .expect("check present")
+    }
+
+    #[test]
+    fn healthy_component_passes_every_check() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(temp.path().join("VERSION"), "1.2.3\n").expect("version");
+        let mut component = fixture(temp.path());
+        component.version_targets = Some(vec![VersionTarget {
+            file: "VERSION".to_string(),
+            pattern: Some(r"(\d+\.\d+\.\d+)".to_string()),
+            ..Default::default()
+        }]);
+        component.build_artifact = Some("build/fixture.zip".to_string());
+        component.extract_command = Some("unzip -o {{artifact}}".to_string());
+        component.scripts = Some(homeboy_core::component::ComponentScriptsConfig {
+            build: vec!["make".to_string()],
+            ..Default::default()
+        });
+
+        let report = component_doctor(&component);
+
+        assert_eq!(
+            report.status,
+            DoctorCheckStatus::Pass,
+            "{:?}",
+            report.checks
+        );
+        assert!(!report.has_failures());
+    }
+
+    #[test]
+    fn missing_local_path_fails_and_skips_dependent_checks() {
+        let component = fixture(Path::new("/nonexistent/homeboy-doctor-fixture"));
+
+        let report = component_doctor(&component);
+
+        assert!(report.has_failures());
+        assert_eq!(status_of(&report, "local_path"), DoctorCheckStatus::Fail);
+        assert_eq!(status_of(&report, "version"), DoctorCheckStatus::Warn);
+        assert_eq!(status_of(&report, "artifact"), DoctorCheckStatus::Warn);
+    }
+
+    #[test]
+    fn unparseable_version_and_unknown_strategy_fail() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(temp.path().join("VERSION"), "no version here").expect("version");
+        let mut component = fixture(temp.path());
+        component.version_targets = Some(vec![VersionTarget {
+            file: "VERSION".to_string(),
+            pattern: Some(r"(\d+\.\d+\.\d+)".to_string()),
+            ..Default::default()
+        }]);
+        component.deploy_strategy = Some("ftp".to_string());
+        component.build_command = Some("make".to_string());
+
+        let report = component_doctor(&component);
+
+        assert_eq!(status_of(&report, "version"), DoctorCheckStatus::Fail);
+        assert_eq!(status_of(&report, "build"), DoctorCheckStatus::Fail);
+        assert_eq!(status_of(&report, "artifact"), DoctorCheckStatus::Fail);
+    }
+}
@@ -67,11 +67,10 @@ pub(super) fn remote_version_after_deploy_effect(
 #[cfg(test)]
 mod tests {
     use super::remote_version_after_deploy_effect;
+    use crate::deploy::local_test_client;
     use crate::deploy::types::DeployEffect;
     use homeboy_core::component::{Component, VersionTarget};
     use homeboy_core::project::Project;
-    use homeboy_core::server::SshClient;
-    use std::collections::HashMap;
 
     #[test]
     fn post_effect_version_read_uses_applied_remote_tree() {
@@ -94,6 +93,7 @@ mod tests {
             remote_path: remote_dir.to_string_lossy().to_string(),
             artifact_path: Some("/tmp/plugin.zip".to_string()),
             verified: false,
+            ownership: None,
         };
         let expected_version = "1.2.3".to_string();
 
@@ -101,7 +101,7 @@ mod tests {
             &component,
             &Project::default(),
             temp.path().to_str().expect("base path"),
-            &local_client(),
+            &local_test_client(),
             Some(&effect),
             Some(&expected_version),
         )
@@ -130,6 +130,7 @@ mod tests {
             remote_path: remote_dir.to_string_lossy().to_string(),
             artifact_path: Some("/tmp/plugin.zip".to_string()),
             verified: false,
+            ownership: None,
         };
         let expected_version = "1.2.3".to_string();
 
@@ -137,7 +138,7 @@ mod tests {
             &component,
             &Project::default(),
             temp.path().to_str().expect("base path"),
-            &local_client(),
+            &local_test_client(),
             Some(&effect),
             Some(&expected_version),
         )
@@ -167,6 +168,7 @@ mod tests {
                     .to_string(),
             ),
             verified: false,
+            ownership: None,
         };
         let expected_version = "0.14.0".to_string();
 
@@ -174,7 +176,7 @@ mod tests {
             &component,
             &Project::default(),
             "/srv/site",
-            &local_client(),
+            &local_test_client(),
             Some(&effect),
             Some(&expected_version),
         )
@@ -200,6 +202,7 @@ mod tests {
             remote_path: "wp-content/plugins/sample-plugin-socials".to_string(),
             artifact_path: Some("/srv/staging/.homeboy-sample-plugin-socials.zip".to_string()),
             verified: true,
+            ownership: None,
         };
         let expected_version = "0.14.0".to_string();
 
@@ -207,7 +210,7 @@ mod tests {
             &component,
             &Project::default(),
             "/srv/site",
-            &local_client(),
+            &local_test_client(),
             Some(&effect),
             Some(&expected_version),
         )
@@ -232,7 +235,7 @@ mod tests {
             &component,
             &Project::default(),
             "/srv/site",
-            &local_client(),
+            &local_test_client(),
             None,
             Some(&expected_version),
         )
@@ -240,16 +243,4 @@ mod tests {
 
         assert_eq!(version.as_deref(), Some("1.0.0"));
     }
-
-    fn local_client() -> SshClient {
-        SshClient {
-            host: "localhost".to_string(),
-            user: "test".to_string(),
-            port: 22,
-            identity_file: None,
-            auth: None,
-            is_local: true,
-            env: HashMap::new(),
-        }
-    }
 }
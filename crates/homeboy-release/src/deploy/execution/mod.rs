@@ -7,6 +7,7 @@ mod prepare;
 mod release_plan;
 mod strategies;
 
+pub(super) use preflight::artifact_requires_extract_command;
 pub(super) use prepare::{
     execute_preflighted_component_deploy, prepare_component_deploy, PreparedComponentDeploy,
 };
@@ -55,11 +56,13 @@ mod tests {
         artifact_requires_component_extract_command, resolve_preflight_artifact_path,
         validate_predeploy_artifact_version,
     };
-    use super::prepare::{failed_component_deploy_result, prepare_component_deploy};
+    use super::prepare::{
+        failed_component_deploy_result, prepare_component_deploy, run_pre_deploy_command,
+    };
     use super::release_plan::{release_artifact_plan, should_try_download_release_artifact};
     use super::strategies::cleanup_deploy_build_artifact;
     use super::{bound_captured_read, ReleaseArtifactPlan, ARTIFACT_VERSION_READ_LIMIT_BYTES};
-    use crate::deploy::types::{DeployConfig, PreparedDeployArtifact};
+    use crate::deploy::types::{DeployConfig, DeployTransport, PreparedDeployArtifact};
     use homeboy_core::component::{
         ArtifactInput, Component, ComponentScriptsConfig, VersionTarget,
     };
@@ -119,6 +122,26 @@ mod tests {
         assert_eq!(result.error.as_deref(), Some("deploy failed"));
     }
 
+    #[test]
+    fn pre_deploy_command_runs_in_local_path_and_reports_failure_exit_code() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let mut component = Component {
+            id: "fixture".to_string(),
+            local_path: temp.path().display().to_string(),
+            ..Component::default()
+        };
+        assert_eq!(run_pre_deploy_command(&component), Ok(None));
+
+        component.pre_deploy_command = Some("touch compiled.mo".to_string());
+        assert_eq!(run_pre_deploy_command(&component), Ok(Some(0)));
+        assert!(temp.path().join("compiled.mo").exists());
+
+        component.pre_deploy_command = Some("echo broken >&2; exit 3".to_string());
+        let (exit_code, error) = run_pre_deploy_command(&component).expect_err("failure");
+        assert_eq!(exit_code, 3);
+        assert!(error.contains("pre_deploy_command failed for 'fixture' (exit 3): broken"));
+    }
+
     #[test]
     fn prepared_artifact_skips_local_build_invocation() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -170,6 +193,12 @@ mod tests {
                 source_commit: "0123456789abcdef".to_string(),
             }),
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         let prepared = prepare_component_deploy(
@@ -251,6 +280,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         let result = resolve_preflight_artifact_path(
@@ -317,6 +352,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         assert!(!should_try_download_release_artifact(
@@ -356,6 +397,12 @@ mod tests {
             tagged: true,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         assert!(!should_try_download_release_artifact(
@@ -406,6 +453,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         assert!(should_try_download_release_artifact(
@@ -456,6 +509,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         assert!(should_try_download_release_artifact(
@@ -497,6 +556,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         match release_artifact_plan(&component, &config, false, false) {
@@ -551,6 +616,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         assert!(should_try_download_release_artifact(
@@ -630,6 +701,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         let artifact = resolve_preflight_artifact_path(
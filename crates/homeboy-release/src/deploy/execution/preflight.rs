@@ -112,10 +112,21 @@ pub(super) fn resolve_preflight_artifact_path(
             }
         }
 
-        match resolve_artifact_path_from_root(
-            artifact_pattern,
-            Some(Path::new(&component.local_path)),
-        ) {
+        let build_root = match component.build_root(Path::new(&component.local_path)) {
+            Ok(root) => root,
+            Err(error) => {
+                return Err(failed_preflight_artifact_result(
+                    component,
+                    base_path,
+                    local_version,
+                    remote_version,
+                    build_exit_code,
+                    error.message,
+                ));
+            }
+        };
+
+        match resolve_artifact_path_from_root(artifact_pattern, Some(&build_root)) {
             Ok(path) => {
                 if config.requested_ref_for(&component.id).is_some()
                     && !path.starts_with(Path::new(&component.local_path))
@@ -439,7 +450,7 @@ fn zip_entry_matches_version_target(entry_name: &str, target_file: &str) -> bool
         .is_some_and(|basename| relative == basename)
 }
 
-fn artifact_requires_extract_command(path: &Path) -> bool {
+pub(in crate::deploy) fn artifact_requires_extract_command(path: &Path) -> bool {
     path.extension()
         .and_then(|ext| ext.to_str())
         .map(|ext| matches!(ext, "zip" | "tar" | "gz" | "tgz"))
@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use homeboy_core::component::Component;
 use homeboy_core::context::RemoteProjectContext;
 use homeboy_core::project::Project;
+use homeboy_core::server::execute_local_command_in_dir;
 use homeboy_extension::build;
 
 use super::super::generated_artifacts::GeneratedBuildArtifactCleanupGuard;
@@ -25,6 +26,7 @@ pub(crate) struct PreparedComponentDeploy {
     pub local_version: Option<String>,
     pub remote_version: Option<String>,
     pub build_exit_code: Option<i32>,
+    pub pre_deploy_exit_code: Option<i32>,
     pub artifact_path: Option<PathBuf>,
     pub artifact_source: Option<DeployArtifactSource>,
     pub build_provenance: BuildProvenance,
@@ -135,6 +137,24 @@ pub(crate) fn prepare_component_deploy(
         .with_build_exit_code(build_exit_code));
     }
 
+    // Local pre-deploy step (runs against the source tree, so downloaded or
+    // prepared artifacts skip it).
+    let pre_deploy_exit_code = if release_artifact.is_none() {
+        run_pre_deploy_command(component).map_err(|(exit_code, error)| {
+            failed_component_deploy_result(
+                component,
+                base_path,
+                local_version.clone(),
+                remote_version.clone(),
+                build_exit_code,
+                error,
+            )
+            .with_pre_deploy_exit_code(Some(exit_code))
+        })?
+    } else {
+        None
+    };
+
     // Auto-resolve remote_path from linked extension deploy policy when not explicitly set.
     // This is a deploy-time safety net; the primary resolution happens in
     // resolve_project_component (#812).
@@ -249,6 +269,7 @@ pub(crate) fn prepare_component_deploy(
         local_version,
         remote_version,
         build_exit_code,
+        pre_deploy_exit_code,
         artifact_path,
         artifact_source,
         build_provenance,
@@ -267,8 +288,8 @@ pub(crate) fn execute_preflighted_component_deploy(
     // Dispatch by deploy strategy
     let strategy = component.deploy_strategy.as_deref().unwrap_or("rsync");
 
-    if strategy == "git" {
-        return execute_git_deploy(
+    let result = if strategy == "git" {
+        execute_git_deploy(
             component,
             &prepared.config,
             ctx,
@@ -276,21 +297,56 @@ pub(crate) fn execute_preflighted_component_deploy(
             &prepared.install_dir,
             prepared.local_version.clone(),
             prepared.remote_version.clone(),
-        );
-    }
-
-    if strategy == "file" {
-        return execute_file_deploy(
+        )
+    } else if strategy == "file" {
+        execute_file_deploy(
             component,
+            &prepared.config,
             ctx,
             base_path,
             &prepared.install_dir,
             prepared.local_version.clone(),
             prepared.remote_version.clone(),
-        );
+        )
+    } else {
+        execute_artifact_deploy(prepared, ctx, base_path, project)
+    };
+
+    result.with_pre_deploy_exit_code(prepared.pre_deploy_exit_code)
+}
+
+/// Run the component's `pre_deploy_command` locally in `local_path`.
+///
+/// Returns the exit code when a command ran, or `(exit_code, error)` when it failed.
+pub(super) fn run_pre_deploy_command(
+    component: &Component,
+) -> std::result::Result<Option<i32>, (i32, String)> {
+    let Some(command) = component
+        .pre_deploy_command
+        .as_deref()
+        .filter(|command| !command.trim().is_empty())
+    else {
+        return Ok(None);
+    };
+
+    homeboy_core::log_status!("deploy", "pre-deploy> {}", command);
+    let output = execute_local_command_in_dir(command, Some(&component.local_path), None);
+    if output.success {
+        return Ok(Some(output.exit_code));
     }
 
-    execute_artifact_deploy(prepared, ctx, base_path, project)
+    let detail = if output.stderr.trim().is_empty() {
+        output.stdout.trim()
+    } else {
+        output.stderr.trim()
+    };
+    Err((
+        output.exit_code,
+        format!(
+            "pre_deploy_command failed for '{}' (exit {}): {}",
+            component.id, output.exit_code, detail
+        ),
+    ))
 }
 
 pub(super) fn failed_component_deploy_result(
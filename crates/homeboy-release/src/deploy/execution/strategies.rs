@@ -6,10 +6,12 @@ use homeboy_core::context::RemoteProjectContext;
 use homeboy_core::error::Result;
 use homeboy_core::project::Project;
 
+use super::super::backup::backup_before_deploy;
 use super::super::effect::remote_version_after_deploy_effect;
 use super::super::generated_artifacts::GeneratedBuildArtifactCleanupGuard;
+use super::super::manifest;
 use super::super::planning::{calculate_directory_size, format_bytes};
-use super::super::safety_and_artifact::{deploy_artifact, deploy_via_git};
+use super::super::safety_and_artifact::{deploy_artifact, deploy_via_git, ArtifactStaging};
 use super::super::types::{ComponentDeployResult, DeployConfig, DeployResult};
 use super::super::version_overrides::{
     deploy_with_override, find_deploy_override, find_deploy_verification, is_self_deploy,
@@ -84,6 +86,7 @@ pub(super) fn execute_git_deploy(
 /// Upload uses atomic SCP (temp file + mv) to prevent partial writes.
 pub(super) fn execute_file_deploy(
     component: &Component,
+    config: &DeployConfig,
     ctx: &RemoteProjectContext,
     base_path: &str,
     install_dir: &str,
@@ -147,7 +150,8 @@ pub(super) fn execute_file_deploy(
         install_dir
     );
 
-    let deploy_result = super::super::transfer::upload_file(&ctx.client, local_path, install_dir);
+    let deploy_result =
+        super::super::transfer::upload_file(&ctx.client, local_path, install_dir, config.compress);
 
     match deploy_result {
         Ok(super::super::types::DeployResult {
@@ -268,6 +272,23 @@ pub(super) fn execute_artifact_deploy(
         }
     };
 
+    // Keep a copy of what is live so `deploy --rollback` can restore it. A
+    // failed backup is reported but does not block the deploy.
+    let backup_warning = match backup_before_deploy(
+        &ctx.client,
+        &project.id,
+        &component.id,
+        install_dir,
+        prepared.remote_version.as_deref(),
+        config.keep_backups,
+    ) {
+        Ok(_) => None,
+        Err(err) => {
+            homeboy_core::log_status!("deploy", "Warning: {}", err.message);
+            Some(format!("backup_failed: {}", err.message))
+        }
+    };
+
     // Look up verification from extensions
     let verification = find_deploy_verification(install_dir);
 
@@ -285,6 +306,8 @@ pub(super) fn execute_artifact_deploy(
                 project.domain.as_deref(),
                 component.remote_owner.as_deref(),
                 component.cli_path.as_deref(),
+                config.compress,
+                config.verify_checksum,
             )
         } else {
             deploy_artifact(
@@ -294,6 +317,19 @@ pub(super) fn execute_artifact_deploy(
                 component.extract_command.as_deref(),
                 verification.as_ref(),
                 component.remote_owner.as_deref(),
+                project
+                    .remote_temp_dir
+                    .as_deref()
+                    .or(ctx.server.remote_temp_dir.as_deref())
+                    .map(|dir| ArtifactStaging {
+                        dir,
+                        project_id: &project.id,
+                        component_id: &component.id,
+                    })
+                    .as_ref(),
+                config.transport,
+                config.compress,
+                config.verify_checksum,
             )
         };
 
@@ -301,8 +337,8 @@ pub(super) fn execute_artifact_deploy(
         Ok(DeployResult {
             success: true,
             exit_code,
+            error: transfer_warning,
             effect,
-            ..
         }) => {
             let reported_remote_version = match remote_version_after_deploy_effect(
                 component,
@@ -329,6 +365,13 @@ pub(super) fn execute_artifact_deploy(
                 }
             };
 
+            manifest::record(
+                &project.id,
+                &component.id,
+                artifact_path,
+                prepared.local_version.as_deref(),
+            );
+
             if prepared.cleanup_local_artifact {
                 cleanup_deploy_build_artifact(component, artifact_path);
             }
@@ -350,6 +393,9 @@ pub(super) fn execute_artifact_deploy(
                 .with_versions(prepared.local_version.clone(), reported_remote_version)
                 .with_remote_path(install_dir.to_string())
                 .with_artifact_inputs(artifact_input_metadata)
+                .with_ownership(effect.and_then(|effect| effect.ownership))
+                .with_warning(backup_warning)
+                .with_warning(transfer_warning)
                 .with_build_exit_code(prepared.build_exit_code)
                 .with_deploy_exit_code(Some(exit_code));
             with_prepared_artifact_source(result, prepared)
@@ -0,0 +1,173 @@
+//! Post-deploy health poll (`deploy --wait-healthy`).
+//!
+//! After a component deploys, GET the project's health URL until it returns
+//! 2xx or the timeout elapses. Unlike the smoke check this tolerates a slow
+//! recovery (opcache reset, service restart) and only asks "did the site come
+//! back", so it asserts nothing about the response body.
+
+use std::time::Duration;
+
+use homeboy_core::error::{Error, Result};
+use homeboy_core::http_probe::get_status;
+use homeboy_core::project::Project;
+
+use super::types::{DeployHealth, DeployHealthStatus, DeployHealthWait};
+
+/// Per-request timeout for a single health poll.
+const HEALTH_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Resolve the URL `--wait-healthy` polls: `health_path` as-is when it is an
+/// absolute URL, otherwise joined onto `https://<domain>`.
+pub(super) fn health_url(project: &Project) -> Result<String> {
+    let path = project
+        .health_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .unwrap_or("/");
+    if path.starts_with("http://") || path.starts_with("https://") {
+        return Ok(path.to_string());
+    }
+
+    let domain = project
+        .domain
+        .as_deref()
+        .map(|d| d.trim().trim_end_matches('/'))
+        .filter(|d| !d.is_empty())
+        .ok_or_else(|| {
+            Error::validation_invalid_argument(
+                "wait-healthy",
+                format!(
+                    "Project '{}' has no domain to health-check; set domain or an absolute health_path",
+                    project.id
+                ),
+                Some(project.id.clone()),
+                None,
+            )
+        })?;
+    let base = if domain.contains("://") {
+        domain.to_string()
+    } else {
+        format!("https://{}", domain)
+    };
+    let separator = if path.starts_with('/') { "" } else { "/" };
+    Ok(format!("{}{}{}", base, separator, path))
+}
+
+/// Number of polls a wait policy allows: one immediately, then one per
+/// interval until the timeout.
+fn max_attempts(wait: &DeployHealthWait) -> u32 {
+    let interval = wait.interval_secs.max(1);
+    1 + (wait.timeout_secs / interval) as u32
+}
+
+/// Poll `url` until it returns 2xx or the policy's attempts run out.
+pub(super) fn wait_healthy(url: &str, wait: &DeployHealthWait) -> DeployHealth {
+    poll(
+        url,
+        wait,
+        |url| get_status(url, HEALTH_REQUEST_TIMEOUT).map_err(|e| e.message),
+        std::thread::sleep,
+    )
+}
+
+/// Polling loop split out from [`wait_healthy`] so attempt counting is
+/// unit-testable without network I/O or real sleeps.
+fn poll<F, S>(url: &str, wait: &DeployHealthWait, mut fetch: F, mut sleep: S) -> DeployHealth
+where
+    F: FnMut(&str) -> std::result::Result<u16, String>,
+    S: FnMut(Duration),
+{
+    let max_attempts = max_attempts(wait);
+    let mut health = DeployHealth {
+        url: url.to_string(),
+        status: DeployHealthStatus::Unhealthy,
+        attempts: 0,
+        http_status: None,
+        error: None,
+    };
+
+    while health.attempts < max_attempts {
+        if health.attempts > 0 {
+            sleep(Duration::from_secs(wait.interval_secs.max(1)));
+        }
+        health.attempts += 1;
+        match fetch(url) {
+            Ok(status) => {
+                health.http_status = Some(status);
+                health.error = None;
+                if (200..300).contains(&status) {
+                    health.status = DeployHealthStatus::Healthy;
+                    break;
+                }
+            }
+            Err(message) => {
+                health.http_status = None;
+                health.error = Some(message);
+            }
+        }
+    }
+
+    health
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wait() -> DeployHealthWait {
+        DeployHealthWait {
+            interval_secs: 5,
+            timeout_secs: 20,
+        }
+    }
+
+    #[test]
+    fn health_url_joins_path_onto_domain() {
+        let mut project = Project {
+            id: "site".to_string(),
+            domain: Some("example.com".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(health_url(&project).unwrap(), "https://example.com/");
+
+        project.health_path = Some("healthz".to_string());
+        assert_eq!(health_url(&project).unwrap(), "https://example.com/healthz");
+
+        project.health_path = Some("http://127.0.0.1:8080/up".to_string());
+        project.domain = None;
+        assert_eq!(health_url(&project).unwrap(), "http://127.0.0.1:8080/up");
+
+        project.health_path = Some("/up".to_string());
+        assert!(health_url(&project).is_err());
+    }
+
+    #[test]
+    fn healthy_after_recovery_reports_attempts() {
+        let mut responses =
+            vec![Err("connection refused".to_string()), Ok(503), Ok(200)].into_iter();
+        let mut sleeps = Vec::new();
+
+        let health = poll(
+            "https://example.com/",
+            &wait(),
+            |_| responses.next().unwrap(),
+            |d| sleeps.push(d),
+        );
+
+        assert_eq!(health.status, DeployHealthStatus::Healthy);
+        assert_eq!(health.attempts, 3);
+        assert_eq!(health.http_status, Some(200));
+        assert!(health.error.is_none());
+        assert_eq!(sleeps, vec![Duration::from_secs(5); 2]);
+    }
+
+    #[test]
+    fn unhealthy_when_attempts_run_out() {
+        let health = poll("https://example.com/", &wait(), |_| Ok(502), |_| {});
+
+        assert_eq!(health.status, DeployHealthStatus::Unhealthy);
+        assert_eq!(health.attempts, 5);
+        assert_eq!(health.http_status, Some(502));
+    }
+}
@@ -0,0 +1,590 @@
+//! Per-component manifests of the last deployed artifact contents.
+//!
+//! A successful artifact deploy records every file it shipped with a checksum
+//! so `deploy --diff` can preview the next deploy against what actually landed
+//! on the target, not against version strings. `deploy --dry-run` compares the
+//! artifact against a checksum listing taken from the remote itself.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use homeboy_core::component::Component;
+use homeboy_core::engine::shell;
+use homeboy_core::error::{Error, Result};
+use homeboy_core::paths;
+use homeboy_core::server::SshClient;
+
+use super::sha256_file;
+
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployManifest {
+    pub schema_version: u32,
+    pub project_id: String,
+    pub component_id: String,
+    pub artifact: String,
+    pub deployed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Artifact-relative file path to SHA-256 checksum.
+    pub files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployManifestDiff {
+    pub component_id: String,
+    pub artifact: String,
+    /// `None` when this component has never been deployed with a manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_deployed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_deployed_version: Option<String>,
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: usize,
+}
+
+impl DeployManifestDiff {
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.changed.is_empty() || !self.removed.is_empty()
+    }
+}
+
+pub(super) fn manifest_path(project_id: &str, component_id: &str) -> Result<PathBuf> {
+    Ok(paths::homeboy_data()?
+        .join("deploy-manifests")
+        .join(project_id)
+        .join(format!("{component_id}.json")))
+}
+
+pub fn load(project_id: &str, component_id: &str) -> Result<Option<DeployManifest>> {
+    let path = manifest_path(project_id, component_id)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(&path).map_err(|error| {
+        Error::internal_io(
+            error.to_string(),
+            Some(format!("read deploy manifest {}", path.display())),
+        )
+    })?;
+    serde_json::from_str(&contents).map(Some).map_err(|error| {
+        Error::internal_io(
+            error.to_string(),
+            Some(format!("parse deploy manifest {}", path.display())),
+        )
+    })
+}
+
+fn save(manifest: &DeployManifest) -> Result<()> {
+    let path = manifest_path(&manifest.project_id, &manifest.component_id)?;
+    let parent = path.parent().expect("deploy manifest path has parent");
+    fs::create_dir_all(parent).map_err(|error| {
+        Error::internal_io(
+            error.to_string(),
+            Some(format!("create {}", parent.display())),
+        )
+    })?;
+    let temporary = path.with_extension("json.tmp");
+    let contents = serde_json::to_vec_pretty(manifest).map_err(|error| {
+        Error::internal_io(
+            error.to_string(),
+            Some("serialize deploy manifest".to_string()),
+        )
+    })?;
+    fs::write(&temporary, contents).map_err(|error| {
+        Error::internal_io(
+            error.to_string(),
+            Some(format!("write {}", temporary.display())),
+        )
+    })?;
+    fs::rename(&temporary, &path).map_err(|error| {
+        Error::internal_io(
+            error.to_string(),
+            Some(format!("commit {}", path.display())),
+        )
+    })
+}
+
+/// Record what was just deployed. Best-effort: a manifest failure is logged
+/// and never fails the deploy that already succeeded.
+pub(super) fn record(
+    project_id: &str,
+    component_id: &str,
+    artifact_path: &Path,
+    version: Option<&str>,
+) {
+    let result = artifact_files(artifact_path).and_then(|files| {
+        save(&DeployManifest {
+            schema_version: SCHEMA_VERSION,
+            project_id: project_id.to_string(),
+            component_id: component_id.to_string(),
+            artifact: artifact_path.display().to_string(),
+            deployed_at: chrono::Utc::now().to_rfc3339(),
+            version: version.map(str::to_string),
+            files,
+        })
+    });
+    if let Err(error) = result {
+        homeboy_core::log_status!(
+            "deploy",
+            "Warning: could not record deploy manifest for '{}': {}",
+            component_id,
+            error.message
+        );
+    }
+}
+
+/// Drop a component's deploy manifest once the release it describes is no
+/// longer live (e.g. after a rollback). Best-effort, like [`record`].
+pub(super) fn forget(project_id: &str, component_id: &str) {
+    let result =
+        manifest_path(project_id, component_id).and_then(|path| match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(Error::internal_io(
+                error.to_string(),
+                Some(format!("remove {}", path.display())),
+            )),
+        });
+    if let Err(error) = result {
+        homeboy_core::log_status!(
+            "deploy",
+            "Warning: could not remove deploy manifest for '{}': {}",
+            component_id,
+            error.message
+        );
+    }
+}
+
+/// Compare a component's current local artifact against its last deploy manifest.
+pub fn diff_against_last(project_id: &str, component: &Component) -> Result<DeployManifestDiff> {
+    let artifact_path = built_artifact_path("diff", component)?;
+    let current = artifact_files(&artifact_path)?;
+    let previous = load(project_id, &component.id)?;
+    Ok(diff_files(
+        &component.id,
+        &artifact_path,
+        previous.as_ref(),
+        &current,
+    ))
+}
+
+/// Compare a component's current local artifact against the files actually
+/// present in `install_dir` on the remote. Only directory and zip artifacts
+/// are comparable; other artifacts yield `None`.
+pub(super) fn diff_against_remote(
+    client: &SshClient,
+    component: &Component,
+    install_dir: &str,
+) -> Result<Option<DeployManifestDiff>> {
+    let artifact_path = built_artifact_path("dry_run", component)?;
+    let is_zip = artifact_path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("zip"));
+    if !artifact_path.is_dir() && !is_zip {
+        return Ok(None);
+    }
+    let remote = remote_files(client, install_dir)?;
+    let mut current = artifact_files(&artifact_path)?;
+    if is_zip {
+        current = strip_archive_root(current, &remote);
+    }
+    Ok(Some(diff_file_maps(
+        &component.id,
+        &artifact_path,
+        &remote,
+        &current,
+    )))
+}
+
+fn built_artifact_path(field: &str, component: &Component) -> Result<PathBuf> {
+    let artifact_path = super::preparation::artifact_path(component)?;
+    if !artifact_path.exists() {
+        return Err(Error::validation_invalid_argument(
+            field,
+            format!(
+                "Component '{}' has no built artifact at {}",
+                component.id,
+                artifact_path.display()
+            ),
+            Some(component.id.clone()),
+            Some(vec![format!("homeboy build {}", component.id)]),
+        ));
+    }
+    Ok(artifact_path)
+}
+
+/// Checksum every file under `install_dir` on the remote. A missing directory
+/// is an empty listing: everything in the artifact would be added.
+fn remote_files(client: &SshClient, install_dir: &str) -> Result<BTreeMap<String, String>> {
+    let command = format!(
+        "cd {} 2>/dev/null || exit 0; \
+         if command -v sha256sum >/dev/null 2>&1; then find . -type f -exec sha256sum {{}} +; \
+         elif command -v shasum >/dev/null 2>&1; then find . -type f -exec shasum -a 256 {{}} +; \
+         else exit 127; fi",
+        shell::quote_path(install_dir)
+    );
+    let output = client.execute(&command);
+    if !output.success {
+        let reason = if output.exit_code == 127 {
+            "no sha256sum or shasum on the remote".to_string()
+        } else {
+            output.stderr.trim().to_string()
+        };
+        return Err(Error::internal_io(
+            format!("Could not list remote files: {}", reason),
+            Some(install_dir.to_string()),
+        ));
+    }
+    Ok(parse_checksum_listing(&output.stdout))
+}
+
+/// Parse `sha256sum` output (`<hash>  ./<path>`) into path -> checksum.
+fn parse_checksum_listing(listing: &str) -> BTreeMap<String, String> {
+    listing
+        .lines()
+        .filter_map(|line| {
+            let (checksum, path) = line.split_once(char::is_whitespace)?;
+            let path = path.trim_start_matches([' ', '*']);
+            let path = path.strip_prefix("./").unwrap_or(path);
+            (!path.is_empty()).then(|| (path.to_string(), checksum.to_lowercase()))
+        })
+        .collect()
+}
+
+/// Plugin and theme zips usually wrap everything in one top-level directory
+/// that the install directory itself stands for. Drop that prefix when the
+/// remote listing shows it is not a real subdirectory.
+fn strip_archive_root(
+    files: BTreeMap<String, String>,
+    remote: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    let Some(root) = files
+        .keys()
+        .next()
+        .and_then(|path| path.split_once('/'))
+        .map(|(root, _)| format!("{}/", root))
+    else {
+        return files;
+    };
+    let wrapped = files.keys().all(|path| path.starts_with(&root));
+    let root_on_remote = remote.keys().any(|path| path.starts_with(&root));
+    if !wrapped || root_on_remote {
+        return files;
+    }
+    files
+        .into_iter()
+        .map(|(path, checksum)| (path[root.len()..].to_string(), checksum))
+        .collect()
+}
+
+/// Detect the "forgot to bump" case: local and remote versions are equal, yet
+/// the local artifact no longer matches what the last deploy shipped.
+/// Best-effort: anything missing (artifact, manifest, versions) yields `None`.
+pub(super) fn version_stale_warning(
+    project_id: &str,
+    component: &Component,
+    local_version: Option<&str>,
+    remote_version: Option<&str>,
+) -> Option<String> {
+    let version = local_version?;
+    if remote_version != Some(version) {
+        return None;
+    }
+    let artifact_path = super::preparation::artifact_path(component).ok()?;
+    if !artifact_path.exists() {
+        return None;
+    }
+    let previous = load(project_id, &component.id).ok()??;
+    let current = artifact_files(&artifact_path).ok()?;
+    let diff = diff_files(&component.id, &artifact_path, Some(&previous), &current);
+    diff.has_changes().then(|| {
+        format!(
+            "content changed but version unchanged ({}): {} added, {} changed, {} removed since the last deploy; bump the version before deploying",
+            version,
+            diff.added.len(),
+            diff.changed.len(),
+            diff.removed.len()
+        )
+    })
+}
+
+fn diff_files(
+    component_id: &str,
+    artifact_path: &Path,
+    previous: Option<&DeployManifest>,
+    current: &BTreeMap<String, String>,
+) -> DeployManifestDiff {
+    let empty = BTreeMap::new();
+    let previous_files = previous.map(|manifest| &manifest.files).unwrap_or(&empty);
+    let mut diff = diff_file_maps(component_id, artifact_path, previous_files, current);
+    diff.last_deployed_at = previous.map(|manifest| manifest.deployed_at.clone());
+    diff.last_deployed_version = previous.and_then(|manifest| manifest.version.clone());
+    diff
+}
+
+fn diff_file_maps(
+    component_id: &str,
+    artifact_path: &Path,
+    previous_files: &BTreeMap<String, String>,
+    current: &BTreeMap<String, String>,
+) -> DeployManifestDiff {
+    let mut diff = DeployManifestDiff {
+        component_id: component_id.to_string(),
+        artifact: artifact_path.display().to_string(),
+        last_deployed_at: None,
+        last_deployed_version: None,
+        added: Vec::new(),
+        changed: Vec::new(),
+        removed: Vec::new(),
+        unchanged: 0,
+    };
+    for (path, checksum) in current {
+        match previous_files.get(path) {
+            None => diff.added.push(path.clone()),
+            Some(previous) if previous != checksum => diff.changed.push(path.clone()),
+            Some(_) => diff.unchanged += 1,
+        }
+    }
+    diff.removed = previous_files
+        .keys()
+        .filter(|path| !current.contains_key(*path))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// Checksum every file an artifact would ship. Zip archives are hashed per
+/// entry; directories are walked; any other artifact is a single file.
+fn artifact_files(artifact_path: &Path) -> Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    if artifact_path.is_dir() {
+        collect_dir_files(artifact_path, artifact_path, &mut files)?;
+    } else if artifact_path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("zip"))
+    {
+        collect_zip_files(artifact_path, &mut files)?;
+    } else {
+        let name = artifact_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| artifact_path.display().to_string());
+        files.insert(name, sha256_file(artifact_path)?);
+    }
+    Ok(files)
+}
+
+fn collect_dir_files(root: &Path, dir: &Path, files: &mut BTreeMap<String, String>) -> Result<()> {
+    let entries = fs::read_dir(dir).map_err(|error| {
+        Error::internal_io(error.to_string(), Some(format!("read {}", dir.display())))
+    })?;
+    for entry in entries {
+        let path = entry
+            .map_err(|error| {
+                Error::internal_io(error.to_string(), Some(format!("read {}", dir.display())))
+            })?
+            .path();
+        if path.is_dir() {
+            collect_dir_files(root, &path, files)?;
+        } else {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            files.insert(
+                relative.to_string_lossy().replace('\\', "/"),
+                sha256_file(&path)?,
+            );
+        }
+    }
+    Ok(())
+}
+
+fn collect_zip_files(artifact_path: &Path, files: &mut BTreeMap<String, String>) -> Result<()> {
+    let io_error = |error: String| {
+        Error::internal_io(error, Some(format!("read {}", artifact_path.display())))
+    };
+    let file = fs::File::open(artifact_path).map_err(|error| io_error(error.to_string()))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|error| io_error(error.to_string()))?;
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|error| io_error(error.to_string()))?;
+        if entry.is_dir() {
+            continue;
+        }
+        let mut hasher = Sha256::new();
+        let mut buffer = [0u8; 64 * 1024];
+        loop {
+            let read = entry
+                .read(&mut buffer)
+                .map_err(|error| io_error(error.to_string()))?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+        files.insert(entry.name().to_string(), format!("{:x}", hasher.finalize()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deploy::local_test_client;
+    use homeboy_core::test_support::with_isolated_home;
+
+    #[test]
+    fn record_and_diff_report_added_changed_and_removed_files() {
+        with_isolated_home(|home| {
+            let artifact = home.path().join("artifact");
+            fs::create_dir_all(artifact.join("inc")).expect("mkdir");
+            fs::write(artifact.join("plugin.php"), "v1").expect("write");
+            fs::write(artifact.join("inc/old.php"), "old").expect("write");
+            fs::write(artifact.join("readme.txt"), "same").expect("write");
+
+            record("site", "plugin", &artifact, Some("1.0.0"));
+            let previous = load("site", "plugin")
+                .expect("load")
+                .expect("manifest recorded");
+            assert_eq!(previous.files.len(), 3);
+            assert_eq!(previous.version.as_deref(), Some("1.0.0"));
+
+            fs::write(artifact.join("plugin.php"), "v2").expect("write");
+            fs::remove_file(artifact.join("inc/old.php")).expect("remove");
+            fs::write(artifact.join("inc/new.php"), "new").expect("write");
+
+            let current = artifact_files(&artifact).expect("current files");
+            let diff = diff_files("plugin", &artifact, Some(&previous), &current);
+            assert_eq!(diff.added, vec!["inc/new.php".to_string()]);
+            assert_eq!(diff.changed, vec!["plugin.php".to_string()]);
+            assert_eq!(diff.removed, vec!["inc/old.php".to_string()]);
+            assert_eq!(diff.unchanged, 1);
+            assert!(diff.has_changes());
+        });
+    }
+
+    #[test]
+    fn forget_removes_the_recorded_manifest() {
+        with_isolated_home(|home| {
+            let artifact = home.path().join("plugin.php");
+            fs::write(&artifact, "v2").expect("write");
+            record("site", "plugin", &artifact, Some("2.0.0"));
+            assert!(load("site", "plugin").expect("load").is_some());
+
+            forget("site", "plugin");
+            assert!(load("site", "plugin").expect("load").is_none());
+            forget("site", "plugin");
+        });
+    }
+
+    #[test]
+    fn version_stale_warning_flags_changed_content_at_equal_versions() {
+        with_isolated_home(|home| {
+            fs::write(home.path().join("plugin.zip.txt"), "v1").expect("write");
+            let component = Component {
+                id: "plugin".to_string(),
+                local_path: home.path().display().to_string(),
+                build_artifact: Some("plugin.zip.txt".to_string()),
+                ..Default::default()
+            };
+            let artifact = home.path().join("plugin.zip.txt");
+            record("site", "plugin", &artifact, Some("1.0.0"));
+
+            let same = version_stale_warning("site", &component, Some("1.0.0"), Some("1.0.0"));
+            assert_eq!(same, None);
+
+            fs::write(&artifact, "v2").expect("write");
+            let stale = version_stale_warning("site", &component, Some("1.0.0"), Some("1.0.0"))
+                .expect("changed content at the same version warns");
+            assert!(stale.contains("content changed but version unchanged"));
+            assert_eq!(
+                version_stale_warning("site", &component, Some("1.0.1"), Some("1.0.0")),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn remote_diff_reports_files_that_would_be_added_changed_and_deleted() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let artifact = temp.path().join("build/plugin");
+        fs::create_dir_all(artifact.join("inc")).expect("mkdir");
+        fs::write(artifact.join("plugin.php"), "v2").expect("write");
+        fs::write(artifact.join("inc/new.php"), "new").expect("write");
+        fs::write(artifact.join("readme.txt"), "same").expect("write");
+
+        let remote = temp.path().join("remote/plugin");
+        fs::create_dir_all(remote.join("inc")).expect("mkdir");
+        fs::write(remote.join("plugin.php"), "v1").expect("write");
+        fs::write(remote.join("inc/old.php"), "old").expect("write");
+        fs::write(remote.join("readme.txt"), "same").expect("write");
+
+        let component = Component {
+            id: "plugin".to_string(),
+            local_path: temp.path().display().to_string(),
+            build_artifact: Some("build/plugin".to_string()),
+            ..Default::default()
+        };
+        let diff = diff_against_remote(
+            &local_test_client(),
+            &component,
+            &remote.display().to_string(),
+        )
+        .expect("remote diff")
+        .expect("directory artifacts are comparable");
+        assert_eq!(diff.added, vec!["inc/new.php".to_string()]);
+        assert_eq!(diff.changed, vec!["plugin.php".to_string()]);
+        assert_eq!(diff.removed, vec!["inc/old.php".to_string()]);
+        assert_eq!(diff.unchanged, 1);
+
+        let missing = temp.path().join("remote/missing");
+        let diff = diff_against_remote(
+            &local_test_client(),
+            &component,
+            &missing.display().to_string(),
+        )
+        .expect("missing target is an empty listing")
+        .expect("directory artifacts are comparable");
+        assert_eq!(diff.added.len(), 3);
+        assert!(diff.removed.is_empty());
+    }
+
+    #[test]
+    fn archive_root_is_stripped_only_when_the_remote_lacks_it() {
+        let files = BTreeMap::from([
+            ("plugin/plugin.php".to_string(), "a".to_string()),
+            ("plugin/inc/x.php".to_string(), "b".to_string()),
+        ]);
+        let flat_remote = BTreeMap::from([("plugin.php".to_string(), "a".to_string())]);
+        let stripped = strip_archive_root(files.clone(), &flat_remote);
+        assert!(stripped.contains_key("plugin.php"));
+        assert!(stripped.contains_key("inc/x.php"));
+
+        let nested_remote = BTreeMap::from([("plugin/plugin.php".to_string(), "a".to_string())]);
+        assert_eq!(strip_archive_root(files.clone(), &nested_remote), files);
+    }
+
+    #[test]
+    fn checksum_listing_parses_sha256sum_and_shasum_output() {
+        let listing = "ABC123  ./plugin.php\ndef456 *./inc/x.php\n\n";
+        let files = parse_checksum_listing(listing);
+        assert_eq!(files.get("plugin.php").map(String::as_str), Some("abc123"));
+        assert_eq!(files.get("inc/x.php").map(String::as_str), Some("def456"));
+    }
+
+    #[test]
+    fn diff_without_previous_manifest_lists_everything_as_added() {
+        let current = BTreeMap::from([("a.txt".to_string(), "1".to_string())]);
+        let diff = diff_files("plugin", Path::new("build/plugin.zip"), None, &current);
+        assert_eq!(diff.added, vec!["a.txt".to_string()]);
+        assert!(diff.last_deployed_at.is_none());
+    }
+}
@@ -1,8 +1,12 @@
+mod backup;
 pub(crate) mod binding;
+mod doctor;
 mod effect;
 mod execution;
 mod generated_artifacts;
+mod health;
 mod lifecycle;
+mod manifest;
 mod orchestration;
 mod orchestration_ref_checkout;
 mod orchestration_tag_checkout;
@@ -14,22 +18,30 @@ mod policy;
 pub(crate) mod preparation;
 pub(crate) mod provenance;
 mod safety_and_artifact;
+mod self_test;
 mod smoke;
 mod transfer;
 mod types;
 mod version_overrides;
 
 // Public API — re-export types and entry points used outside the deploy module
+pub use backup::{rollback, DEFAULT_KEEP_BACKUPS};
+pub use doctor::{
+    component_doctor, ComponentDoctorCheck, ComponentDoctorReport, DoctorCheckStatus,
+};
+pub use manifest::{diff_against_last, DeployManifest, DeployManifestDiff};
 pub use planning::{
     bucket_release_states, calculate_release_state, calculate_release_state_from_baseline,
     classify_release_state,
 };
+pub use self_test::{self_test, SelfTestCheck, SelfTestReport};
 pub(crate) use types::sha256_file;
 pub use types::{
     compare_deployed_versions, parse_bulk_component_ids, ComponentDeployResult, ComponentStatus,
-    DeployConfig, DeployOrchestrationResult, DeployReason, DeploySummary, MultiDeployResult,
-    MultiDeploySummary, PreparedDeployArtifact, ProjectDeployResult, ReleaseState,
-    ReleaseStateBuckets, ReleaseStateStatus,
+    DeployConfig, DeployHealth, DeployHealthStatus, DeployHealthWait, DeployOrchestrationResult,
+    DeployOwnership, DeployOwnershipSource, DeployOwnershipStatus, DeployReason, DeployRollback,
+    DeploySummary, DeployTransport, MultiDeployResult, MultiDeploySummary, PreparedDeployArtifact,
+    ProjectDeployResult, ReleaseState, ReleaseStateBuckets, ReleaseStateStatus,
 };
 pub use version_overrides::fetch_remote_versions;
 pub use version_overrides::{RemoteVersionProbeFailure, RemoteVersionProbeResult};
@@ -79,7 +91,10 @@ fn run_with_release_artifacts(
     config: &DeployConfig,
     release_artifacts: &mut homeboy_core::git::release_download::ReleaseArtifactStore,
 ) -> Result<DeployOrchestrationResult> {
-    let project = project::load(project_id)?;
+    let mut project = project::load(project_id)?;
+    let mut config = config.clone();
+    planning::expand_component_globs(&mut project, &mut config)?;
+    let config = &config;
     // A version-pinned release asset is resolved remotely before orchestration;
     // requiring its configured checkout to exist would reintroduce a mutable
     // source gate. Other modes retain the existing early local-path validation.
@@ -282,6 +297,12 @@ pub fn run_multi(
             tagged: config.tagged,
             prepared_artifact: config.prepared_artifact.clone(),
             resume_run_id: None,
+            wait_healthy: config.wait_healthy,
+            compress: config.compress,
+            transport: config.transport,
+            verify_checksum: config.verify_checksum,
+            jobs: config.jobs,
+            keep_backups: config.keep_backups,
         };
 
         if lifecycle_run
@@ -481,6 +502,72 @@ fn lifecycle_identity(
     }
 }
 
+/// Deploy components to every fleet project that uses them.
+///
+/// Fleet members without any of the components attached are reported as
+/// `not_applicable` (counted as skipped) rather than attempted. The remaining
+/// projects deploy through [`run_multi`].
+pub fn run_fleet(
+    fleet_id: &str,
+    project_ids: &[String],
+    component_ids: &[String],
+    config: &DeployConfig,
+) -> Result<MultiDeployResult> {
+    let mut applicable = Vec::new();
+    let mut not_applicable = Vec::new();
+    for project_id in project_ids {
+        match project::load(project_id) {
+            Ok(project) if !planning::project_uses_any_component(&project, component_ids) => {
+                not_applicable.push(project_id.clone());
+            }
+            // Unknown projects stay in the run so run_multi reports them.
+            _ => applicable.push(project_id.clone()),
+        }
+    }
+
+    if applicable.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "component",
+            format!(
+                "No projects in fleet '{}' use component(s): {:?}",
+                fleet_id, component_ids
+            ),
+            Some(fleet_id.to_string()),
+            Some(vec![format!("homeboy fleet components {}", fleet_id)]),
+        ));
+    }
+
+    let mut result = run_multi(&applicable, component_ids, config)?;
+    for project_id in &not_applicable {
+        homeboy_core::log_status!(
+            "deploy",
+            "Skipping project '{}' — it does not use {:?}",
+            project_id,
+            component_ids
+        );
+        result.projects.push(ProjectDeployResult {
+            project_id: project_id.clone(),
+            status: "not_applicable".to_string(),
+            error: Some(format!(
+                "Project '{}' does not use component(s) {:?} — not applicable",
+                project_id, component_ids
+            )),
+            results: vec![],
+            summary: DeploySummary {
+                total: 0,
+                succeeded: 0,
+                failed: 0,
+                skipped: 0,
+            },
+            phase_timings: None,
+        });
+    }
+    result.summary.skipped += not_applicable.len() as u32;
+    result.summary.total_projects = result.projects.len() as u32;
+
+    Ok(result)
+}
+
 /// Find all projects that use any of the specified components.
 ///
 /// Used by `--shared` flag to deploy a component to every project that has it.
@@ -518,6 +605,23 @@ pub fn resolve_shared_targets(component_ids: &[String]) -> Result<Vec<String>> {
     Ok(project_ids)
 }
 
+/// Local (non-SSH) client shared by the deploy unit tests.
+#[cfg(test)]
+pub(crate) fn local_test_client() -> homeboy_core::server::SshClient {
+    homeboy_core::server::SshClient {
+        host: "localhost".to_string(),
+        user: "test".to_string(),
+        port: 22,
+        identity_file: None,
+        auth: None,
+        is_local: true,
+        multiplex: false,
+        connect_timeout: 10,
+        retries: 2,
+        env: std::collections::HashMap::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -550,6 +654,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         }
     }
 
@@ -581,6 +691,61 @@ mod tests {
         });
     }
 
+    #[test]
+    fn fleet_deploy_reports_projects_without_the_component_as_not_applicable() {
+        with_isolated_home(|_| {
+            project::save(&Project {
+                id: "site-a".to_string(),
+                base_path: Some("/srv/site-a".to_string()),
+                components: vec![ProjectComponentAttachment {
+                    id: "plugin".to_string(),
+                    local_path: "/tmp/homeboy-missing-component-path".to_string(),
+                    remote_path: Some("wp-content/plugins/plugin".to_string()),
+                }],
+                ..Project::default()
+            })
+            .expect("save site-a");
+            project::save(&Project {
+                id: "site-b".to_string(),
+                base_path: Some("/srv/site-b".to_string()),
+                ..Project::default()
+            })
+            .expect("save site-b");
+
+            let result = run_fleet(
+                "production",
+                &["site-a".to_string(), "site-b".to_string()],
+                &["plugin".to_string()],
+                &deploy_config(),
+            )
+            .expect("fleet deploy runs");
+
+            let statuses: Vec<(&str, &str)> = result
+                .projects
+                .iter()
+                .map(|p| (p.project_id.as_str(), p.status.as_str()))
+                .collect();
+            assert_eq!(
+                statuses,
+                vec![("site-a", "failed"), ("site-b", "not_applicable")]
+            );
+            assert_eq!(result.summary.total_projects, 2);
+            assert_eq!(result.summary.failed, 1);
+            assert_eq!(result.summary.skipped, 1);
+
+            let error = run_fleet(
+                "production",
+                &["site-b".to_string()],
+                &["plugin".to_string()],
+                &deploy_config(),
+            )
+            .expect_err("a fleet with no users of the component has nothing to deploy");
+            assert!(error
+                .message
+                .contains("No projects in fleet 'production' use"));
+        });
+    }
+
     #[test]
     fn prepared_artifact_mismatch_fails_before_project_ssh_resolution() {
         with_isolated_home(|_| {
@@ -8,22 +8,28 @@ use homeboy_core::project::Project;
 
 use super::execution::{
     execute_preflighted_component_deploy, release_artifact_plan, resolve_planned_release_artifact,
-    ReleaseArtifactPlan,
+    PreparedComponentDeploy, ReleaseArtifactPlan,
 };
+use super::health;
 use super::orchestration_ref_checkout::{ExactRefCheckout, ExactRefIdentity};
 use super::orchestration_tag_checkout::{checkout_deploy_tags, restore_branches};
 use super::path_roots::{project_with_detected_path_roots, resolve_effective_remote_path};
 use super::planning::{load_project_components, plan_components};
-use super::types::{ComponentDeployResult, DeployConfig, DeployOrchestrationResult, DeploySummary};
+use super::types::{
+    ComponentDeployResult, DeployConfig, DeployHealthStatus, DeployHealthWait,
+    DeployOrchestrationResult, DeploySummary,
+};
 use super::version_overrides::fetch_remote_versions_for_project;
 use homeboy_core::git::release_download::{ReleaseArtifactLease, ReleaseArtifactStore};
 
 mod modes;
+mod parallel;
 mod preflight;
 mod prepared_payloads;
 mod smoke_check;
 
 use modes::{extension_skipped_results, run_check_mode, run_dry_run_mode};
+use parallel::deploy_batches;
 use preflight::{
     check_uncommitted_changes, check_unreleased_commits, guard_head_matches_invocation_checkout,
     guard_local_build_downgrades, guard_local_build_source_freshness, local_build_components,
@@ -45,6 +51,7 @@ pub(super) fn deploy_components(
     let loaded = load_project_components(project, &config.component_ids, config.check)?;
     validate_preflighted_component_identities(&loaded.deployable, config)?;
     validate_supported_build_configs(&loaded.deployable)?;
+    validate_remote_owners(&loaded.deployable)?;
 
     // In check mode, components whose required extensions are missing are skipped
     // (not hard-failed) so the read-only diff still reports everything else.
@@ -61,6 +68,7 @@ pub(super) fn deploy_components(
                 failed: 0,
                 skipped,
             },
+            deploy_order: Vec::new(),
         });
     }
 
@@ -98,6 +106,7 @@ pub(super) fn deploy_components(
         base_path,
         &ctx.client,
     )?;
+    let deploy_order = reported_deploy_order(&components);
 
     if components.is_empty() {
         return Ok(DeployOrchestrationResult {
@@ -108,6 +117,7 @@ pub(super) fn deploy_components(
                 failed: 0,
                 skipped: 0,
             },
+            deploy_order: Vec::new(),
         });
     }
 
@@ -202,7 +212,7 @@ pub(super) fn deploy_components(
 
     // Check and dry-run modes return early without building or deploying
     if config.check {
-        return Ok(run_check_mode(
+        let mut result = run_check_mode(
             &components,
             &local_versions,
             &remote_versions,
@@ -210,19 +220,31 @@ pub(super) fn deploy_components(
             &project,
             base_path,
             config,
-        ));
+        );
+        result.deploy_order = deploy_order;
+        return Ok(result);
     }
     if config.dry_run {
-        return Ok(run_dry_run_mode(
+        let mut result = run_dry_run_mode(
             &components,
             &local_versions,
             &remote_versions,
             &project,
             base_path,
             config,
-        )?);
+            &ctx.client,
+        )?;
+        result.deploy_order = deploy_order;
+        return Ok(result);
     }
 
+    // Resolve the health URL before any mutation so a project without a
+    // domain fails `--wait-healthy` up front instead of after deploying.
+    let health_check = config
+        .wait_healthy
+        .map(|wait| health::health_url(&project).map(|url| (url, wait)))
+        .transpose()?;
+
     // Only local builds require mutable checkout safety checks. Release assets are
     // resolved and verified above and must not read or alter a source checkout.
     let local_build_components: Vec<Component> = components
@@ -308,16 +330,15 @@ pub(super) fn deploy_components(
                     failed,
                     skipped: 0,
                 },
+                deploy_order,
             });
         }
     };
 
     // Execute deployments only after every component passed the local preflight.
-    let mut results: Vec<ComponentDeployResult> = vec![];
-    let mut succeeded: u32 = 0;
-    let mut failed: u32 = 0;
-
-    for prepared in prepared_deployments.iter() {
+    let deploy_prepared = |prepared: &PreparedComponentDeploy,
+                           ctx: &RemoteProjectContext|
+     -> ComponentDeployResult {
         let component = &prepared.component;
 
         let mut result = execute_preflighted_component_deploy(prepared, ctx, base_path, &project);
@@ -381,14 +402,58 @@ pub(super) fn deploy_components(
         }
         result = result.with_build_provenance(build_provenance);
 
-        if result.status == "deployed" {
-            succeeded += 1;
-        } else {
-            failed += 1;
+        if let Some((url, wait)) = health_check.as_ref() {
+            if result.status == "deployed" {
+                result = wait_for_health(result, url, wait);
+            }
         }
-        results.push(result);
+        result
+    };
+
+    // With `--jobs N`, independent components deploy concurrently in batches;
+    // each worker gets its own SSH client and results keep the planned order.
+    let mut results: Vec<ComponentDeployResult> = Vec::with_capacity(prepared_deployments.len());
+    let batches = deploy_batches(
+        prepared_deployments
+            .iter()
+            .map(|prepared| &prepared.component),
+        config.jobs,
+    );
+    for batch in batches {
+        let batch = &prepared_deployments[batch];
+        if batch.len() == 1 {
+            results.push(deploy_prepared(&batch[0], ctx));
+            continue;
+        }
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = batch
+                .iter()
+                .map(|prepared| {
+                    let worker_ctx = ctx.clone();
+                    let deploy_prepared = &deploy_prepared;
+                    scope.spawn(move || deploy_prepared(prepared, &worker_ctx))
+                })
+                .collect();
+            for (handle, prepared) in handles.into_iter().zip(batch) {
+                results.push(handle.join().unwrap_or_else(|_| {
+                    ComponentDeployResult::failed(
+                        &prepared.component,
+                        base_path,
+                        prepared.local_version.clone(),
+                        prepared.remote_version.clone(),
+                        "Deploy worker panicked".to_string(),
+                    )
+                }));
+            }
+        });
     }
 
+    let mut succeeded = results
+        .iter()
+        .filter(|result| result.status == "deployed")
+        .count() as u32;
+    let mut failed = results.len() as u32 - succeeded;
+
     // Restore original branches after deployment
     if !tag_checkouts.is_empty() {
         restore_branches(&tag_checkouts);
@@ -424,9 +489,61 @@ pub(super) fn deploy_components(
             failed,
             skipped: 0,
         },
+        deploy_order,
     })
 }
 
+/// Poll the health URL after a component deployed, marking the result
+/// `degraded` (which counts as failed) when it never returns 2xx.
+fn wait_for_health(
+    result: ComponentDeployResult,
+    url: &str,
+    wait: &DeployHealthWait,
+) -> ComponentDeployResult {
+    homeboy_core::log_status!(
+        "deploy",
+        "Waiting for '{}' to report healthy at {}",
+        result.id,
+        url
+    );
+    let health = health::wait_healthy(url, wait);
+    if health.status == DeployHealthStatus::Healthy {
+        return result.with_health(health);
+    }
+
+    let last = match (&health.http_status, &health.error) {
+        (Some(status), _) => format!("HTTP {}", status),
+        (None, Some(error)) => error.clone(),
+        (None, None) => "no response".to_string(),
+    };
+    let message = format!(
+        "{} did not become healthy after {} attempt(s) over {}s (last: {})",
+        url, health.attempts, wait.timeout_secs, last
+    );
+    homeboy_core::log_status!("deploy", "Warning: {}", message);
+    let mut result = result.with_status("degraded").with_health(health);
+    result.warnings.push(message);
+    result
+}
+
+/// Report the resolved order only when `depends_on`, `deploy_exclusive`, or
+/// `deploy_priority` influenced it, keeping existing deploy output unchanged for
+/// projects without scheduling config.
+fn reported_deploy_order(components: &[Component]) -> Vec<String> {
+    if components.iter().any(|component| {
+        !component.depends_on.is_empty()
+            || component.deploy_exclusive
+            || component.deploy_priority.is_some()
+    }) {
+        components
+            .iter()
+            .map(|component| component.id.clone())
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
 /// Resolve and verify reusable GitHub release assets for a deploy.
 ///
 /// Release assets are immutable remote inputs: resolving them downloads the
@@ -527,6 +644,16 @@ fn validate_supported_build_configs(components: &[Component]) -> Result<()> {
     Ok(())
 }
 
+fn validate_remote_owners(components: &[Component]) -> Result<()> {
+    for component in components {
+        if let Some(owner) = component.remote_owner.as_deref() {
+            super::permissions::validate_remote_owner(&component.id, owner)?;
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_effective_remote_paths(
     components: &[Component],
     project: &Project,
@@ -542,10 +669,12 @@ fn validate_effective_remote_paths(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::deploy::local_test_client;
     use crate::deploy::orchestration_tag_checkout::{
         checkout_deploy_tags, deploy_tag_for_version, restore_branches, TagCheckout,
     };
     use crate::deploy::planning::{load_project_components, ExtensionSkippedComponent};
+    use crate::deploy::DeployTransport;
     use homeboy_core::component::ComponentScriptsConfig;
     use homeboy_core::project::ProjectComponentAttachment;
     use homeboy_core::test_support::{home_env_guard, with_isolated_home};
@@ -612,6 +741,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         }
     }
 
@@ -1321,6 +1456,7 @@ mod tests {
             &Project::default(),
             "",
             &config,
+            &local_test_client(),
         )
         .expect("default dry-run should plan the latest deploy tag");
 
@@ -1361,6 +1497,7 @@ mod tests {
             &Project::default(),
             "",
             &config,
+            &local_test_client(),
         )
         .expect("--head dry-run should plan the current branch");
 
@@ -1445,6 +1582,7 @@ mod tests {
             &Project::default(),
             "",
             &config,
+            &local_test_client(),
         )
         .expect("unchanged attachment must reach exact-ref dry-run planning");
 
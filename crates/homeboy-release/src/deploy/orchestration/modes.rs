@@ -3,10 +3,13 @@ use std::collections::HashMap;
 use homeboy_core::component::Component;
 use homeboy_core::error::{Error, Result};
 use homeboy_core::project::Project;
+use homeboy_core::server::SshClient;
 
 use super::super::execution::{release_artifact_plan, ReleaseArtifactPlan};
+use super::super::manifest;
 use super::super::orchestration_ref_checkout::resolve_exact_ref;
 use super::super::orchestration_tag_checkout::{deploy_tag_for_version, TagCheckout};
+use super::super::path_roots::resolve_effective_remote_path;
 use super::super::planning::{
     calculate_component_status_with_git_cache, calculate_release_state, ExtensionSkippedComponent,
     GitProbeCache,
@@ -44,7 +47,7 @@ pub(super) fn run_check_mode(
             if let Some(state) = release_state {
                 result = result.with_release_state(state);
             }
-            result
+            with_version_stale_warning(result, c, project)
         })
         .collect();
 
@@ -63,6 +66,7 @@ pub(super) fn run_check_mode(
             failed: 0,
             skipped,
         },
+        deploy_order: Vec::new(),
     }
 }
 
@@ -98,6 +102,7 @@ pub(super) fn run_dry_run_mode(
     project: &Project,
     base_path: &str,
     config: &DeployConfig,
+    client: &SshClient,
 ) -> Result<DeployOrchestrationResult> {
     let mut git_probe_cache = GitProbeCache::default();
     let results: Vec<ComponentDeployResult> = components
@@ -152,10 +157,11 @@ pub(super) fn run_dry_run_mode(
                 result = result.with_deployed_ref(deploy_ref);
             }
             result = with_dry_run_artifact_plan(result, c, config);
+            result = with_dry_run_changed_files(result, c, project, base_path, client);
             if config.check {
                 result = result.with_component_status(status);
             }
-            Ok(result)
+            Ok(with_version_stale_warning(result, c, project))
         })
         .collect::<Result<Vec<_>>>()?;
 
@@ -168,14 +174,93 @@ pub(super) fn run_dry_run_mode(
             failed: 0,
             skipped: 0,
         },
+        deploy_order: Vec::new(),
     })
 }
 
+/// Flag equal local/remote versions whose artifact content changed since the
+/// last recorded deploy manifest.
+fn with_version_stale_warning(
+    mut result: ComponentDeployResult,
+    component: &Component,
+    project: &Project,
+) -> ComponentDeployResult {
+    result.version_stale_warning = manifest::version_stale_warning(
+        &project.id,
+        component,
+        result.local_version.as_deref(),
+        result.remote_version.as_deref(),
+    );
+    result
+}
+
+/// Most removed files listed in the dry-run deletion warning.
+const REMOVED_FILES_SHOWN: usize = 10;
+
+/// Compare a locally built artifact against the remote target so a dry run
+/// shows which files would change, and warns before any would be deleted.
+/// Best-effort: a missing artifact or unreadable remote becomes a warning.
+fn with_dry_run_changed_files(
+    mut result: ComponentDeployResult,
+    component: &Component,
+    project: &Project,
+    base_path: &str,
+    client: &SshClient,
+) -> ComponentDeployResult {
+    if result.artifact_source != Some(DeployArtifactSource::LocalBuild) {
+        return result;
+    }
+    let diff = resolve_effective_remote_path(project, component, base_path)
+        .and_then(|install_dir| manifest::diff_against_remote(client, component, &install_dir));
+    let diff = match diff {
+        Ok(Some(diff)) => diff,
+        Ok(None) => return result,
+        Err(err) => {
+            result
+                .warnings
+                .push(format!("changed_files_unavailable: {}", err.message));
+            return result;
+        }
+    };
+
+    if !diff.removed.is_empty() {
+        let shown = diff.removed[..diff.removed.len().min(REMOVED_FILES_SHOWN)].join(", ");
+        let more = diff.removed.len().saturating_sub(REMOVED_FILES_SHOWN);
+        result.warnings.push(format!(
+            "remote_files_removed: {} remote file(s) are not in the artifact and would be deleted: {}{}",
+            diff.removed.len(),
+            shown,
+            if more > 0 {
+                format!(" (and {} more)", more)
+            } else {
+                String::new()
+            }
+        ));
+    }
+    let mut changed_files = diff.added;
+    changed_files.extend(diff.changed);
+    changed_files.extend(diff.removed);
+    changed_files.sort();
+    result.changed_files = changed_files;
+    result
+}
+
 fn with_dry_run_artifact_plan(
     mut result: ComponentDeployResult,
     component: &Component,
     config: &DeployConfig,
 ) -> ComponentDeployResult {
+    if let Some(command) = component
+        .pre_deploy_command
+        .as_deref()
+        .filter(|command| !command.trim().is_empty())
+    {
+        result.warnings.push(format!(
+            "pre-deploy: would run `{command}` locally in {}",
+            component.local_path
+        ));
+    }
+
     let deploy_config = component.deploy_config();
     let is_git_deploy = deploy_config.is_git_deploy();
     let is_file_deploy = deploy_config.is_file_deploy();
@@ -287,7 +372,8 @@ fn latest_deploy_tag(component: &Component, expected_version: Option<&str>) -> R
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::deploy::PreparedDeployArtifact;
+    use crate::deploy::local_test_client;
+    use crate::deploy::{DeployTransport, PreparedDeployArtifact};
     use std::collections::BTreeMap;
     use std::path::Path;
     use std::process::Command;
@@ -338,6 +424,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         let result = run_dry_run_mode(
@@ -347,6 +439,7 @@ mod tests {
             &Project::default(),
             "/srv/site",
             &config,
+            &local_test_client(),
         )
         .expect("dry-run plan");
         let evidence = &result.results[0];
@@ -433,6 +526,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         let result = run_dry_run_mode(
@@ -442,6 +541,7 @@ mod tests {
             &Project::default(),
             "/srv/site",
             &config,
+            &local_test_client(),
         )
         .expect("multi-ref dry-run plan");
 
@@ -465,6 +565,7 @@ mod tests {
             &Project::default(),
             "/srv/site",
             &invalid_config,
+            &local_test_client(),
         )
         .expect_err("an unresolved member ref must fail before any mutation");
         assert!(error.message.contains("missing-ref"));
@@ -526,6 +627,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
         config.prepared_artifact = Some(PreparedDeployArtifact {
             component_id: "fixture".to_string(),
@@ -545,6 +652,7 @@ mod tests {
             &Project::default(),
             "/srv/site",
             &config,
+            &local_test_client(),
         )
         .expect("dry-run plan");
         let evidence = &result.results[0];
@@ -593,6 +701,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         };
 
         let checked = run_check_mode(
@@ -615,6 +729,7 @@ mod tests {
             &Project::default(),
             "/srv/site",
             &config,
+            &local_test_client(),
         )
         .expect("dry-run must report rather than refuse a remote-newer version");
         assert_eq!(planned.results[0].status, "planned");
@@ -622,6 +737,47 @@ mod tests {
         assert_eq!(planned.results[0].remote_version.as_deref(), Some("1.3.0"));
     }
 
+    #[test]
+    fn dry_run_lists_changed_files_and_warns_before_remote_deletions() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let artifact = temp.path().join("build/demo");
+        std::fs::create_dir_all(&artifact).expect("mkdir");
+        std::fs::write(artifact.join("demo.php"), "v2").expect("write");
+        std::fs::write(artifact.join("readme.txt"), "same").expect("write");
+        let remote = temp.path().join("site/plugins/demo");
+        std::fs::create_dir_all(&remote).expect("mkdir");
+        std::fs::write(remote.join("demo.php"), "v1").expect("write");
+        std::fs::write(remote.join("readme.txt"), "same").expect("write");
+        std::fs::write(remote.join("legacy.php"), "old").expect("write");
+
+        let component = Component {
+            id: "demo".to_string(),
+            local_path: temp.path().display().to_string(),
+            remote_path: "plugins/demo".to_string(),
+            build_artifact: Some("build/demo".to_string()),
+            ..Default::default()
+        };
+        let project = Project::default();
+        let base_path = temp.path().join("site").display().to_string();
+        let result = ComponentDeployResult::new_for_project(&component, &project, &base_path)
+            .with_artifact_source(DeployArtifactSource::LocalBuild);
+
+        let result = with_dry_run_changed_files(
+            result,
+            &component,
+            &project,
+            &base_path,
+            &local_test_client(),
+        );
+        assert_eq!(
+            result.changed_files,
+            vec!["demo.php".to_string(), "legacy.php".to_string()]
+        );
+        assert!(result.warnings.iter().any(|warning| warning
+            .starts_with("remote_files_removed: 1 remote file(s)")
+            && warning.ends_with("legacy.php")));
+    }
+
     fn git(path: &Path, args: &[&str]) {
         let output = Command::new("git")
             .args(args)
@@ -0,0 +1,86 @@
+//! Concurrency batching for `deploy --jobs`.
+//!
+//! Components arrive already ordered by `order_by_dependencies`. They are cut
+//! into consecutive batches that may deploy concurrently: a batch never holds
+//! more than `jobs` components, never holds a component together with one it
+//! `depends_on`, and a `deploy_exclusive` component always runs alone. Batches
+//! run one after another, so the scheduling guarantees of the sequential order
+//! still hold.
+
+use std::ops::Range;
+
+use homeboy_core::component::Component;
+
+/// Split ordered components into index ranges that can deploy concurrently.
+pub(super) fn deploy_batches<'a>(
+    components: impl IntoIterator<Item = &'a Component>,
+    jobs: u32,
+) -> Vec<Range<usize>> {
+    let jobs = jobs.max(1) as usize;
+    let mut batches = Vec::new();
+    let mut batch_ids: Vec<&str> = Vec::new();
+    let mut start = 0;
+
+    for (idx, component) in components.into_iter().enumerate() {
+        let depends_on_batch = component
+            .depends_on
+            .iter()
+            .any(|dependency| batch_ids.contains(&dependency.as_str()));
+        let starts_new_batch = !batch_ids.is_empty()
+            && (component.deploy_exclusive || batch_ids.len() == jobs || depends_on_batch);
+        if starts_new_batch {
+            batches.push(start..idx);
+            batch_ids.clear();
+            start = idx;
+        }
+
+        batch_ids.push(component.id.as_str());
+        if component.deploy_exclusive {
+            batches.push(start..idx + 1);
+            batch_ids.clear();
+            start = idx + 1;
+        }
+    }
+    if !batch_ids.is_empty() {
+        batches.push(start..start + batch_ids.len());
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(id: &str, depends_on: &[&str], exclusive: bool) -> Component {
+        let mut component = Component::new(id.to_string(), String::new(), String::new(), None);
+        component.depends_on = depends_on.iter().map(|dep| dep.to_string()).collect();
+        component.deploy_exclusive = exclusive;
+        component
+    }
+
+    #[test]
+    fn single_job_deploys_one_component_at_a_time() {
+        let components = [
+            component("a", &[], false),
+            component("b", &[], false),
+            component("c", &[], false),
+        ];
+
+        assert_eq!(deploy_batches(&components, 1), vec![0..1, 1..2, 2..3]);
+    }
+
+    #[test]
+    fn batches_respect_job_limit_dependencies_and_exclusivity() {
+        let components = [
+            component("a", &[], false),
+            component("b", &[], false),
+            component("c", &["a"], false),
+            component("d", &[], false),
+            component("e", &[], false),
+            component("migrate", &[], true),
+            component("cache", &[], true),
+        ];
+
+        assert_eq!(deploy_batches(&components, 3), vec![0..2, 2..5, 5..6, 6..7]);
+    }
+}
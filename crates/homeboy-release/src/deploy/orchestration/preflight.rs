@@ -599,7 +599,7 @@ mod tests {
         guard_deployment_provenance, guard_head_matches_invocation_checkout,
         guard_local_build_downgrades, guard_local_build_source_freshness, local_build_components,
     };
-    use crate::deploy::DeployConfig;
+    use crate::deploy::{DeployConfig, DeployTransport};
     use homeboy_core::component::Component;
     use homeboy_core::project::{
         DeploymentForgeEvidence, DeploymentProvenanceMode, DeploymentProvenancePolicy, Project,
@@ -630,6 +630,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         }
     }
 
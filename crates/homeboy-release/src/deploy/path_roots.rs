@@ -327,8 +327,8 @@ fn strip_path_prefix<'a>(path: &'a str, prefix: &str) -> &'a str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::deploy::local_test_client;
     use homeboy_core::component::{Component, ScopedExtensionConfig};
-    use homeboy_core::server::SshClient;
     use homeboy_core::test_support::with_isolated_home;
     use homeboy_extension::{DeployCapability, ExtensionManifest};
     use std::collections::HashMap;
@@ -390,18 +390,6 @@ mod tests {
         .expect("save extension");
     }
 
-    fn local_client() -> SshClient {
-        SshClient {
-            host: "localhost".to_string(),
-            user: "local".to_string(),
-            port: 22,
-            identity_file: None,
-            auth: None,
-            is_local: true,
-            env: HashMap::new(),
-        }
-    }
-
     #[test]
     fn test_component_remote_path() {
         assert_eq!(
@@ -585,7 +573,7 @@ mod tests {
                 &project,
                 &[component("packages/foo")],
                 "/tmp",
-                &local_client(),
+                &local_test_client(),
             );
 
             assert_eq!(
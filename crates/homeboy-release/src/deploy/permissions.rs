@@ -3,16 +3,21 @@ use homeboy_core::engine::shell;
 use homeboy_core::error::{Error, Result};
 use homeboy_core::server::{CommandOutput, SshClient};
 
+use super::types::{DeployOwnership, DeployOwnershipSource, DeployOwnershipStatus};
+
 /// Fix file permissions after deployment.
+///
+/// Returns the outcome of the best-effort ownership fix, or `None` when no
+/// owner was configured or detectable.
 pub(crate) fn fix_deployed_permissions(
     ssh_client: &SshClient,
     remote_path: &str,
     remote_owner: Option<&str>,
-) -> Result<()> {
+) -> Result<Option<DeployOwnership>> {
     let quoted_path = shell::quote_path(remote_path);
 
     // Step 1: Fix ownership (chown before chmod)
-    fix_deployed_ownership(ssh_client, remote_path, remote_owner, &quoted_path);
+    let ownership = fix_deployed_ownership(ssh_client, remote_path, remote_owner, &quoted_path);
 
     // Step 2: Fix permissions
     let perms = defaults::load_defaults().permissions.remote;
@@ -31,7 +36,41 @@ pub(crate) fn fix_deployed_permissions(
     let file_output = ssh_client.execute(&file_cmd);
     ensure_remote_success(file_output, "chmod files", remote_path)?;
 
-    Ok(())
+    Ok(ownership)
+}
+
+/// Validate a configured `remote_owner` before any remote mutation.
+///
+/// Accepts `user` or `user:group`, where each part is a POSIX-style name or a
+/// numeric ID.
+pub(crate) fn validate_remote_owner(component_id: &str, owner: &str) -> Result<()> {
+    let valid_part = |part: &str| {
+        let mut chars = part.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    };
+    let valid = match owner.split_once(':') {
+        Some((user, group)) => valid_part(user) && valid_part(group),
+        None => valid_part(owner),
+    };
+    if valid {
+        return Ok(());
+    }
+
+    Err(Error::validation_invalid_argument(
+        "remote_owner",
+        format!(
+            "Component '{}' has invalid remote_owner '{}': expected 'user' or 'user:group'",
+            component_id, owner
+        ),
+        Some(owner.to_string()),
+        Some(vec![format!(
+            "Example: homeboy component set {} --json '{{\"remote_owner\": \"www-data:www-data\"}}'",
+            component_id
+        )]),
+    ))
 }
 
 /// Fix ownership of deployed files via chown.
@@ -41,9 +80,9 @@ fn fix_deployed_ownership(
     remote_path: &str,
     remote_owner: Option<&str>,
     quoted_path: &str,
-) {
-    let owner = if let Some(configured) = remote_owner {
-        configured.to_string()
+) -> Option<DeployOwnership> {
+    let (owner, source) = if let Some(configured) = remote_owner {
+        (configured.to_string(), DeployOwnershipSource::Configured)
     } else {
         // Auto-detect ownership from the PARENT directory, not the target itself.
         // After deployment, the target dir is owned by whoever ran the deploy (usually root).
@@ -66,7 +105,7 @@ fn fix_deployed_ownership(
                 "Could not detect ownership of parent {}, skipping chown",
                 parent_path
             );
-            return;
+            return None;
         }
         let detected = stat_output.stdout.trim().to_string();
         // If the parent is root:root, there's nothing meaningful to inherit —
@@ -77,7 +116,7 @@ fn fix_deployed_ownership(
                 "Parent directory {} is root:root — set remote_owner on the component to fix ownership",
                 parent_path
             );
-            return;
+            return None;
         }
         homeboy_core::log_status!(
             "deploy",
@@ -85,7 +124,7 @@ fn fix_deployed_ownership(
             detected,
             parent_path
         );
-        detected
+        (detected, DeployOwnershipSource::Detected)
     };
 
     homeboy_core::log_status!(
@@ -100,7 +139,9 @@ fn fix_deployed_ownership(
         quoted_path
     );
     let chown_output = ssh_client.execute(&chown_cmd);
+    let mut status = DeployOwnershipStatus::Applied;
     if !chown_output.success {
+        status = DeployOwnershipStatus::Failed;
         homeboy_core::log_status!(
             "deploy",
             "Warning: chown failed (exit {}): {}",
@@ -118,7 +159,9 @@ fn fix_deployed_ownership(
                 quoted_path
             );
             let chgrp_output = ssh_client.execute(&chgrp_cmd);
-            if !chgrp_output.success {
+            if chgrp_output.success {
+                status = DeployOwnershipStatus::GroupOnly;
+            } else {
                 homeboy_core::log_status!(
                     "deploy",
                     "Warning: chgrp failed (exit {}): {}",
@@ -128,6 +171,12 @@ fn fix_deployed_ownership(
             }
         }
     }
+
+    Some(DeployOwnership {
+        owner,
+        source,
+        status,
+    })
 }
 
 fn ensure_remote_success(output: CommandOutput, operation: &str, remote_path: &str) -> Result<()> {
@@ -149,3 +198,55 @@ fn ensure_remote_success(output: CommandOutput, operation: &str, remote_path: &s
         },
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deploy::local_test_client;
+
+    #[test]
+    fn validate_remote_owner_accepts_user_and_user_group() {
+        for owner in [
+            "www-data",
+            "www-data:www-data",
+            "deploy_user:staff",
+            "1000:1000",
+        ] {
+            validate_remote_owner("plugin", owner).expect(owner);
+        }
+    }
+
+    #[test]
+    fn validate_remote_owner_rejects_malformed_owners() {
+        for owner in [
+            "",
+            ":www-data",
+            "www-data:",
+            "a:b:c",
+            "www data",
+            "root;rm -rf /",
+        ] {
+            let err = validate_remote_owner("plugin", owner).expect_err(owner);
+            assert!(err.message.contains("invalid remote_owner"));
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn fix_deployed_permissions_reports_configured_ownership() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let target = temp.path().join("plugin");
+        std::fs::create_dir_all(&target).expect("target dir");
+        let client = local_test_client();
+        let current = client.execute("id -un").stdout.trim().to_string();
+
+        let ownership =
+            fix_deployed_permissions(&client, target.to_str().expect("target"), Some(&current))
+                .expect("permissions fixed")
+                .expect("ownership attempted");
+
+        assert_eq!(ownership.owner, current);
+        assert_eq!(ownership.source, DeployOwnershipSource::Configured);
+        assert_eq!(ownership.status, DeployOwnershipStatus::Applied);
+    }
+}
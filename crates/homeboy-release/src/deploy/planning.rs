@@ -79,7 +79,171 @@ pub(super) fn plan_components(
     );
     validate_deploy_plan(config, &plan)?;
 
-    Ok(plan.ready_components())
+    order_by_dependencies(plan.ready_components())
+}
+
+/// Expand glob patterns in the component selection (e.g. `theme-*`) against
+/// the project's attached component IDs.
+///
+/// Without a selection flag the matches become explicit component IDs. With
+/// `--all`, `--outdated`, or `--behind-upstream` the matches (plus any literal
+/// IDs) narrow the project's attachments instead, so the flag only considers
+/// that subset. A pattern that matches nothing is an error listing the
+/// available components.
+pub(super) fn expand_component_globs(
+    project: &mut Project,
+    config: &mut DeployConfig,
+) -> Result<()> {
+    if !config.component_ids.iter().any(|id| is_component_glob(id)) {
+        return Ok(());
+    }
+
+    let available: Vec<String> = project.components.iter().map(|c| c.id.clone()).collect();
+    let mut expanded: Vec<String> = Vec::new();
+    for selector in &config.component_ids {
+        if !is_component_glob(selector) {
+            if !expanded.contains(selector) {
+                expanded.push(selector.clone());
+            }
+            continue;
+        }
+
+        let matches: Vec<&String> = available
+            .iter()
+            .filter(|id| glob_match::glob_match(selector, id))
+            .collect();
+        if matches.is_empty() {
+            return Err(Error::validation_invalid_argument(
+                "componentIds",
+                format!(
+                    "Component pattern '{}' matched no components in project '{}'",
+                    selector, project.id
+                ),
+                Some(selector.clone()),
+                Some(vec![format!(
+                    "Available components: {}",
+                    available.join(", ")
+                )]),
+            ));
+        }
+        for id in matches {
+            if !expanded.contains(id) {
+                expanded.push(id.clone());
+            }
+        }
+    }
+
+    if config.all || config.outdated || config.behind_upstream {
+        project
+            .components
+            .retain(|attachment| expanded.contains(&attachment.id));
+        config.component_ids.clear();
+    } else {
+        config.component_ids = expanded;
+    }
+
+    Ok(())
+}
+
+/// Whether any selector (exact ID or glob) names a component attached to
+/// `project`. Fleet deploys use this to skip projects a component does not
+/// apply to instead of failing them.
+pub(super) fn project_uses_any_component(project: &Project, selectors: &[String]) -> bool {
+    project.components.iter().any(|attachment| {
+        selectors.iter().any(|selector| {
+            if is_component_glob(selector) {
+                glob_match::glob_match(selector, &attachment.id)
+            } else {
+                selector == &attachment.id
+            }
+        })
+    })
+}
+
+fn is_component_glob(selector: &str) -> bool {
+    selector.contains(['*', '?', '['])
+}
+
+/// Order components so each builds and deploys after the components it
+/// `depends_on`. Dependencies outside this deploy are ignored.
+///
+/// Among components whose dependencies are satisfied, non-exclusive ones go
+/// first, then `deploy_exclusive` ones one at a time; within each tier lower
+/// `deploy_priority` values go first and ties keep their configured order.
+pub(super) fn order_by_dependencies(components: Vec<Component>) -> Result<Vec<Component>> {
+    let index_by_id: HashMap<&str, usize> = components
+        .iter()
+        .enumerate()
+        .map(|(idx, component)| (component.id.as_str(), idx))
+        .collect();
+
+    let mut indegree = vec![0usize; components.len()];
+    let mut dependents = vec![Vec::<usize>::new(); components.len()];
+    for (idx, component) in components.iter().enumerate() {
+        for dependency in &component.depends_on {
+            // A self-dependency never resolves and is reported as a cycle.
+            if let Some(&dependency_idx) = index_by_id.get(dependency.as_str()) {
+                indegree[idx] += 1;
+                if dependency_idx != idx {
+                    dependents[dependency_idx].push(idx);
+                }
+            }
+        }
+    }
+
+    // Release by (tier, deploy_priority, configured index) so unrelated
+    // components keep their existing relative order.
+    let schedule_key = |idx: usize| {
+        let component = &components[idx];
+        (
+            component.deploy_exclusive,
+            component.deploy_priority.unwrap_or(0),
+            idx,
+        )
+    };
+    let mut ready: std::collections::BTreeSet<(bool, i32, usize)> = indegree
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count == 0)
+        .map(|(idx, _)| schedule_key(idx))
+        .collect();
+    let mut ordered = Vec::with_capacity(components.len());
+    while let Some((_, _, idx)) = ready.pop_first() {
+        ordered.push(idx);
+        for &dependent_idx in &dependents[idx] {
+            indegree[dependent_idx] -= 1;
+            if indegree[dependent_idx] == 0 {
+                ready.insert(schedule_key(dependent_idx));
+            }
+        }
+    }
+
+    if ordered.len() != components.len() {
+        let cycle_members = components
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| indegree[*idx] > 0)
+            .map(|(_, component)| component.id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(Error::validation_invalid_argument(
+            "depends_on",
+            format!(
+                "Component dependency cycle detected involving {}",
+                cycle_members
+            ),
+            None,
+            Some(vec![
+                "Remove one of the depends_on edges between these components".to_string(),
+            ]),
+        ));
+    }
+
+    let mut slots: Vec<Option<Component>> = components.into_iter().map(Some).collect();
+    Ok(ordered
+        .into_iter()
+        .filter_map(|idx| slots[idx].take())
+        .collect())
 }
 
 pub(super) struct DeployComponentPlan {
@@ -871,12 +1035,75 @@ fn missing_extension_reason(err: &homeboy_core::error::Error) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::deploy::types::DeployConfig;
+    use crate::deploy::types::{DeployConfig, DeployTransport};
     use homeboy_core::component::VersionTarget;
     use homeboy_core::project::Project;
     use homeboy_core::server::SshClient;
     use tempfile::TempDir;
 
+    fn dependent(id: &str, depends_on: &[&str]) -> Component {
+        Component {
+            id: id.to_string(),
+            depends_on: depends_on.iter().map(|id| id.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn ids(components: &[Component]) -> Vec<&str> {
+        components.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    #[test]
+    fn order_by_dependencies_builds_libraries_first_and_keeps_unrelated_order() {
+        let ordered = order_by_dependencies(vec![
+            dependent("theme", &["lib"]),
+            dependent("standalone", &[]),
+            dependent("plugin", &["lib", "not-in-this-deploy"]),
+            dependent("lib", &[]),
+        ])
+        .expect("acyclic order");
+
+        assert_eq!(ids(&ordered), ["standalone", "lib", "theme", "plugin"]);
+    }
+
+    #[test]
+    fn order_by_dependencies_runs_exclusive_components_last_by_deploy_priority() {
+        let mut migrations = dependent("migrations", &[]);
+        migrations.deploy_exclusive = true;
+        let mut cache_flush = dependent("cache-flush", &[]);
+        cache_flush.deploy_exclusive = true;
+        cache_flush.deploy_priority = Some(10);
+        let mut assets = dependent("assets", &[]);
+        assets.deploy_priority = Some(5);
+
+        let ordered = order_by_dependencies(vec![
+            cache_flush,
+            migrations,
+            assets,
+            dependent("theme", &[]),
+            dependent("plugin", &["migrations"]),
+        ])
+        .expect("acyclic order");
+
+        assert_eq!(
+            ids(&ordered),
+            ["theme", "assets", "migrations", "plugin", "cache-flush"]
+        );
+    }
+
+    #[test]
+    fn order_by_dependencies_rejects_cycles() {
+        let error = order_by_dependencies(vec![
+            dependent("a", &["b"]),
+            dependent("b", &["a"]),
+            dependent("c", &[]),
+        ])
+        .expect_err("cycle must fail");
+
+        assert!(error.message.contains("cycle"));
+        assert!(error.message.contains("a, b"));
+    }
+
     fn run_git(path: &Path, args: &[&str]) {
         let output = std::process::Command::new("git")
             .args(args)
@@ -985,6 +1212,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         }
     }
 
@@ -1003,6 +1236,9 @@ mod tests {
             identity_file: None,
             auth: None,
             is_local: true,
+            multiplex: false,
+            connect_timeout: 10,
+            retries: 2,
             env: HashMap::new(),
         }
     }
@@ -1015,6 +1251,96 @@ mod tests {
             .expect("component step")
     }
 
+    fn project_with_components(ids: &[&str]) -> Project {
+        Project {
+            components: ids
+                .iter()
+                .map(|id| project::ProjectComponentAttachment {
+                    id: id.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..project()
+        }
+    }
+
+    #[test]
+    fn project_uses_any_component_matches_exact_ids_and_globs() {
+        let project = project_with_components(&["theme-a", "plugin"]);
+
+        assert!(project_uses_any_component(
+            &project,
+            &["plugin".to_string()]
+        ));
+        assert!(project_uses_any_component(
+            &project,
+            &["theme-*".to_string()]
+        ));
+        assert!(project_uses_any_component(
+            &project,
+            &["other".to_string(), "plugin".to_string()]
+        ));
+        assert!(!project_uses_any_component(
+            &project,
+            &["other".to_string()]
+        ));
+        assert!(!project_uses_any_component(
+            &project,
+            &["widget-*".to_string()]
+        ));
+        assert!(!project_uses_any_component(
+            &project_with_components(&[]),
+            &["plugin".to_string()]
+        ));
+    }
+
+    #[test]
+    fn expand_component_globs_expands_patterns_into_explicit_ids() {
+        let mut project = project_with_components(&["theme-a", "plugin", "theme-b"]);
+        let mut config = DeployConfig {
+            component_ids: vec!["plugin".to_string(), "theme-*".to_string()],
+            ..deploy_config()
+        };
+
+        expand_component_globs(&mut project, &mut config).expect("globs expand");
+
+        assert_eq!(config.component_ids, ["plugin", "theme-a", "theme-b"]);
+        assert_eq!(project.components.len(), 3);
+    }
+
+    #[test]
+    fn expand_component_globs_narrows_project_for_selection_flags() {
+        let mut project = project_with_components(&["theme-a", "plugin", "theme-b"]);
+        let mut config = DeployConfig {
+            component_ids: vec!["theme-*".to_string()],
+            outdated: true,
+            ..deploy_config()
+        };
+
+        expand_component_globs(&mut project, &mut config).expect("globs expand");
+
+        assert!(config.component_ids.is_empty());
+        let remaining: Vec<&str> = project.components.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(remaining, ["theme-a", "theme-b"]);
+    }
+
+    #[test]
+    fn expand_component_globs_rejects_patterns_without_matches() {
+        let mut project = project_with_components(&["plugin"]);
+        let mut config = DeployConfig {
+            component_ids: vec!["theme-*".to_string()],
+            ..deploy_config()
+        };
+
+        let err = expand_component_globs(&mut project, &mut config).expect_err("no match");
+
+        assert!(err.message.contains("matched no components"));
+        assert!(err
+            .details
+            .to_string()
+            .contains("Available components: plugin"));
+    }
+
     #[test]
     fn plan_component_deploys_marks_explicit_selection_ready() {
         let temp = TempDir::new().expect("temp dir");
@@ -12,7 +12,7 @@ use super::execution::{
     release_artifact_plan, resolve_planned_release_artifact, ReleaseArtifactPlan,
 };
 use super::orchestration_ref_checkout::{ExactRefCheckout, ExactRefIdentity};
-use super::{sha256_file, DeployConfig, PreparedDeployArtifact};
+use super::{sha256_file, DeployConfig, DeployTransport, PreparedDeployArtifact};
 use homeboy_core::git::release_download::{ReleaseArtifactLease, ReleaseArtifactStore};
 
 /// Immutable, target-independent inputs used to prepare a component payload.
@@ -610,12 +610,10 @@ fn copy_prepared_artifact(source: &Path) -> Result<(PathBuf, PreparedArtifactCle
     Ok((destination, cleanup))
 }
 
-fn artifact_path(component: &Component) -> Result<PathBuf> {
+pub(super) fn artifact_path(component: &Component) -> Result<PathBuf> {
     let artifact = artifact_pattern(component)?;
-    homeboy_extension::build::resolve_artifact_path_from_root(
-        &artifact,
-        Some(Path::new(&component.local_path)),
-    )
+    let build_root = component.build_root(Path::new(&component.local_path))?;
+    homeboy_extension::build::resolve_artifact_path_from_root(&artifact, Some(&build_root))
 }
 
 fn configured_artifact_path(component: &Component) -> Result<PathBuf> {
@@ -624,7 +622,9 @@ fn configured_artifact_path(component: &Component) -> Result<PathBuf> {
     Ok(if path.is_absolute() {
         path.to_path_buf()
     } else {
-        Path::new(&component.local_path).join(path)
+        component
+            .build_root(Path::new(&component.local_path))?
+            .join(path)
     })
 }
 
@@ -737,6 +737,12 @@ impl DeployConfig {
             tagged: request.config.tagged,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         }
     }
 }
@@ -774,6 +780,12 @@ mod tests {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         }
     }
 
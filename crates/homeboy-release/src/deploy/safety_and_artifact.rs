@@ -10,8 +10,8 @@ use homeboy_core::error::{Error, Result};
 use homeboy_core::server::SshClient;
 use homeboy_extension::DeployVerification;
 
-use super::transfer::{upload_directory, upload_file};
-use super::types::{DeployEffect, DeployResult};
+use super::transfer::{upload_directory, upload_file, verify_upload_checksum};
+use super::types::{DeployEffect, DeployResult, DeployTransport};
 
 /// Framework-neutral shared directory names that typically contain sibling components.
 const DANGEROUS_PATH_SUFFIXES: &[&str] = &["/node_modules", "/vendor", "/packages", "/extensions"];
@@ -97,7 +97,19 @@ pub(super) fn deploy_via_git(
     Ok(DeployResult::success(0))
 }
 
+/// Shared remote directory where archives are staged before extraction.
+///
+/// The directory may be shared by every project and component on the server,
+/// so the staged file name is scoped to the project and component plus the
+/// deploying process.
+pub(super) struct ArtifactStaging<'a> {
+    pub dir: &'a str,
+    pub project_id: &'a str,
+    pub component_id: &'a str,
+}
+
 /// Main entry point - uploads artifact and runs extract command if configured
+#[allow(clippy::too_many_arguments)]
 pub(super) fn deploy_artifact(
     ssh_client: &SshClient,
     local_path: &Path,
@@ -105,21 +117,28 @@ pub(super) fn deploy_artifact(
     extract_command: Option<&str>,
     verification: Option<&DeployVerification>,
     remote_owner: Option<&str>,
+    staging: Option<&ArtifactStaging<'_>>,
+    transport: DeployTransport,
+    compress: bool,
+    verify_checksum: bool,
 ) -> Result<DeployResult> {
     let mut uploaded_artifact_path: Option<String> = None;
+    let mut transfer_warning = None;
     let mut verified = false;
+    let mut ownership = None;
 
     // Step 1: Upload (directory or file)
     if local_path.is_dir() {
-        let result = upload_directory(ssh_client, local_path, remote_path)?;
+        let result = upload_directory(ssh_client, local_path, remote_path, transport, compress)?;
         if !result.success {
             return Ok(result);
         }
+        transfer_warning = result.error;
 
         // Directory artifacts bypass the extraction branch below, so normalize
         // them here instead of preserving the build user's ownership and modes.
         homeboy_core::log_status!("deploy", "Fixing file permissions");
-        permissions::fix_deployed_permissions(ssh_client, remote_path, remote_owner)?;
+        ownership = permissions::fix_deployed_permissions(ssh_client, remote_path, remote_owner)?;
     } else {
         // Validate: archive artifacts require an extract command
         let is_archive = local_path
@@ -142,7 +161,7 @@ pub(super) fn deploy_artifact(
         // For archives, upload to temp location in target directory
         let deploy_defaults = defaults::load_defaults().deploy;
         let artifact_prefix = &deploy_defaults.artifact_prefix;
-        let artifact_filename = local_path
+        let original_filename = local_path
             .file_name()
             .and_then(|name| name.to_str())
             .ok_or_else(|| {
@@ -154,9 +173,27 @@ pub(super) fn deploy_artifact(
                 )
             })?
             .to_string();
-        let artifact_filename = format!("{}{}", artifact_prefix, artifact_filename);
+        let artifact_filename = format!("{}{}", artifact_prefix, original_filename);
 
-        let upload_path = if extract_command.is_some() {
+        // Archives may be staged outside the target when the project configures a
+        // writable temp dir; the extract command then reads them by absolute path.
+        let staging = staging
+            .filter(|_| extract_command.is_some())
+            .map(|staging| (staging, staging.dir.trim_end_matches('/')))
+            .filter(|(_, dir)| !dir.is_empty());
+        let staging_dir = staging.map(|(_, dir)| dir);
+
+        let upload_path = if let Some((staging, staging_dir)) = staging {
+            format!(
+                "{}/{}{}-{}-{}-{}",
+                staging_dir,
+                artifact_prefix,
+                staging.project_id,
+                staging.component_id,
+                std::process::id(),
+                original_filename
+            )
+        } else if extract_command.is_some() {
             // Archives are uploaded into the target directory (often with a prefix) then extracted.
             format!("{}/{}", remote_path, artifact_filename)
         } else {
@@ -187,12 +224,37 @@ pub(super) fn deploy_artifact(
             ));
         }
 
-        let result = upload_file(ssh_client, local_path, &upload_path)?;
+        if let Some(staging_dir) = staging_dir {
+            let quoted = shell::quote_path(staging_dir);
+            let prepare_cmd = format!("mkdir -p {} && test -w {}", quoted, quoted);
+            let prepare_output = ssh_client.execute(&prepare_cmd);
+            if !prepare_output.success {
+                return Ok(DeployResult::failure(
+                    prepare_output.exit_code,
+                    format!(
+                        "Remote temp directory '{}' is not writable: {}",
+                        staging_dir, prepare_output.stderr
+                    ),
+                ));
+            }
+        }
+
+        let result = upload_file(ssh_client, local_path, &upload_path, compress)?;
         if !result.success {
             return Ok(result);
         }
         uploaded_artifact_path = Some(upload_path.clone());
 
+        // Catch a truncated or corrupted archive before extraction replaces
+        // the live files with its contents.
+        if verify_checksum && extract_command.is_some() {
+            let result = verify_upload_checksum(ssh_client, local_path, &upload_path)?;
+            if !result.success {
+                return Ok(result);
+            }
+            transfer_warning = result.error;
+        }
+
         // Step 2: Execute extract command if configured
         if let Some(cmd_template) = extract_command {
             // Defense-in-depth: refuse to clean known shared parent directories.
@@ -239,8 +301,13 @@ pub(super) fn deploy_artifact(
                 ));
             }
 
+            let artifact_ref = if staging_dir.is_some() {
+                upload_path.clone()
+            } else {
+                artifact_filename
+            };
             let mut vars = HashMap::new();
-            vars.insert("artifact".to_string(), artifact_filename);
+            vars.insert("artifact".to_string(), artifact_ref);
             vars.insert("targetDir".to_string(), remote_path.to_string());
 
             let rendered_cmd = render_extract_command(cmd_template, &vars);
@@ -289,7 +356,8 @@ pub(super) fn deploy_artifact(
 
             // Fix file permissions after extraction
             homeboy_core::log_status!("deploy", "Fixing file permissions");
-            permissions::fix_deployed_permissions(ssh_client, remote_path, remote_owner)?;
+            ownership =
+                permissions::fix_deployed_permissions(ssh_client, remote_path, remote_owner)?;
         }
     }
 
@@ -320,11 +388,14 @@ pub(super) fn deploy_artifact(
         verified = true;
     }
 
-    Ok(DeployResult::success(0).with_effect(DeployEffect {
-        remote_path: remote_path.to_string(),
-        artifact_path: uploaded_artifact_path,
-        verified,
-    }))
+    Ok(DeployResult::success(0)
+        .with_effect(DeployEffect {
+            remote_path: remote_path.to_string(),
+            artifact_path: uploaded_artifact_path,
+            verified,
+            ownership,
+        })
+        .with_warning(transfer_warning))
 }
 
 /// Return the final path segment of `remote_path` (its basename), if any.
@@ -470,8 +541,10 @@ fn render_extract_command(template: &str, vars: &HashMap<String, String>) -> Str
 mod tests {
     use super::{
         deploy_artifact, ensure_not_double_nested, flatten_double_nested_dir, remote_basename,
-        render_extract_command, DANGEROUS_PATH_SUFFIXES,
+        render_extract_command, ArtifactStaging, DANGEROUS_PATH_SUFFIXES,
     };
+    use crate::deploy::local_test_client;
+    use crate::deploy::types::DeployTransport;
     use homeboy_core::server::SshClient;
     use homeboy_extension::DeployVerification;
     use std::collections::HashMap;
@@ -482,22 +555,10 @@ mod tests {
     #[cfg(unix)]
     use std::os::unix::fs::PermissionsExt;
 
-    fn local_client() -> SshClient {
-        SshClient {
-            host: "localhost".to_string(),
-            user: "test".to_string(),
-            port: 22,
-            identity_file: None,
-            auth: None,
-            is_local: true,
-            env: HashMap::new(),
-        }
-    }
-
     fn local_client_with_env(env: HashMap<String, String>) -> SshClient {
         SshClient {
             env,
-            ..local_client()
+            ..local_test_client()
         }
     }
 
@@ -552,12 +613,16 @@ mod tests {
             .expect("source permissions");
 
         let result = deploy_artifact(
-            &local_client(),
+            &local_test_client(),
             &source,
             target.to_str().expect("target path"),
             None,
             None,
             None,
+            None,
+            DeployTransport::default(),
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -600,12 +665,16 @@ mod tests {
         };
 
         let result = deploy_artifact(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             None,
             Some(&verification),
             None,
+            None,
+            DeployTransport::default(),
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -652,6 +721,10 @@ mod tests {
             Some("true"),
             None,
             None,
+            None,
+            DeployTransport::default(),
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -734,12 +807,16 @@ mod tests {
         let target = temp.path().join("wp-content/plugins").join(plugin);
 
         let result = deploy_artifact(
-            &local_client(),
+            &local_test_client(),
             &archive,
             target.to_str().expect("target path"),
             Some("unzip -o {artifact} && rm {artifact}"),
             None,
             None,
+            None,
+            DeployTransport::default(),
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -773,12 +850,16 @@ mod tests {
 
         let target = temp.path().join("wp-content/plugins/flat-plugin");
         let result = deploy_artifact(
-            &local_client(),
+            &local_test_client(),
             &archive,
             target.to_str().expect("target path"),
             Some("unzip -o {artifact} && rm {artifact}"),
             None,
             None,
+            None,
+            DeployTransport::default(),
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -789,6 +870,48 @@ mod tests {
         assert!(!target.join("flat-plugin").exists());
     }
 
+    /// With a remote temp dir configured, the archive is staged there and the
+    /// extract command reads it by absolute path, so nothing lands in the target.
+    #[test]
+    #[cfg(unix)]
+    fn test_deploy_artifact_stages_archive_in_remote_temp_dir() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let archive = temp.path().join("flat.zip");
+        write_zip(&archive, &[("plugin.php", "<?php // main")]);
+
+        let target = temp.path().join("wp-content/plugins/flat-plugin");
+        let staging = temp.path().join("tmp");
+        let result = deploy_artifact(
+            &local_test_client(),
+            &archive,
+            target.to_str().expect("target path"),
+            Some("unzip -o {artifact}"),
+            None,
+            None,
+            Some(&ArtifactStaging {
+                dir: staging.to_str().expect("staging path"),
+                project_id: "site",
+                component_id: "flat-plugin",
+            }),
+            DeployTransport::default(),
+            false,
+            true,
+        )
+        .expect("deploy result");
+
+        assert!(result.success, "deploy should succeed: {:?}", result.error);
+        assert!(target.join("plugin.php").is_file());
+        assert_eq!(fs::read_dir(&target).expect("target dir").count(), 1);
+        let staged = result
+            .effect
+            .and_then(|effect| effect.artifact_path)
+            .expect("staged artifact path");
+        assert!(staged.starts_with(staging.to_str().expect("staging path")));
+        let staged_name = remote_basename(&staged).expect("staged file name");
+        assert!(staged_name.contains("site-flat-plugin-"));
+        assert!(staged_name.ends_with("-flat.zip"));
+    }
+
     /// The mandatory sanity check must report failure when a double-nested layout
     /// remains (e.g. an extract path the flatten heuristic could not repair).
     #[test]
@@ -804,7 +927,8 @@ mod tests {
         // (more than one top-level entry), but the broken layout still exists.
         fs::write(target.join("stray.txt"), "stray").expect("stray");
 
-        let result = ensure_not_double_nested(&local_client(), target.to_str().expect("target"));
+        let result =
+            ensure_not_double_nested(&local_test_client(), target.to_str().expect("target"));
         let result = result.expect("should detect broken layout");
         assert!(!result.success);
         let error = result.error.expect("error message");
@@ -821,8 +945,9 @@ mod tests {
         fs::create_dir_all(&target).expect("target");
         fs::write(target.join("plugin.php"), "<?php").expect("main");
 
-        let result = flatten_double_nested_dir(&local_client(), target.to_str().expect("target"))
-            .expect("ok");
+        let result =
+            flatten_double_nested_dir(&local_test_client(), target.to_str().expect("target"))
+                .expect("ok");
         assert!(
             result.is_none(),
             "flatten should be a no-op on a flat layout"
@@ -840,12 +965,16 @@ mod tests {
         let target = temp.path().join("wp-content/plugins/plugin");
 
         let result = deploy_artifact(
-            &local_client(),
+            &local_test_client(),
             &archive,
             target.to_str().expect("target"),
             None,
             None,
             None,
+            None,
+            DeployTransport::default(),
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -884,7 +1013,7 @@ mod tests {
             .to_str()
             .expect("relative target");
 
-        let result = flatten_double_nested_dir(&local_client(), relative_target).expect("ok");
+        let result = flatten_double_nested_dir(&local_test_client(), relative_target).expect("ok");
 
         assert!(result.is_none(), "flatten should not fail");
         assert!(target.join("sample-plugin.php").is_file());
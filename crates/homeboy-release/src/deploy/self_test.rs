@@ -0,0 +1,419 @@
+//! End-to-end deploy rehearsal against a server (`homeboy self-test`).
+//!
+//! Runs the same primitives a real deploy uses — SSH exec, SCP upload, archive
+//! extraction, and the post-deploy permission fix — on a throwaway payload in
+//! an isolated temp dir, then removes it. Each step becomes one checklist
+//! entry so host-specific blockers (no `unzip`, unwritable base path, `chmod`
+//! refused) show up before a real deploy trips over them.
+
+use std::io::Write;
+
+use homeboy_core::context::resolve_project_ssh;
+use homeboy_core::engine::shell;
+use homeboy_core::error::{Error, Result};
+use homeboy_core::project;
+use homeboy_core::server::{self, CommandOutput, SshClient};
+use serde::Serialize;
+
+use super::doctor::DoctorCheckStatus;
+use super::permissions::fix_deployed_permissions;
+use super::transfer::upload_file;
+use super::types::DeployOwnershipStatus;
+
+const ARCHIVE_NAME: &str = "homeboy-self-test.zip";
+const PAYLOAD_DIR: &str = "homeboy-self-test";
+const PAYLOAD_FILE: &str = "probe.txt";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestCheck {
+    pub id: &'static str,
+    pub status: DoctorCheckStatus,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfTestReport {
+    pub target: String,
+    /// `project` or `server`, depending on how `target` resolved.
+    pub target_type: &'static str,
+    pub server_id: String,
+    pub host: String,
+    /// Directory the temp workspace was created under.
+    pub parent_dir: String,
+    pub status: DoctorCheckStatus,
+    pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestReport {
+    pub fn has_failures(&self) -> bool {
+        self.status == DoctorCheckStatus::Fail
+    }
+}
+
+/// Rehearse a deploy against `target`, a project ID or server ID.
+///
+/// Projects run inside their `base_path` so the write and `chmod` checks
+/// exercise the directory deploys actually land in; bare servers use `/tmp`.
+pub fn self_test(target: &str) -> Result<SelfTestReport> {
+    let (target_type, server_id, client, parent_dir) = if project::exists(target) {
+        let ctx = resolve_project_ssh(target)?;
+        let parent_dir = ctx
+            .base_path
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .unwrap_or("/tmp")
+            .to_string();
+        ("project", ctx.server_id, ctx.client, parent_dir)
+    } else if server::exists(target) {
+        let server = server::load(target)?;
+        let client = SshClient::from_server(&server, target)?;
+        ("server", target.to_string(), client, "/tmp".to_string())
+    } else {
+        return Err(Error::validation_invalid_argument(
+            "target",
+            format!("'{}' is neither a project nor a server", target),
+            Some(target.to_string()),
+            Some(vec![
+                "Run 'homeboy project list' to see available projects".to_string(),
+                "Run 'homeboy server list' to see available servers".to_string(),
+            ]),
+        ));
+    };
+
+    let checks = run_checks(&client, &parent_dir);
+    Ok(SelfTestReport {
+        target: target.to_string(),
+        target_type,
+        server_id,
+        host: client.host.clone(),
+        parent_dir,
+        status: overall_status(&checks),
+        checks,
+    })
+}
+
+fn overall_status(checks: &[SelfTestCheck]) -> DoctorCheckStatus {
+    checks
+        .iter()
+        .map(|check| check.status)
+        .max()
+        .unwrap_or(DoctorCheckStatus::Pass)
+}
+
+fn check(id: &'static str, status: DoctorCheckStatus, message: impl Into<String>) -> SelfTestCheck {
+    SelfTestCheck {
+        id,
+        status,
+        message: message.into(),
+    }
+}
+
+fn skipped(id: &'static str, reason: &str) -> SelfTestCheck {
+    check(id, DoctorCheckStatus::Warn, format!("skipped: {reason}"))
+}
+
+fn output_detail(output: &CommandOutput) -> String {
+    let detail = if output.stderr.trim().is_empty() {
+        output.stdout.trim()
+    } else {
+        output.stderr.trim()
+    };
+    if detail.is_empty() {
+        format!("exit code {}", output.exit_code)
+    } else {
+        detail.to_string()
+    }
+}
+
+/// Run the checklist. Steps that depend on an earlier failure are reported as
+/// skipped; cleanup always runs once a temp dir exists.
+fn run_checks(client: &SshClient, parent_dir: &str) -> Vec<SelfTestCheck> {
+    const ALL: &[&str] = &[
+        "connect", "unzip", "tar", "temp_dir", "upload", "extract", "verify", "chmod", "cleanup",
+    ];
+    let mut checks = Vec::new();
+
+    let connect = client.execute("echo homeboy-self-test");
+    if !connect.success || !connect.stdout.contains("homeboy-self-test") {
+        checks.push(check(
+            "connect",
+            DoctorCheckStatus::Fail,
+            format!("could not run a command: {}", output_detail(&connect)),
+        ));
+        checks.extend(ALL[1..].iter().map(|id| skipped(id, "connect failed")));
+        return checks;
+    }
+    checks.push(check(
+        "connect",
+        DoctorCheckStatus::Pass,
+        format!("ran a command as {}@{}", client.user, client.host),
+    ));
+
+    let has_unzip = tool_available(client, "unzip");
+    checks.push(if has_unzip {
+        check("unzip", DoctorCheckStatus::Pass, "unzip is installed")
+    } else {
+        check(
+            "unzip",
+            DoctorCheckStatus::Fail,
+            "unzip is not installed; zip artifacts cannot be extracted",
+        )
+    });
+    checks.push(if tool_available(client, "tar") {
+        check("tar", DoctorCheckStatus::Pass, "tar is installed")
+    } else {
+        check(
+            "tar",
+            DoctorCheckStatus::Warn,
+            "tar is not installed; tarball artifacts cannot be extracted",
+        )
+    });
+
+    let mktemp = client.execute(&format!(
+        "mktemp -d {}",
+        shell::quote_path(&format!(
+            "{}/.homeboy-self-test.XXXXXX",
+            parent_dir.trim_end_matches('/')
+        ))
+    ));
+    let temp_dir = mktemp.stdout.trim().to_string();
+    if !mktemp.success || temp_dir.is_empty() {
+        checks.push(check(
+            "temp_dir",
+            DoctorCheckStatus::Fail,
+            format!(
+                "cannot create a directory under {}: {}",
+                parent_dir,
+                output_detail(&mktemp)
+            ),
+        ));
+        checks.extend(ALL[4..].iter().map(|id| skipped(id, "temp_dir failed")));
+        return checks;
+    }
+    checks.push(check(
+        "temp_dir",
+        DoctorCheckStatus::Pass,
+        format!("created {}", temp_dir),
+    ));
+
+    let token = format!("homeboy-self-test-{}", uuid::Uuid::new_v4());
+    checks.extend(exercise_deploy(client, &temp_dir, &token, has_unzip));
+    checks.push(cleanup(client, &temp_dir));
+    checks
+}
+
+/// Upload, extract, verify, and chmod steps inside `temp_dir`.
+fn exercise_deploy(
+    client: &SshClient,
+    temp_dir: &str,
+    token: &str,
+    has_unzip: bool,
+) -> Vec<SelfTestCheck> {
+    let remote_archive = format!("{}/{}", temp_dir, ARCHIVE_NAME);
+    let upload_error = match build_archive(token)
+        .and_then(|archive| upload_file(client, archive.path(), &remote_archive, false))
+    {
+        Ok(result) if result.success => None,
+        Ok(result) => Some(result.error.unwrap_or_else(|| "upload failed".to_string())),
+        Err(error) => Some(error.message),
+    };
+    if let Some(error) = upload_error {
+        return vec![
+            check("upload", DoctorCheckStatus::Fail, error),
+            skipped("extract", "upload failed"),
+            skipped("verify", "upload failed"),
+            skipped("chmod", "upload failed"),
+        ];
+    }
+    let mut checks = vec![check(
+        "upload",
+        DoctorCheckStatus::Pass,
+        format!("uploaded {}", remote_archive),
+    )];
+
+    if !has_unzip {
+        checks.extend([
+            skipped("extract", "unzip is not installed"),
+            skipped("verify", "unzip is not installed"),
+            skipped("chmod", "unzip is not installed"),
+        ]);
+        return checks;
+    }
+
+    let extract = client.execute(&format!(
+        "cd {} && unzip -o -q {} && rm {}",
+        shell::quote_path(temp_dir),
+        ARCHIVE_NAME,
+        ARCHIVE_NAME
+    ));
+    if !extract.success {
+        checks.extend([
+            check(
+                "extract",
+                DoctorCheckStatus::Fail,
+                format!("unzip failed: {}", output_detail(&extract)),
+            ),
+            skipped("verify", "extract failed"),
+            skipped("chmod", "extract failed"),
+        ]);
+        return checks;
+    }
+    checks.push(check(
+        "extract",
+        DoctorCheckStatus::Pass,
+        "archive extracted",
+    ));
+
+    let payload_dir = format!("{}/{}", temp_dir, PAYLOAD_DIR);
+    let read = client.execute(&format!(
+        "cat {}",
+        shell::quote_path(&format!("{}/{}", payload_dir, PAYLOAD_FILE))
+    ));
+    checks.push(if read.success && read.stdout.trim() == token {
+        check(
+            "verify",
+            DoctorCheckStatus::Pass,
+            "extracted contents match the uploaded payload",
+        )
+    } else {
+        check(
+            "verify",
+            DoctorCheckStatus::Fail,
+            format!("extracted contents do not match: {}", output_detail(&read)),
+        )
+    });
+
+    checks.push(match fix_deployed_permissions(client, &payload_dir, None) {
+        Ok(Some(ownership)) => {
+            let (status, outcome) = match ownership.status {
+                DeployOwnershipStatus::Applied => (DoctorCheckStatus::Pass, "succeeded"),
+                DeployOwnershipStatus::GroupOnly => {
+                    (DoctorCheckStatus::Warn, "was refused; group applied")
+                }
+                DeployOwnershipStatus::Failed => (DoctorCheckStatus::Warn, "was refused"),
+            };
+            check(
+                "chmod",
+                status,
+                format!(
+                    "permissions applied; chown to {} {}",
+                    ownership.owner, outcome
+                ),
+            )
+        }
+        Ok(None) => check(
+            "chmod",
+            DoctorCheckStatus::Pass,
+            "permissions applied; no owner detected, chown skipped",
+        ),
+        Err(error) => check("chmod", DoctorCheckStatus::Fail, error.message),
+    });
+
+    checks
+}
+
+fn cleanup(client: &SshClient, temp_dir: &str) -> SelfTestCheck {
+    let quoted = shell::quote_path(temp_dir);
+    let output = client.execute(&format!("rm -rf {} && test ! -e {}", quoted, quoted));
+    if output.success {
+        check(
+            "cleanup",
+            DoctorCheckStatus::Pass,
+            format!("removed {}", temp_dir),
+        )
+    } else {
+        check(
+            "cleanup",
+            DoctorCheckStatus::Fail,
+            format!("could not remove {}: {}", temp_dir, output_detail(&output)),
+        )
+    }
+}
+
+fn tool_available(client: &SshClient, tool: &str) -> bool {
+    client
+        .execute(&format!("command -v {} >/dev/null 2>&1", tool))
+        .success
+}
+
+/// Write a one-file zip (`homeboy-self-test/probe.txt` containing `token`) to
+/// a local temp file.
+fn build_archive(token: &str) -> Result<tempfile::NamedTempFile> {
+    let io_error = |error: std::io::Error| {
+        Error::internal_io(error.to_string(), Some("self-test archive".to_string()))
+    };
+    let zip_error = |error: zip::result::ZipError| {
+        Error::internal_io(error.to_string(), Some("self-test archive".to_string()))
+    };
+
+    let archive = tempfile::Builder::new()
+        .suffix(".zip")
+        .tempfile()
+        .map_err(io_error)?;
+    let mut zip = zip::ZipWriter::new(archive.reopen().map_err(io_error)?);
+    zip.start_file(
+        format!("{}/{}", PAYLOAD_DIR, PAYLOAD_FILE),
+        zip::write::FileOptions::default(),
+    )
+    .map_err(zip_error)?;
+    zip.write_all(format!("{}\n", token).as_bytes())
+        .map_err(io_error)?;
+    zip.finish().map_err(zip_error)?;
+    Ok(archive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deploy::local_test_client;
+
+    fn status_of(checks: &[SelfTestCheck], id: &str) -> DoctorCheckStatus {
+        checks
+            .iter()
+            .find(|check| check.id == id)
+            .map(|check| check.status)
+            .expect("check present")
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn local_rehearsal_round_trips_and_cleans_up() {
+        if !tool_available(&local_test_client(), "unzip") {
+            return;
+        }
+        let temp = tempfile::tempdir().expect("temp dir");
+        let parent = temp.path().to_str().expect("parent");
+
+        let checks = run_checks(&local_test_client(), parent);
+
+        for id in [
+            "connect", "unzip", "temp_dir", "upload", "extract", "verify", "cleanup",
+        ] {
+            assert_eq!(
+                status_of(&checks, id),
+                DoctorCheckStatus::Pass,
+                "{id}: {checks:?}"
+            );
+        }
+        assert_ne!(status_of(&checks, "chmod"), DoctorCheckStatus::Fail);
+        assert_eq!(std::fs::read_dir(temp.path()).expect("read").count(), 0);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn unwritable_parent_fails_temp_dir_and_skips_the_rest() {
+        let checks = run_checks(
+            &local_test_client(),
+            "/nonexistent/homeboy-self-test-parent",
+        );
+
+        assert_eq!(status_of(&checks, "connect"), DoctorCheckStatus::Pass);
+        assert_eq!(status_of(&checks, "temp_dir"), DoctorCheckStatus::Fail);
+        for id in ["upload", "extract", "verify", "chmod", "cleanup"] {
+            let check = checks.iter().find(|check| check.id == id).expect(id);
+            assert!(check.message.starts_with("skipped"), "{id}");
+        }
+        assert_eq!(overall_status(&checks), DoctorCheckStatus::Fail);
+    }
+}
@@ -1,19 +1,168 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::process::{Command, Output};
+use std::sync::{Mutex, OnceLock};
 
 use homeboy_core::defaults;
 use homeboy_core::engine::shell;
 use homeboy_core::error::{Error, Result};
 use homeboy_core::server::SshClient;
 
-use super::types::DeployResult;
+use super::types::{sha256_file, DeployResult, DeployTransport};
 
+/// Copy a directory artifact so the remote mirrors it. A successful result
+/// carries a warning in `error` when rsync was requested but scp was used.
 pub(super) fn upload_directory(
     ssh_client: &SshClient,
     local_path: &Path,
     remote_path: &str,
+    transport: DeployTransport,
+    compress: bool,
 ) -> Result<DeployResult> {
-    rsync_directory(ssh_client, local_path, remote_path)
+    let rsync_available = transport == DeployTransport::Rsync && rsync_available(ssh_client);
+    let (transport, warning) = resolve_directory_transport(transport, rsync_available);
+    if let Some(warning) = &warning {
+        homeboy_core::log_status!("deploy", "{}", warning);
+    }
+
+    let result = match transport {
+        DeployTransport::Rsync => rsync_directory(ssh_client, local_path, remote_path, compress)?,
+        DeployTransport::Scp => scp_directory(ssh_client, local_path, remote_path, compress)?,
+    };
+    Ok(result.with_warning(warning))
+}
+
+/// Pick the backend actually used for a directory upload, falling back from
+/// rsync to scp (with a warning) when rsync cannot run.
+fn resolve_directory_transport(
+    requested: DeployTransport,
+    rsync_available: bool,
+) -> (DeployTransport, Option<String>) {
+    match requested {
+        DeployTransport::Rsync if !rsync_available => (
+            DeployTransport::Scp,
+            Some(
+                "transport_fallback: rsync is not available locally or on the server; uploaded with scp instead"
+                    .to_string(),
+            ),
+        ),
+        transport => (transport, None),
+    }
+}
+
+/// Whether rsync can run on both ends of the transfer.
+///
+/// The answer is probed once per server and cached for the process, so
+/// deploying many components does not add an SSH round trip to each one.
+fn rsync_available(ssh_client: &SshClient) -> bool {
+    static PROBED: OnceLock<Mutex<HashMap<String, bool>>> = OnceLock::new();
+
+    let key = if ssh_client.is_local {
+        "local".to_string()
+    } else {
+        format!(
+            "{}@{}:{}",
+            ssh_client.user, ssh_client.host, ssh_client.port
+        )
+    };
+    let cache = PROBED.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(available) = cache.lock().ok().and_then(|cache| cache.get(&key).copied()) {
+        return available;
+    }
+
+    let available = probe_rsync(ssh_client);
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(key, available);
+    }
+    available
+}
+
+fn probe_rsync(ssh_client: &SshClient) -> bool {
+    let local = Command::new("rsync")
+        .arg("--version")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+    if !local || ssh_client.is_local {
+        return local;
+    }
+    ssh_client
+        .execute("command -v rsync >/dev/null 2>&1")
+        .success
+}
+
+/// Replace the remote directory with a recursive scp copy.
+///
+/// `scp -r src host:dest` only copies *contents* when `dest` does not exist,
+/// so the copy lands in a fresh sibling temp directory that is swapped in
+/// with `mv` only after the upload succeeds. The live directory is untouched
+/// if scp fails, and files deleted from the source are dropped, matching the
+/// `rsync --delete` result.
+fn scp_directory(
+    ssh_client: &SshClient,
+    local_path: &Path,
+    remote_path: &str,
+    compress: bool,
+) -> Result<DeployResult> {
+    let remote_path = remote_path.trim_end_matches('/');
+    let parent = Path::new(remote_path)
+        .parent()
+        .and_then(|p| p.to_str())
+        .filter(|p| !p.is_empty())
+        .unwrap_or(".");
+    let staging_path = format!("{}.homeboy-tmp-{}", remote_path, std::process::id());
+    let previous_path = format!("{}.homeboy-old-{}", remote_path, std::process::id());
+    let quoted_staging = shell::quote_path(&staging_path);
+
+    let prepare_cmd = format!(
+        "rm -rf {} && mkdir -p {}",
+        quoted_staging,
+        shell::quote_path(parent)
+    );
+    let prepare_output = ssh_client.execute(&prepare_cmd);
+    if !prepare_output.success {
+        return Ok(DeployResult::failure(
+            prepare_output.exit_code,
+            format!(
+                "Failed to prepare remote directory for scp: {}",
+                prepare_output.stderr
+            ),
+        ));
+    }
+
+    let result = scp_transfer(ssh_client, local_path, &staging_path, true, compress)?;
+    if !result.success {
+        let _ = ssh_client.execute(&format!("rm -rf {}", quoted_staging));
+        return Ok(result);
+    }
+
+    let quoted_target = shell::quote_path(remote_path);
+    let quoted_previous = shell::quote_path(&previous_path);
+    let swap_cmd = format!(
+        "rm -rf {previous} && if [ -e {target} ]; then mv {target} {previous}; fi && mv {staging} {target} && rm -rf {previous}",
+        previous = quoted_previous,
+        target = quoted_target,
+        staging = quoted_staging,
+    );
+    let swap_output = ssh_client.execute(&swap_cmd);
+    if !swap_output.success {
+        let restore_cmd = format!(
+            "if [ ! -e {target} ] && [ -e {previous} ]; then mv {previous} {target}; fi; rm -rf {staging}",
+            previous = quoted_previous,
+            target = quoted_target,
+            staging = quoted_staging,
+        );
+        let _ = ssh_client.execute(&restore_cmd);
+        return Ok(DeployResult::failure(
+            swap_output.exit_code,
+            format!(
+                "Failed to swap uploaded directory into place: {}",
+                swap_output.stderr
+            ),
+        ));
+    }
+
+    Ok(result)
 }
 
 /// Sync a local directory to the remote using rsync with --delete.
@@ -26,6 +175,7 @@ fn rsync_directory(
     ssh_client: &SshClient,
     local_path: &Path,
     remote_path: &str,
+    compress: bool,
 ) -> Result<DeployResult> {
     // Ensure local_path ends with / so rsync copies contents, not the directory itself
     let local_str = format!(
@@ -61,6 +211,9 @@ fn rsync_directory(
 
     // Remote deploy: rsync over SSH
     let mut rsync_args = vec!["-a".to_string(), "--delete".to_string()];
+    if compress {
+        rsync_args.push("-z".to_string());
+    }
 
     let mut ssh_cmd_parts = vec!["ssh".to_string()];
     ssh_cmd_parts.extend(homeboy_core::server::ssh_args::client_option_args(
@@ -100,10 +253,51 @@ pub(super) fn upload_file(
     ssh_client: &SshClient,
     local_path: &Path,
     remote_path: &str,
+    compress: bool,
 ) -> Result<DeployResult> {
     // Upload to a temporary file in the same directory and atomically replace the destination.
     // This avoids failures like: `scp: ...: Text file busy` when updating an in-use binary.
-    scp_file_atomic(ssh_client, local_path, remote_path)
+    scp_file_atomic(ssh_client, local_path, remote_path, compress)
+}
+
+/// Build the scp argument vector: configured default flags, then `-r`, `-C`,
+/// `-i`, and `-P` options, then source and destination operands. All options
+/// precede the operands so scp parses them regardless of which are present.
+fn scp_args(
+    ssh_client: &SshClient,
+    scp_flags: &[String],
+    default_ssh_port: u16,
+    local_path: &Path,
+    remote_path: &str,
+    recursive: bool,
+    compress: bool,
+) -> Vec<String> {
+    let mut args: Vec<String> = scp_flags.to_vec();
+
+    if recursive {
+        args.push("-r".to_string());
+    }
+
+    if compress {
+        args.push("-C".to_string());
+    }
+
+    if let Some(identity_file) = &ssh_client.identity_file {
+        args.extend(["-i".to_string(), identity_file.clone()]);
+    }
+
+    if ssh_client.port != default_ssh_port {
+        args.extend(["-P".to_string(), ssh_client.port.to_string()]);
+    }
+
+    args.push(local_path.to_string_lossy().to_string());
+    args.push(format!(
+        "{}@{}:{}",
+        ssh_client.user,
+        ssh_client.host,
+        shell::quote_path(remote_path)
+    ));
+    args
 }
 
 /// Core SCP transfer function.
@@ -112,6 +306,7 @@ fn scp_transfer(
     local_path: &Path,
     remote_path: &str,
     recursive: bool,
+    compress: bool,
 ) -> Result<DeployResult> {
     let label = if recursive { "directory" } else { "file" };
 
@@ -142,27 +337,15 @@ fn scp_transfer(
     }
 
     let deploy_defaults = defaults::load_defaults().deploy;
-    let mut scp_args: Vec<String> = deploy_defaults.scp_flags.clone();
-
-    if recursive {
-        scp_args.push("-r".to_string());
-    }
-
-    if let Some(identity_file) = &ssh_client.identity_file {
-        scp_args.extend(["-i".to_string(), identity_file.clone()]);
-    }
-
-    if ssh_client.port != deploy_defaults.default_ssh_port {
-        scp_args.extend(["-P".to_string(), ssh_client.port.to_string()]);
-    }
-
-    scp_args.push(local_path.to_string_lossy().to_string());
-    scp_args.push(format!(
-        "{}@{}:{}",
-        ssh_client.user,
-        ssh_client.host,
-        shell::quote_path(remote_path)
-    ));
+    let scp_args = scp_args(
+        ssh_client,
+        &deploy_defaults.scp_flags,
+        deploy_defaults.default_ssh_port,
+        local_path,
+        remote_path,
+        recursive,
+        compress,
+    );
 
     homeboy_core::log_status!(
         "deploy",
@@ -181,6 +364,61 @@ fn scp_transfer(
     }
 }
 
+/// Compare a local artifact's SHA-256 with the uploaded copy before it is
+/// extracted. On mismatch the corrupt upload is removed and a failure is
+/// returned. When the server has no hashing tool the check is skipped and a
+/// successful result carries a warning instead.
+pub(super) fn verify_upload_checksum(
+    ssh_client: &SshClient,
+    local_path: &Path,
+    remote_path: &str,
+) -> Result<DeployResult> {
+    let expected = sha256_file(local_path)?;
+    let quoted = shell::quote_path(remote_path);
+    let hash_cmd = format!(
+        "if command -v sha256sum >/dev/null 2>&1; then sha256sum {quoted}; \
+         elif command -v shasum >/dev/null 2>&1; then shasum -a 256 {quoted}; \
+         else exit 127; fi"
+    );
+    homeboy_core::log_status!("deploy", "Verifying checksum: {}", remote_path);
+    let output = ssh_client.execute(&hash_cmd);
+
+    if output.exit_code == 127 {
+        return Ok(DeployResult::success(0).with_warning(Some(format!(
+            "checksum_unverified: neither sha256sum nor shasum is available on the server; {} was not verified",
+            remote_path
+        ))));
+    }
+    let actual = output
+        .stdout
+        .split_whitespace()
+        .next()
+        .map(str::to_ascii_lowercase);
+    let Some(actual) = actual.filter(|_| output.success) else {
+        return Ok(DeployResult::failure(
+            output.exit_code,
+            format!(
+                "Failed to checksum uploaded artifact {}: {}",
+                remote_path, output.stderr
+            ),
+        ));
+    };
+
+    if actual != expected {
+        let _ = ssh_client.execute(&format!("rm -f {}", quoted));
+        return Ok(DeployResult::failure(
+            1,
+            format!(
+                "Checksum mismatch for uploaded artifact {}: local sha256 {}, remote sha256 {}. \
+                 The upload was corrupted or truncated; it was removed and nothing was extracted.",
+                remote_path, expected, actual
+            ),
+        ));
+    }
+
+    Ok(DeployResult::success(0))
+}
+
 fn process_output_result(output: Output) -> DeployResult {
     if output.status.success() {
         return DeployResult::success(0);
@@ -196,14 +434,16 @@ pub(super) fn scp_file(
     ssh_client: &SshClient,
     local_path: &Path,
     remote_path: &str,
+    compress: bool,
 ) -> Result<DeployResult> {
-    scp_transfer(ssh_client, local_path, remote_path, false)
+    scp_transfer(ssh_client, local_path, remote_path, false, compress)
 }
 
 fn scp_file_atomic(
     ssh_client: &SshClient,
     local_path: &Path,
     remote_path: &str,
+    compress: bool,
 ) -> Result<DeployResult> {
     let remote = Path::new(remote_path);
     let remote_dir = remote.parent().and_then(|p| p.to_str()).unwrap_or(".");
@@ -223,7 +463,7 @@ fn scp_file_atomic(
         std::process::id()
     );
 
-    let upload_result = scp_transfer(ssh_client, local_path, &tmp_path, false)?;
+    let upload_result = scp_transfer(ssh_client, local_path, &tmp_path, false, compress)?;
     if !upload_result.success {
         return Ok(upload_result);
     }
@@ -253,24 +493,17 @@ fn scp_file_atomic(
 
 #[cfg(test)]
 mod tests {
-    use super::{process_output_result, scp_file, upload_directory, upload_file};
+    use super::{
+        process_output_result, resolve_directory_transport, scp_args, scp_file, upload_directory,
+        upload_file, verify_upload_checksum,
+    };
+    use crate::deploy::local_test_client;
+    use crate::deploy::types::DeployTransport;
     use homeboy_core::server::SshClient;
     use std::collections::HashMap;
     use std::fs;
     use std::process::Command;
 
-    fn local_client() -> SshClient {
-        SshClient {
-            host: "localhost".to_string(),
-            user: "test".to_string(),
-            port: 22,
-            identity_file: None,
-            auth: None,
-            is_local: true,
-            env: HashMap::new(),
-        }
-    }
-
     #[test]
     fn test_upload_directory() {
         let temp = tempfile::tempdir().expect("create tempdir");
@@ -280,8 +513,14 @@ mod tests {
         fs::create_dir_all(&target).expect("create target dir");
         fs::write(source.join("file.txt"), "hello").expect("write source file");
 
-        let result = upload_directory(&local_client(), &source, target.to_str().unwrap())
-            .expect("upload directory");
+        let result = upload_directory(
+            &local_test_client(),
+            &source,
+            target.to_str().unwrap(),
+            DeployTransport::Rsync,
+            false,
+        )
+        .expect("upload directory");
 
         assert!(result.success);
         assert_eq!(
@@ -290,6 +529,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scp_directory_upload_mirrors_source_contents() {
+        let temp = tempfile::tempdir().expect("create tempdir");
+        let source = temp.path().join("source");
+        let target = temp.path().join("deploy").join("plugin");
+        fs::create_dir_all(source.join("inc")).expect("create source dir");
+        fs::create_dir_all(&target).expect("create target dir");
+        fs::write(source.join("inc/file.txt"), "hello").expect("write source file");
+        fs::write(target.join("stale.txt"), "old").expect("write stale file");
+
+        let result = upload_directory(
+            &local_test_client(),
+            &source,
+            target.to_str().unwrap(),
+            DeployTransport::Scp,
+            false,
+        )
+        .expect("upload directory");
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.error.is_none());
+        assert_eq!(
+            fs::read_to_string(target.join("inc/file.txt")).expect("read copied file"),
+            "hello"
+        );
+        assert!(!target.join("stale.txt").exists());
+        assert!(!target.join("source").exists());
+        let leftovers: Vec<_> = fs::read_dir(target.parent().unwrap())
+            .expect("read deploy dir")
+            .flatten()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| name.contains(".homeboy-"))
+            .collect();
+        assert!(leftovers.is_empty(), "{:?}", leftovers);
+    }
+
+    #[test]
+    fn failed_scp_directory_upload_keeps_live_directory() {
+        let temp = tempfile::tempdir().expect("create tempdir");
+        let target = temp.path().join("deploy").join("plugin");
+        fs::create_dir_all(&target).expect("create target dir");
+        fs::write(target.join("live.txt"), "live").expect("write live file");
+
+        let result = upload_directory(
+            &local_test_client(),
+            &temp.path().join("missing-source"),
+            target.to_str().unwrap(),
+            DeployTransport::Scp,
+            false,
+        )
+        .expect("upload directory");
+
+        assert!(!result.success);
+        assert_eq!(
+            fs::read_to_string(target.join("live.txt")).expect("live file survives"),
+            "live"
+        );
+    }
+
+    #[test]
+    fn missing_rsync_falls_back_to_scp_with_warning() {
+        assert_eq!(
+            resolve_directory_transport(DeployTransport::Rsync, true),
+            (DeployTransport::Rsync, None)
+        );
+        assert_eq!(
+            resolve_directory_transport(DeployTransport::Scp, false),
+            (DeployTransport::Scp, None)
+        );
+
+        let (transport, warning) = resolve_directory_transport(DeployTransport::Rsync, false);
+        assert_eq!(transport, DeployTransport::Scp);
+        assert!(warning
+            .expect("fallback warning")
+            .starts_with("transport_fallback:"));
+    }
+
     #[test]
     fn test_upload_file() {
         let temp = tempfile::tempdir().expect("create tempdir");
@@ -297,8 +613,13 @@ mod tests {
         let target = temp.path().join("target.txt");
         fs::write(&source, "hello").expect("write source file");
 
-        let result =
-            upload_file(&local_client(), &source, target.to_str().unwrap()).expect("upload file");
+        let result = upload_file(
+            &local_test_client(),
+            &source,
+            target.to_str().unwrap(),
+            false,
+        )
+        .expect("upload file");
 
         assert!(result.success);
         assert_eq!(
@@ -314,8 +635,13 @@ mod tests {
         let target = temp.path().join("target.txt");
         fs::write(&source, "hello").expect("write source file");
 
-        let result =
-            scp_file(&local_client(), &source, target.to_str().unwrap()).expect("scp file");
+        let result = scp_file(
+            &local_test_client(),
+            &source,
+            target.to_str().unwrap(),
+            false,
+        )
+        .expect("scp file");
 
         assert!(result.success);
         assert_eq!(
@@ -324,6 +650,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scp_args_place_compress_with_identity_and_port_before_operands() {
+        let client = SshClient {
+            host: "example.com".to_string(),
+            user: "deploy".to_string(),
+            port: 2222,
+            identity_file: Some("/keys/id_ed25519".to_string()),
+            auth: None,
+            is_local: false,
+            multiplex: false,
+            connect_timeout: 10,
+            retries: 2,
+            env: HashMap::new(),
+        };
+        let flags = vec!["-O".to_string()];
+        let source = std::path::Path::new("/tmp/plugin.zip");
+
+        let args = scp_args(&client, &flags, 22, source, "/srv/plugin.zip", false, true);
+
+        assert_eq!(
+            args,
+            vec![
+                "-O",
+                "-C",
+                "-i",
+                "/keys/id_ed25519",
+                "-P",
+                "2222",
+                "/tmp/plugin.zip",
+                "deploy@example.com:'/srv/plugin.zip'",
+            ]
+        );
+
+        let uncompressed = scp_args(&client, &flags, 22, source, "/srv/plugin.zip", false, false);
+        assert!(!uncompressed.contains(&"-C".to_string()));
+    }
+
+    #[test]
+    fn verify_upload_checksum_accepts_identical_copy() {
+        let temp = tempfile::tempdir().expect("create tempdir");
+        let source = temp.path().join("plugin.zip");
+        let uploaded = temp.path().join("uploaded.zip");
+        fs::write(&source, "archive bytes").expect("write source");
+        fs::copy(&source, &uploaded).expect("copy upload");
+
+        let result =
+            verify_upload_checksum(&local_test_client(), &source, uploaded.to_str().unwrap())
+                .expect("verify checksum");
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.error.is_none());
+        assert!(uploaded.exists());
+    }
+
+    #[test]
+    fn verify_upload_checksum_rejects_and_removes_truncated_copy() {
+        let temp = tempfile::tempdir().expect("create tempdir");
+        let source = temp.path().join("plugin.zip");
+        let uploaded = temp.path().join("uploaded.zip");
+        fs::write(&source, "archive bytes").expect("write source");
+        fs::write(&uploaded, "archive").expect("write truncated upload");
+
+        let result =
+            verify_upload_checksum(&local_test_client(), &source, uploaded.to_str().unwrap())
+                .expect("verify checksum");
+
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .is_some_and(|error| error.starts_with("Checksum mismatch")));
+        assert!(!uploaded.exists());
+    }
+
     #[test]
     fn process_output_result_returns_success_for_zero_exit() {
         let output = Command::new("sh")
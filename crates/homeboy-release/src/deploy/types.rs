@@ -35,6 +35,87 @@ pub(super) struct DeployEffect {
     pub remote_path: String,
     pub artifact_path: Option<String>,
     pub verified: bool,
+    pub ownership: Option<DeployOwnership>,
+}
+
+/// Outcome of the best-effort `chown -R` applied after files land remotely.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployOwnership {
+    /// `user` or `user:group` that deploy tried to apply.
+    pub owner: String,
+    pub source: DeployOwnershipSource,
+    pub status: DeployOwnershipStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployOwnershipSource {
+    /// From the component's (or project override's) `remote_owner`.
+    Configured,
+    /// Inherited from the owner of the target's parent directory.
+    Detected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployOwnershipStatus {
+    Applied,
+    /// `chown` was refused but the group could still be applied with `chgrp`.
+    GroupOnly,
+    Failed,
+}
+
+/// How directory artifacts are copied to the remote.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployTransport {
+    /// Delta sync with `rsync -a --delete`; falls back to scp when rsync is
+    /// missing locally or on the server.
+    #[default]
+    Rsync,
+    /// Full recursive copy with `scp -r`, replacing the target directory.
+    Scp,
+}
+
+/// Backup restored by `deploy --rollback`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployRollback {
+    /// Remote backup directory that was swapped into `remote_path`.
+    pub backup_path: String,
+    /// Version recorded when the backup was taken, when it was known.
+    pub restored_version: Option<String>,
+}
+
+/// `deploy --wait-healthy` polling policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeployHealthWait {
+    /// Seconds between health polls.
+    pub interval_secs: u64,
+    /// Give up once this many seconds have elapsed since the first poll.
+    pub timeout_secs: u64,
+}
+
+/// Outcome of polling the project health URL after a component deployed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployHealth {
+    pub url: String,
+    pub status: DeployHealthStatus,
+    /// Number of HTTP polls made, including the one that succeeded.
+    pub attempts: u32,
+    /// HTTP status of the last poll that got a response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+    /// Transport error from the last poll, when it got no response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployHealthStatus {
+    Healthy,
+    /// Never returned 2xx before the timeout; the deploy is marked `degraded`.
+    Unhealthy,
 }
 
 impl DeployResult {
@@ -60,6 +141,15 @@ impl DeployResult {
         self.effect = Some(effect);
         self
     }
+
+    /// Attach a non-fatal note to a successful result. `error` carries it so
+    /// callers can surface it as a warning without failing the deploy.
+    pub(super) fn with_warning(mut self, warning: Option<String>) -> Self {
+        if self.success {
+            self.error = warning;
+        }
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -103,6 +193,19 @@ pub struct DeployConfig {
     pub prepared_artifact: Option<PreparedDeployArtifact>,
     /// Resume a durable multi-target deploy run after exact identity validation.
     pub resume_run_id: Option<String>,
+    /// Poll the project health URL after each component deploys.
+    pub wait_healthy: Option<DeployHealthWait>,
+    /// Compress artifact uploads in transit (`scp -C`, `rsync -z`).
+    pub compress: bool,
+    /// Transfer backend for directory artifacts.
+    pub transport: DeployTransport,
+    /// Compare the uploaded archive's SHA-256 with the local artifact before
+    /// extracting it.
+    pub verify_checksum: bool,
+    /// Maximum number of components deployed concurrently within a project.
+    pub jobs: u32,
+    /// Remote backups kept per component before an artifact deploy; 0 disables them.
+    pub keep_backups: u32,
 }
 
 impl DeployConfig {
@@ -133,6 +236,12 @@ impl DeployConfig {
             tagged: false,
             prepared_artifact: None,
             resume_run_id: None,
+            wait_healthy: None,
+            compress: false,
+            transport: DeployTransport::default(),
+            verify_checksum: true,
+            jobs: 1,
+            keep_backups: 0,
         }
     }
 }
@@ -441,6 +550,14 @@ pub struct ComponentDeployResult {
     pub behind_upstream: Option<u32>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
+    /// Set when local and remote versions match but the artifact content
+    /// differs from the last deploy manifest (a likely missed version bump).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_stale_warning: Option<String>,
+    /// Dry-run only: files the deploy would add, overwrite, or delete on the
+    /// remote, from a checksum comparison against the target directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed_files: Vec<String>,
     pub error: Option<String>,
     pub artifact_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -449,6 +566,9 @@ pub struct ComponentDeployResult {
     pub artifact_inputs: Vec<ResolvedArtifactInput>,
     pub remote_path: Option<String>,
     pub build_exit_code: Option<i32>,
+    /// Exit code of the component's local `pre_deploy_command`, when one ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_deploy_exit_code: Option<i32>,
     pub deploy_exit_code: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub release_state: Option<ReleaseState>,
@@ -477,6 +597,15 @@ pub struct ComponentDeployResult {
     /// Project policy proof that authorized this component's source.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deployment_provenance: Option<DeploymentProvenanceEvidence>,
+    /// Ownership fix applied after deploy, when one was attempted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ownership: Option<DeployOwnership>,
+    /// Post-deploy health poll result, when `--wait-healthy` was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<DeployHealth>,
+    /// Backup restored by `--rollback`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollback: Option<DeployRollback>,
 }
 
 impl ComponentDeployResult {
@@ -496,12 +625,15 @@ impl ComponentDeployResult {
             is_worktree: None,
             behind_upstream: None,
             warnings: Vec::new(),
+            version_stale_warning: None,
+            changed_files: Vec::new(),
             error: None,
             artifact_path: component.build_artifact.clone(),
             artifact_source: None,
             artifact_inputs: Vec::new(),
             remote_path: base_path::join_remote_path(Some(base_path), &component.remote_path).ok(),
             build_exit_code: None,
+            pre_deploy_exit_code: None,
             deploy_exit_code: None,
             release_state: None,
             deployed_ref: None,
@@ -512,6 +644,9 @@ impl ComponentDeployResult {
             build_provenance: None,
             prepared_artifact: None,
             deployment_provenance: None,
+            ownership: None,
+            health: None,
+            rollback: None,
         }
     }
 
@@ -560,6 +695,11 @@ impl ComponentDeployResult {
         self
     }
 
+    pub(super) fn with_pre_deploy_exit_code(mut self, code: Option<i32>) -> Self {
+        self.pre_deploy_exit_code = code;
+        self
+    }
+
     pub(super) fn with_deploy_exit_code(mut self, code: Option<i32>) -> Self {
         self.deploy_exit_code = code;
         self
@@ -575,6 +715,26 @@ impl ComponentDeployResult {
         self
     }
 
+    pub(super) fn with_ownership(mut self, ownership: Option<DeployOwnership>) -> Self {
+        self.ownership = ownership;
+        self
+    }
+
+    pub(super) fn with_warning(mut self, warning: Option<String>) -> Self {
+        self.warnings.extend(warning);
+        self
+    }
+
+    pub(super) fn with_rollback(mut self, rollback: DeployRollback) -> Self {
+        self.rollback = Some(rollback);
+        self
+    }
+
+    pub(super) fn with_health(mut self, health: DeployHealth) -> Self {
+        self.health = Some(health);
+        self
+    }
+
     pub(super) fn with_prepared_artifact(mut self, artifact: PreparedDeployArtifact) -> Self {
         self.prepared_artifact = Some(artifact);
         self
@@ -1229,4 +1389,7 @@ pub struct DeploySummary {
 pub struct DeployOrchestrationResult {
     pub results: Vec<ComponentDeployResult>,
     pub summary: DeploySummary,
+    /// Resolved build/deploy order, reported when any component declares `depends_on`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deploy_order: Vec<String>,
 }
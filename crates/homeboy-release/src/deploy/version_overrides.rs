@@ -19,7 +19,7 @@ use homeboy_extension::{
 };
 
 use super::path_roots::resolve_effective_remote_path;
-use super::transfer::scp_file;
+use super::transfer::{scp_file, verify_upload_checksum};
 use super::types::{DeployEffect, DeployResult};
 
 const REMOTE_VERSION_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
@@ -455,6 +455,8 @@ pub(super) fn deploy_with_override(
     domain: Option<&str>,
     remote_owner: Option<&str>,
     cli_path_override: Option<&str>,
+    compress: bool,
+    verify_checksum: bool,
 ) -> Result<DeployResult> {
     let artifact_filename = local_path
         .file_name()
@@ -470,6 +472,7 @@ pub(super) fn deploy_with_override(
 
     let staging_artifact = format!("{}/{}", override_config.staging_path, artifact_filename);
     let mut verified = false;
+    let mut transfer_warning = None;
 
     // Step 1: Create staging directory
     let mkdir_cmd = format!(
@@ -498,10 +501,17 @@ pub(super) fn deploy_with_override(
     }
 
     // Step 2: Upload artifact to staging
-    let upload_result = scp_file(ssh_client, local_path, &staging_artifact)?;
+    let upload_result = scp_file(ssh_client, local_path, &staging_artifact, compress)?;
     if !upload_result.success {
         return Ok(upload_result);
     }
+    if verify_checksum {
+        let checksum_result = verify_upload_checksum(ssh_client, local_path, &staging_artifact)?;
+        if !checksum_result.success {
+            return Ok(checksum_result);
+        }
+        transfer_warning = checksum_result.error;
+    }
 
     // Step 3: Render and execute install command
     // Resolution order: component/project cli_path override → extension default → "wp"
@@ -558,10 +568,12 @@ pub(super) fn deploy_with_override(
     }
 
     // Step 4: Fix permissions unless skipped
-    if !override_config.skip_permissions_fix {
+    let ownership = if override_config.skip_permissions_fix {
+        None
+    } else {
         homeboy_core::log_status!("deploy", "Fixing file permissions");
-        permissions::fix_deployed_permissions(ssh_client, remote_path, remote_owner)?;
-    }
+        permissions::fix_deployed_permissions(ssh_client, remote_path, remote_owner)?
+    };
 
     // Step 5: Run verification if configured. Keep the staged artifact around
     // until after this step so extension verifiers can compare installed files
@@ -595,11 +607,14 @@ pub(super) fn deploy_with_override(
         let _ = ssh_client.execute(&cleanup_cmd); // Best effort cleanup
     }
 
-    Ok(DeployResult::success(0).with_effect(DeployEffect {
-        remote_path: remote_path.to_string(),
-        artifact_path: Some(staging_artifact),
-        verified,
-    }))
+    Ok(DeployResult::success(0)
+        .with_effect(DeployEffect {
+            remote_path: remote_path.to_string(),
+            artifact_path: Some(staging_artifact),
+            verified,
+            ownership,
+        })
+        .with_warning(transfer_warning))
 }
 
 fn deploy_override_template_vars(
@@ -698,28 +713,15 @@ pub(super) fn run_post_deploy_hooks(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::deploy::local_test_client;
     use homeboy_core::component::VersionTarget;
-    use homeboy_core::server::SshClient;
     use homeboy_extension::{
         DeployArchiveInstallPolicy, DeployOverride, DeployRequiredHeader, DeployVerification,
         ExtensionManifest,
     };
-    use std::collections::HashMap;
     use std::fs;
     use std::io::Write;
 
-    fn local_client() -> SshClient {
-        SshClient {
-            host: "localhost".to_string(),
-            user: "test".to_string(),
-            port: 22,
-            identity_file: None,
-            auth: None,
-            is_local: true,
-            env: HashMap::new(),
-        }
-    }
-
     fn extension() -> ExtensionManifest {
         serde_json::from_value(serde_json::json!({
             "id": "fixture",
@@ -791,7 +793,7 @@ mod tests {
         let versions = fetch_remote_versions(
             &[versioned_component(".")],
             temp.path().to_str().expect("base path"),
-            &local_client(),
+            &local_test_client(),
         );
 
         assert_eq!(versions.get("fixture").map(String::as_str), Some("1.2.3"));
@@ -808,7 +810,7 @@ mod tests {
             &[versioned_component("plugin")],
             None,
             temp.path().to_str().expect("base path"),
-            &local_client(),
+            &local_test_client(),
         );
 
         assert_eq!(
@@ -846,7 +848,7 @@ mod tests {
             &[component],
             None,
             temp.path().to_str().expect("base path"),
-            &local_client(),
+            &local_test_client(),
         );
 
         assert_eq!(
@@ -859,7 +861,8 @@ mod tests {
     #[test]
     fn remote_version_probe_timeout_is_bounded_and_cleans_up() {
         let started = std::time::Instant::now();
-        let output = local_client().execute_with_timeout("sleep 30", Duration::from_millis(100));
+        let output =
+            local_test_client().execute_with_timeout("sleep 30", Duration::from_millis(100));
 
         assert!(started.elapsed() < Duration::from_secs(2));
         assert!(output.timed_out);
@@ -891,7 +894,7 @@ mod tests {
             ..Default::default()
         };
 
-        run_post_deploy_hooks(&local_client(), &component, "/tmp/fixture", "/tmp");
+        run_post_deploy_hooks(&local_test_client(), &component, "/tmp/fixture", "/tmp");
     }
 
     #[test]
@@ -921,7 +924,7 @@ mod tests {
         };
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -931,6 +934,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -961,7 +966,7 @@ mod tests {
         let verification = archive_install_verification(&policy).expect("verification");
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -971,6 +976,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -1000,7 +1007,7 @@ mod tests {
         let verification = archive_install_verification(&policy).expect("verification");
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -1010,6 +1017,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -1038,7 +1047,7 @@ mod tests {
         let verification = archive_install_verification(&policy).expect("verification");
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -1048,6 +1057,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -1072,7 +1083,7 @@ mod tests {
         let verification = archive_install_verification(&policy).expect("verification");
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -1082,6 +1093,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -1158,7 +1171,7 @@ mod tests {
         let override_config = archive_install_override(&policy);
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -1168,6 +1181,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -1207,7 +1222,7 @@ mod tests {
         let override_config = archive_install_override(&policy);
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -1217,6 +1232,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -1255,7 +1272,7 @@ mod tests {
         let override_config = archive_install_override(&policy);
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -1265,6 +1282,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
@@ -1354,18 +1373,18 @@ mod tests {
         std::os::unix::fs::symlink(&real, &link).expect("symlink");
 
         assert!(target_is_symlink(
-            &local_client(),
+            &local_test_client(),
             link.to_str().expect("link path")
         ));
         assert!(!target_is_symlink(
-            &local_client(),
+            &local_test_client(),
             real.to_str().expect("real path")
         ));
         assert!(!target_is_symlink(
-            &local_client(),
+            &local_test_client(),
             temp.path().join("missing").to_str().expect("missing path")
         ));
-        assert!(!target_is_symlink(&local_client(), ""));
+        assert!(!target_is_symlink(&local_test_client(), ""));
     }
 
     // A dev symlink at the deploy target must be replaced by a real directory
@@ -1397,7 +1416,7 @@ mod tests {
         let verification = archive_install_verification(&policy).expect("verification");
 
         let result = deploy_with_override(
-            &local_client(),
+            &local_test_client(),
             &artifact,
             target.to_str().expect("target path"),
             &override_config,
@@ -1407,6 +1426,8 @@ mod tests {
             None,
             None,
             None,
+            false,
+            true,
         )
         .expect("deploy result");
 
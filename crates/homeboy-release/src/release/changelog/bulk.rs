@@ -1,10 +1,12 @@
 use serde::Serialize;
 
-use homeboy_core::component;
+use homeboy_core::component::{self, Component};
 use homeboy_core::engine::local_files;
-use homeboy_core::error::Result;
+use homeboy_core::error::{Error, Result};
 
 use super::io::*;
+use super::sections::{append_item_to_subsection, ensure_next_section};
+use super::settings::{resolve_effective_settings, KEEP_A_CHANGELOG_SUBSECTIONS};
 
 // === Changelog Show Operations ===
 
@@ -30,3 +32,210 @@ pub fn show(component_id: &str) -> Result<ShowOutput> {
         content,
     })
 }
+
+// === Changelog Notes Operations ===
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotesOutput {
+    pub component_id: String,
+    pub changelog_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    pub versions: Vec<String>,
+    pub notes: String,
+}
+
+/// Merge the finalized changelog sections between `since` and `until`.
+pub fn notes(component_id: &str, since: Option<&str>, until: Option<&str>) -> Result<NotesOutput> {
+    let shown = show(component_id)?;
+    let range = crate::release::extract_notes_range(&shown.content, since, until)?;
+
+    Ok(NotesOutput {
+        component_id: shown.component_id,
+        changelog_path: shown.changelog_path,
+        since: since.map(str::to_string),
+        until: until.map(str::to_string),
+        versions: range.versions,
+        notes: range.notes,
+    })
+}
+
+// === Changelog Add Operations ===
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AddOutput {
+    pub component_id: String,
+    pub changelog_path: String,
+    pub entry_type: String,
+    /// Entries written to the unreleased section.
+    pub added: Vec<String>,
+    /// Entries already present in the unreleased section.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<String>,
+}
+
+/// Add entries under the `entry_type` subsection (e.g. `### Fixed`) of the
+/// component's unreleased section, creating the section or subsection when
+/// missing.
+///
+/// Release normally generates the changelog, so this requires the component to
+/// opt in with `release.allow_manual_changelog_edits`.
+pub fn add(component_id: &str, entry_type: &str, messages: &[String]) -> Result<AddOutput> {
+    let component = component::resolve_effective(Some(component_id), None, None)?;
+    add_to_component(&component, entry_type, messages)
+}
+
+pub(crate) fn add_to_component(
+    component: &Component,
+    entry_type: &str,
+    messages: &[String],
+) -> Result<AddOutput> {
+    let entry_type = entry_type.trim().to_lowercase();
+    let valid_types: Vec<String> = KEEP_A_CHANGELOG_SUBSECTIONS
+        .iter()
+        .map(|header| header.trim_start_matches("### ").to_lowercase())
+        .collect();
+    if !valid_types.contains(&entry_type) {
+        return Err(Error::validation_invalid_argument(
+            "type",
+            format!("Unknown changelog entry type '{}'", entry_type),
+            None,
+            None,
+        )
+        .with_hint(format!("Use one of: {}", valid_types.join(", "))));
+    }
+
+    let messages: Vec<&str> = messages
+        .iter()
+        .map(|message| message.trim())
+        .filter(|message| !message.is_empty())
+        .collect();
+    if messages.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "message",
+            "At least one non-empty changelog message is required",
+            None,
+            None,
+        ));
+    }
+
+    if !component.release.allow_manual_changelog_edits {
+        return Err(Error::validation_invalid_argument(
+            "changelog",
+            format!(
+                "Component '{}' changelog is release-generated; manual entries are disabled",
+                component.id
+            ),
+            Some(component.id.clone()),
+            None,
+        )
+        .with_hint("Set `release.allow_manual_changelog_edits` to true on the component to allow `changelog add`"));
+    }
+
+    let changelog_path = resolve_changelog_path(component)?;
+    let original = local_files::read_file(
+        &changelog_path,
+        &format!("read changelog at {}", changelog_path.display()),
+    )?;
+    let aliases = resolve_effective_settings(Some(component)).next_section_aliases;
+
+    let (mut content, _) = ensure_next_section(&original, &aliases)?;
+    let mut added = Vec::new();
+    let mut skipped = Vec::new();
+    for message in messages {
+        let (updated, changed) =
+            append_item_to_subsection(&content, &aliases, message, &entry_type)?;
+        content = updated;
+        if changed {
+            added.push(message.to_string());
+        } else {
+            skipped.push(message.to_string());
+        }
+    }
+
+    if content != original {
+        local_files::write_file(
+            &changelog_path,
+            &content,
+            &format!("write changelog at {}", changelog_path.display()),
+        )?;
+    }
+
+    Ok(AddOutput {
+        component_id: component.id.clone(),
+        changelog_path: changelog_path.to_string_lossy().to_string(),
+        entry_type,
+        added,
+        skipped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_to_component;
+    use homeboy_core::component::Component;
+
+    fn component(dir: &std::path::Path, allow_manual_edits: bool) -> Component {
+        let mut component = Component {
+            id: "fixture".to_string(),
+            local_path: dir.to_string_lossy().to_string(),
+            changelog_target: Some("CHANGELOG.md".to_string()),
+            ..Component::default()
+        };
+        component.release.allow_manual_changelog_edits = allow_manual_edits;
+        component
+    }
+
+    #[test]
+    fn add_inserts_entries_under_typed_unreleased_subsection() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            temp.path().join("CHANGELOG.md"),
+            "# Changelog\n\n## [1.0.0] - 2026-01-01\n\n- Initial\n",
+        )
+        .expect("write changelog");
+        let component = component(temp.path(), true);
+
+        let output = add_to_component(
+            &component,
+            "Fixed",
+            &[
+                "Handle empty input".to_string(),
+                "Handle empty input".to_string(),
+            ],
+        )
+        .expect("add");
+
+        assert_eq!(output.entry_type, "fixed");
+        assert_eq!(output.added, vec!["Handle empty input"]);
+        assert_eq!(output.skipped, vec!["Handle empty input"]);
+        let changelog =
+            std::fs::read_to_string(temp.path().join("CHANGELOG.md")).expect("read changelog");
+        let unreleased = changelog.find("## Unreleased").expect("unreleased section");
+        let fixed = changelog.find("### Fixed").expect("fixed subsection");
+        let released = changelog.find("## [1.0.0]").expect("released section");
+        assert!(unreleased < fixed && fixed < released);
+        assert!(changelog.contains("- Handle empty input"));
+    }
+
+    #[test]
+    fn add_rejects_unknown_types_and_generated_changelogs() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        std::fs::write(temp.path().join("CHANGELOG.md"), "# Changelog\n").expect("write");
+        let message = vec!["Something".to_string()];
+
+        let err = add_to_component(&component(temp.path(), true), "misc", &message)
+            .expect_err("unknown type");
+        assert!(err.message.contains("misc"));
+
+        let err = add_to_component(&component(temp.path(), false), "added", &message)
+            .expect_err("manual edits disabled");
+        assert!(err.message.contains("release-generated"));
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join("CHANGELOG.md")).unwrap(),
+            "# Changelog\n"
+        );
+    }
+}
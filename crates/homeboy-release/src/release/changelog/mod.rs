@@ -4,7 +4,7 @@ mod io;
 mod sections;
 mod settings;
 
-pub use bulk::{show, ShowOutput};
+pub use bulk::{add, notes, show, AddOutput, NotesOutput, ShowOutput};
 pub use guard::{
     detect_changelog_edit, detect_manual_changelog_edit, generated_file_mutation_is_authorized,
     generated_file_mutation_is_authorized_for, ChangelogGuardViolation,
@@ -560,6 +560,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_latest_finalized_version_keeps_prerelease_suffix() {
+        let content = "# Changelog\n\n## [1.2.3-rc.2] - 2025-01-14\n\n## [1.2.3-rc.1]\n";
+        assert_eq!(
+            get_latest_finalized_version(content),
+            Some("1.2.3-rc.2".to_string())
+        );
+    }
+
+    #[test]
+    fn get_latest_finalized_version_accepts_two_and_four_segment_headings() {
+        let content = "# Changelog\n\n## Unreleased\n\n## [3.5] - 2025-01-14\n\n## 3.4\n";
+        assert_eq!(
+            get_latest_finalized_version(content),
+            Some("3.5".to_string())
+        );
+        let content = "# Changelog\n\n## [1.2.3.4]\n";
+        assert_eq!(
+            get_latest_finalized_version(content),
+            Some("1.2.3.4".to_string())
+        );
+    }
+
     #[test]
     fn get_latest_finalized_version_returns_none_when_no_versions() {
         let content = "# Changelog\n\n## Unreleased\n\n- Item\n";
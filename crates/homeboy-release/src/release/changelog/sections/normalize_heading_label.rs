@@ -63,9 +63,14 @@ pub(crate) fn is_matching_next_section_heading(line: &str, aliases: &[String]) -
 /// - "[0.1.0]" -> Some("0.1.0")
 /// - "0.1.0 - 2025-01-14" -> Some("0.1.0")
 /// - "[0.1.0] - 2025-01-14" -> Some("0.1.0")
+/// - "[1.2.3-rc.1] - 2025-01-14" -> Some("1.2.3-rc.1")
+/// - "[3.5]" -> Some("3.5"), "[1.2.3.4]" -> Some("1.2.3.4")
 /// - "Unreleased" -> None
+///
+/// Versions may have 2 to 4 numeric segments, like the versions a release
+/// bumps. The pre-release suffix is kept so `1.2.3-rc.1` sorts before `1.2.3`.
 pub(crate) fn extract_version_from_heading(label: &str) -> Option<String> {
-    text::extract_first(label, r"\[?(\d+\.\d+\.\d+)\]?")
+    text::extract_first(label, r"\[?(\d+\.\d+(?:\.\d+){0,2}(?:-[0-9A-Za-z.-]+)?)\]?")
 }
 
 /// Get the latest finalized version from the changelog (first ## heading that contains a semver).
@@ -79,6 +79,12 @@ impl ReleaseCheckoutGuard {
             final_head,
         })
     }
+
+    /// Delete a local tag the failed release created. Remote tags are never
+    /// touched here.
+    pub(super) fn delete_local_tag(&self, tag: &str) -> Result<()> {
+        run_git_checked(&self.path, &["tag", "-d", tag])
+    }
 }
 
 fn current_ref(path: &str) -> Result<OriginalRef> {
@@ -246,6 +252,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn delete_local_tag_removes_tag_left_by_rolled_back_release() {
+        let temp = init_repo();
+        let dir = temp.path();
+        let guard = ReleaseCheckoutGuard::capture(&component(dir))
+            .expect("capture")
+            .expect("git repo");
+
+        std::fs::write(dir.join("file.txt"), "1.0.0\n").expect("bump version");
+        run_git(dir, &["commit", "-q", "-am", "release: v1.0.0"]);
+        run_git(dir, &["tag", "v1.0.0"]);
+
+        guard.restore_after_failure().expect("restore");
+        assert_eq!(git_stdout_for_test(dir, &["tag", "--list"]), "v1.0.0");
+
+        guard.delete_local_tag("v1.0.0").expect("delete tag");
+
+        assert_eq!(git_stdout_for_test(dir, &["tag", "--list"]), "");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("file.txt")).unwrap(),
+            "main\n"
+        );
+    }
+
     #[test]
     fn restore_after_failure_aborts_merge_conflicts() {
         let temp = init_repo();
@@ -0,0 +1,91 @@
+//! Release commit message rendering (`release.git_commit.message_template`).
+//!
+//! The amend and orphan-tag checks recognize earlier release commits by
+//! matching the whole rendered template, so rendering and recognition share
+//! this module.
+
+use homeboy_core::component::Component;
+
+/// Template used when the component does not configure one.
+pub(super) const DEFAULT_TEMPLATE: &str = "release: v{version}";
+
+/// The component's configured template, or [`DEFAULT_TEMPLATE`].
+pub(super) fn template(component: &Component) -> &str {
+    component
+        .release
+        .git_commit
+        .message_template
+        .as_deref()
+        .filter(|template| !template.trim().is_empty())
+        .unwrap_or(DEFAULT_TEMPLATE)
+}
+
+/// Substitute `{version}`, `{tag}`, and `{date}` (local `YYYY-MM-DD`).
+pub(super) fn render(template: &str, version: &str, tag: &str) -> String {
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    template
+        .replace("{version}", version)
+        .replace("{tag}", tag)
+        .replace("{date}", &date)
+}
+
+/// What each placeholder matches when recognizing a rendered message.
+const VERSION_PATTERN: &str = r"\d+(?:\.\d+){1,3}(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?";
+const TAG_PATTERN: &str = r"\S+";
+const DATE_PATTERN: &str = r"\d{4}-\d{2}-\d{2}";
+
+/// Whether `message` could have been rendered from `template`: its literal
+/// text must match exactly, with `{version}` matching a version, `{tag}` a
+/// tag, and `{date}` a `YYYY-MM-DD` date.
+pub(super) fn matches(template: &str, message: &str) -> bool {
+    let pattern = regex::escape(template)
+        .replace(r"\{version\}", VERSION_PATTERN)
+        .replace(r"\{tag\}", TAG_PATTERN)
+        .replace(r"\{date\}", DATE_PATTERN);
+    regex::Regex::new(&format!("^{}$", pattern)).is_ok_and(|regex| regex.is_match(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches, render, DEFAULT_TEMPLATE};
+
+    #[test]
+    fn render_substitutes_placeholders() {
+        assert_eq!(
+            render(DEFAULT_TEMPLATE, "1.2.3", "v1.2.3"),
+            "release: v1.2.3"
+        );
+
+        let template = "chore(release): {tag} ({version})";
+        let message = render(template, "1.2.3", "api-v1.2.3");
+        assert_eq!(message, "chore(release): api-v1.2.3 (1.2.3)");
+
+        let dated = render("release {version} on {date}", "2.0.0", "v2.0.0");
+        assert!(!dated.contains("{date}"));
+    }
+
+    #[test]
+    fn matches_recognizes_only_full_rendered_messages() {
+        for (template, version, tag) in [
+            (DEFAULT_TEMPLATE, "1.2.3", "v1.2.3"),
+            (
+                "chore(release): {tag} ({version})",
+                "1.2.3-rc.1",
+                "api-v1.2.3-rc.1",
+            ),
+            ("release {version} on {date}", "3.5", "v3.5"),
+            ("{version} release", "1.2.3.4", "v1.2.3.4"),
+        ] {
+            let message = render(template, version, tag);
+            assert!(matches(template, &message), "{template}: {message}");
+        }
+
+        assert!(!matches(DEFAULT_TEMPLATE, "release: v1.2.3 and a hotfix"));
+        assert!(!matches("v{version}", "very important fix"));
+        assert!(!matches(
+            "chore(release): {tag} ({version})",
+            "chore(release): tidy"
+        ));
+        assert!(!matches("{version} release", "fix release notes"));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::deploy::{self, DeployConfig, PreparedDeployArtifact};
+use crate::deploy::{self, DeployConfig, DeployTransport, PreparedDeployArtifact};
 
 use super::executor::release_cleanup_paths;
 use super::types::{
@@ -280,6 +280,12 @@ fn release_deployment_config(
         tagged: false,
         prepared_artifact: Some(prepared_artifact),
         resume_run_id: None,
+        wait_healthy: None,
+        compress: false,
+        transport: DeployTransport::default(),
+        verify_checksum: true,
+        jobs: 1,
+        keep_backups: deploy::DEFAULT_KEEP_BACKUPS,
     }
 }
 
@@ -378,6 +384,8 @@ mod tests {
             durable_path: None,
             artifact_type: None,
             platform: None,
+            step_id: None,
+            extension_id: None,
         }];
 
         let result = super::run_deployment_step(
@@ -5,7 +5,7 @@ use homeboy_core::error::{Error, Result};
 use homeboy_core::git;
 use homeboy_core::plan::{PlanStep, PlanStepStatus};
 use homeboy_extension::ExtensionManifest;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::path::Path;
 use std::process::Command;
 
@@ -16,6 +16,9 @@ pub(super) struct ReleaseExecutionContext<'a> {
     pub(super) options: &'a ReleaseOptions,
     pub(super) state: ReleaseState,
     pub(super) publish_failed: bool,
+    /// Results of the concurrent publish fan-out (`--parallel-publish`),
+    /// keyed by target and handed out as each `publish.*` step is reached.
+    pub(super) parallel_publish: Option<HashMap<String, ReleaseStepResult>>,
 }
 
 pub(super) fn execute_release_plan_step(
@@ -113,8 +116,19 @@ pub(super) fn execute_release_plan_step(
             .unwrap_or_else(|err| failed_result("release.prepare", "release.prepare", err)),
         )),
         "git.commit" => {
-            executor::run_git_commit(context.component, context.component_id, &context.state)
-                .map(Some)
+            let include_notes_summary = step
+                .inputs
+                .get("include_notes_summary")
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false);
+            executor::run_git_commit(
+                context.component,
+                context.component_id,
+                &context.state,
+                include_notes_summary,
+                context.options.git_identity.is_none(),
+            )
+            .map(Some)
         }
         "package" => Ok(Some(
             executor::run_package(
@@ -232,18 +246,18 @@ pub(super) fn execute_release_plan_step(
         ))),
         step_kind if step_kind.starts_with("publish.") => {
             let target = step_kind.strip_prefix("publish.").unwrap_or_default();
-            let result = executor::run_publish(
-                context.extensions,
-                &context.state,
-                context.component_id,
-                &context.component.local_path,
-                Some(&context.component.github),
-                target,
-            )
-            .unwrap_or_else(|err| {
-                context.publish_failed = true;
-                failed_result(step_kind, step_kind, err)
-            });
+            let result = match take_parallel_publish_result(context, target) {
+                Some(result) => result,
+                None => executor::run_publish(
+                    context.extensions,
+                    &context.state,
+                    context.component_id,
+                    &context.component.local_path,
+                    Some(&context.component.github),
+                    target,
+                )
+                .unwrap_or_else(|err| failed_result(step_kind, step_kind, err)),
+            };
 
             if matches!(result.status, ReleaseStepStatus::Failed) {
                 context.publish_failed = true;
@@ -289,22 +303,11 @@ fn planned_release_tag_name(context: &ReleaseExecutionContext) -> Result<String>
         super::scope::ReleaseScope::resolve(context.component, context.component_id)?;
     let (version_floor_base, _) =
         super::planning_semver::release_version_floor_base(&release_scope, &version_info.version)?;
-    let new_version =
-        super::version::increment_version(&version_floor_base, &context.options.bump_type)
-            .ok_or_else(|| {
-                Error::validation_invalid_argument(
-                    "bump_type",
-                    format!(
-                        "Invalid bump type '{}' for current version {}",
-                        context.options.bump_type, version_info.version
-                    ),
-                    Some(context.options.bump_type.clone()),
-                    Some(vec![
-                        "Use one of: patch, minor, major, or an explicit version like 2.0.0"
-                            .to_string(),
-                    ]),
-                )
-            })?;
+    let new_version = super::version::increment_version_with_options(
+        &version_floor_base,
+        &context.options.bump_type,
+        context.options.bump_policy.preserve_prerelease,
+    )?;
 
     Ok(release_scope.tag_name(&new_version))
 }
@@ -343,13 +346,15 @@ fn run_remote_sync_preflight(
     step: &PlanStep,
     context: &ReleaseExecutionContext,
 ) -> ReleaseStepResult {
-    let result = super::planning_git::validate_remote_sync(context.component).and_then(|()| {
-        if context.options.pipeline.head {
-            super::planning_git::validate_head_reachable_from_default_branch(context.component)
-        } else {
-            super::planning_git::validate_default_branch_ancestry(context.component)
-        }
-    });
+    let result = super::planning_git::validate_push_upstream(context.component)
+        .and_then(|()| super::planning_git::validate_remote_sync(context.component))
+        .and_then(|()| {
+            if context.options.pipeline.head {
+                super::planning_git::validate_head_reachable_from_default_branch(context.component)
+            } else {
+                super::planning_git::validate_default_branch_ancestry(context.component)
+            }
+        });
 
     match result {
         Ok(()) => ReleaseStepResult {
@@ -576,6 +581,71 @@ fn configure_git_identity(
     })
 }
 
+/// With `--parallel-publish`, the first `publish.*` step runs every publish
+/// target at once; each later step picks up its own precomputed result.
+fn take_parallel_publish_result(
+    context: &mut ReleaseExecutionContext,
+    target: &str,
+) -> Option<ReleaseStepResult> {
+    if !context.options.pipeline.parallel_publish {
+        return None;
+    }
+    if context.parallel_publish.is_none() {
+        let targets = super::pipeline_capabilities::get_publish_targets(context.extensions);
+        let results = executor::run_publish_parallel(
+            context.extensions,
+            &context.state,
+            context.component_id,
+            &context.component.local_path,
+            Some(&context.component.github),
+            &targets,
+        );
+        context.parallel_publish = Some(
+            targets
+                .into_iter()
+                .zip(results)
+                .map(|(target, result)| {
+                    let step_id = format!("publish.{}", target);
+                    let result =
+                        result.unwrap_or_else(|err| failed_result(&step_id, &step_id, err));
+                    (target, result)
+                })
+                .collect(),
+        );
+    }
+    context.parallel_publish.as_mut()?.remove(target)
+}
+
+/// Module steps run extension-provided work (dependency hydration, lint, test,
+/// packaging, prepare). Under `--keep-going` a failed module step does not halt
+/// its siblings; every other step is core and stays fail-fast.
+pub(super) fn release_step_is_module(step_type: &str) -> bool {
+    matches!(
+        step_type,
+        "preflight.dependencies"
+            | "preflight.lint"
+            | "preflight.test"
+            | "preflight.package"
+            | "release.prepare"
+            | "package"
+    ) || step_type.starts_with("preflight.extension.")
+}
+
+/// Result recorded for a core step that `--keep-going` refused to run because
+/// an earlier module step already failed.
+pub(super) fn keep_going_halt_result(step: &PlanStep) -> ReleaseStepResult {
+    ReleaseStepResult {
+        id: step.id.clone(),
+        step_type: step.kind.clone(),
+        status: ReleaseStepStatus::Skipped,
+        error: Some(
+            "Not run: an earlier module step failed and core steps never run after a failure (--keep-going)"
+                .to_string(),
+        ),
+        ..Default::default()
+    }
+}
+
 pub(super) fn release_step_is_show_stopper(result: &ReleaseStepResult) -> bool {
     if !matches!(result.status, ReleaseStepStatus::Failed) {
         return false;
@@ -864,6 +934,7 @@ mod tests {
                     ..ReleaseState::default()
                 },
                 publish_failed: false,
+                parallel_publish: None,
             };
 
             execute_release_plan_step(&plan_step("preflight.package"), &mut context)
@@ -926,6 +997,7 @@ mod tests {
                     ..ReleaseState::default()
                 },
                 publish_failed: false,
+                parallel_publish: None,
             };
 
             let result = execute_release_plan_step(&plan_step("package"), &mut context)
@@ -994,6 +1066,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let deps = execute_release_plan_step(&plan_step("preflight.dependencies"), &mut context)
@@ -1044,6 +1117,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let deps = execute_release_plan_step(&plan_step("preflight.dependencies"), &mut context)
@@ -1075,6 +1149,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let deps = execute_release_plan_step(&plan_step("preflight.dependencies"), &mut context)
@@ -1109,6 +1184,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: true,
+            parallel_publish: None,
         };
         let step = plan_step("cleanup");
 
@@ -1146,6 +1222,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let result =
@@ -1191,6 +1268,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let result = execute_release_plan_step(&plan_step("preflight.working_tree"), &mut context)
@@ -1248,6 +1326,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let result = execute_release_plan_step(&plan_step("preflight.remote_sync"), &mut context)
@@ -1278,6 +1357,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         for step_type in ["preflight.lint", "preflight.test"] {
@@ -1330,6 +1410,7 @@ mod tests {
                 options: &options,
                 state: ReleaseState::default(),
                 publish_failed: false,
+                parallel_publish: None,
             };
             let mut step = plan_step("preflight.extension.registry.publish_token");
             step.inputs
@@ -1357,6 +1438,55 @@ mod tests {
         });
     }
 
+    #[test]
+    fn failing_scripts_test_is_a_release_show_stopper() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        run_in(temp.path(), &["git", "init", "-q"]);
+        configure_git_user(temp.path());
+        std::fs::write(temp.path().join("tracked.txt"), "clean\n").expect("write tracked file");
+        run_in(temp.path(), &["git", "add", "."]);
+        run_in(
+            temp.path(),
+            &["git", "commit", "-q", "-m", "Initial commit"],
+        );
+
+        let component = Component {
+            id: "fixture".to_string(),
+            local_path: temp.path().to_string_lossy().to_string(),
+            scripts: Some(ComponentScriptsConfig {
+                test: vec!["echo 'assertion failed' >&2; exit 3".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let options = ReleaseOptions::default();
+        let mut context = ReleaseExecutionContext {
+            component: &component,
+            extensions: &[],
+            component_id: "fixture",
+            options: &options,
+            state: ReleaseState::default(),
+            publish_failed: false,
+            parallel_publish: None,
+        };
+
+        let result = execute_release_plan_step(&plan_step("preflight.test"), &mut context)
+            .expect("dispatch")
+            .expect("result");
+
+        assert_eq!(result.status, ReleaseStepStatus::Failed);
+        assert!(release_step_is_show_stopper(&result));
+        assert!(
+            result
+                .error
+                .as_deref()
+                .unwrap_or_default()
+                .contains("Tests failed"),
+            "{:?}",
+            result.error
+        );
+    }
+
     #[test]
     fn quality_preflight_reports_phase_that_dirties_tracked_files() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -1392,6 +1522,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let result = execute_release_plan_step(&plan_step("preflight.test"), &mut context)
@@ -1450,6 +1581,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let unexpected = release_step_unexpected_dirty_files(
@@ -1488,6 +1620,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let result = execute_release_plan_step(&step, &mut context)
@@ -1528,6 +1661,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let result = execute_release_plan_step(&step, &mut context)
@@ -1564,6 +1698,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let result =
@@ -1614,6 +1749,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
         let mut step = plan_step("version");
         step.inputs
@@ -1685,6 +1821,7 @@ mod tests {
             options: &options,
             state: ReleaseState::default(),
             publish_failed: false,
+            parallel_publish: None,
         };
 
         let tag = planned_release_tag_name(&context).expect("planned tag");
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::collections::HashSet;
 use std::path::Path;
 
@@ -8,7 +9,8 @@ use homeboy_core::plan::{PlanStep, PlanValues};
 
 use super::context::{load_component, resolve_extensions};
 use super::execution_dispatch::{
-    execute_release_plan_step, release_step_is_show_stopper, ReleaseExecutionContext,
+    execute_release_plan_step, keep_going_halt_result, release_step_is_module,
+    release_step_is_show_stopper, ReleaseExecutionContext,
 };
 use super::plan_steps::build_preflight_steps;
 use super::types::{ReleaseOptions, ReleasePlan, ReleaseState, ReleaseStepResult};
@@ -107,17 +109,55 @@ pub(super) fn execute_plan_steps(
         options,
         state: initial_release_state(&component, component_id, options)?,
         publish_failed: false,
+        parallel_publish: None,
     };
 
+    let gate = KeepGoingGate::new(options.pipeline.keep_going);
     let run = homeboy_core::execution::execute_plan_steps_filtered(
         steps,
         |step| skip_step_ids.contains(step.id.as_str()),
-        |step| execute_release_plan_step(step, &mut context),
-        release_step_is_show_stopper,
+        |step| {
+            if gate.halts_before(step) {
+                return Ok(Some(keep_going_halt_result(step)));
+            }
+            execute_release_plan_step(step, &mut context)
+        },
+        |result| gate.should_stop(result),
     )?;
     results.extend(run.results);
 
-    Ok(run.stopped)
+    Ok(run.stopped || gate.deferred_failure.get())
+}
+
+/// Stop policy for one plan pass. Without `--keep-going` every show-stopper
+/// halts immediately. With it, a failed module step is deferred so its
+/// siblings still run, and the first core step after it is halted instead.
+struct KeepGoingGate {
+    keep_going: bool,
+    deferred_failure: Cell<bool>,
+}
+
+impl KeepGoingGate {
+    fn new(keep_going: bool) -> Self {
+        Self {
+            keep_going,
+            deferred_failure: Cell::new(false),
+        }
+    }
+
+    fn halts_before(&self, step: &PlanStep) -> bool {
+        self.deferred_failure.get() && !release_step_is_module(&step.kind)
+    }
+
+    fn should_stop(&self, result: &ReleaseStepResult) -> bool {
+        if !self.keep_going || !release_step_is_module(&result.step_type) {
+            return self.deferred_failure.get() || release_step_is_show_stopper(result);
+        }
+        if release_step_is_show_stopper(result) {
+            self.deferred_failure.set(true);
+        }
+        false
+    }
 }
 
 fn initial_release_state(
@@ -311,14 +351,43 @@ fn read_current_release_notes(component: &Component) -> Result<Option<String>> {
 mod tests {
     use super::{
         build_dry_run_preflight_plan, execute_plan_steps, initial_executable_preflight_ids,
-        resolve_head_release,
+        resolve_head_release, KeepGoingGate,
     };
-    use crate::release::types::{ReleaseOptions, ReleaseStepStatus};
-    use homeboy_core::plan::PlanStepStatus;
+    use crate::release::types::{ReleaseOptions, ReleaseStepResult, ReleaseStepStatus};
+    use homeboy_core::plan::{PlanStep, PlanStepStatus, PlanValues};
     use std::collections::HashSet;
     use std::path::Path;
     use std::process::Command;
 
+    fn failed(step_type: &str) -> ReleaseStepResult {
+        ReleaseStepResult {
+            id: step_type.to_string(),
+            step_type: step_type.to_string(),
+            status: ReleaseStepStatus::Failed,
+            ..Default::default()
+        }
+    }
+
+    fn planned(kind: &str) -> PlanStep {
+        PlanStep::ready_labeled(kind, kind, kind, Vec::new(), PlanValues::new())
+    }
+
+    #[test]
+    fn keep_going_defers_module_failures_and_halts_core_steps() {
+        let fail_fast = KeepGoingGate::new(false);
+        assert!(fail_fast.should_stop(&failed("preflight.lint")));
+
+        let gate = KeepGoingGate::new(true);
+        assert!(!gate.should_stop(&failed("preflight.lint")));
+        assert!(!gate.halts_before(&planned("preflight.test")));
+        assert!(!gate.should_stop(&failed("preflight.test")));
+        assert!(gate.halts_before(&planned("version")));
+        assert!(gate.deferred_failure.get());
+
+        let core_first = KeepGoingGate::new(true);
+        assert!(core_first.should_stop(&failed("git.tag")));
+    }
+
     #[test]
     fn test_initial_executable_preflight_ids() {
         assert_eq!(
@@ -94,6 +94,8 @@ fn release_artifacts_from_step(run_id: &str, step: &ReleaseStepResult) -> Vec<Ch
                 durable_path: _,
                 artifact_type,
                 platform,
+                step_id,
+                extension_id,
             } = artifact;
             let id = format!("{}.artifact.{}", step.id, index + 1);
             let artifact_type = artifact_type.unwrap_or_else(|| "release_artifact".to_string());
@@ -101,6 +103,12 @@ fn release_artifacts_from_step(run_id: &str, step: &ReleaseStepResult) -> Vec<Ch
             if let Some(platform) = platform {
                 metadata.insert("platform".to_string(), serde_json::Value::String(platform));
             }
+            if let Some(extension_id) = extension_id {
+                metadata.insert(
+                    "extension_id".to_string(),
+                    serde_json::Value::String(extension_id),
+                );
+            }
 
             ChangeArtifact {
                 id,
@@ -108,7 +116,7 @@ fn release_artifacts_from_step(run_id: &str, step: &ReleaseStepResult) -> Vec<Ch
                 provenance: ChangeArtifactProvenance {
                     source: "release".to_string(),
                     run_id: Some(run_id.to_string()),
-                    step_id: Some(step.id.clone()),
+                    step_id: Some(step_id.unwrap_or_else(|| step.id.clone())),
                     command: None,
                     captured_at: None,
                 },
@@ -17,8 +17,10 @@ use homeboy_core::component::Component;
 use homeboy_core::engine::validation;
 use homeboy_core::error::{Error, Result};
 
+use super::commit_message;
+use super::scope::ReleaseScope;
 use super::types::{ReleaseArtifact, ReleaseState, ReleaseStepResult, ReleaseStepStatus};
-use super::utils::extract_latest_notes;
+use super::utils::{extract_latest_notes, summarize_release_notes};
 
 pub(crate) mod artifacts;
 pub(crate) mod changelog;
@@ -35,7 +37,7 @@ pub(crate) mod version_targets;
 pub(crate) use git_push::run_git_push;
 pub(crate) use github_release::run_github_release;
 pub(crate) use package::{build_release_payload, run_extension_release_preflight, run_package};
-pub(crate) use publish::{publish_response_output, run_publish};
+pub(crate) use publish::{publish_response_output, run_publish, run_publish_parallel};
 pub(crate) use tagging::{
     github_release_exists_for_tag, run_git_tag, run_tag_availability_preflight,
 };
@@ -254,10 +256,20 @@ pub(crate) fn run_version(
 /// Commit any staged release artifacts (changelog/version files). Amends the
 /// HEAD commit when the last commit is already a release commit and the
 /// branch is ahead of origin — matches the original amend heuristic.
+///
+/// The subject is rendered from `release.git_commit.message_template`
+/// (default `release: v{version}`). With `include_notes_summary`, a one-line
+/// summary of the release notes is appended to the commit body.
+///
+/// With `use_component_identity`, the component's `git_author_name` /
+/// `git_author_email` are passed as `-c user.*` overrides. Callers turn it off
+/// when an explicit `--git-identity` already configured the repository.
 pub(crate) fn run_git_commit(
     component: &Component,
     component_id: &str,
     state: &ReleaseState,
+    include_notes_summary: bool,
+    use_component_identity: bool,
 ) -> Result<ReleaseStepResult> {
     let status_output =
         homeboy_core::git::status_at(Some(component_id), Some(&component.local_path))?;
@@ -276,19 +288,29 @@ pub(crate) fn run_git_commit(
         ));
     }
 
-    let should_amend = should_amend_release_commit(&component.local_path)?;
-    let message = state
-        .version
-        .as_ref()
-        .map(|v| format!("release: v{}", v))
-        .unwrap_or_else(|| "release: unknown".to_string());
-
-    let options = homeboy_core::git::CommitOptions {
-        staged_only: false,
-        files: None,
-        exclude: None,
+    let template = commit_message::template(component);
+    let should_amend = should_amend_release_commit(&component.local_path, template)?;
+    let mut message = match state.version.as_deref() {
+        Some(version) => {
+            let tag = ReleaseScope::resolve(component, component_id)?.tag_name(version);
+            commit_message::render(template, version, &tag)
+        }
+        None => "release: unknown".to_string(),
+    };
+    if include_notes_summary {
+        if let Some(summary) = state.notes.as_deref().and_then(summarize_release_notes) {
+            message = format!("{message}\n\n{summary}");
+        }
+    }
+
+    let mut options = homeboy_core::git::CommitOptions {
         amend: should_amend,
+        ..Default::default()
     };
+    if use_component_identity {
+        options.author_name = component.git_author_name.clone();
+        options.author_email = component.git_author_email.clone();
+    }
 
     let output = homeboy_core::git::commit_at(
         Some(component_id),
@@ -455,7 +477,7 @@ fn load_release_notes(component: &Component) -> Result<String> {
     )
 }
 
-fn should_amend_release_commit(local_path: &str) -> Result<bool> {
+fn should_amend_release_commit(local_path: &str, template: &str) -> Result<bool> {
     let log_output =
         homeboy_core::git::execute_git_for_release(local_path, &["log", "-1", "--format=%s"])
             .map_err(|e| Error::internal_io(e.to_string(), Some("git log".to_string())))?;
@@ -466,7 +488,7 @@ fn should_amend_release_commit(local_path: &str) -> Result<bool> {
         .trim()
         .to_string();
 
-    if !last_message.starts_with("release: v") {
+    if !commit_message::matches(template, &last_message) {
         return Ok(false);
     }
 
@@ -493,7 +515,9 @@ fn should_amend_release_commit(local_path: &str) -> Result<bool> {
 #[cfg(test)]
 mod tests {
     use super::package::store_artifacts_from_output;
-    use super::{github_release, package_preflight, run_cleanup, run_package};
+    use super::{
+        github_release, package_preflight, run_cleanup, run_package, should_amend_release_commit,
+    };
     use crate::release::types::ReleaseState;
     use crate::release::{ReleaseArtifact, ReleaseStepStatus};
     use homeboy_core::component::Component;
@@ -612,12 +636,16 @@ mod tests {
                     durable_path: Some(durable.display().to_string()),
                     artifact_type: None,
                     platform: None,
+                    step_id: None,
+                    extension_id: None,
                 },
                 ReleaseArtifact {
                     path: temp.path().join("missing.zip").display().to_string(),
                     durable_path: None,
                     artifact_type: None,
                     platform: None,
+                    step_id: None,
+                    extension_id: None,
                 },
             ],
             ..ReleaseState::default()
@@ -667,6 +695,8 @@ mod tests {
                 durable_path: None,
                 artifact_type: None,
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
             ..ReleaseState::default()
         };
@@ -934,6 +964,10 @@ mod tests {
             assert_eq!(state.artifacts.len(), 2);
             assert_eq!(state.artifacts[0].path, "packages/plugin/dist/plugin.zip");
             assert_eq!(state.artifacts[1].path, "target/plugin-1.2.3.tgz");
+            assert_eq!(state.artifacts[0].step_id.as_deref(), Some("package"));
+            assert_eq!(state.artifacts[0].extension_id, None);
+            assert_eq!(state.artifacts[1].step_id.as_deref(), Some("package"));
+            assert_eq!(state.artifacts[1].extension_id.as_deref(), Some("nodejs"));
             assert!(!component_dir
                 .path()
                 .join("packages/plugin/dist/plugin.zip")
@@ -1015,6 +1049,8 @@ mod tests {
                 durable_path: Some(".homeboy/artifacts/missing-plugin.zip".to_string()),
                 artifact_type: None,
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
             ..ReleaseState::default()
         };
@@ -1076,6 +1112,8 @@ mod tests {
                 durable_path: None,
                 artifact_type: Some("npm".to_string()),
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
             ..ReleaseState::default()
         };
@@ -1985,4 +2023,44 @@ mod tests {
             "expected replan/recover guidance in hints"
         );
     }
+
+    #[test]
+    fn amend_detection_recognizes_commits_from_custom_template() {
+        let origin = tempfile::tempdir().expect("origin tempdir");
+        let local = tempfile::tempdir().expect("local tempdir");
+        let git = |dir: &std::path::Path, args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .expect("run git");
+            assert!(output.status.success(), "git {:?} failed", args);
+        };
+        git(origin.path(), &["init", "-q", "--bare"]);
+        git(local.path(), &["init", "-q", "--initial-branch", "main"]);
+        git(local.path(), &["config", "user.email", "test@example.com"]);
+        git(local.path(), &["config", "user.name", "Test"]);
+        std::fs::write(local.path().join("VERSION"), "1.0.0\n").expect("write version");
+        git(local.path(), &["add", "."]);
+        git(local.path(), &["commit", "-q", "-m", "Initial commit"]);
+        let origin_path = origin.path().to_string_lossy().to_string();
+        git(local.path(), &["remote", "add", "origin", &origin_path]);
+        git(local.path(), &["push", "-q", "-u", "origin", "main"]);
+        std::fs::write(local.path().join("VERSION"), "1.1.0\n").expect("bump version");
+        git(
+            local.path(),
+            &["commit", "-q", "-am", "chore(release): 1.1.0"],
+        );
+
+        let path = local.path().to_string_lossy();
+        assert!(
+            should_amend_release_commit(&path, "chore(release): {version}").expect("amend check")
+        );
+        assert!(!should_amend_release_commit(&path, "release: v{version}").expect("amend check"));
+
+        // A user commit that only shares the template's leading text is kept.
+        std::fs::write(local.path().join("VERSION"), "1.1.1\n").expect("edit version");
+        git(local.path(), &["commit", "-q", "-am", "very important fix"]);
+        assert!(!should_amend_release_commit(&path, "v{version}").expect("amend check"));
+    }
 }
@@ -126,6 +126,8 @@ fn inventory_directory_files(
             durable_path: None,
             artifact_type: None,
             platform: None,
+            step_id: Some("artifacts.inventory".to_string()),
+            extension_id: None,
         });
     }
     Ok(artifacts)
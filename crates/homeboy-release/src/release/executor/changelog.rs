@@ -101,4 +101,42 @@ mod tests {
         assert!(changelog.contains("## [0.6.13]"));
         assert!(changelog.contains("Close release plan gap"));
     }
+
+    #[test]
+    fn changelog_finalize_dates_existing_unreleased_section() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let dir = temp.path();
+        std::fs::write(dir.join("VERSION"), "1.4.0\n").expect("write version");
+        std::fs::write(
+            dir.join("CHANGELOG.md"),
+            "# Changelog\n\n## Unreleased\n\n### Fixed\n\n- Hand-written entry\n\n## [1.4.0] - 2026-01-01\n\n- Old\n",
+        )
+        .expect("write changelog");
+        let component = Component {
+            id: "fixture".to_string(),
+            local_path: dir.to_string_lossy().to_string(),
+            changelog_target: Some("CHANGELOG.md".to_string()),
+            version_targets: Some(vec![VersionTarget {
+                file: "VERSION".to_string(),
+                pattern: Some(r"([0-9]+\.[0-9]+\.[0-9]+)".to_string()),
+                artifact_path: None,
+            }]),
+            ..Component::default()
+        };
+        let mut step = PlanStep::ready("changelog.finalize", "changelog.finalize").build();
+        step.inputs
+            .insert("from".to_string(), serde_json::json!("1.4.0"));
+        step.inputs
+            .insert("to".to_string(), serde_json::json!("1.4.1"));
+        let mut state = ReleaseState::default();
+
+        let result = run_changelog_finalize(&step, &component, &mut state).expect("finalize");
+
+        assert_eq!(result.status, ReleaseStepStatus::Success);
+        let changelog = std::fs::read_to_string(dir.join("CHANGELOG.md")).expect("read changelog");
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        assert!(changelog.contains(&format!("## [1.4.1] - {}", today)));
+        assert!(!changelog.contains("## Unreleased"));
+        assert!(changelog.contains("- Hand-written entry"));
+    }
 }
@@ -468,6 +468,8 @@ mod tests {
                 durable_path: Some(durable.display().to_string()),
                 artifact_type: None,
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
             ..ReleaseState::default()
         };
@@ -493,6 +495,8 @@ mod tests {
                 durable_path: None,
                 artifact_type: None,
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
             ..ReleaseState::default()
         };
@@ -519,6 +523,8 @@ mod tests {
                 durable_path: None,
                 artifact_type: None,
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
             ..ReleaseState::default()
         };
@@ -543,6 +549,8 @@ mod tests {
                 durable_path: None,
                 artifact_type: None,
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
             ..ReleaseState::default()
         };
@@ -90,6 +90,10 @@ pub(crate) fn run_package(
         let artifact_start = state.artifacts.len();
         store_artifacts_from_output(state, &response)
             .map_err(|err| package_provider_error(&extension.id, err))?;
+        for artifact in &mut state.artifacts[artifact_start..] {
+            artifact.step_id = Some("package".to_string());
+            artifact.extension_id = Some(extension.id.clone());
+        }
         persist_package_artifacts(state, artifact_start, component_id, component_local_path)
             .map_err(|err| package_provider_error(&extension.id, err))?;
         responses.push(serde_json::json!({
@@ -204,6 +208,8 @@ fn collect_declared_build_artifact(
         durable_path: None,
         artifact_type: None,
         platform: None,
+        step_id: Some("package".to_string()),
+        extension_id: None,
     });
     persist_package_artifacts(state, artifact_start, component_id, component_local_path)
 }
@@ -408,6 +408,8 @@ mod tests {
             durable_path: None,
             artifact_type: Some("archive".to_string()),
             platform: None,
+            step_id: None,
+            extension_id: None,
         }];
         let error = validate_package_completeness(&component, repo.path(), &artifacts)
             .expect_err("missing tracked runtime file should fail");
@@ -442,6 +444,8 @@ mod tests {
             durable_path: None,
             artifact_type: Some("archive".to_string()),
             platform: None,
+            step_id: None,
+            extension_id: None,
         }];
         validate_package_completeness(&component, repo.path(), &artifacts)
             .expect("excluded runtime file should not fail");
@@ -702,6 +706,8 @@ mod tests {
             durable_path: None,
             artifact_type: Some("archive".to_string()),
             platform: None,
+            step_id: None,
+            extension_id: None,
         }]
     }
 
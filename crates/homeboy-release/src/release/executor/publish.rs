@@ -74,6 +74,47 @@ pub(crate) fn run_publish(
     ))
 }
 
+/// Run [`run_publish`] for every target concurrently. Results come back in
+/// `targets` order, and one target failing never stops the others.
+pub(crate) fn run_publish_parallel(
+    extensions: &[ExtensionManifest],
+    state: &ReleaseState,
+    component_id: &str,
+    component_local_path: &str,
+    github_config: Option<&GithubConfig>,
+    targets: &[String],
+) -> Vec<Result<ReleaseStepResult>> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = targets
+            .iter()
+            .map(|target| {
+                scope.spawn(move || {
+                    run_publish(
+                        extensions,
+                        state,
+                        component_id,
+                        component_local_path,
+                        github_config,
+                        target,
+                    )
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .zip(targets)
+            .map(|(handle, target)| {
+                handle.join().unwrap_or_else(|_| {
+                    Err(Error::internal_unexpected(format!(
+                        "publish to {} panicked",
+                        target
+                    )))
+                })
+            })
+            .collect()
+    })
+}
+
 fn release_publish_config(
     github_config: Option<&GithubConfig>,
 ) -> Option<std::collections::HashMap<String, serde_json::Value>> {
@@ -426,7 +467,7 @@ fn publish_failure_message(target: &str, response: &serde_json::Value) -> String
 
 #[cfg(test)]
 mod tests {
-    use super::{publish_step_result, run_publish};
+    use super::{publish_step_result, run_publish, run_publish_parallel};
     use crate::release::types::ReleaseState;
     use crate::release::ReleaseStepStatus;
     use homeboy_core::component::{GithubConfig, GithubHostConfig};
@@ -523,6 +564,35 @@ mod tests {
         });
     }
 
+    #[test]
+    fn run_publish_parallel_reports_every_target_in_order() {
+        homeboy_core::test_support::with_isolated_home(|_| {
+            let component = tempfile::tempdir_in(std::env::temp_dir()).expect("component tempdir");
+            let zip_host = release_publish_extension("zip-host", "printf '{\"success\":true}'");
+            let registry = release_publish_extension("registry", "exit 3");
+            homeboy_extension::save_manifest(&zip_host).expect("save zip host extension");
+            homeboy_extension::save_manifest(&registry).expect("save registry extension");
+            let targets = vec!["registry".to_string(), "zip-host".to_string()];
+
+            let results = run_publish_parallel(
+                &[zip_host, registry],
+                &ReleaseState::default(),
+                "fixture",
+                &component.path().to_string_lossy(),
+                None,
+                &targets,
+            );
+
+            assert_eq!(results.len(), 2);
+            let registry_result = results[0].as_ref().expect("registry publish");
+            assert_eq!(registry_result.id, "publish.registry");
+            assert_eq!(registry_result.status, ReleaseStepStatus::Failed);
+            let zip_host_result = results[1].as_ref().expect("zip host publish");
+            assert_eq!(zip_host_result.id, "publish.zip-host");
+            assert_eq!(zip_host_result.status, ReleaseStepStatus::Success);
+        });
+    }
+
     #[test]
     fn run_publish_passes_github_host_config_to_action_payload() {
         homeboy_core::test_support::with_isolated_home(|_| {
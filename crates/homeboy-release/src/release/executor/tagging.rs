@@ -173,6 +173,8 @@ pub(crate) fn run_git_tag(
         ));
     }
 
+    let mut data = data;
+    data["tag"] = serde_json::Value::String(tag_name.to_string());
     state.tag = Some(tag_name.to_string());
     Ok(step_success("git.tag", "git.tag", Some(data), Vec::new()))
 }
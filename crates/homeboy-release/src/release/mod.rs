@@ -2,6 +2,7 @@ mod advanced_remote;
 pub mod cascade;
 pub mod changelog;
 mod checkout_guard;
+mod commit_message;
 mod context;
 mod deployment;
 mod execution_dispatch;
@@ -41,7 +42,7 @@ pub use types::{
     ReleaseRunSummary, ReleaseSemverCommit, ReleaseSemverRecommendation, ReleaseStepResult,
     ReleaseStepStatus,
 };
-pub use utils::{extract_latest_notes, parse_release_artifacts};
+pub use utils::{extract_latest_notes, extract_notes_range, parse_release_artifacts, RangeNotes};
 pub use workflow::{run_batch, run_command, SKIPPED_RELEASE_EXIT_CODE};
 
 /// Return the release tag name this component uses for a version.
@@ -65,7 +65,7 @@ fn run_with_plan_inner(
 
     if initial_stop {
         let mut run = finalize(component_id, results, timer.into_report());
-        restore_checkout_after_failed_run(checkout_guard, &mut run)?;
+        restore_checkout_after_failed_run(checkout_guard, options, &mut run)?;
         return Ok((initial_plan, run));
     }
 
@@ -88,7 +88,7 @@ fn run_with_plan_inner(
     })?;
 
     let mut run = finalize(component_id, results, timer.into_report());
-    restore_checkout_after_failed_run(checkout_guard, &mut run)?;
+    restore_checkout_after_failed_run(checkout_guard, options, &mut run)?;
 
     Ok((release_plan, run))
 }
@@ -119,6 +119,7 @@ fn finalize(
 
 fn restore_checkout_after_failed_run(
     checkout_guard: Option<&super::checkout_guard::ReleaseCheckoutGuard>,
+    options: &ReleaseOptions,
     run: &mut ReleaseRun,
 ) -> Result<()> {
     if matches!(run.result.status, ReleaseStepStatus::Success) {
@@ -127,18 +128,178 @@ fn restore_checkout_after_failed_run(
 
     if let Some(checkout_guard) = checkout_guard {
         let evidence = checkout_guard.restore_after_failure()?;
+        let mut deleted_tag = None;
+        let mut next_actions = Vec::new();
+        if let Some(tag) = created_unpushed_tag(&run.result.steps) {
+            if options.pipeline.rollback_on_failure {
+                // The checkout is already restored; a failed tag delete must
+                // not hide the release failure or the rollback evidence.
+                match checkout_guard.delete_local_tag(&tag) {
+                    Ok(()) => deleted_tag = Some(tag),
+                    Err(err) => {
+                        run.result.warnings.push(format!(
+                            "Could not delete unpushed release tag {}: {}",
+                            tag, err.message
+                        ));
+                        next_actions.push(format!(
+                            "Delete the unpushed release tag manually before retrying: git tag -d {}",
+                            tag
+                        ));
+                    }
+                }
+            } else {
+                next_actions.push(format!(
+                    "Delete the unpushed release tag before retrying: git tag -d {} (or re-run with --rollback-on-failure)",
+                    tag
+                ));
+            }
+        }
+        next_actions.push(
+            "Inspect remote branch and tag state before retrying: git ls-remote --heads --tags origin"
+                .to_string(),
+        );
+
         run.result.rollback = Some(ReleaseRollbackEvidence {
             original_head: evidence.original_head,
             temporary_head: evidence.temporary_head,
             final_head: evidence.final_head,
+            deleted_tag,
         });
         if let Some(summary) = &mut run.result.summary {
-            summary.next_actions.push(
-                "Inspect remote branch and tag state before retrying: git ls-remote --heads --tags origin"
-                    .to_string(),
-            );
+            summary.next_actions.extend(next_actions);
         }
     }
 
     Ok(())
 }
+
+/// Tag created by this run that never reached the remote. Once `git.push`
+/// succeeds the tag is public and rollback leaves it alone.
+fn created_unpushed_tag(steps: &[ReleaseStepResult]) -> Option<String> {
+    let succeeded = |step_type: &str| {
+        steps.iter().find(|step| {
+            step.step_type == step_type && matches!(step.status, ReleaseStepStatus::Success)
+        })
+    };
+    if succeeded("git.push").is_some() {
+        return None;
+    }
+    let data = succeeded("git.tag")?.data.as_ref()?;
+    if data.get("skipped").and_then(|value| value.as_bool()) == Some(true) {
+        return None;
+    }
+    data.get("tag")
+        .and_then(|value| value.as_str())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{created_unpushed_tag, finalize, restore_checkout_after_failed_run};
+    use crate::release::checkout_guard::ReleaseCheckoutGuard;
+    use crate::release::types::{ReleaseOptions, ReleaseStepResult, ReleaseStepStatus};
+    use homeboy_core::component::Component;
+    use homeboy_core::phase_timing::PhaseTimer;
+
+    fn step(
+        step_type: &str,
+        status: ReleaseStepStatus,
+        data: serde_json::Value,
+    ) -> ReleaseStepResult {
+        ReleaseStepResult {
+            id: step_type.to_string(),
+            step_type: step_type.to_string(),
+            status,
+            data: Some(data),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn only_created_and_unpushed_tags_are_rolled_back() {
+        let tagged = step(
+            "git.tag",
+            ReleaseStepStatus::Success,
+            serde_json::json!({ "tag": "v1.2.0" }),
+        );
+        let failed_push = step("git.push", ReleaseStepStatus::Failed, serde_json::json!({}));
+        assert_eq!(
+            created_unpushed_tag(&[tagged.clone(), failed_push]).as_deref(),
+            Some("v1.2.0")
+        );
+
+        let pushed = step(
+            "git.push",
+            ReleaseStepStatus::Success,
+            serde_json::json!({}),
+        );
+        assert_eq!(created_unpushed_tag(&[tagged, pushed]), None);
+
+        let reused = step(
+            "git.tag",
+            ReleaseStepStatus::Success,
+            serde_json::json!({ "tag": "v1.2.0", "skipped": true }),
+        );
+        assert_eq!(created_unpushed_tag(&[reused]), None);
+    }
+
+    #[test]
+    fn failed_tag_delete_keeps_rollback_evidence_and_hints_manual_delete() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let dir = temp.path();
+        for args in [
+            &["init", "-q"][..],
+            &[
+                "-c",
+                "user.email=homeboy@example.com",
+                "-c",
+                "user.name=Homeboy Test",
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                "Initial commit",
+            ],
+        ] {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .status()
+                .expect("run git");
+            assert!(status.success(), "git {:?} failed", args);
+        }
+        let component = Component {
+            id: "fixture".to_string(),
+            local_path: dir.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let guard = ReleaseCheckoutGuard::capture(&component)
+            .expect("capture")
+            .expect("git repo");
+
+        // The run reports a tag that does not exist locally, so deleting it fails.
+        let steps = vec![
+            step(
+                "git.tag",
+                ReleaseStepStatus::Success,
+                serde_json::json!({ "tag": "v9.9.9" }),
+            ),
+            step("git.push", ReleaseStepStatus::Failed, serde_json::json!({})),
+        ];
+        let mut run = finalize("fixture", steps, PhaseTimer::new().into_report());
+        let mut options = ReleaseOptions::default();
+        options.pipeline.rollback_on_failure = true;
+
+        restore_checkout_after_failed_run(Some(&guard), &options, &mut run)
+            .expect("tag delete failure is not propagated");
+
+        let rollback = run.result.rollback.as_ref().expect("rollback evidence");
+        assert_eq!(rollback.deleted_tag, None);
+        assert!(run.result.warnings.iter().any(|w| w.contains("v9.9.9")));
+        let summary = run.result.summary.as_ref().expect("summary");
+        assert!(summary
+            .next_actions
+            .iter()
+            .any(|action| action.contains("git tag -d v9.9.9")));
+    }
+}
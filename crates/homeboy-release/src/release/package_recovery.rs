@@ -206,6 +206,8 @@ fn copy_release_artifacts(
             durable_path: Some(destination.display().to_string()),
             artifact_type: artifact.artifact_type.clone(),
             platform: artifact.platform.clone(),
+            step_id: artifact.step_id.clone(),
+            extension_id: artifact.extension_id.clone(),
         });
     }
     Ok(copied)
@@ -246,6 +248,8 @@ mod tests {
                 durable_path: None,
                 artifact_type: Some("archive".to_string()),
                 platform: None,
+                step_id: None,
+                extension_id: None,
             }],
         )
         .expect("copy artifacts");
@@ -44,6 +44,17 @@ pub(super) fn build_summary(
         .filter(|r| matches!(r.status, ReleaseStepStatus::Missing))
         .count();
 
+    let failed_steps = results
+        .iter()
+        .filter(|r| {
+            matches!(
+                r.status,
+                ReleaseStepStatus::Failed | ReleaseStepStatus::Missing
+            )
+        })
+        .map(|r| r.id.clone())
+        .collect();
+
     let mut next_actions = match status {
         ReleaseStepStatus::PartialSuccess | ReleaseStepStatus::Failed => vec![
             "Fix the issue and re-run (idempotent - completed steps will succeed again)"
@@ -85,6 +96,7 @@ pub(super) fn build_summary(
         failed,
         skipped,
         missing,
+        failed_steps,
         next_actions,
         success_summary,
     }
@@ -266,6 +278,7 @@ mod tests {
         assert_eq!(summary.succeeded, 1);
         assert_eq!(summary.failed, 1);
         assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.failed_steps, vec!["release.prepare".to_string()]);
         assert_eq!(summary.next_actions.len(), 1);
     }
 
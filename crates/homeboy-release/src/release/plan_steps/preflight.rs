@@ -1,6 +1,6 @@
 use super::builders::{disabled_step, ready_step, string_config, StepConfig};
 use crate::release::types::{ReleaseOptions, ReleaseSemverRecommendation};
-use homeboy_core::plan::PlanStep;
+use homeboy_core::plan::{PlanStep, PlanStepStatus};
 use homeboy_core::quality::{
     build_quality_steps as build_shared_quality_steps, QualityPlanOptions,
 };
@@ -121,15 +121,30 @@ fn build_extension_release_preflight_steps(extensions: &[ExtensionManifest]) ->
         .flat_map(|extension| {
             extension.release_preflights.iter().map(|preflight| {
                 let step_id = format!("preflight.extension.{}.{}", extension.id, preflight.id);
+                let config = StepConfig::new()
+                    .string("extension", extension.id.clone())
+                    .string("action", preflight.action.clone())
+                    .string("preflight", preflight.id.clone());
+                // Surface an unresolvable action at plan time so `--dry-run`
+                // reports it instead of the real run failing mid-preflight.
+                if !extension
+                    .actions
+                    .iter()
+                    .any(|action| action.id == preflight.action)
+                {
+                    return PlanStep::builder(&step_id, &step_id, PlanStepStatus::Missing)
+                        .label(preflight.label.clone())
+                        .needs(preflight.needs.clone())
+                        .inputs(config)
+                        .missing([preflight.action.clone()])
+                        .build();
+                }
                 ready_step(
                     &step_id,
                     &step_id,
                     preflight.label.clone(),
                     preflight.needs.clone(),
-                    StepConfig::new()
-                        .string("extension", extension.id.clone())
-                        .string("action", preflight.action.clone())
-                        .string("preflight", preflight.id.clone()),
+                    config,
                 )
             })
         })
@@ -1,6 +1,7 @@
 use super::builders::{ready_step, string_array_config, string_config, StepConfig};
 use super::changelog::build_changelog_steps;
 use super::hints::{github_release_applies, push_publish_vs_github_release_hints};
+use crate::release::commit_message;
 use crate::release::pipeline_capabilities::{
     get_publish_targets, has_package_capability, has_prepare_capability,
 };
@@ -118,12 +119,24 @@ pub(in crate::release) fn build_release_steps(
         vec!["version".to_string()]
     };
 
+    let commit_config = if component.release.git_commit.include_notes_summary {
+        StepConfig::new().bool("include_notes_summary", true)
+    } else {
+        StepConfig::new()
+    };
     steps.push(ready_step(
         "git.commit",
         "git.commit",
-        format!("Commit release: v{}", new_version),
+        format!(
+            "Commit {}",
+            commit_message::render(
+                commit_message::template(component),
+                new_version,
+                &release_scope.tag_name(new_version),
+            )
+        ),
         commit_needs,
-        StepConfig::new(),
+        commit_config,
     ));
 
     let tag_needs = if package_step_needed {
@@ -119,6 +119,36 @@ fn release_plan_adds_extension_declared_release_preflight() {
     );
 }
 
+#[test]
+fn release_plan_marks_extension_preflight_with_unknown_action_missing() {
+    let options = ReleaseOptions {
+        bump_type: "patch".to_string(),
+        ..Default::default()
+    };
+    let mut extension: ExtensionManifest = serde_json::from_value(serde_json::json!({
+        "name": "Registry",
+        "version": "1.0.0",
+        "release_preflights": [
+            {
+                "id": "publish_token",
+                "label": "Validate registry publish token",
+                "action": "release.preflight.publish-token"
+            }
+        ]
+    }))
+    .expect("extension manifest");
+    extension.id = "registry".to_string();
+
+    let steps = build_preflight_steps(&options, None, &[extension]);
+    let token = steps
+        .iter()
+        .find(|step| step.id == "preflight.extension.registry.publish_token")
+        .expect("extension release preflight");
+
+    assert_eq!(token.status, PlanStepStatus::Missing);
+    assert_eq!(token.missing, vec!["release.preflight.publish-token"]);
+}
+
 #[test]
 fn release_plan_marks_quality_preflights_disabled_when_checks_are_skipped() {
     let options = ReleaseOptions {
@@ -431,6 +461,44 @@ fn test_build_release_steps() {
     );
 }
 
+#[test]
+fn release_plan_passes_commit_notes_summary_option_to_git_commit() {
+    let mut component = fixture_component();
+    component.release.git_commit.include_notes_summary = true;
+    let mut warnings = Vec::new();
+    let mut hints = Vec::new();
+    let release_scope = ReleaseScope::resolve(&component, &component.id).expect("release scope");
+    let options = ReleaseOptions {
+        bump_type: "patch".to_string(),
+        ..Default::default()
+    };
+
+    let steps = build_release_steps(
+        &component,
+        &[],
+        "1.0.0",
+        "1.0.1",
+        &fixture_changelog_plan(),
+        &options,
+        &release_scope,
+        &mut warnings,
+        &mut hints,
+    )
+    .expect("steps");
+
+    let commit = steps
+        .iter()
+        .find(|step| step.id == "git.commit")
+        .expect("git.commit step");
+    assert_eq!(
+        commit
+            .inputs
+            .get("include_notes_summary")
+            .and_then(|value| value.as_bool()),
+        Some(true)
+    );
+}
+
 #[test]
 fn release_plan_runs_package_preflight_before_mutating_release_steps() {
     let mut component = fixture_component();
@@ -114,9 +114,41 @@ pub fn plan(component_id: &str, options: &ReleaseOptions) -> Result<ReleasePlan>
         .unwrap_or_default()
     };
 
+    if options.pipeline.head && options.from_version.is_some() {
+        v.push(
+            "from_version",
+            "--from-version cannot be combined with --head, which releases the current source version as-is",
+            None,
+        );
+    }
+
     let new_version = if let Some(ref info) = version_info {
         if options.pipeline.head {
             Some(info.version.clone())
+        } else if let Some(ref from_version) = options.from_version {
+            match validate_from_version(from_version) {
+                Ok(()) => {
+                    warnings.push(format!(
+                        "Release baseline overridden: planning from {} (--from-version) instead of source version {}.",
+                        from_version, info.version
+                    ));
+                    match version::increment_version_with_options(
+                        from_version,
+                        &options.bump_type,
+                        options.bump_policy.preserve_prerelease,
+                    ) {
+                        Ok(ver) => Some(ver),
+                        Err(err) => {
+                            v.push("version", &err.message, None);
+                            None
+                        }
+                    }
+                }
+                Err(message) => {
+                    v.push("from_version", &message, None);
+                    None
+                }
+            }
         } else {
             let (version_floor_base, floor_tag) = v
                 .capture(
@@ -130,14 +162,14 @@ pub fn plan(component_id: &str, options: &ReleaseOptions) -> Result<ReleasePlan>
                     tag, info.version, version_floor_base
                 ));
             }
-            match version::increment_version(&version_floor_base, &options.bump_type) {
-                Some(ver) => Some(ver),
-                None => {
-                    v.push(
-                        "version",
-                        &format!("Invalid version format: {}", info.version),
-                        None,
-                    );
+            match version::increment_version_with_options(
+                &version_floor_base,
+                &options.bump_type,
+                options.bump_policy.preserve_prerelease,
+            ) {
+                Ok(ver) => Some(ver),
+                Err(err) => {
+                    v.push("version", &err.message, None);
                     None
                 }
             }
@@ -311,6 +343,19 @@ fn apply_oversized_patch_release_policy(
     ))
 }
 
+/// `--from-version` must be a full semver version so the bump and the
+/// release-floor checks compare like with like.
+fn validate_from_version(from_version: &str) -> std::result::Result<(), String> {
+    semver::Version::parse(from_version)
+        .map(|_| ())
+        .map_err(|err| {
+            format!(
+                "--from-version '{}' is not a valid semver version: {}",
+                from_version, err
+            )
+        })
+}
+
 fn is_explicit_patch_bump(requested_bump: &str, current_version: &str) -> bool {
     if requested_bump == "patch" {
         return true;
@@ -331,7 +376,9 @@ fn is_explicit_patch_bump(requested_bump: &str, current_version: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::guard_stale_primary_at_head;
-    use super::{apply_oversized_patch_release_policy, oversized_patch_release_bump};
+    use super::{
+        apply_oversized_patch_release_policy, oversized_patch_release_bump, validate_from_version,
+    };
     use crate::release::types::{
         ReleaseChangelogPlan, ReleaseSemverCommit, ReleaseSemverRecommendation,
     };
@@ -424,6 +471,16 @@ mod tests {
         assert_eq!(oversized_patch_release_bump("minor", 50), "minor");
     }
 
+    #[test]
+    fn from_version_must_be_full_semver() {
+        assert!(validate_from_version("1.4.0").is_ok());
+        assert!(validate_from_version("2.0.0-rc.1").is_ok());
+
+        let err = validate_from_version("1.4").expect_err("two segments are not semver");
+        assert!(err.contains("--from-version '1.4' is not a valid semver version"));
+        assert!(validate_from_version("v1.4.0").is_err());
+    }
+
     #[test]
     fn oversized_patch_release_warning_is_quiet_for_small_patch_scope() {
         let mut recommendation = Some(semver_recommendation("patch", 3));
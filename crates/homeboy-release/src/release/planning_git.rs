@@ -22,6 +22,62 @@ pub(super) fn validate_remote_sync(component: &Component) -> Result<()> {
     Ok(())
 }
 
+/// Check that the release push has somewhere to go before any tag is created.
+///
+/// The push step targets the resolved source remote explicitly, so a branch
+/// without an upstream still pushes — but only if that remote exists. A missing
+/// remote fails here with the detected branch and remote instead of leaving a
+/// local tag behind a raw `git push` error. A missing upstream is only warned.
+pub(super) fn validate_push_upstream(component: &Component) -> Result<()> {
+    let path = std::path::Path::new(&component.local_path);
+    let Some(branch) = git::current_branch(path).filter(|branch| !branch.is_empty()) else {
+        return Ok(());
+    };
+    let remote = source_remote(component);
+    let has_upstream = command::run_in_optional(
+        &component.local_path,
+        "git",
+        &["rev-parse", "--abbrev-ref", "@{u}"],
+    )
+    .is_some();
+
+    if git::remote_url(path, &remote).is_none() {
+        let message = if has_upstream {
+            format!(
+                "Remote '{}' is not configured for branch '{}'; the release push would fail after tagging",
+                remote, branch
+            )
+        } else {
+            format!(
+                "Branch '{}' has no upstream and remote '{}' is not configured; the release push would fail after tagging",
+                branch, remote
+            )
+        };
+        return Err(Error::validation_invalid_argument(
+            "remote",
+            message,
+            Some(format!("{}@{}", branch, remote)),
+            Some(vec![
+                format!("Add the remote: git remote add {} <url>", remote),
+                format!("Then publish the branch: git push -u {} {}", remote, branch),
+            ]),
+        ));
+    }
+
+    if !has_upstream {
+        homeboy_core::log_status!(
+            "release",
+            "Branch '{}' has no upstream; release will push HEAD to '{}' explicitly. Run `git push -u {} {}` to set tracking.",
+            branch,
+            remote,
+            remote,
+            branch
+        );
+    }
+
+    Ok(())
+}
+
 pub(super) fn validate_default_branch(component: &Component) -> Result<()> {
     let current_branch = current_branch(component)?;
     let default_branch = default_branch(component);
@@ -293,7 +349,7 @@ fn source_remote(component: &Component) -> String {
 mod tests {
     use super::{
         release_push_branch, validate_default_branch, validate_default_branch_ancestry,
-        validate_head_reachable_from_default_branch, validate_remote_sync,
+        validate_head_reachable_from_default_branch, validate_push_upstream, validate_remote_sync,
     };
     use homeboy_core::component::Component;
 
@@ -567,4 +623,46 @@ mod tests {
                 .stdout
         );
     }
+
+    #[test]
+    fn test_validate_push_upstream_rejects_missing_remote() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        run_git(temp.path(), &["init", "-q", "--initial-branch", "main"]);
+        configure_git_user(temp.path());
+        run_git(
+            temp.path(),
+            &["commit", "-q", "--allow-empty", "-m", "Initial commit"],
+        );
+
+        let err = validate_push_upstream(&git_component(temp.path()))
+            .expect_err("release push without a remote should fail before tagging");
+
+        assert!(err.message.contains("Branch 'main' has no upstream"));
+        assert!(err.message.contains("remote 'origin'"));
+        assert!(err.details.to_string().contains("git push -u origin main"));
+    }
+
+    #[test]
+    fn test_validate_push_upstream_allows_remote_without_tracking() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let remote = temp.path().join("remote.git");
+        let checkout = temp.path().join("checkout");
+        let remote_str = remote.to_string_lossy().to_string();
+
+        run_git(
+            temp.path(),
+            &["init", "--bare", "--initial-branch", "main", &remote_str],
+        );
+        std::fs::create_dir(&checkout).expect("create checkout");
+        run_git(&checkout, &["init", "-q", "--initial-branch", "main"]);
+        configure_git_user(&checkout);
+        run_git(
+            &checkout,
+            &["commit", "-q", "--allow-empty", "-m", "Initial commit"],
+        );
+        run_git(&checkout, &["remote", "add", "origin", &remote_str]);
+
+        validate_push_upstream(&git_component(&checkout))
+            .expect("explicit push target does not require tracking");
+    }
 }
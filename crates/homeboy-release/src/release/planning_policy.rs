@@ -93,6 +93,9 @@ fn force_release_command_hint(
     if let Some(ref identity) = options.git_identity {
         parts.push(format!("--git-identity {}", quote_if_needed(identity)));
     }
+    if let Some(ref from_version) = options.from_version {
+        parts.push(format!("--from-version {}", quote_if_needed(from_version)));
+    }
     parts.join(" ")
 }
 
@@ -18,8 +18,12 @@ pub(super) fn build_semver_recommendation(
 
     // Explicit version strings (e.g. "2.0.0") skip semver keyword parsing.
     // The version is used verbatim: no underbump check, no rank comparison.
-    let is_explicit_version =
-        requested_bump.contains('.') && requested_bump.split('.').all(|p| p.parse::<u32>().is_ok());
+    // Pre-release bumps (rc, finalize, ...) step within one version's release
+    // series rather than ranking against the commit-derived bump, so they are
+    // treated the same way.
+    let is_explicit_version = (requested_bump.contains('.')
+        && requested_bump.split('.').all(|p| p.parse::<u32>().is_ok()))
+        || super::version::is_prerelease_bump(requested_bump);
 
     let recommended = git::recommended_bump_from_commits(&commits);
 
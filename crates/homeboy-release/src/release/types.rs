@@ -201,6 +201,9 @@ pub struct ReleaseRollbackEvidence {
     pub original_head: String,
     pub temporary_head: String,
     pub final_head: String,
+    /// Local release tag deleted during rollback (`--rollback-on-failure`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_tag: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -259,6 +262,9 @@ pub struct ReleaseRunSummary {
     pub failed: usize,
     pub skipped: usize,
     pub missing: usize,
+    /// IDs of every failed or missing step, in execution order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_steps: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub next_actions: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -274,6 +280,12 @@ pub struct ReleaseArtifact {
     pub artifact_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub platform: Option<String>,
+    /// Release step that produced this artifact (e.g. `package`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step_id: Option<String>,
+    /// Extension whose action emitted this artifact, when one did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extension_id: Option<String>,
 }
 
 /// Mutable state threaded through sequential release execution.
@@ -307,6 +319,19 @@ pub struct ReleasePipelineOptions {
     /// Deploy after release — defers artifact cleanup until after deployment.
     #[serde(default)]
     pub deploy: bool,
+    /// Keep running sibling module steps (dependencies/lint/test/package/
+    /// prepare) after one fails so every failure is reported in one run. Core
+    /// steps stay fail-fast and never run once any step has failed.
+    #[serde(default)]
+    pub keep_going: bool,
+    /// When a failed run is rolled back, also delete the local release tag it
+    /// created, provided the tag was never pushed.
+    #[serde(default)]
+    pub rollback_on_failure: bool,
+    /// Run the independent `publish.*` steps concurrently instead of one after
+    /// another. Results are still reported in plan order.
+    #[serde(default)]
+    pub parallel_publish: bool,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -346,6 +371,10 @@ pub struct ReleaseOptions {
     /// Git identity for release commits: "bot", "Name <email>", or None (use existing config).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub git_identity: Option<String>,
+    /// Baseline version to bump from instead of the version read from the
+    /// component's version targets. Must be valid semver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_version: Option<String>,
     /// Bump policy controls that affect release plan validation.
     #[serde(default, skip_serializing_if = "ReleaseBumpPolicyOptions::is_default")]
     pub bump_policy: ReleaseBumpPolicyOptions,
@@ -362,6 +391,10 @@ pub struct ReleaseBumpPolicyOptions {
     /// Require an explicit `--bump major` for stable major releases.
     #[serde(default)]
     pub require_explicit_major: bool,
+    /// Carry the current pre-release suffix over to a keyword bump
+    /// (`1.2.3-beta` patch → `1.2.4-beta`) instead of dropping it.
+    #[serde(default)]
+    pub preserve_prerelease: bool,
 }
 
 impl ReleaseBumpPolicyOptions {
@@ -406,6 +439,10 @@ pub struct ReleaseCommandInput {
     /// Permit a keyword bump lower than the commit-derived recommendation.
     #[serde(default)]
     pub force_lower_bump: bool,
+    /// Keep the pre-release suffix on a keyword bump (see
+    /// [`ReleaseBumpPolicyOptions::preserve_prerelease`]).
+    #[serde(default)]
+    pub preserve_prerelease: bool,
     #[serde(default, flatten)]
     pub pipeline: ReleasePipelineOptions,
     /// Skip the GitHub Release creation step (tag + notes on github.com).
@@ -414,6 +451,9 @@ pub struct ReleaseCommandInput {
     /// Git identity for release commits: "bot", "Name <email>", or None (use existing config).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub git_identity: Option<String>,
+    /// Baseline version override (see [`ReleaseOptions::from_version`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_version: Option<String>,
     /// Internal execution contract resolved before the workflow runs.
     #[serde(skip_serializing)]
     pub execution: Option<ReleaseExecutionPlan>,
@@ -655,5 +695,6 @@ mod tests {
         let input = ReleaseCommandInput::default();
 
         assert!(!input.force_lower_bump);
+        assert!(!input.preserve_prerelease);
     }
 }
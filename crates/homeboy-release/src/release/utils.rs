@@ -1,9 +1,18 @@
 use homeboy_core::engine::validation;
-use homeboy_core::error::Result;
+use homeboy_core::error::{Error, Result};
 
 use super::types::ReleaseArtifact;
 
+/// Body of the newest release section in a changelog.
+///
+/// The section starts at the first `## ` heading that carries a version. When
+/// no heading does (date or named headings such as `## 2024-06-01`), the first
+/// `## ` heading starts the section instead.
 pub fn extract_latest_notes(content: &str) -> Option<String> {
+    let has_versioned_heading = content.lines().any(|line| {
+        let trimmed = line.trim();
+        trimmed.starts_with("## ") && extract_version_from_heading(trimmed).is_some()
+    });
     let mut in_section = false;
     let mut buffer = Vec::new();
 
@@ -13,7 +22,7 @@ pub fn extract_latest_notes(content: &str) -> Option<String> {
             if in_section {
                 break;
             }
-            if extract_version_from_heading(trimmed).is_some() {
+            if !has_versioned_heading || extract_version_from_heading(trimmed).is_some() {
                 in_section = true;
                 continue;
             }
@@ -32,8 +41,132 @@ pub fn extract_latest_notes(content: &str) -> Option<String> {
     }
 }
 
+/// Notes for every finalized changelog section between two versions.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RangeNotes {
+    /// Versions included, newest first (changelog order).
+    pub versions: Vec<String>,
+    /// Included sections concatenated with their original headings.
+    pub notes: String,
+}
+
+/// Collect all finalized changelog sections from `since` up to `until`
+/// (both inclusive). An omitted bound is open-ended, so `since` alone runs to
+/// the latest release and `until` alone runs back to the oldest.
+pub fn extract_notes_range(
+    content: &str,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Result<RangeNotes> {
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("## ") {
+            match extract_version_from_heading(trimmed) {
+                Some(version) => sections.push((version, vec![line])),
+                // An unversioned heading (e.g. Unreleased) ends the previous section.
+                None => sections.push((String::new(), Vec::new())),
+            }
+            continue;
+        }
+        if let Some((_, lines)) = sections.last_mut() {
+            if !lines.is_empty() {
+                lines.push(line);
+            }
+        }
+    }
+    sections.retain(|(version, _)| !version.is_empty());
+
+    let available: Vec<String> = sections.iter().map(|(v, _)| v.clone()).collect();
+    let position = |field: &str, version: Option<&str>| -> Result<Option<usize>> {
+        let Some(version) = version else {
+            return Ok(None);
+        };
+        let version = version.trim_start_matches('v');
+        available
+            .iter()
+            .position(|candidate| candidate == version)
+            .map(Some)
+            .ok_or_else(|| {
+                Error::validation_invalid_argument(
+                    field,
+                    format!("Version {} does not appear in the changelog", version),
+                    Some(version.to_string()),
+                    Some(available.clone()),
+                )
+            })
+    };
+    // Changelogs list newest first, so `until` is the smaller index.
+    let end = position("since", since)?.unwrap_or(available.len().saturating_sub(1));
+    let start = position("until", until)?.unwrap_or(0);
+    if start > end {
+        return Err(Error::validation_invalid_argument(
+            "since",
+            format!(
+                "--since {} is newer than --until {}",
+                since.unwrap_or_default(),
+                until.unwrap_or_default()
+            ),
+            None,
+            None,
+        ));
+    }
+
+    let selected = sections.get(start..=end).unwrap_or_default();
+    Ok(RangeNotes {
+        versions: selected.iter().map(|(v, _)| v.clone()).collect(),
+        notes: selected
+            .iter()
+            .map(|(_, lines)| lines.join("\n").trim().to_string())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    })
+}
+
+/// One-line summary of a release-notes section: per-category entry counts
+/// from `### Added`-style subsections (`2 added, 1 fixed`), a plain entry
+/// count when the notes are uncategorized, or the first line when they have
+/// no list entries at all.
+pub fn summarize_release_notes(notes: &str) -> Option<String> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    let mut category: Option<String> = None;
+
+    for line in notes.lines() {
+        let trimmed = line.trim();
+        if let Some(heading) = trimmed.strip_prefix("### ") {
+            category = Some(heading.trim().to_lowercase());
+        } else if trimmed.starts_with("- ") || trimmed.starts_with("* ") {
+            let key = category.clone().unwrap_or_default();
+            match counts.iter_mut().find(|(name, _)| *name == key) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((key, 1)),
+            }
+        }
+    }
+
+    if counts.is_empty() {
+        return notes
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string);
+    }
+
+    Some(
+        counts
+            .iter()
+            .map(|(name, count)| match name.as_str() {
+                "" if *count == 1 => "1 change".to_string(),
+                "" => format!("{count} changes"),
+                name => format!("{count} {name}"),
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
 fn extract_version_from_heading(label: &str) -> Option<String> {
-    let semver_pattern = regex::Regex::new(r"\[?(\d+\.\d+\.\d+)\]?").ok()?;
+    let semver_pattern = regex::Regex::new(r"\[?(\d+\.\d+(?:\.\d+){0,2})\]?").ok()?;
     semver_pattern
         .captures(label)
         .and_then(|caps| caps.get(1))
@@ -48,7 +181,6 @@ pub fn parse_release_artifacts(value: &serde_json::Value) -> Result<Vec<ReleaseA
         _ => Vec::new(),
     };
 
-    use homeboy_core::error::Error;
     for item in items {
         let artifact = match item {
             serde_json::Value::String(path) => ReleaseArtifact {
@@ -56,6 +188,8 @@ pub fn parse_release_artifacts(value: &serde_json::Value) -> Result<Vec<ReleaseA
                 durable_path: None,
                 artifact_type: None,
                 platform: None,
+                step_id: None,
+                extension_id: None,
             },
             serde_json::Value::Object(map) => {
                 let path = validation::require(
@@ -77,6 +211,8 @@ pub fn parse_release_artifacts(value: &serde_json::Value) -> Result<Vec<ReleaseA
                     durable_path: None,
                     artifact_type,
                     platform,
+                    step_id: None,
+                    extension_id: None,
                 }
             }
             _ => {
@@ -93,3 +229,75 @@ pub fn parse_release_artifacts(value: &serde_json::Value) -> Result<Vec<ReleaseA
 
     Ok(artifacts)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_latest_notes_falls_back_to_unversioned_headings() {
+        let dated = "# Changelog\n\n## 2024-06-01\n\n- Dated entry\n\n## 2024-05-01\n\n- Older\n";
+        assert_eq!(
+            extract_latest_notes(dated).as_deref(),
+            Some("- Dated entry")
+        );
+
+        let named = "# Changelog\n\n## Unreleased\n\n- Pending entry\n";
+        assert_eq!(
+            extract_latest_notes(named).as_deref(),
+            Some("- Pending entry")
+        );
+
+        let mixed =
+            "# Changelog\n\n## Unreleased\n\n- Pending\n\n## [1.2.0] - 2024-06-01\n\n- Shipped\n";
+        assert_eq!(extract_latest_notes(mixed).as_deref(), Some("- Shipped"));
+
+        let two_segment = "# Changelog\n\n## Unreleased\n\n- Pending\n\n## [3.5]\n\n- Shipped\n";
+        assert_eq!(
+            extract_latest_notes(two_segment).as_deref(),
+            Some("- Shipped")
+        );
+    }
+
+    const CHANGELOG: &str = "# Changelog\n\n## Unreleased\n\n- pending\n\n## [1.2.0] - 2025-03-01\n\n- minor\n\n## [1.1.1] - 2025-02-01\n\n- patch two\n\n## [1.1.0] - 2025-01-01\n\n- patch one\n";
+
+    #[test]
+    fn notes_range_concatenates_inclusive_sections() {
+        let range = extract_notes_range(CHANGELOG, Some("1.1.1"), Some("1.2.0")).unwrap();
+        assert_eq!(range.versions, vec!["1.2.0", "1.1.1"]);
+        assert!(range.notes.starts_with("## [1.2.0] - 2025-03-01"));
+        assert!(range.notes.contains("- patch two"));
+        assert!(!range.notes.contains("pending"));
+        assert!(!range.notes.contains("patch one"));
+    }
+
+    #[test]
+    fn notes_range_open_bounds_and_unknown_versions() {
+        let all = extract_notes_range(CHANGELOG, None, None).unwrap();
+        assert_eq!(all.versions, vec!["1.2.0", "1.1.1", "1.1.0"]);
+
+        let err = extract_notes_range(CHANGELOG, Some("0.9.0"), None).unwrap_err();
+        assert!(err.message.contains("0.9.0"));
+        assert!(err.details.to_string().contains("1.1.0"));
+
+        assert!(extract_notes_range(CHANGELOG, Some("1.2.0"), Some("1.1.0")).is_err());
+    }
+
+    #[test]
+    fn summarize_release_notes_counts_entries_per_category() {
+        let notes = "### Added\n\n- one\n- two\n\n### Fixed\n\n- three\n";
+        assert_eq!(
+            summarize_release_notes(notes).as_deref(),
+            Some("2 added, 1 fixed")
+        );
+        assert_eq!(
+            summarize_release_notes("- only\n").as_deref(),
+            Some("1 change")
+        );
+        assert_eq!(
+            summarize_release_notes("\nSecurity hotfix.\nDetails follow.").as_deref(),
+            Some("Security hotfix.")
+        );
+        assert_eq!(summarize_release_notes("  \n"), None);
+    }
+}
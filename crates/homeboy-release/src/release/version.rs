@@ -41,37 +41,208 @@ pub fn parse_version(content: &str, pattern: &str) -> Option<String> {
     text::extract_first(content, pattern)
 }
 
-/// Increment semver version or set an explicit version.
+/// Increment a version or set an explicit version.
 ///
 /// bump_type can be:
-/// - "patch", "minor", or "major" — increments the corresponding semver component
+/// - "patch", "minor", or "major" — increments the corresponding component
 /// - An explicit version string like "2.0.0" — returned as-is after validation
-pub fn increment_version(version: &str, bump_type: &str) -> Option<String> {
+///
+/// - "alpha", "beta", or "rc" — a numbered pre-release; see [`PRERELEASE_BUMP_TYPES`]
+/// - "prerelease" — the next pre-release of the current label (`rc` when stable)
+/// - "finalize" — strips the pre-release suffix (`1.2.3-rc.2` → `1.2.3`)
+///
+/// Versions may have 2 to 4 numeric segments (`3.4`, `1.2.3`, `1.2.3.4`) and
+/// an optional pre-release/build suffix (`1.2.3-beta`), which is dropped. See
+/// [`increment_version_with_options`] to keep it.
+pub fn increment_version(version: &str, bump_type: &str) -> Result<String> {
+    increment_version_with_options(version, bump_type, false)
+}
+
+/// [`increment_version`], optionally carrying the pre-release suffix over to
+/// the bumped version (`1.2.3-beta` → `1.2.4-beta`).
+///
+/// Segments are positional: major, minor, patch, then build. Bumping one
+/// zeroes every later segment, and bumping a segment the version lacks
+/// appends it (`3.4` patch → `3.4.1`), so the segment count never shrinks.
+pub fn increment_version_with_options(
+    version: &str,
+    bump_type: &str,
+    preserve_prerelease: bool,
+) -> Result<String> {
     if bump_type.contains('.') {
-        let parts: Vec<&str> = bump_type.split('.').collect();
-        if parts.len() != 3 || parts.iter().any(|p| p.parse::<u32>().is_err()) {
-            return None;
+        parse_version_segments(bump_type, "bump_type")?;
+        return Ok(bump_type.to_string());
+    }
+    if is_prerelease_bump(bump_type) {
+        return increment_prerelease(version, bump_type);
+    }
+
+    let bump_index = match bump_type {
+        "major" => 0,
+        "minor" => 1,
+        "patch" => 2,
+        _ => {
+            return Err(Error::validation_invalid_argument(
+                "bump_type",
+                format!("Unknown bump type '{}'", bump_type),
+                Some(bump_type.to_string()),
+                Some(vec![
+                    "Use one of: patch, minor, major, alpha, beta, rc, prerelease, finalize, or an explicit version like 2.0.0"
+                        .to_string(),
+                ]),
+            ))
+        }
+    };
+
+    let (mut segments, suffix) = parse_version_segments(version, "version")?;
+    if segments.len() <= bump_index {
+        segments.resize(bump_index + 1, 0);
+    }
+    segments[bump_index] += 1;
+    for segment in &mut segments[bump_index + 1..] {
+        *segment = 0;
+    }
+
+    let core = join_segments(&segments);
+    Ok(if preserve_prerelease {
+        format!("{}{}", core, suffix)
+    } else {
+        core
+    })
+}
+
+/// Pre-release bump types, in precedence order for the labelled ones:
+/// `alpha` < `beta` < `rc`. A labelled bump on a stable version starts the
+/// next patch's pre-release (`1.2.3` rc → `1.2.4-rc.1`); on a pre-release of
+/// the same label it increments the counter (`1.2.4-rc.1` → `1.2.4-rc.2`); on
+/// a lower label it moves up and restarts at 1 (`1.2.4-beta.3` rc →
+/// `1.2.4-rc.1`). Moving to a lower label is rejected, since the result would
+/// sort before the current version.
+pub const PRERELEASE_BUMP_TYPES: &[&str] = &["alpha", "beta", "rc", "prerelease", "finalize"];
+
+const PRERELEASE_LABELS: &[&str] = &["alpha", "beta", "rc"];
+
+/// Whether `bump_type` is one of [`PRERELEASE_BUMP_TYPES`].
+pub fn is_prerelease_bump(bump_type: &str) -> bool {
+    PRERELEASE_BUMP_TYPES.contains(&bump_type)
+}
+
+fn increment_prerelease(version: &str, bump_type: &str) -> Result<String> {
+    let (segments, suffix) = parse_version_segments(version, "version")?;
+    let core = join_segments(&segments);
+    // Build metadata (`+...`) never survives a pre-release bump.
+    let prerelease = suffix
+        .split('+')
+        .next()
+        .and_then(|pre| pre.strip_prefix('-'));
+    let current = prerelease.map(|pre| match pre.rsplit_once('.') {
+        Some((label, counter)) if counter.parse::<u32>().is_ok() => {
+            (label, counter.parse::<u32>().ok())
         }
-        return Some(bump_type.to_string());
+        _ => (pre, None),
+    });
+
+    if bump_type == "finalize" {
+        return match current {
+            Some(_) => Ok(core),
+            None => Err(Error::validation_invalid_argument(
+                "bump_type",
+                format!("Cannot finalize '{}': it is not a pre-release", version),
+                Some(bump_type.to_string()),
+                None,
+            )),
+        };
     }
 
-    let parts: Vec<&str> = version.split('.').collect();
-    if parts.len() != 3 {
-        return None;
+    let label = match (bump_type, current) {
+        ("prerelease", Some((label, _))) => label,
+        ("prerelease", None) => "rc",
+        (label, _) => label,
+    };
+    let Some((current_label, counter)) = current else {
+        let next_core = increment_version(&core, "patch")?;
+        return Ok(format!("{}-{}.1", next_core, label));
+    };
+
+    if current_label == label {
+        return Ok(format!("{}-{}.{}", core, label, counter.unwrap_or(0) + 1));
+    }
+    let rank = |label: &str| PRERELEASE_LABELS.iter().position(|known| *known == label);
+    if let (Some(from), Some(to)) = (rank(current_label), rank(label)) {
+        if to < from {
+            return Err(Error::validation_invalid_argument(
+                "bump_type",
+                format!(
+                    "Cannot bump '{}' to a {} pre-release: {} sorts before {}",
+                    version, label, label, current_label
+                ),
+                Some(bump_type.to_string()),
+                Some(vec![format!(
+                    "Use --bump {} to continue the {} series, or --bump finalize",
+                    current_label, current_label
+                )]),
+            ));
+        }
     }
+    Ok(format!("{}-{}.1", core, label))
+}
 
-    let major: u32 = parts[0].parse().ok()?;
-    let minor: u32 = parts[1].parse().ok()?;
-    let patch: u32 = parts[2].parse().ok()?;
+fn join_segments(segments: &[u32]) -> String {
+    segments
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
 
-    let (new_major, new_minor, new_patch) = match bump_type {
-        "patch" => (major, minor, patch + 1),
-        "minor" => (major, minor + 1, 0),
-        "major" => (major + 1, 0, 0),
-        _ => return None,
+/// Split a version into its numeric segments and any `-pre`/`+build` suffix,
+/// naming the offending segment when it is not a number.
+fn parse_version_segments<'a>(version: &'a str, field: &str) -> Result<(Vec<u32>, &'a str)> {
+    let (core, suffix) = version
+        .find(['-', '+'])
+        .map_or((version, ""), |at| version.split_at(at));
+    let invalid = |message: String| {
+        Error::validation_invalid_argument(
+            field,
+            message,
+            Some(version.to_string()),
+            Some(vec![
+                "Expected 2 to 4 numeric segments with an optional suffix, e.g. 3.4, 1.2.3, 1.2.3.4, or 1.2.3-beta"
+                    .to_string(),
+            ]),
+        )
     };
 
-    Some(format!("{}.{}.{}", new_major, new_minor, new_patch))
+    let parts: Vec<&str> = core.split('.').collect();
+    if !(2..=4).contains(&parts.len()) {
+        return Err(invalid(format!(
+            "Invalid version '{}': expected 2 to 4 segments, found {}",
+            version,
+            parts.len()
+        )));
+    }
+    let segments = parts
+        .iter()
+        .enumerate()
+        .map(|(index, part)| {
+            part.parse::<u32>().map_err(|_| {
+                invalid(format!(
+                    "Invalid version '{}': segment {} ('{}') is not a number",
+                    version,
+                    index + 1,
+                    part
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if suffix.len() == 1 {
+        return Err(invalid(format!(
+            "Invalid version '{}': empty pre-release or build suffix",
+            version
+        )));
+    }
+
+    Ok((segments, suffix))
 }
 
 /// Get version string from a component's first version target.
@@ -102,20 +273,21 @@ fn find_version_pattern_in_extension(
     None
 }
 
-/// Update version in a file, handling both JSON and text-based version files.
-/// Returns the number of replacements made.
-pub(crate) fn update_version_in_file(
+/// Compute a version file's bumped content without writing it, handling both
+/// JSON and text-based version files. Returns the new content and the number
+/// of replacements made.
+fn render_version_update(
     path: &str,
+    content: &str,
     pattern: &str,
     old_version: &str,
     new_version: &str,
-) -> Result<usize> {
+) -> Result<(String, usize)> {
     // JSON files with default pattern use structured update
     if Path::new(path).extension().is_some_and(|ext| ext == "json")
         && default_pattern_for_file(path).as_deref() == Some(pattern)
     {
-        let content = local_files::local().read(Path::new(path))?;
-        let mut json: Value = from_str(&content)?;
+        let mut json: Value = from_str(content)?;
         let Some(current) = json.get("version").and_then(|v: &Value| v.as_str()) else {
             return Err(Error::config_missing_key("version", Some(path.to_string())));
         };
@@ -132,15 +304,11 @@ pub(crate) fn update_version_in_file(
             "/version",
             serde_json::Value::String(new_version.to_string()),
         )?;
-        let output = to_string_pretty(&json)?;
-        local_files::local().write(Path::new(path), &output)?;
-        return Ok(1);
+        return Ok((to_string_pretty(&json)?, 1));
     }
 
     // Text files use regex replacement
-    let content = local_files::read_file(Path::new(path), "read version file")?;
-
-    let versions = parse_versions(&content, pattern).ok_or_else(|| {
+    let versions = parse_versions(content, pattern).ok_or_else(|| {
         Error::validation_invalid_argument(
             "versionPattern",
             format!("Invalid version regex pattern '{}'", pattern),
@@ -166,19 +334,89 @@ pub(crate) fn update_version_in_file(
         }
     }
 
-    let (new_content, replaced_count) = replace_versions(&content, pattern, new_version)
-        .ok_or_else(|| {
-            Error::validation_invalid_argument(
-                "versionPattern",
-                format!("Invalid version regex pattern '{}'", pattern),
-                None,
-                Some(vec![pattern.to_string()]),
-            )
-        })?;
+    replace_versions(content, pattern, new_version).ok_or_else(|| {
+        Error::validation_invalid_argument(
+            "versionPattern",
+            format!("Invalid version regex pattern '{}'", pattern),
+            None,
+            Some(vec![pattern.to_string()]),
+        )
+    })
+}
+
+/// A version file's original and bumped content, staged before any write.
+struct StagedVersionFile {
+    path: String,
+    original: String,
+    updated: String,
+}
+
+/// Render the bumped content of every version target without writing
+/// anything. Targets that share a file (e.g. a plugin header and a `define`)
+/// are applied on top of each other.
+fn stage_version_targets(
+    target_infos: &[VersionTargetInfo],
+    old_version: &str,
+    new_version: &str,
+) -> Result<Vec<StagedVersionFile>> {
+    let mut staged: Vec<StagedVersionFile> = Vec::new();
+
+    for info in target_infos {
+        let existing = staged.iter().position(|file| file.path == info.full_path);
+        let current = match existing {
+            Some(index) => staged[index].updated.clone(),
+            None => local_files::read_file(Path::new(&info.full_path), "read version file")?,
+        };
+
+        let (updated, replaced_count) = render_version_update(
+            &info.full_path,
+            &current,
+            &info.pattern,
+            old_version,
+            new_version,
+        )?;
+        if replaced_count != info.match_count {
+            return Err(Error::internal_unexpected(format!(
+                "Unexpected replacement count in {}",
+                info.file
+            )));
+        }
 
-    local_files::write_file(Path::new(path), &new_content, "write version file")?;
+        match existing {
+            Some(index) => staged[index].updated = updated,
+            None => staged.push(StagedVersionFile {
+                path: info.full_path.clone(),
+                original: current,
+                updated,
+            }),
+        }
+    }
 
-    Ok(replaced_count)
+    Ok(staged)
+}
+
+/// Write staged version files, each one atomically. If any write fails, the
+/// files already written are restored so the targets never disagree about the
+/// version.
+fn write_staged_version_files(staged: &[StagedVersionFile]) -> Result<()> {
+    for (index, file) in staged.iter().enumerate() {
+        if let Err(err) = local_files::write_file_atomic(
+            Path::new(&file.path),
+            &file.updated,
+            "write version file",
+        ) {
+            for written in &staged[..index] {
+                let _ = local_files::write_file_atomic(
+                    Path::new(&written.path),
+                    &written.original,
+                    "restore version file",
+                );
+            }
+            return Err(err);
+        }
+    }
+
+    Ok(())
 }
 
 /// Read version from a local file for a component's version target.
@@ -496,18 +734,14 @@ pub(crate) fn bump_component_version_with_changelog(
     }
 
     let old_version = text::require_identical(&primary_versions, &primary.file)?;
-    let new_version = increment_version(&old_version, bump_type).ok_or_else(|| {
-        Error::validation_invalid_argument(
-            "version",
-            format!("Invalid version format: {}", old_version),
-            None,
-            Some(vec![old_version.clone()]),
-        )
-    })?;
+    let new_version = increment_version(&old_version, bump_type)?;
 
     // Pre-validate ALL version targets BEFORE any file modifications.
     // This prevents changelog finalization when version files are out of sync.
     let target_infos = validate_version_targets(targets, &component.local_path, &old_version)?;
+    // Render every target's new content up front: a target that can't be
+    // updated fails the bump before the changelog or any version file changes.
+    let staged = stage_version_targets(&target_infos, &old_version, &new_version)?;
 
     // Now safe to finalize changelog - all targets validated. Release execution
     // can provide an already-finalized changelog result from the executable
@@ -522,18 +756,8 @@ pub(crate) fn bump_component_version_with_changelog(
         )?,
     };
 
-    // Update all version files (validation already done, just write new versions)
-    for info in &target_infos {
-        let replaced_count =
-            update_version_in_file(&info.full_path, &info.pattern, &old_version, &new_version)?;
-
-        if replaced_count != info.match_count {
-            return Err(Error::internal_unexpected(format!(
-                "Unexpected replacement count in {}",
-                info.file
-            )));
-        }
-    }
+    // Update all version files together (all written, or none left changed)
+    write_staged_version_files(&staged)?;
 
     // Replace @since placeholder tags with the new version (extension-driven).
     let since_tags_replaced = replace_since_tag_placeholders(component, &new_version)?;
@@ -619,49 +843,109 @@ mod tests {
 
     #[test]
     fn increment_version_patch() {
-        assert_eq!(
-            increment_version("1.2.3", "patch"),
-            Some("1.2.4".to_string())
-        );
+        assert_eq!(increment_version("1.2.3", "patch").unwrap(), "1.2.4");
     }
 
     #[test]
     fn increment_version_minor() {
-        assert_eq!(
-            increment_version("1.2.3", "minor"),
-            Some("1.3.0".to_string())
-        );
+        assert_eq!(increment_version("1.2.3", "minor").unwrap(), "1.3.0");
     }
 
     #[test]
     fn increment_version_major() {
+        assert_eq!(increment_version("1.2.3", "major").unwrap(), "2.0.0");
+    }
+
+    #[test]
+    fn increment_version_explicit_version() {
+        assert_eq!(increment_version("1.25.5", "2.0.0").unwrap(), "2.0.0");
+        assert_eq!(increment_version("0.5.0", "1.0.0").unwrap(), "1.0.0");
+        assert_eq!(increment_version("3.4", "3.5").unwrap(), "3.5");
+    }
+
+    #[test]
+    fn increment_version_explicit_invalid() {
+        assert!(increment_version("1.0.0", "2.").is_err());
+        let err = increment_version("1.0.0", "abc.def.ghi").unwrap_err();
+        assert!(err.message.contains("segment 1 ('abc')"), "{}", err.message);
+    }
+
+    #[test]
+    fn increment_version_unknown_bump_type() {
+        let err = increment_version("1.0.0", "huge").unwrap_err();
+        assert!(err.message.contains("Unknown bump type 'huge'"));
+    }
+
+    #[test]
+    fn increment_version_handles_two_and_four_segments() {
+        assert_eq!(increment_version("3.4", "patch").unwrap(), "3.4.1");
+        assert_eq!(increment_version("3.4", "minor").unwrap(), "3.5");
+        assert_eq!(increment_version("3.4", "major").unwrap(), "4.0");
+        assert_eq!(increment_version("1.2.3.4", "patch").unwrap(), "1.2.4.0");
+        assert_eq!(increment_version("1.2.3.4", "minor").unwrap(), "1.3.0.0");
+    }
+
+    #[test]
+    fn increment_version_drops_or_keeps_prerelease_suffix() {
+        assert_eq!(increment_version("1.2.3-beta", "patch").unwrap(), "1.2.4");
         assert_eq!(
-            increment_version("1.2.3", "major"),
-            Some("2.0.0".to_string())
+            increment_version_with_options("1.2.3-beta.2", "minor", true).unwrap(),
+            "1.3.0-beta.2"
+        );
+        assert_eq!(
+            increment_version_with_options("3.4+build7", "patch", true).unwrap(),
+            "3.4.1+build7"
         );
     }
 
     #[test]
-    fn increment_version_explicit_version() {
+    fn increment_version_prerelease_series_and_finalize() {
+        assert_eq!(increment_version("1.2.2", "rc").unwrap(), "1.2.3-rc.1");
+        assert_eq!(increment_version("1.2.3-rc.1", "rc").unwrap(), "1.2.3-rc.2");
+        assert_eq!(
+            increment_version("1.2.3-rc.2", "prerelease").unwrap(),
+            "1.2.3-rc.3"
+        );
+        assert_eq!(
+            increment_version("1.2.3-beta.4", "rc").unwrap(),
+            "1.2.3-rc.1"
+        );
+        assert_eq!(
+            increment_version("1.2.3-beta", "prerelease").unwrap(),
+            "1.2.3-beta.1"
+        );
         assert_eq!(
-            increment_version("1.25.5", "2.0.0"),
-            Some("2.0.0".to_string())
+            increment_version("1.2.2", "prerelease").unwrap(),
+            "1.2.3-rc.1"
         );
         assert_eq!(
-            increment_version("0.5.0", "1.0.0"),
-            Some("1.0.0".to_string())
+            increment_version("1.2.3-rc.2", "finalize").unwrap(),
+            "1.2.3"
         );
+        assert_eq!(increment_version("3.4", "alpha").unwrap(), "3.4.1-alpha.1");
     }
 
     #[test]
-    fn increment_version_explicit_invalid() {
-        assert_eq!(increment_version("1.0.0", "2.0"), None);
-        assert_eq!(increment_version("1.0.0", "abc.def.ghi"), None);
+    fn increment_version_rejects_backwards_prerelease_and_stable_finalize() {
+        let err = increment_version("1.2.3-rc.1", "beta").unwrap_err();
+        assert!(
+            err.message.contains("beta sorts before rc"),
+            "{}",
+            err.message
+        );
+        let err = increment_version("1.2.3", "finalize").unwrap_err();
+        assert!(err.message.contains("not a pre-release"), "{}", err.message);
     }
 
     #[test]
-    fn increment_version_unknown_bump_type() {
-        assert_eq!(increment_version("1.0.0", "huge"), None);
+    fn increment_version_names_the_invalid_segment() {
+        let err = increment_version("1.x.3", "patch").unwrap_err();
+        assert!(err.message.contains("segment 2 ('x')"), "{}", err.message);
+        let err = increment_version("7", "patch").unwrap_err();
+        assert!(err.message.contains("found 1"), "{}", err.message);
+        let err = increment_version("1.2.3.4.5", "patch").unwrap_err();
+        assert!(err.message.contains("found 5"), "{}", err.message);
+        assert!(increment_version("1.2.3-", "patch").is_err());
     }
 
     #[test]
@@ -757,4 +1041,85 @@ mod tests {
         let generated = fs::read_to_string(temp_dir.path().join("generated.lock")).unwrap();
         assert!(generated.contains("0.1.1"));
     }
+
+    #[test]
+    fn bump_component_version_updates_every_target_together() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("plugin.php"),
+            "<?php\n/*\n * Version: 1.0.0\n * Requires at least: 1.0.0\n */\ndefine( 'PLUGIN_VERSION', '1.0.0' );\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            "{\n  \"version\": \"1.0.0\"\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("CHANGELOG.md"),
+            "# Changelog\n\n## Unreleased\n\n",
+        )
+        .unwrap();
+
+        let mut component = make_test_component(&temp_dir);
+        component.version_targets = Some(vec![
+            VersionTarget {
+                file: "plugin.php".to_string(),
+                pattern: Some(r"Version:\s*([0-9.]+)".to_string()),
+                artifact_path: None,
+            },
+            VersionTarget {
+                file: "plugin.php".to_string(),
+                pattern: Some(r"'PLUGIN_VERSION', '([0-9.]+)'".to_string()),
+                artifact_path: None,
+            },
+            VersionTarget {
+                file: "package.json".to_string(),
+                pattern: Some(r#""version"\s*:\s*"([^"]+)""#.to_string()),
+                artifact_path: None,
+            },
+        ]);
+        let mut entries: std::collections::HashMap<String, Vec<String>> =
+            std::collections::HashMap::new();
+        entries.insert("fixed".to_string(), vec!["sync versions".to_string()]);
+
+        let result =
+            bump_component_version_with_changelog(&component, "patch", Some(&entries), None)
+                .expect("bump all targets");
+
+        let files: Vec<&str> = result.targets.iter().map(|t| t.file.as_str()).collect();
+        assert_eq!(files, vec!["plugin.php", "plugin.php", "package.json"]);
+        let plugin = fs::read_to_string(temp_dir.path().join("plugin.php")).unwrap();
+        assert!(plugin.contains("Version: 1.0.1"));
+        assert!(plugin.contains("Requires at least: 1.0.0"));
+        assert!(plugin.contains("'PLUGIN_VERSION', '1.0.1'"));
+        let package = fs::read_to_string(temp_dir.path().join("package.json")).unwrap();
+        assert!(package.contains("\"1.0.1\""));
+    }
+
+    #[test]
+    fn write_staged_version_files_restores_written_files_on_failure() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let plugin = temp_dir.path().join("plugin.php");
+        fs::write(&plugin, "Version: 1.0.0\n").unwrap();
+        let staged = vec![
+            StagedVersionFile {
+                path: plugin.to_string_lossy().to_string(),
+                original: "Version: 1.0.0\n".to_string(),
+                updated: "Version: 1.0.1\n".to_string(),
+            },
+            StagedVersionFile {
+                path: temp_dir
+                    .path()
+                    .join("missing/package.json")
+                    .to_string_lossy()
+                    .to_string(),
+                original: String::new(),
+                updated: "{}".to_string(),
+            },
+        ];
+
+        assert!(write_staged_version_files(&staged).is_err());
+        assert_eq!(fs::read_to_string(&plugin).unwrap(), "Version: 1.0.0\n");
+    }
 }
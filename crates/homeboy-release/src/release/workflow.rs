@@ -93,13 +93,15 @@ pub fn run_command(input: ReleaseCommandInput) -> Result<(ReleaseCommandResult,
             }
             override_value.clone()
         } else {
-            // Semver keyword: major, minor, patch
+            // Semver keyword (major, minor, patch) or pre-release bump
             let bump = override_value.to_lowercase();
-            if !["major", "minor", "patch"].contains(&bump.as_str()) {
+            if !["major", "minor", "patch"].contains(&bump.as_str())
+                && !super::version::is_prerelease_bump(&bump)
+            {
                 return Err(Error::validation_invalid_argument(
                     "bump",
                     format!(
-                        "Invalid --bump value '{}'. Use: major, minor, patch, or a version like 2.0.0",
+                        "Invalid --bump value '{}'. Use: major, minor, patch, alpha, beta, rc, prerelease, finalize, or a version like 2.0.0",
                         override_value
                     ),
                     Some(override_value.clone()),
@@ -168,10 +170,12 @@ pub fn run_command(input: ReleaseCommandInput) -> Result<(ReleaseCommandResult,
         pipeline: input.pipeline.clone(),
         skip_github_release: input.skip_github_release,
         git_identity: input.git_identity.clone(),
+        from_version: input.from_version.clone(),
         bump_policy: ReleaseBumpPolicyOptions {
             force_lower_bump: input.force_lower_bump,
             force_empty_release: input.bump_override.is_some(),
             require_explicit_major,
+            preserve_prerelease: input.preserve_prerelease,
         },
     };
 
@@ -666,7 +670,10 @@ fn git_tag_summary_line(run: &ReleaseRun) -> String {
         .as_ref()
         .and_then(|data| data.get("tag"))
         .and_then(|value| value.as_str());
-    if run.result.rollback.is_some() {
+    if let Some(rollback) = &run.result.rollback {
+        if let Some(deleted) = rollback.deleted_tag.as_deref() {
+            return format!("Tag {} deleted during rollback", deleted);
+        }
         return tag
             .map(|tag| {
                 format!(
@@ -814,9 +821,11 @@ pub fn run_batch(
             skip_deps_hydration: input_template.skip_deps_hydration,
             bump_override: input_template.bump_override.clone(),
             force_lower_bump: input_template.force_lower_bump,
+            preserve_prerelease: input_template.preserve_prerelease,
             pipeline: input_template.pipeline.clone(),
             skip_github_release: input_template.skip_github_release,
             git_identity: input_template.git_identity.clone(),
+            from_version: input_template.from_version.clone(),
             execution: input_template.execution.clone(),
         };
 
@@ -1251,6 +1260,7 @@ mod tests {
                     original_head: "original".to_string(),
                     temporary_head: "release-commit".to_string(),
                     final_head: "original".to_string(),
+                    deleted_tag: None,
                 }),
             },
         };
@@ -1346,7 +1356,7 @@ mod tests {
         );
         run_in(dir, &["git", "tag", "v0.7.6"]);
 
-        let warning = diagnose_orphan_tag(&dir.to_string_lossy(), "v0.7.6")
+        let warning = diagnose_orphan_tag(&dir.to_string_lossy(), "v0.7.6", "release: v{version}")
             .expect("orphan tag should produce a warning");
 
         assert!(warning.contains("v0.7.6"));
@@ -1367,7 +1377,9 @@ mod tests {
         run_in(dir, &["git", "commit", "-q", "-m", "release: v0.7.4"]);
         run_in(dir, &["git", "tag", "v0.7.4"]);
 
-        assert!(diagnose_orphan_tag(&dir.to_string_lossy(), "v0.7.4").is_none());
+        assert!(
+            diagnose_orphan_tag(&dir.to_string_lossy(), "v0.7.4", "release: v{version}").is_none()
+        );
     }
 
     #[test]
@@ -9,6 +9,7 @@ use homeboy_core::git;
 use homeboy_core::plan::PlanStep;
 
 use super::advanced_remote;
+use super::commit_message;
 use super::context::load_component;
 use super::scope::ReleaseScope;
 use super::types::{ReleaseCommandInput, ReleaseCommandResult, ReleaseOptions, ReleasePlan};
@@ -108,7 +109,11 @@ pub(super) fn run_recover(input: &ReleaseCommandInput) -> Result<(ReleaseCommand
     // back-fill a release: commit, or run `--recover` to commit the version
     // files at the tagged commit.
     if let Some(latest_tag) = latest_release_tag(&release_scope) {
-        if let Some(diagnostic) = diagnose_orphan_tag(&component.local_path, &latest_tag) {
+        if let Some(diagnostic) = diagnose_orphan_tag(
+            &component.local_path,
+            &latest_tag,
+            commit_message::template(&component),
+        ) {
             homeboy_core::log_status!("recover", "{}", diagnostic);
         }
     }
@@ -416,15 +421,27 @@ pub(super) fn run_recover(input: &ReleaseCommandInput) -> Result<(ReleaseCommand
 
     if uncommitted.has_changes {
         homeboy_core::log_status!("recover", "Committing uncommitted changes...");
-        let msg = format!("release: v{}", current_version);
+        let msg = commit_message::render(
+            commit_message::template(&component),
+            current_version,
+            &tag_name,
+        );
+        // An explicit --git-identity was already written to the repo config above.
+        let (author_name, author_email) = if input.git_identity.is_none() {
+            (
+                component.git_author_name.clone(),
+                component.git_author_email.clone(),
+            )
+        } else {
+            (None, None)
+        };
         let commit_result = git::commit(
             Some(&input.component_id),
             Some(msg.as_str()),
             git::CommitOptions {
-                staged_only: false,
-                files: None,
-                exclude: None,
-                amend: false,
+                author_name,
+                author_email,
+                ..Default::default()
             },
         )?;
         if !commit_result.success {
@@ -767,7 +784,7 @@ fn latest_release_tag(release_scope: &ReleaseScope) -> Option<String> {
 /// right move (re-commit the working tree), but the operator deserves to
 /// know they're recovering on top of a misplaced tag before they push more
 /// state to origin.
-pub(super) fn diagnose_orphan_tag(local_path: &str, tag: &str) -> Option<String> {
+pub(super) fn diagnose_orphan_tag(local_path: &str, tag: &str, template: &str) -> Option<String> {
     let tag_commit = git::get_tag_commit(local_path, tag).ok()?;
     let subject_output =
         git::execute_git_for_release(local_path, &["log", "-1", "--format=%s", &tag_commit])
@@ -779,7 +796,10 @@ pub(super) fn diagnose_orphan_tag(local_path: &str, tag: &str) -> Option<String>
         .trim()
         .to_string();
 
-    if subject.starts_with("release: v") || subject.starts_with("release:v") {
+    if commit_message::matches(template, &subject)
+        || subject.starts_with("release: v")
+        || subject.starts_with("release:v")
+    {
         return None;
     }
 
@@ -30,7 +30,10 @@ fn api_mutating_commands_require_apply() {
             apply: false,
         },
     ] {
-        let err = require_apply_for_mutation(&ApiArgs { command })
+        let err = require_apply_for_mutation(&ApiArgs {
+            debug: false,
+            command,
+        })
             .expect_err("mutating API command should require --apply");
 
         assert!(err.message.contains("requires explicit --apply"));
@@ -42,6 +45,7 @@ fn api_mutating_commands_require_apply() {
 #[test]
 fn api_get_and_applied_mutations_pass_apply_guard() {
     require_apply_for_mutation(&ApiArgs {
+        debug: false,
         command: ApiCommand::Get {
             project_id: "site".to_string(),
             endpoint: "/wp/v2/posts".to_string(),
@@ -50,6 +54,7 @@ fn api_get_and_applied_mutations_pass_apply_guard() {
     .expect("GET should not require --apply");
 
     require_apply_for_mutation(&ApiArgs {
+        debug: false,
         command: ApiCommand::Post {
             project_id: "site".to_string(),
             endpoint: "/wp/v2/posts".to_string(),
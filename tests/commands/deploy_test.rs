@@ -1,4 +1,4 @@
-use super::{resolve_multi_args, run, DeployArgs};
+use super::{build_config, resolve_multi_args, run, DeployArgs, DeployTransportArg};
 use crate::cli_surface::{Cli, Commands};
 use crate::commands::GlobalArgs;
 use clap::Parser;
@@ -121,6 +121,136 @@ fn deploy_parser_accepts_release_set_manifest() {
     assert_eq!(args.release_set.as_deref(), Some("release-set.json"));
 }
 
+#[test]
+fn deploy_parser_accepts_wait_healthy_policy() {
+    let cli = Cli::try_parse_from([
+        "homeboy",
+        "deploy",
+        "project-a",
+        "component-a",
+        "--wait-healthy",
+        "--health-interval",
+        "2",
+        "--health-timeout",
+        "30",
+    ])
+    .expect("--wait-healthy should parse");
+
+    let Commands::Deploy(args) = cli.command else {
+        panic!("expected deploy command");
+    };
+    assert!(args.wait_healthy);
+    assert_eq!((args.health_interval, args.health_timeout), (2, 30));
+
+    assert!(
+        Cli::try_parse_from(["homeboy", "deploy", "project-a", "--health-timeout", "30"]).is_err(),
+        "--health-timeout should require --wait-healthy"
+    );
+}
+
+#[test]
+fn deploy_compress_flag_reaches_deploy_config() {
+    let cli = Cli::try_parse_from(["homeboy", "deploy", "project-a", "component-a", "--compress"])
+        .expect("--compress should parse");
+
+    let Commands::Deploy(args) = cli.command else {
+        panic!("expected deploy command");
+    };
+    assert!(args.compress);
+    assert!(build_config(&args, false).compress);
+    assert!(!build_config(&deploy_args(|_| {}), false).compress);
+}
+
+#[test]
+fn deploy_transport_defaults_to_rsync_and_accepts_scp() {
+    let cli = Cli::try_parse_from(["homeboy", "deploy", "project-a", "--transport", "scp"])
+        .expect("--transport scp should parse");
+
+    let Commands::Deploy(args) = cli.command else {
+        panic!("expected deploy command");
+    };
+    assert_eq!(
+        build_config(&args, false).transport,
+        homeboy_release::deploy::DeployTransport::Scp
+    );
+    assert_eq!(
+        build_config(&deploy_args(|_| {}), false).transport,
+        homeboy_release::deploy::DeployTransport::Rsync
+    );
+    assert!(Cli::try_parse_from(["homeboy", "deploy", "project-a", "--transport", "ftp"]).is_err());
+}
+
+#[test]
+fn deploy_verifies_archive_checksum_unless_opted_out() {
+    assert!(build_config(&deploy_args(|_| {}), false).verify_checksum);
+
+    let cli = Cli::try_parse_from(["homeboy", "deploy", "project-a", "--no-verify-checksum"])
+        .expect("--no-verify-checksum should parse");
+    let Commands::Deploy(args) = cli.command else {
+        panic!("expected deploy command");
+    };
+    assert!(!build_config(&args, false).verify_checksum);
+}
+
+#[test]
+fn deploy_jobs_defaults_to_sequential_and_rejects_zero() {
+    assert_eq!(build_config(&deploy_args(|_| {}), false).jobs, 1);
+
+    let cli = Cli::try_parse_from(["homeboy", "deploy", "project-a", "--all", "--jobs", "4"])
+        .expect("--jobs should parse");
+    let Commands::Deploy(args) = cli.command else {
+        panic!("expected deploy command");
+    };
+    assert_eq!(build_config(&args, false).jobs, 4);
+
+    assert!(Cli::try_parse_from(["homeboy", "deploy", "project-a", "--jobs", "0"]).is_err());
+}
+
+#[test]
+fn deploy_rollback_parses_and_conflicts_with_preview_modes() {
+    let cli = Cli::try_parse_from(["homeboy", "deploy", "project-a", "component-a", "--rollback"])
+        .expect("--rollback should parse");
+    let Commands::Deploy(args) = cli.command else {
+        panic!("expected deploy command");
+    };
+    assert!(args.rollback);
+    assert_eq!(build_config(&args, false).keep_backups, 0);
+
+    assert!(Cli::try_parse_from([
+        "homeboy",
+        "deploy",
+        "project-a",
+        "component-a",
+        "--rollback",
+        "--dry-run"
+    ])
+    .is_err());
+
+    let cli = Cli::try_parse_from(["homeboy", "deploy", "project-a", "--keep-backups", "0"])
+        .expect("--keep-backups should parse");
+    let Commands::Deploy(args) = cli.command else {
+        panic!("expected deploy command");
+    };
+    assert_eq!(build_config(&args, false).keep_backups, 0);
+}
+
+#[test]
+fn deploy_diff_rejects_preview_and_multi_target_modes() {
+    for conflicting in [
+        vec!["--dry-run"],
+        vec!["--check"],
+        vec!["--projects", "project-b"],
+        vec!["--fleet", "fleet-a"],
+    ] {
+        let mut argv = vec!["homeboy", "deploy", "project-a", "component-a", "--diff"];
+        argv.extend(conflicting.iter().copied());
+        assert!(
+            Cli::try_parse_from(argv).is_err(),
+            "--diff should conflict with {conflicting:?}"
+        );
+    }
+}
+
 #[test]
 fn release_set_rejects_conflicting_source_selectors() {
     for conflicting in [vec!["--head"], vec!["--tagged"], vec!["--outdated"]] {
@@ -395,6 +525,7 @@ fn deploy_args(mut customize: impl FnMut(&mut DeployArgs)) -> DeployArgs {
         dry_run: false,
         apply: false,
         check: false,
+        diff: false,
         force: false,
         projects: None,
         fleet: None,
@@ -409,6 +540,15 @@ fn deploy_args(mut customize: impl FnMut(&mut DeployArgs)) -> DeployArgs {
         requested_ref: None,
         tagged: false,
         resume: None,
+        wait_healthy: false,
+        health_interval: 5,
+        health_timeout: 20,
+        compress: false,
+        transport: DeployTransportArg::Rsync,
+        no_verify_checksum: false,
+        jobs: 1,
+        keep_backups: 3,
+        rollback: false,
         exact_refs: BTreeMap::new(),
         resolved_refs: BTreeMap::new(),
         preflighted_source_paths: BTreeMap::new(),
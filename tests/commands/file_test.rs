@@ -38,6 +38,9 @@ fn file_read_json_includes_size_metadata() {
                     project_id: project_id.to_string(),
                     path: "sample.txt".to_string(),
                     raw: false,
+                    head: None,
+                    tail: None,
+                    max_bytes: None,
                 },
             },
             &GlobalArgs {},
@@ -59,6 +62,7 @@ fn file_read_json_includes_size_metadata() {
     assert_eq!(payload.path.as_deref(), Some(expected_path.as_str()));
     assert_eq!(payload.content.as_deref(), Some(content));
     assert_eq!(payload.size, Some(content.len() as i64));
+    assert_eq!(payload.truncated, Some(false));
 }
 
 #[test]
@@ -113,6 +117,7 @@ fn file_mkdir_without_apply_returns_plan_and_preserves_filesystem() {
                 command: FileCommand::Mkdir {
                     project_id: project_id.to_string(),
                     path: "new-dir".to_string(),
+                    parents: false,
                     apply: false,
                 },
             },
@@ -135,6 +140,54 @@ fn file_mkdir_without_apply_returns_plan_and_preserves_filesystem() {
     assert!(!dir_path.exists());
 }
 
+#[test]
+fn file_mkdir_parents_and_touch_apply_against_local_project() {
+    let project_root = tempfile::tempdir().expect("project tempdir");
+    let project_id = "local-file-mkdir-touch";
+
+    let (strict, nested, touched) = with_isolated_home(|home| {
+        write_project_config(home.path(), project_id, project_root.path());
+        let mkdir = |parents| {
+            run(
+                FileArgs {
+                    command: FileCommand::Mkdir {
+                        project_id: project_id.to_string(),
+                        path: "a/b/c".to_string(),
+                        parents,
+                        apply: true,
+                    },
+                },
+                &GlobalArgs {},
+            )
+        };
+        let strict = mkdir(false);
+        let nested = mkdir(true);
+        let touched = run(
+            FileArgs {
+                command: FileCommand::Touch {
+                    project_id: project_id.to_string(),
+                    path: "a/b/c/.keep".to_string(),
+                    apply: true,
+                },
+            },
+            &GlobalArgs {},
+        );
+        (strict, nested, touched)
+    });
+
+    let error = strict
+        .err()
+        .expect("mkdir without --parents fails on missing parent");
+    assert!(error.details.to_string().contains("MKDIR_FAILED"));
+    nested.expect("mkdir --parents creates nested directories");
+    let (output, _) = touched.expect("touch creates the file");
+    let FileCommandOutput::Standard(payload) = output else {
+        panic!("expected standard file output");
+    };
+    assert_eq!(payload.command, "file.touch");
+    assert!(project_root.path().join("a/b/c/.keep").is_file());
+}
+
 #[test]
 fn file_rename_without_apply_returns_plan_and_preserves_filesystem() {
     let project_root = tempfile::tempdir().expect("project tempdir");
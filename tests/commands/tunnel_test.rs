@@ -19,6 +19,7 @@ fn create_server() {
         auth: None,
         env: HashMap::new(),
         runner: None,
+        remote_temp_dir: None,
     })
     .expect("save server");
 }